@@ -0,0 +1,197 @@
+use super::{BreakpointHit, DebugBackend, RunOutcome};
+use crate::{
+    error::{Error, Result},
+    ContractId, Transaction,
+};
+use fuel_types::BlockHeight;
+use fuel_vm::{
+    checked_transaction::IntoChecked,
+    interpreter::{Interpreter, NotSupportedEcal},
+    prelude::{Breakpoint, ConsensusParameters, Script, Word},
+    state::{DebugEval, ProgramState},
+    storage::MemoryStorage,
+};
+
+/// The block height at which transactions are executed by default.
+const DEFAULT_BLOCK_HEIGHT: u32 = 0;
+
+/// Debugs transactions on an in-process [Interpreter], without the need for a fuel-core node.
+///
+/// Only script transactions may be executed.  As when debugging on a node, their signatures and
+/// predicates aren't verified.
+pub struct InterpreterBackend {
+    /// The storage the interpreter starts from, and is restored to when reset.
+    storage: MemoryStorage,
+    consensus_params: ConsensusParameters,
+    block_height: BlockHeight,
+    interpreter: Interpreter<MemoryStorage, Script, NotSupportedEcal>,
+}
+
+impl Default for InterpreterBackend {
+    fn default() -> Self {
+        Self::new(
+            MemoryStorage::default(),
+            ConsensusParameters::default(),
+            DEFAULT_BLOCK_HEIGHT.into(),
+        )
+    }
+}
+
+impl InterpreterBackend {
+    /// Create an interpreter which executes transactions at `block_height`, starting from
+    /// `storage`, e.g., containing the contracts called by the transactions.
+    pub fn new(
+        storage: MemoryStorage,
+        consensus_params: ConsensusParameters,
+        block_height: BlockHeight,
+    ) -> Self {
+        let interpreter = Interpreter::with_storage(storage.clone(), (&consensus_params).into());
+        InterpreterBackend {
+            storage,
+            consensus_params,
+            block_height,
+            interpreter,
+        }
+    }
+
+    fn outcome(&self, state: ProgramState) -> RunOutcome {
+        let breakpoint = match state {
+            ProgramState::RunProgram(DebugEval::Breakpoint(breakpoint)) => Some(BreakpointHit {
+                contract: *breakpoint.contract(),
+                pc: breakpoint.pc(),
+            }),
+            _ => None,
+        };
+        RunOutcome {
+            breakpoint,
+            receipts: self.interpreter.receipts().to_vec(),
+        }
+    }
+}
+
+impl DebugBackend for InterpreterBackend {
+    fn start_tx(&mut self, tx: &Transaction) -> Result<RunOutcome> {
+        let Transaction::Script(script) = tx else {
+            return Err(Error::InvalidTransaction(
+                "only script transactions can be executed".to_string(),
+            ));
+        };
+        let checked = script
+            .clone()
+            .into_checked_basic(self.block_height, &self.consensus_params)
+            .map_err(|err| Error::InvalidTransaction(format!("{err:?}")))?;
+        let state = *self
+            .interpreter
+            .transact(checked)
+            .map_err(|err| Error::Interpreter(err.to_string()))?
+            .state();
+        Ok(self.outcome(state))
+    }
+
+    fn continue_tx(&mut self) -> Result<RunOutcome> {
+        let state = self
+            .interpreter
+            .resume()
+            .map_err(|err| Error::Interpreter(err.to_string()))?;
+        Ok(self.outcome(state))
+    }
+
+    fn reset(&mut self) -> Result<()> {
+        self.interpreter =
+            Interpreter::with_storage(self.storage.clone(), (&self.consensus_params).into());
+        Ok(())
+    }
+
+    fn set_breakpoint(&mut self, contract: ContractId, instruction: u64) -> Result<()> {
+        self.interpreter
+            .set_breakpoint(Breakpoint::new(contract, instruction));
+        Ok(())
+    }
+
+    fn remove_breakpoint(&mut self, contract: ContractId, instruction: u64) -> Result<()> {
+        self.interpreter
+            .remove_breakpoint(&Breakpoint::new(contract, instruction));
+        Ok(())
+    }
+
+    fn set_single_stepping(&mut self, enable: bool) -> Result<()> {
+        self.interpreter.set_single_stepping(enable);
+        Ok(())
+    }
+
+    fn register(&mut self, index: usize) -> Result<Word> {
+        self.interpreter
+            .registers()
+            .get(index)
+            .copied()
+            .ok_or(Error::InvalidRegister(index))
+    }
+
+    fn memory(&mut self, start: usize, size: usize) -> Result<Vec<u8>> {
+        let memory = self.interpreter.memory();
+        let end = start.saturating_add(size).min(memory.len());
+        Ok(memory.get(start..end).unwrap_or_default().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_tx() -> Transaction {
+        serde_json::from_str(include_str!(
+            "../../../forc-plugins/forc-debug/examples/example_tx.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_breakpoint_and_single_stepping() {
+        let mut backend = InterpreterBackend::default();
+        backend.set_breakpoint(ContractId::zeroed(), 0).unwrap();
+
+        let outcome = backend.start_tx(&example_tx()).unwrap();
+        assert_eq!(
+            outcome.breakpoint,
+            Some(BreakpointHit {
+                contract: ContractId::zeroed(),
+                pc: 0
+            })
+        );
+
+        // The first instruction jumps over the data section offset.
+        backend.set_single_stepping(true).unwrap();
+        let outcome = backend.continue_tx().unwrap();
+        assert_eq!(outcome.breakpoint.map(|hit| hit.pc), Some(16));
+
+        backend.set_single_stepping(false).unwrap();
+        let outcome = backend.continue_tx().unwrap();
+        assert!(outcome.breakpoint.is_none());
+        assert!(outcome
+            .receipts
+            .iter()
+            .any(|receipt| matches!(receipt, fuel_vm::prelude::Receipt::Return { .. })));
+    }
+
+    #[test]
+    fn test_remove_breakpoint() {
+        let mut backend = InterpreterBackend::default();
+        backend.set_breakpoint(ContractId::zeroed(), 0).unwrap();
+        backend.set_breakpoint(ContractId::zeroed(), 14).unwrap();
+        backend.remove_breakpoint(ContractId::zeroed(), 0).unwrap();
+
+        // Only the breakpoint on the `MUL` within the loop remains.
+        let outcome = backend.start_tx(&example_tx()).unwrap();
+        assert_eq!(outcome.breakpoint.map(|hit| hit.pc), Some(56));
+    }
+
+    #[test]
+    fn test_reset_removes_breakpoints() {
+        let mut backend = InterpreterBackend::default();
+        backend.set_breakpoint(ContractId::zeroed(), 0).unwrap();
+        backend.reset().unwrap();
+
+        let outcome = backend.start_tx(&example_tx()).unwrap();
+        assert!(outcome.breakpoint.is_none());
+    }
+}
@@ -0,0 +1,65 @@
+//! The VMs which transactions can be debugged on.
+//!
+//! A [DebugBackend] either drives a fuel-core node through its GraphQL debugging API, or runs an
+//! in-process [fuel_vm] interpreter, allowing transactions and unit tests to be debugged offline.
+
+mod interpreter;
+#[cfg(feature = "node")]
+mod node;
+
+pub use interpreter::InterpreterBackend;
+#[cfg(feature = "node")]
+pub use node::NodeBackend;
+
+use crate::{error::Result, ContractId, Transaction};
+use fuel_vm::prelude::{Receipt, Word};
+
+/// A VM location at which execution was suspended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BreakpointHit {
+    /// The contract being executed, which is zeroed for scripts.
+    pub contract: ContractId,
+    /// The offset in bytes of the next instruction to execute, relative to the start of the
+    /// contract or script.
+    pub pc: u64,
+}
+
+/// The outcome of executing a transaction until the VM stops.
+#[derive(Clone, Debug)]
+pub struct RunOutcome {
+    /// Where execution was suspended, or `None` if the transaction completed.
+    pub breakpoint: Option<BreakpointHit>,
+    /// The receipts emitted so far.
+    pub receipts: Vec<Receipt>,
+}
+
+/// A VM which can execute transactions under the control of a debugger.
+pub trait DebugBackend {
+    /// Start executing `tx`, stopping at the first breakpoint.
+    fn start_tx(&mut self, tx: &Transaction) -> Result<RunOutcome>;
+
+    /// Continue executing the suspended transaction, stopping at the next breakpoint.
+    fn continue_tx(&mut self) -> Result<RunOutcome>;
+
+    /// Reset the VM, removing all breakpoints and any executed transaction.
+    fn reset(&mut self) -> Result<()>;
+
+    /// Set a breakpoint at the instruction index `instruction` of `contract`, or of the script if
+    /// `contract` is zeroed.
+    fn set_breakpoint(&mut self, contract: ContractId, instruction: u64) -> Result<()>;
+
+    /// Remove the breakpoint at the instruction index `instruction` of `contract`.
+    ///
+    /// Backends which can't remove individual breakpoints leave it set, so callers must be
+    /// prepared to be stopped at it again.
+    fn remove_breakpoint(&mut self, contract: ContractId, instruction: u64) -> Result<()>;
+
+    /// Turn single-stepping, which stops execution after every instruction, on or off.
+    fn set_single_stepping(&mut self, enable: bool) -> Result<()>;
+
+    /// Read the register at `index`.
+    fn register(&mut self, index: usize) -> Result<Word>;
+
+    /// Read `size` bytes of VM memory, starting at `start`.
+    fn memory(&mut self, start: usize, size: usize) -> Result<Vec<u8>>;
+}
@@ -0,0 +1,106 @@
+use super::{BreakpointHit, DebugBackend, RunOutcome};
+use crate::{
+    error::{Error, Result},
+    ContractId, FuelClient, RunResult, Transaction,
+};
+use fuel_vm::prelude::Word;
+
+/// Debugs transactions on a fuel-core node, started with `--debug`, through its GraphQL API.
+pub struct NodeBackend {
+    runtime: tokio::runtime::Runtime,
+    client: FuelClient,
+    session_id: String,
+}
+
+impl NodeBackend {
+    /// Connect to the GraphQL API at `api_url` and start a debugging session.
+    pub fn new(api_url: &str) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let client = FuelClient::new(api_url).map_err(|err| Error::Client(err.to_string()))?;
+        let session_id = runtime.block_on(client.start_session())?;
+        Ok(NodeBackend {
+            runtime,
+            client,
+            session_id,
+        })
+    }
+}
+
+impl Drop for NodeBackend {
+    fn drop(&mut self) {
+        let _ = self
+            .runtime
+            .block_on(self.client.end_session(&self.session_id));
+    }
+}
+
+impl From<RunResult> for RunOutcome {
+    fn from(result: RunResult) -> Self {
+        RunOutcome {
+            breakpoint: result.breakpoint.as_ref().map(|breakpoint| BreakpointHit {
+                contract: breakpoint.contract.clone().into(),
+                pc: breakpoint.pc.0,
+            }),
+            receipts: result.receipts().collect(),
+        }
+    }
+}
+
+impl DebugBackend for NodeBackend {
+    fn start_tx(&mut self, tx: &Transaction) -> Result<RunOutcome> {
+        let result = self
+            .runtime
+            .block_on(self.client.start_tx(&self.session_id, tx))?;
+        Ok(result.into())
+    }
+
+    fn continue_tx(&mut self) -> Result<RunOutcome> {
+        let result = self
+            .runtime
+            .block_on(self.client.continue_tx(&self.session_id))?;
+        Ok(result.into())
+    }
+
+    fn reset(&mut self) -> Result<()> {
+        self.runtime.block_on(self.client.reset(&self.session_id))?;
+        Ok(())
+    }
+
+    fn set_breakpoint(&mut self, contract: ContractId, instruction: u64) -> Result<()> {
+        self.runtime.block_on(self.client.set_breakpoint(
+            &self.session_id,
+            contract,
+            instruction,
+        ))?;
+        Ok(())
+    }
+
+    fn remove_breakpoint(&mut self, _contract: ContractId, _instruction: u64) -> Result<()> {
+        // The GraphQL API can only remove breakpoints by resetting the VM.
+        Ok(())
+    }
+
+    fn set_single_stepping(&mut self, enable: bool) -> Result<()> {
+        self.runtime
+            .block_on(self.client.set_single_stepping(&self.session_id, enable))?;
+        Ok(())
+    }
+
+    fn register(&mut self, index: usize) -> Result<Word> {
+        let value = self
+            .runtime
+            .block_on(self.client.register(&self.session_id, index as u32))?;
+        Ok(value)
+    }
+
+    fn memory(&mut self, start: usize, size: usize) -> Result<Vec<u8>> {
+        let memory = self.runtime.block_on(self.client.memory(
+            &self.session_id,
+            start as u32,
+            size as u32,
+        ))?;
+        Ok(memory)
+    }
+}
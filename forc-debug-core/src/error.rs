@@ -0,0 +1,15 @@
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Failed to connect to fuel-core: {0}")]
+    Client(String),
+    #[error("Invalid transaction: {0}")]
+    InvalidTransaction(String),
+    #[error("Interpreter error: {0}")]
+    Interpreter(String),
+    #[error("Invalid register index {0}")]
+    InvalidRegister(usize),
+}
@@ -0,0 +1,10 @@
+pub mod backend;
+pub mod error;
+pub mod profiler;
+pub mod source_locations;
+pub mod variables;
+
+// Re-exports
+#[cfg(feature = "node")]
+pub use fuel_core_client::client::{schema::RunResult, FuelClient};
+pub use fuel_vm::prelude::{ContractId, Transaction};
@@ -0,0 +1,286 @@
+//! Attributing the gas used by a transaction to the functions and source lines which use it.
+//!
+//! The transaction is executed one instruction at a time, and the gas used by each instruction,
+//! i.e., the decrease of the `$ggas` register, is charged to the source line of the instruction and
+//! to the stack of functions executing it.  The stacks are reconstructed from the ranges of the
+//! functions in the source map: reaching the first instruction of a function is a call, and
+//! reaching an instruction of a function lower in the stack is a return to it.
+
+use crate::{
+    backend::{BreakpointHit, DebugBackend},
+    error::Result,
+    source_locations::SourceLocations,
+    ContractId, Transaction,
+};
+use fuel_vm::{
+    fuel_asm::RegId,
+    prelude::{Instruction, Receipt, Word},
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+/// The gas used by a transaction, per stack of functions and per source line.
+#[derive(Clone, Debug, Default)]
+pub struct GasProfile {
+    /// The gas used by each stack of frames, outermost first.
+    stacks: BTreeMap<Vec<String>, u64>,
+    /// The gas used by each line of the script, keyed by file and line.
+    lines: BTreeMap<(PathBuf, i64), u64>,
+}
+
+impl GasProfile {
+    /// The conventional name of a file of folded stacks, as written by [GasProfile::to_folded].
+    pub const FOLDED_FILE_NAME: &'static str = "gas.folded";
+
+    /// The total gas used.
+    pub fn total(&self) -> u64 {
+        self.stacks.values().sum()
+    }
+
+    /// The gas used by each stack in the folded format read by flame graph tools, such as
+    /// `flamegraph.pl` and `inferno-flamegraph`: a line per stack, of its frames separated by `;`
+    /// followed by the gas used.
+    pub fn to_folded(&self) -> String {
+        let mut folded = String::new();
+        for (stack, gas) in &self.stacks {
+            let _ = writeln!(folded, "{} {gas}", stack.join(";"));
+        }
+        folded
+    }
+
+    /// The gas used by each line of the script, in decreasing order of gas.
+    pub fn lines(&self) -> Vec<(&Path, i64, u64)> {
+        let mut lines = self
+            .lines
+            .iter()
+            .map(|((path, line), gas)| (path.as_path(), *line, *gas))
+            .collect::<Vec<_>>();
+        lines.sort_by_key(|(_, _, gas)| std::cmp::Reverse(*gas));
+        lines
+    }
+
+    /// Add the gas used in `other`, e.g., by another transaction executing the same program.
+    pub fn merge(&mut self, other: &GasProfile) {
+        for (stack, gas) in &other.stacks {
+            *self.stacks.entry(stack.clone()).or_default() += gas;
+        }
+        for (line, gas) in &other.lines {
+            *self.lines.entry(line.clone()).or_default() += gas;
+        }
+    }
+}
+
+/// A function being executed, identified by the contract and the index of its first instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Frame {
+    contract: ContractId,
+    start: Option<usize>,
+    name: String,
+}
+
+/// Builds a [GasProfile] from the instructions executed by a transaction, as they are executed.
+///
+/// Only the functions and lines of the script are known, any contract it calls is a single frame.
+pub struct GasProfiler<'a> {
+    source_locations: &'a SourceLocations,
+    /// The frames of the stack, the outermost being the root frame named after the program.
+    frames: Vec<Frame>,
+    /// The names of the frames, as charged in the profile.
+    stack: Vec<String>,
+    /// The line of the last instruction reached, whose gas is charged once the next is reached.
+    line: Option<(PathBuf, i64)>,
+    /// The gas remaining before executing the last instruction reached.
+    remaining_gas: Option<Word>,
+    profile: GasProfile,
+}
+
+impl<'a> GasProfiler<'a> {
+    /// Start profiling a transaction executing the script named `root`, whose instructions are at
+    /// `source_locations`.
+    pub fn new(root: &str, source_locations: &'a SourceLocations) -> Self {
+        GasProfiler {
+            source_locations,
+            frames: vec![Frame {
+                contract: ContractId::zeroed(),
+                start: None,
+                name: root.to_string(),
+            }],
+            stack: vec![root.to_string()],
+            line: None,
+            remaining_gas: None,
+            profile: GasProfile::default(),
+        }
+    }
+
+    /// Record reaching the instruction at `location`, before it is executed, with `remaining_gas`
+    /// in the `$ggas` register.
+    pub fn step(&mut self, location: BreakpointHit, remaining_gas: Word) {
+        self.charge(remaining_gas);
+        self.enter(location);
+        self.remaining_gas = Some(remaining_gas);
+    }
+
+    /// Finish profiling once the transaction completes, with `remaining_gas` in the `$ggas`
+    /// register if it's known, and return the profile.
+    pub fn finish(mut self, remaining_gas: Option<Word>) -> GasProfile {
+        if let Some(remaining_gas) = remaining_gas {
+            self.charge(remaining_gas);
+        }
+        self.profile
+    }
+
+    /// Charge the gas used by the last instruction reached.
+    fn charge(&mut self, remaining_gas: Word) {
+        let Some(previous) = self.remaining_gas else {
+            return;
+        };
+        let gas = previous.saturating_sub(remaining_gas);
+        match self.profile.stacks.get_mut(&self.stack) {
+            Some(total) => *total += gas,
+            None => {
+                self.profile.stacks.insert(self.stack.clone(), gas);
+            }
+        }
+        if let Some(line) = self.line.take() {
+            *self.profile.lines.entry(line).or_default() += gas;
+        }
+    }
+
+    /// Update the stack of frames on reaching the instruction at `location`.
+    fn enter(&mut self, location: BreakpointHit) {
+        let instruction = location.pc as usize / Instruction::SIZE;
+        self.line = (location.contract == ContractId::zeroed())
+            .then(|| self.source_locations.instruction_to_location(instruction))
+            .flatten()
+            .map(|(path, line_col)| (path.to_path_buf(), line_col.line));
+        if self.update_frames(location) {
+            self.stack = self.frames.iter().map(|frame| frame.name.clone()).collect();
+        }
+    }
+
+    /// Update the frames on reaching the instruction at `location`, returning whether they changed.
+    fn update_frames(&mut self, location: BreakpointHit) -> bool {
+        let instruction = location.pc as usize / Instruction::SIZE;
+        let is_script = location.contract == ContractId::zeroed();
+        let function = is_script
+            .then(|| self.source_locations.function_at(instruction))
+            .flatten();
+        let frame = match function {
+            Some(function) => Frame {
+                contract: location.contract,
+                start: Some(function.start_pc),
+                name: function.name.clone(),
+            },
+            None => Frame {
+                contract: location.contract,
+                start: None,
+                name: format!("contract {:#x}", location.contract),
+            },
+        };
+        let top = self.frames.last().expect("the root frame is never popped");
+        if top.contract != location.contract {
+            match self
+                .frames
+                .iter()
+                .rposition(|frame| frame.contract == location.contract)
+            {
+                // Returned from a contract call.
+                Some(idx) => self.frames.truncate(idx + 1),
+                // Called a contract.
+                None => {
+                    self.frames.push(frame);
+                    return true;
+                }
+            }
+        }
+
+        // Instructions outside of any function, e.g., those setting up the program, are charged
+        // to the current frame.
+        let Some(start) = frame.start else {
+            return true;
+        };
+        let top = self.frames.last().expect("the root frame is never popped");
+        if start == instruction {
+            // Called a function, possibly recursively.
+            self.frames.push(frame);
+        } else if *top != frame {
+            match self.frames.iter().rposition(|other| *other == frame) {
+                // Returned to a calling function.
+                Some(idx) => self.frames.truncate(idx + 1),
+                // Jumped to another function without calling it, e.g., from the program setup.
+                None if self.frames.len() > 1 => {
+                    self.frames.pop();
+                    self.frames.push(frame);
+                }
+                None => self.frames.push(frame),
+            }
+        } else {
+            return false;
+        }
+        true
+    }
+}
+
+/// Execute `tx` on `backend` one instruction at a time, profiling its gas with `profiler`.
+///
+/// Returns the profile, and the receipts of the transaction.
+pub fn profile_tx(
+    backend: &mut dyn DebugBackend,
+    tx: &Transaction,
+    mut profiler: GasProfiler,
+) -> Result<(GasProfile, Vec<Receipt>)> {
+    backend.set_single_stepping(true)?;
+    let mut outcome = backend.start_tx(tx)?;
+    while let Some(location) = outcome.breakpoint {
+        profiler.step(location, backend.register(RegId::GGAS.into())?);
+        outcome = backend.continue_tx()?;
+    }
+    let profile = profiler.finish(backend.register(RegId::GGAS.into()).ok());
+    Ok((profile, outcome.receipts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sway_core::source_map::SourceMap;
+
+    fn step(profiler: &mut GasProfiler, instruction: u64, remaining_gas: Word) {
+        let location = BreakpointHit {
+            contract: ContractId::zeroed(),
+            pc: instruction * Instruction::SIZE as u64,
+        };
+        profiler.step(location, remaining_gas);
+    }
+
+    #[test]
+    fn test_gas_per_instruction() {
+        let source_locations = SourceLocations::new(&SourceMap::new());
+        let mut profiler = GasProfiler::new("script", &source_locations);
+        step(&mut profiler, 0, 100);
+        step(&mut profiler, 1, 98);
+        step(&mut profiler, 2, 97);
+        let profile = profiler.finish(Some(90));
+        assert_eq!(profile.total(), 10);
+        assert_eq!(profile.to_folded(), "script 10\n");
+    }
+
+    #[test]
+    fn test_contract_call_frames() {
+        let source_locations = SourceLocations::new(&SourceMap::new());
+        let mut profiler = GasProfiler::new("script", &source_locations);
+        step(&mut profiler, 0, 100);
+        let contract = ContractId::from([1; 32]);
+        profiler.step(BreakpointHit { contract, pc: 0 }, 90);
+        profiler.step(BreakpointHit { contract, pc: 4 }, 85);
+        step(&mut profiler, 1, 80);
+        let profile = profiler.finish(Some(79));
+        let contract_frame = format!("script;contract {contract:#x}");
+        assert_eq!(
+            profile.to_folded(),
+            format!("script 11\n{contract_frame} 10\n")
+        );
+    }
+}
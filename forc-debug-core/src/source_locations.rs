@@ -0,0 +1,135 @@
+//! Mapping between instructions and the source lines they were compiled from.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+use sway_core::source_map::{DebugFunction, DebugVariable, SourceMap};
+
+/// A 1-based line and column in a source file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineCol {
+    pub line: i64,
+    pub column: i64,
+}
+
+/// The byte offsets at which each line of a source file starts.
+#[derive(Debug, Default)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+        LineIndex { line_starts }
+    }
+
+    /// The line and column of the byte `offset`.
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        LineCol {
+            line: line as i64 + 1,
+            column: (offset - self.line_starts[line]) as i64 + 1,
+        }
+    }
+}
+
+/// The source locations of the instructions of the script being debugged.
+#[derive(Debug, Default)]
+pub struct SourceLocations {
+    /// The source file and position of each mapped instruction, keyed by instruction index.
+    locations: BTreeMap<usize, (PathBuf, LineCol)>,
+    /// The first instruction index of each source line, keyed by file.
+    line_to_instruction: HashMap<PathBuf, BTreeMap<i64, usize>>,
+    /// The source map, which also describes where variables are.
+    source_map: SourceMap,
+}
+
+impl SourceLocations {
+    pub fn new(source_map: &SourceMap) -> Self {
+        let mut line_indices = HashMap::<PathBuf, Option<LineIndex>>::new();
+        let mut source_locations = SourceLocations {
+            source_map: source_map.clone(),
+            ..Default::default()
+        };
+        for (instruction, path, range) in source_map.iter() {
+            let path = canonicalize(&path);
+            let line_index = line_indices.entry(path.clone()).or_insert_with(|| {
+                std::fs::read_to_string(&path)
+                    .ok()
+                    .map(|text| LineIndex::new(&text))
+            });
+            let Some(line_index) = line_index else {
+                continue;
+            };
+            let line_col = line_index.line_col(range.start);
+            // Instructions are visited in ascending order, so the first one mapped to a line wins.
+            source_locations
+                .line_to_instruction
+                .entry(path.clone())
+                .or_default()
+                .entry(line_col.line)
+                .or_insert(instruction);
+            source_locations
+                .locations
+                .insert(instruction, (path, line_col));
+        }
+        source_locations
+    }
+
+    /// The first instruction index mapped to `line` of the file at `path`.
+    pub fn line_to_instruction(&self, path: &Path, line: i64) -> Option<usize> {
+        self.line_to_instruction
+            .get(&canonicalize(path))?
+            .get(&line)
+            .copied()
+    }
+
+    /// The source location of the instruction at `instruction`, or of the closest preceding
+    /// instruction which has one.
+    pub fn instruction_to_location(&self, instruction: usize) -> Option<(&Path, LineCol)> {
+        self.locations
+            .range(..=instruction)
+            .next_back()
+            .map(|(_, (path, line_col))| (path.as_path(), *line_col))
+    }
+
+    /// The variables in scope at `instruction`, whose locations are known there.
+    pub fn variables_at(&self, instruction: usize) -> Vec<&DebugVariable> {
+        self.source_map.variables_at(instruction)
+    }
+
+    /// The function whose instructions include `instruction`.
+    pub fn function_at(&self, instruction: usize) -> Option<&DebugFunction> {
+        self.source_map.function_at(instruction)
+    }
+
+    /// The source location of the instruction at `instruction`, if it is mapped.
+    pub fn exact_location(&self, instruction: usize) -> Option<(&Path, LineCol)> {
+        self.locations
+            .get(&instruction)
+            .map(|(path, line_col)| (path.as_path(), *line_col))
+    }
+}
+
+/// Canonicalize `path` if it exists, so that paths to the same file compare equal.
+pub fn canonicalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_col() {
+        let index = LineIndex::new("script;\n\nfn main() {\n}\n");
+        assert_eq!(index.line_col(0), LineCol { line: 1, column: 1 });
+        assert_eq!(index.line_col(6), LineCol { line: 1, column: 7 });
+        assert_eq!(index.line_col(8), LineCol { line: 2, column: 1 });
+        assert_eq!(index.line_col(12), LineCol { line: 3, column: 4 });
+    }
+}
@@ -0,0 +1,258 @@
+//! Reading the values of the variables described by the debug info.
+
+use crate::{backend::DebugBackend, error::Result};
+use sway_core::source_map::{
+    DebugType, DebugTypeKind, DebugVariable, VariableKind, VariableLocation,
+};
+
+/// The most bytes of a string slice which are read.
+const MAX_STR_LEN: u64 = 1024;
+
+/// Format `var` and its current value, as it would be declared, e.g., `let balance: u64 = 42`.
+pub fn format_variable(backend: &mut dyn DebugBackend, var: &DebugVariable) -> Result<String> {
+    let value = read_value(backend, var)?;
+    let let_keyword = match var.kind {
+        VariableKind::Argument => "",
+        VariableKind::Local => "let ",
+    };
+    Ok(format!(
+        "{let_keyword}{}: {} = {value}",
+        var.name, var.ty.name
+    ))
+}
+
+/// Read and format the current value of `var`.
+pub fn read_value(backend: &mut dyn DebugBackend, var: &DebugVariable) -> Result<String> {
+    let bytes = match var.location {
+        VariableLocation::Register(reg) => {
+            // Values held in registers fit in a word.
+            let word = backend.register(reg as usize)?.to_be_bytes();
+            let size = (var.ty.size as usize).min(word.len());
+            word[word.len() - size..].to_vec()
+        }
+        VariableLocation::Memory { base, offset } => {
+            let address = backend.register(base as usize)?.saturating_add(offset);
+            backend.memory(address as usize, var.ty.size as usize)?
+        }
+    };
+    format_value(backend, &var.ty, &bytes)
+}
+
+/// Format the value of type `ty` at the start of `bytes`, reading any memory it points to from `backend`.
+pub fn format_value(
+    backend: &mut dyn DebugBackend,
+    ty: &DebugType,
+    bytes: &[u8],
+) -> Result<String> {
+    let Some(bytes) = bytes.get(..ty.size as usize) else {
+        return Ok("<unavailable>".to_string());
+    };
+
+    let value = match &ty.kind {
+        DebugTypeKind::Unit => "()".to_string(),
+        DebugTypeKind::Bool => (bytes[0] != 0).to_string(),
+        DebugTypeKind::Uint if bytes.len() <= 8 => word(bytes).to_string(),
+        DebugTypeKind::Uint | DebugTypeKind::B256 | DebugTypeKind::Opaque => hex(bytes),
+        DebugTypeKind::StringArray => format!("{:?}", String::from_utf8_lossy(bytes)),
+        DebugTypeKind::Slice => {
+            let ptr = word(&bytes[..8]);
+            let len = word(&bytes[8..16]);
+            if ty.name == "str" {
+                let string = backend.memory(ptr as usize, len.min(MAX_STR_LEN) as usize)?;
+                format!("{:?}", String::from_utf8_lossy(&string))
+            } else {
+                format!("{} {{ ptr: {ptr:#x}, len: {len} }}", ty.name)
+            }
+        }
+        DebugTypeKind::Struct(fields) => {
+            let values = fields
+                .iter()
+                .map(|field| {
+                    let value = format_value(backend, &field.ty, &bytes[field.offset as usize..])?;
+                    Ok((field.name.as_str(), value))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if ty.name.starts_with('(') {
+                let values = values.into_iter().map(|(_, value)| value);
+                format!("({})", values.collect::<Vec<_>>().join(", "))
+            } else {
+                let fields = values
+                    .into_iter()
+                    .map(|(name, value)| format!("{name}: {value}"))
+                    .collect::<Vec<_>>();
+                format!("{} {{ {} }}", base_name(&ty.name), fields.join(", "))
+            }
+        }
+        DebugTypeKind::Enum(variants) => {
+            let tag = word(&bytes[..8]);
+            match variants.get(tag as usize) {
+                Some(variant) if variant.ty.kind == DebugTypeKind::Unit => {
+                    format!("{}::{}", base_name(&ty.name), variant.name)
+                }
+                Some(variant) => {
+                    let value =
+                        format_value(backend, &variant.ty, &bytes[variant.offset as usize..])?;
+                    format!("{}::{}({value})", base_name(&ty.name), variant.name)
+                }
+                None => format!("{}::<invalid variant {tag}>", base_name(&ty.name)),
+            }
+        }
+        DebugTypeKind::Array { element, length } => {
+            let stride = if *length == 0 { 0 } else { ty.size / length };
+            let elements = (0..*length)
+                .map(|idx| format_value(backend, element, &bytes[(idx * stride) as usize..]))
+                .collect::<Result<Vec<_>>>()?;
+            format!("[{}]", elements.join(", "))
+        }
+    };
+    Ok(value)
+}
+
+/// The big-endian integer in up to eight `bytes`.
+fn word(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .take(8)
+        .fold(0, |word, byte| (word << 8) | u64::from(*byte))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold("0x".to_string(), |mut hex, byte| {
+        hex.push_str(&format!("{byte:02x}"));
+        hex
+    })
+}
+
+/// The name of a type without its type arguments, as used to name its variants.
+fn base_name(name: &str) -> &str {
+    name.split('<').next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::InterpreterBackend, Transaction};
+    use sway_core::source_map::DebugField;
+
+    fn uint(name: &str, size: u64) -> DebugType {
+        DebugType {
+            name: name.to_string(),
+            size,
+            kind: DebugTypeKind::Uint,
+        }
+    }
+
+    fn format(ty: &DebugType, bytes: &[u8]) -> String {
+        format_value(&mut InterpreterBackend::default(), ty, bytes).unwrap()
+    }
+
+    #[test]
+    fn test_format_struct_and_enum() {
+        let point = DebugType {
+            name: "Point".to_string(),
+            size: 9,
+            kind: DebugTypeKind::Struct(vec![
+                DebugField {
+                    name: "x".to_string(),
+                    offset: 0,
+                    ty: uint("u64", 8),
+                },
+                DebugField {
+                    name: "y".to_string(),
+                    offset: 8,
+                    ty: uint("u8", 1),
+                },
+            ]),
+        };
+        let mut bytes = 42u64.to_be_bytes().to_vec();
+        bytes.push(7);
+        assert_eq!(format(&point, &bytes), "Point { x: 42, y: 7 }");
+
+        let option = DebugType {
+            name: "Option<u64>".to_string(),
+            size: 16,
+            kind: DebugTypeKind::Enum(vec![
+                DebugField {
+                    name: "None".to_string(),
+                    offset: 15,
+                    ty: DebugType {
+                        name: "()".to_string(),
+                        size: 1,
+                        kind: DebugTypeKind::Unit,
+                    },
+                },
+                DebugField {
+                    name: "Some".to_string(),
+                    offset: 8,
+                    ty: uint("u64", 8),
+                },
+            ]),
+        };
+        let some = [1u64.to_be_bytes(), 5u64.to_be_bytes()].concat();
+        assert_eq!(format(&option, &some), "Option::Some(5)");
+        assert_eq!(format(&option, &[0; 16]), "Option::None");
+    }
+
+    #[test]
+    fn test_format_array_and_tuple() {
+        let array = DebugType {
+            name: "[u64; 2]".to_string(),
+            size: 16,
+            kind: DebugTypeKind::Array {
+                element: Box::new(uint("u64", 8)),
+                length: 2,
+            },
+        };
+        let bytes = [1u64.to_be_bytes(), 2u64.to_be_bytes()].concat();
+        assert_eq!(format(&array, &bytes), "[1, 2]");
+
+        let tuple = DebugType {
+            name: "(bool, u64)".to_string(),
+            size: 16,
+            kind: DebugTypeKind::Struct(vec![
+                DebugField {
+                    name: "0".to_string(),
+                    offset: 0,
+                    ty: DebugType {
+                        name: "bool".to_string(),
+                        size: 1,
+                        kind: DebugTypeKind::Bool,
+                    },
+                },
+                DebugField {
+                    name: "1".to_string(),
+                    offset: 8,
+                    ty: uint("u64", 8),
+                },
+            ]),
+        };
+        let bytes = [[1, 0, 0, 0, 0, 0, 0, 0], 3u64.to_be_bytes()].concat();
+        assert_eq!(format(&tuple, &bytes), "(true, 3)");
+    }
+
+    #[test]
+    fn test_format_variable_in_register() {
+        let mut backend = InterpreterBackend::default();
+        let tx: Transaction = serde_json::from_str(include_str!(
+            "../../forc-plugins/forc-debug/examples/example_tx.json"
+        ))
+        .unwrap();
+        backend.start_tx(&tx).unwrap();
+
+        // The `$one` register always holds 1.
+        let var = DebugVariable {
+            name: "balance".to_string(),
+            kind: VariableKind::Local,
+            ty: uint("u64", 8),
+            location: VariableLocation::Register(fuel_vm::fuel_asm::RegId::ONE.to_u8()),
+            start_pc: 0,
+            end_pc: 1,
+            decl: None,
+            scope: None,
+        };
+        assert_eq!(
+            format_variable(&mut backend, &var).unwrap(),
+            "let balance: u64 = 1"
+        );
+    }
+}
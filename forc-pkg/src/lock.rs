@@ -36,6 +36,8 @@ pub struct PkgLock {
     version: Option<semver::Version>,
     // Short-hand string describing where this package is sourced from.
     source: String,
+    // The checksum of the package archive, only present for registry packages.
+    checksum: Option<String>,
     dependencies: Option<Vec<PkgDepLine>>,
     contract_dependencies: Option<Vec<PkgDepLine>>,
 }
@@ -61,6 +63,7 @@ impl PkgLock {
         let name = pinned.name.clone();
         let version = pinned.source.semver();
         let source = pinned.source.to_string();
+        let checksum = pinned.source.checksum().map(str::to_string);
         // Collection of all dependencies, so this includes both contract-dependencies and
         // lib-dependencies
         let all_dependencies: Vec<(String, DepKind)> = graph
@@ -119,6 +122,7 @@ impl PkgLock {
             name,
             version,
             source,
+            checksum,
             dependencies,
             contract_dependencies,
         }
@@ -186,9 +190,18 @@ impl Lock {
             // required.
             let key = pkg.name_disambiguated(&disambiguate).into_owned();
             let name = pkg.name.clone();
-            let source: source::Pinned = pkg.source.parse().map_err(|e| {
+            let mut source: source::Pinned = pkg.source.parse().map_err(|e| {
                 anyhow!("invalid 'source' entry for package {} lock: {:?}", name, e)
             })?;
+            // The registry package checksum is recorded separately to the source string.
+            if let source::Pinned::Registry(ref mut reg) = source {
+                reg.checksum = pkg.checksum.clone().ok_or_else(|| {
+                    anyhow!(
+                        "missing 'checksum' entry for registry package {} lock",
+                        name
+                    )
+                })?;
+            }
             let pkg = pkg::Pinned { name, source };
             let node = graph.add_node(pkg);
             pkg_to_node.insert(key, node);
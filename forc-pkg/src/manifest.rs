@@ -206,6 +206,7 @@ pub struct DependencyDetails {
     pub(crate) package: Option<String>,
     pub(crate) rev: Option<String>,
     pub(crate) ipfs: Option<String>,
+    pub(crate) registry: Option<String>,
 }
 
 /// Parameters to pass through to the `sway_core::BuildConfig` during compilation.
@@ -253,18 +254,24 @@ impl DependencyDetails {
     ///
     /// Following dependency details sets are considered to be invalid:
     /// 1. A set of dependency details which declares `branch`, `tag` or `rev` without `git`.
+    /// 2. A set of dependency details which declares `registry` without `version`.
     pub fn validate(&self) -> anyhow::Result<()> {
         let DependencyDetails {
             git,
             branch,
             tag,
             rev,
+            version,
+            registry,
             ..
         } = self;
 
         if git.is_none() && (branch.is_some() || tag.is_some() || rev.is_some()) {
             bail!("Details reserved for git sources used without a git field");
         }
+        if version.is_none() && registry.is_some() {
+            bail!("Details reserved for registry sources used without a version field");
+        }
         Ok(())
     }
 }
@@ -1087,6 +1094,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
 
         let dependency_details_branch = DependencyDetails {
@@ -1109,6 +1117,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
 
         let dependency_details_tag = DependencyDetails {
@@ -1131,6 +1140,7 @@ mod tests {
             package: None,
             ipfs: None,
             rev: Some("9f35b8e".to_string()),
+            registry: None,
         };
 
         let dependency_details_rev = DependencyDetails {
@@ -1208,6 +1218,18 @@ mod tests {
                 .map(|e| e.to_string()),
             Some(expected_mismatch_error.to_string())
         );
+
+        let dependency_details_registry = DependencyDetails {
+            registry: Some("../index".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            dependency_details_registry
+                .validate()
+                .err()
+                .map(|e| e.to_string()),
+            Some("Details reserved for registry sources used without a version field".to_string())
+        );
     }
 
     #[test]
@@ -1255,6 +1277,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
 
         let git_source_string = "https://github.com/FuelLabs/sway".to_string();
@@ -1267,6 +1290,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
         let dependency_details_git_branch = DependencyDetails {
             version: None,
@@ -1277,6 +1301,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: None,
+            registry: None,
         };
         let dependency_details_git_rev = DependencyDetails {
             version: None,
@@ -1287,6 +1312,7 @@ mod tests {
             package: None,
             rev: Some("9f35b8e".to_string()),
             ipfs: None,
+            registry: None,
         };
 
         let dependency_details_ipfs = DependencyDetails {
@@ -1298,6 +1324,7 @@ mod tests {
             package: None,
             rev: None,
             ipfs: Some("QmVxgEbiDDdHpG9AesCpZAqNvHYp1P3tWLFdrpUBWPMBcc".to_string()),
+            registry: None,
         };
 
         assert!(dependency_details_path.validate().is_ok());
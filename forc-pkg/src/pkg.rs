@@ -29,6 +29,7 @@ use std::{
     str::FromStr,
     sync::{atomic::AtomicBool, Arc},
 };
+use sway_ast::Literal;
 pub use sway_core::Programs;
 use sway_core::{
     abi_generation::{
@@ -154,12 +155,47 @@ pub enum TestPassCondition {
     ShouldNotRevert,
 }
 
+/// The arguments a test taking parameters is executed with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestArgs {
+    /// The test doesn't take any arguments.
+    None,
+    /// The test is executed once with each case, e.g., `#[test(case = "1, 2")]`.
+    Cases(Vec<String>),
+    /// The test is executed with randomly generated arguments, the given number of times if any,
+    /// e.g., `#[test(fuzz = "1000")]`.
+    Fuzz(Option<u64>),
+}
+
+/// The transaction a test is executed within, as configured by the arguments of its `#[test]`
+/// attribute, e.g., `#[test(sender = "0x..", coin = "100", block_height = "1000")]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestContext {
+    /// The owner of the coins of the transaction, i.e., the `msg_sender()` of the test and of the
+    /// contract calls it makes.
+    pub sender: Option<fuel_tx::Address>,
+    /// The amounts and assets of the coins of the transaction, besides the coin it is funded by.
+    pub coins: Vec<(u64, fuel_tx::AssetId)>,
+    /// The number of variable outputs of the transaction, e.g., to transfer coins to addresses.
+    pub variable_outputs: u16,
+    /// The height of the block the transaction is executed in.
+    pub block_height: Option<u32>,
+    /// The timestamp of the block the transaction is executed in, as returned by `timestamp()`.
+    pub timestamp: Option<u64>,
+}
+
 /// Data specific to the test entry point.
 #[derive(Debug, Clone)]
 pub struct PkgTestEntry {
     pub pass_condition: TestPassCondition,
     pub span: Span,
     pub file_path: Arc<PathBuf>,
+    pub context: TestContext,
+    pub args: TestArgs,
+    /// The ABI of the parameters of the test.
+    pub params: Vec<program_abi::TypeApplication>,
+    /// The ABI of the types the parameters of the test refer to.
+    pub param_types: Vec<program_abi::TypeDeclaration>,
 }
 
 /// The result of successfully compiling a workspace.
@@ -426,6 +462,11 @@ impl BuiltPackage {
         Ok(())
     }
 
+    /// The source map of the package's bytecode, mapping instructions to source spans.
+    pub fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
     /// Writes debug_info (source_map) of the BuiltPackage to the given `path`.
     pub fn write_debug_info(&self, path: &Path) -> Result<()> {
         let source_map_json =
@@ -1467,6 +1508,7 @@ fn fetch_deps(
     for (dep_name, dep, dep_kind) in deps {
         let name = dep.package().unwrap_or(&dep_name);
         let parent_manifest = &manifest_map[&parent_id];
+        let parent_manifest_dir = parent_manifest.dir().to_path_buf();
         let source =
             Source::from_manifest_dep_patched(parent_manifest, name, &dep, member_manifests)
                 .context(format!("Failed to source dependency: {dep_name}"))?;
@@ -1486,6 +1528,7 @@ fn fetch_deps(
                     name: &pkg.name,
                     offline,
                     ipfs_node,
+                    manifest_dir: &parent_manifest_dir,
                 };
                 let source = pkg.source.pin(ctx, manifest_map)?;
                 let name = pkg.name.clone();
@@ -1878,9 +1921,15 @@ pub fn compile(
         .as_ref()
         .map(|asm| asm.0.entries.clone())
         .unwrap_or_default();
+    let mut abi_ctx = AbiContext {
+        program: typed_program,
+        abi_with_callpaths: profile.json_abi_with_callpaths,
+    };
     let entries = entries
         .iter()
-        .map(|finalized_entry| PkgEntry::from_finalized_entry(finalized_entry, engines))
+        .map(|finalized_entry| {
+            PkgEntry::from_finalized_entry(finalized_entry, engines, &mut abi_ctx)
+        })
         .collect::<anyhow::Result<_>>()?;
 
     let asm = match asm_res {
@@ -1946,10 +1995,15 @@ impl PkgEntry {
         self.kind.test().is_some()
     }
 
-    fn from_finalized_entry(finalized_entry: &FinalizedEntry, engines: &Engines) -> Result<Self> {
+    fn from_finalized_entry(
+        finalized_entry: &FinalizedEntry,
+        engines: &Engines,
+        abi_ctx: &mut AbiContext,
+    ) -> Result<Self> {
         let pkg_entry_kind = match &finalized_entry.test_decl_ref {
             Some(test_decl_ref) => {
-                let pkg_test_entry = PkgTestEntry::from_decl(test_decl_ref.clone(), engines)?;
+                let pkg_test_entry =
+                    PkgTestEntry::from_decl(test_decl_ref.clone(), engines, abi_ctx)?;
                 PkgEntryKind::Test(pkg_test_entry)
             }
             None => PkgEntryKind::Main,
@@ -1973,13 +2027,25 @@ impl PkgEntryKind {
 }
 
 impl PkgTestEntry {
-    fn from_decl(decl_ref: DeclRefFunction, engines: &Engines) -> Result<Self> {
+    fn from_decl(
+        decl_ref: DeclRefFunction,
+        engines: &Engines,
+        abi_ctx: &mut AbiContext,
+    ) -> Result<Self> {
         let span = decl_ref.span();
         let test_function_decl = engines.de().get_function(&decl_ref);
+        let test_name = &test_function_decl.name;
 
         const FAILING_TEST_KEYWORD: &str = "should_revert";
-
-        let test_args: HashMap<String, Option<String>> = test_function_decl
+        const CASE_KEYWORD: &str = "case";
+        const FUZZ_KEYWORD: &str = "fuzz";
+        const SENDER_KEYWORD: &str = "sender";
+        const COIN_KEYWORD: &str = "coin";
+        const VARIABLE_OUTPUTS_KEYWORD: &str = "variable_outputs";
+        const BLOCK_HEIGHT_KEYWORD: &str = "block_height";
+        const TIMESTAMP_KEYWORD: &str = "timestamp";
+
+        let test_args: Vec<(String, Option<String>)> = test_function_decl
             .attributes
             .get(&AttributeKind::Test)
             .expect("test declaration is missing test attribute")
@@ -1988,28 +2054,96 @@ impl PkgTestEntry {
             .map(|arg| {
                 (
                     arg.name.to_string(),
-                    arg.value
-                        .as_ref()
-                        .map(|val| val.span().as_str().to_string()),
+                    arg.value.as_ref().map(|val| match val {
+                        Literal::String(lit) => lit.parsed.clone(),
+                        _ => val.span().as_str().to_string(),
+                    }),
                 )
             })
             .collect();
 
-        let pass_condition = if test_args.is_empty() {
-            anyhow::Ok(TestPassCondition::ShouldNotRevert)
-        } else if let Some(args) = test_args.get(FAILING_TEST_KEYWORD) {
-            let expected_revert_code = args
-                .as_ref()
-                .map(|arg| {
-                    let arg_str = arg.replace('"', "");
-                    arg_str.parse::<u64>()
-                })
-                .transpose()?;
-            anyhow::Ok(TestPassCondition::ShouldRevert(expected_revert_code))
+        let mut pass_condition = TestPassCondition::ShouldNotRevert;
+        let mut cases = vec![];
+        let mut fuzz = None;
+        let mut context = TestContext::default();
+        for (name, value) in test_args {
+            match (name.as_str(), value) {
+                (FAILING_TEST_KEYWORD, expected_revert_code) => {
+                    let expected_revert_code = expected_revert_code
+                        .map(|code| code.parse::<u64>())
+                        .transpose()?;
+                    pass_condition = TestPassCondition::ShouldRevert(expected_revert_code);
+                }
+                (CASE_KEYWORD, Some(case)) => cases.push(case),
+                (FUZZ_KEYWORD, runs) => {
+                    let runs = runs.map(|runs| runs.parse::<u64>()).transpose()?;
+                    fuzz = Some(runs);
+                }
+                (SENDER_KEYWORD, Some(sender)) => {
+                    let sender = fuel_tx::Address::from_str(&sender).map_err(|_| {
+                        anyhow!("Invalid sender `{sender}` for test {test_name}, expected a b256.")
+                    })?;
+                    context.sender = Some(sender);
+                }
+                (COIN_KEYWORD, Some(coin)) => {
+                    // The coin is of the base asset unless an asset id follows its amount.
+                    let (amount, asset_id) = match coin.split_once(',') {
+                        Some((amount, asset_id)) => (amount, Some(asset_id.trim())),
+                        None => (coin.as_str(), None),
+                    };
+                    let amount = amount.trim().parse::<u64>()?;
+                    let asset_id = match asset_id {
+                        Some(asset_id) => fuel_tx::AssetId::from_str(asset_id).map_err(|_| {
+                            anyhow!(
+                                "Invalid asset id `{asset_id}` for test {test_name}, expected a b256."
+                            )
+                        })?,
+                        None => fuel_tx::AssetId::BASE,
+                    };
+                    context.coins.push((amount, asset_id));
+                }
+                (VARIABLE_OUTPUTS_KEYWORD, Some(count)) => {
+                    context.variable_outputs = count.parse()?;
+                }
+                (BLOCK_HEIGHT_KEYWORD, Some(height)) => {
+                    context.block_height = Some(height.parse()?);
+                }
+                (TIMESTAMP_KEYWORD, Some(timestamp)) => {
+                    context.timestamp = Some(timestamp.parse()?);
+                }
+                _ => bail!("Invalid test argument(s) for test: {test_name}."),
+            }
+        }
+        if context.block_height.is_some() && context.timestamp.is_some() {
+            bail!("Test {test_name} can't be given both a block height and a timestamp.")
+        }
+        let args = match (cases.is_empty(), fuzz) {
+            (true, None) => TestArgs::None,
+            (false, None) => TestArgs::Cases(cases),
+            (true, Some(runs)) => TestArgs::Fuzz(runs),
+            (false, Some(_)) => {
+                bail!("Test {test_name} can't both be given cases and be fuzzed.")
+            }
+        };
+
+        let mut param_types = vec![];
+        let params = if test_function_decl.parameters.is_empty() {
+            vec![]
         } else {
-            let test_name = &test_function_decl.name;
-            bail!("Invalid test argument(s) for test: {test_name}.")
-        }?;
+            test_function_decl
+                .generate_abi_function(abi_ctx, engines.te(), engines.de(), &mut param_types)
+                .inputs
+        };
+        match (&args, params.is_empty()) {
+            (TestArgs::None, false) => bail!(
+                "Test {test_name} takes arguments, which must be given by `case` arguments \
+                or generated with `fuzz`, e.g., `#[test(case = \"1, 2\")]` or `#[test(fuzz)]`."
+            ),
+            (TestArgs::Cases(_) | TestArgs::Fuzz(_), true) => {
+                bail!("Test {test_name} takes no arguments, but is given cases or fuzzed.")
+            }
+            _ => {}
+        }
 
         let file_path = Arc::new(
             engines.se().get_path(
@@ -2021,6 +2155,10 @@ impl PkgTestEntry {
             pass_condition,
             span,
             file_path,
+            context,
+            args,
+            params,
+            param_types,
         })
     }
 }
@@ -2828,4 +2966,52 @@ mod test {
 "#;
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_registry_pin_fetch_build() {
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("registry")
+            .join("reg_consumer");
+        let manifest_file = ManifestFile::from_dir(&manifest_dir).unwrap();
+        let member_manifests = manifest_file.member_manifests().unwrap();
+
+        // Pin and fetch the registry dependency from the local index fixture.
+        let plan = BuildPlan::from_manifests(&member_manifests, false, Default::default()).unwrap();
+        let graph = plan.graph();
+        let reg_lib = graph
+            .node_indices()
+            .find(|&n| graph[n].name == "reg_lib")
+            .unwrap();
+        let source = &graph[reg_lib].source;
+        // The index path is recorded relative to the manifest, as written.
+        assert_eq!(source.to_string(), "registry+../index?^0.1#0.1.0");
+        assert_eq!(
+            source.checksum(),
+            Some("b5a43f82abeee74040286d6893d4fc9b2b931e04d75a8a55662cb25371aabe60")
+        );
+
+        // The lock round-trips and the fetched package is found within the cache.
+        let lock = Lock::from_graph(graph);
+        let locked_graph = lock.to_graph().unwrap();
+        let manifest_map = graph_to_manifest_map(&member_manifests, &locked_graph).unwrap();
+        assert!(manifest_map
+            .values()
+            .any(|manifest| manifest.project.name == "reg_lib"));
+
+        // Build the consumer, compiling the fetched dependency along the way.
+        let outputs = plan.member_nodes().collect();
+        let built = build(
+            &plan,
+            BuildTarget::default(),
+            &BuildProfile::debug(),
+            &outputs,
+        )
+        .unwrap();
+        let names: Vec<_> = built
+            .iter()
+            .map(|(node, _)| plan.graph()[*node].name.clone())
+            .collect();
+        assert_eq!(names, vec!["reg_consumer"]);
+    }
 }
@@ -122,6 +122,8 @@ pub(crate) struct PinCtx<'a> {
     pub(crate) name: &'a str,
     /// The IPFS node to use for fetching IPFS sources.
     pub(crate) ipfs_node: &'a IPFSNode,
+    /// The directory of the manifest declaring the dependency on this source.
+    pub(crate) manifest_dir: &'a Path,
 }
 
 pub(crate) enum DependencyPath {
@@ -152,12 +154,8 @@ impl Source {
     ) -> Result<Self> {
         let source = match dep {
             manifest::Dependency::Simple(ref ver_str) => {
-                bail!(
-                    "Unsupported dependency declaration in \"{}\": `{}` - \
-                    currently only `git` and `path` dependencies are supported",
-                    manifest_dir.display(),
-                    ver_str
-                )
+                let source = reg::Source::from_manifest_dep(manifest_dir, ver_str, None)?;
+                Source::Registry(source)
             }
             manifest::Dependency::Detailed(ref det) => {
                 match (&det.path, &det.version, &det.git, &det.ipfs) {
@@ -196,6 +194,12 @@ impl Source {
                         let source = ipfs::Source(cid);
                         Source::Ipfs(source)
                     }
+                    (_, Some(version), _, _) => {
+                        let registry = det.registry.as_deref();
+                        let source =
+                            reg::Source::from_manifest_dep(manifest_dir, version, registry)?;
+                        Source::Registry(source)
+                    }
                     _ => {
                         bail!("unsupported set of fields for dependency: {:?}", dep);
                     }
@@ -300,7 +304,17 @@ impl Pinned {
     /// Used soley for the package lock file.
     pub fn semver(&self) -> Option<semver::Version> {
         match self {
-            Self::Registry(reg) => Some(reg.source.version.clone()),
+            Self::Registry(reg) => Some(reg.version.clone()),
+            _ => None,
+        }
+    }
+
+    /// If the source is associated with a package archive checksum, emit it.
+    ///
+    /// Used soley for the package lock file.
+    pub fn checksum(&self) -> Option<&str> {
+        match self {
+            Self::Registry(reg) => Some(&reg.checksum),
             _ => None,
         }
     }
@@ -346,6 +360,9 @@ impl<'a> PinCtx<'a> {
     fn ipfs_node(&self) -> &'a IPFSNode {
         self.ipfs_node
     }
+    fn manifest_dir(&self) -> &'a Path {
+        self.manifest_dir
+    }
 }
 
 impl fmt::Display for Pinned {
@@ -355,7 +372,7 @@ impl fmt::Display for Pinned {
             Self::Path(src) => src.fmt(f),
             Self::Git(src) => src.fmt(f),
             Self::Ipfs(src) => src.fmt(f),
-            Self::Registry(_reg) => todo!("pkg registries not yet implemented"),
+            Self::Registry(src) => src.fmt(f),
         }
     }
 }
@@ -367,7 +384,7 @@ impl<'a> fmt::Display for DisplayCompiling<'a, Pinned> {
             Pinned::Path(_src) => self.manifest_dir.display().fmt(f),
             Pinned::Git(src) => src.fmt(f),
             Pinned::Ipfs(src) => src.fmt(f),
-            Pinned::Registry(_src) => todo!("registry dependencies not yet implemented"),
+            Pinned::Registry(src) => src.fmt(f),
         }
     }
 }
@@ -385,8 +402,9 @@ impl FromStr for Pinned {
             Self::Git(src)
         } else if let Ok(src) = ipfs::Pinned::from_str(s) {
             Self::Ipfs(src)
+        } else if let Ok(src) = reg::Pinned::from_str(s) {
+            Self::Registry(src)
         } else {
-            // TODO: Try parse registry source.
             return Err(PinnedParseError);
         };
         Ok(source)
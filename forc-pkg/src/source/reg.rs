@@ -1,40 +1,298 @@
-use crate::{manifest::PackageManifestFile, source};
-use anyhow::bail;
+use crate::{
+    manifest::{self, PackageManifestFile},
+    source::{self, git},
+};
+use anyhow::{anyhow, bail, Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::{Path, PathBuf};
+use std::{
+    cmp::Ordering,
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use sway_core::fuel_prelude::fuel_crypto;
+use tar::Archive;
+use tracing::info;
 
-/// A package from the official registry.
+/// A package from a registry index.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct Source {
-    /// The base version specified for the package.
-    pub version: semver::Version,
+    /// The version requirement specified for the package.
+    pub version: VersionReq,
+    /// The index in which the package's published versions are listed.
+    pub index: Index,
 }
 
 /// A pinned instance of the registry source.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct Pinned {
-    /// The registry package with base version.
+    /// The registry package with its version requirement.
     pub source: Source,
     /// The pinned version.
     pub version: semver::Version,
+    /// The hex-encoded sha256 checksum of the package archive.
+    ///
+    /// This is not a part of the pinned source string, and is instead recorded separately under
+    /// the package's `checksum` entry within the lock file.
+    pub checksum: String,
+}
+
+/// A semver version requirement, e.g. `0.1`, `^1.2.3` or `>=0.1, <0.3`.
+///
+/// Wraps `semver::VersionReq` in order to provide an `Ord` implementation.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct VersionReq(pub semver::VersionReq);
+
+/// The location of a package index.
+///
+/// An index is a directory containing one file per package, named after the package. Each line
+/// of a package's file is a JSON-serialized [IndexEntry] describing a single published version.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum Index {
+    /// An index located on the local file system.
+    ///
+    /// Relative paths are relative to the directory of the manifest declaring the dependency. The
+    /// path is stored as written so that lock files remain valid when a project is moved.
+    Path(PathBuf),
+    /// An index hosted within a git repository, checked out at the HEAD of the default branch.
+    Git(git::Url),
+}
+
+/// A single published version of a package within an index.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct IndexEntry {
+    /// The name of the package.
+    pub name: String,
+    /// The published version.
+    pub version: semver::Version,
+    /// The hex-encoded sha256 checksum of the package's `.tar` archive.
+    pub checksum: String,
+    /// The location of the package's `.tar` archive.
+    ///
+    /// Either an `http(s)` URL, or a path relative to the root of the index that lies within it.
+    pub download: String,
+    /// Whether or not the version has been yanked. Yanked versions are never selected for newly
+    /// pinned dependencies, though they may still be fetched if already present in a lock file.
+    #[serde(default)]
+    pub yanked: bool,
+}
+
+/// Error returned upon failed parsing of `Pinned::from_str`.
+#[derive(Clone, Debug)]
+pub enum PinnedParseError {
+    Prefix,
+    Index,
+    VersionReq,
+    Version,
+}
+
+/// The environment variable used to specify the index for registry dependencies that do not
+/// specify a `registry` explicitly.
+pub const INDEX_ENV_VAR: &str = "FORC_REGISTRY_INDEX";
+
+const REG_DIR_NAME: &str = "registry";
+const REG_CACHE_DIR_NAME: &str = "cache";
+const INDEX_CHECKOUT_NAME: &str = "registry-index";
+const GIT_INDEX_PREFIX: &str = "git+";
+
+impl Pinned {
+    pub const PREFIX: &'static str = "registry";
+}
+
+impl Source {
+    /// Construct a registry source from the version requirement and optional `registry` given
+    /// within a manifest dependency declaration.
+    ///
+    /// If no `registry` is specified, the index is read from the `FORC_REGISTRY_INDEX`
+    /// environment variable. Relative `registry` paths are relative to `manifest_dir`, while
+    /// relative paths given by the environment variable are relative to the current directory.
+    pub fn from_manifest_dep(
+        manifest_dir: &Path,
+        version: &str,
+        registry: Option<&str>,
+    ) -> Result<Self> {
+        let version = VersionReq::from_str(version)?;
+        let index = match registry {
+            Some(registry) => Index::from_manifest_str(manifest_dir, registry)?,
+            None => {
+                let registry = std::env::var(INDEX_ENV_VAR).map_err(|_| {
+                    anyhow!(
+                        "no index specified for registry dependency `{}` in \"{}\": \
+                        either specify a `registry` for the dependency or set `{}`",
+                        version,
+                        manifest_dir.display(),
+                        INDEX_ENV_VAR,
+                    )
+                })?;
+                Index::from_env_str(&registry)?
+            }
+        };
+        Ok(Self { version, index })
+    }
+}
+
+impl Index {
+    /// Parse the index from a `registry` manifest entry.
+    ///
+    /// Git indices are specified with a `git+` prefix, e.g. `git+https://github.com/org/index`.
+    /// Everything else is treated as a path, either absolute or relative to `manifest_dir`.
+    fn from_manifest_str(manifest_dir: &Path, s: &str) -> Result<Self> {
+        if let Some(repo) = s.strip_prefix(GIT_INDEX_PREFIX) {
+            return Ok(Index::Git(git::Url::from_str(repo)?));
+        }
+        let path = PathBuf::from(s.strip_prefix("file://").unwrap_or(s));
+        if !manifest_dir.join(&path).is_dir() {
+            bail!(
+                "index path {:?} is not a directory relative to \"{}\"",
+                path,
+                manifest_dir.display()
+            );
+        }
+        Ok(Index::Path(path))
+    }
+
+    /// Parse the index from the `FORC_REGISTRY_INDEX` environment variable.
+    ///
+    /// Relative paths are resolved against the current directory and made absolute, so that the
+    /// index is the same for every manifest depending on it, including those of fetched registry
+    /// packages, and so that it has the same meaning wherever it is recorded in `Forc.lock`.
+    fn from_env_str(s: &str) -> Result<Self> {
+        let current_dir = std::env::current_dir()?;
+        match Self::from_manifest_str(&current_dir, s)? {
+            Index::Path(path) => Ok(Index::Path(current_dir.join(path))),
+            index => Ok(index),
+        }
+    }
+
+    /// Produce the local directory containing the index, fetching it if necessary.
+    ///
+    /// Relative index paths are resolved against `manifest_dir`. Git indices are re-fetched on
+    /// every call unless offline, in which case the most recent local checkout is used.
+    fn local_dir(&self, manifest_dir: &Path, fetch_id: u64, offline: bool) -> Result<PathBuf> {
+        match self {
+            Index::Path(path) => Ok(manifest_dir.join(path)),
+            Index::Git(repo) => {
+                let source = git::Source {
+                    repo: repo.clone(),
+                    reference: git::Reference::DefaultBranch,
+                };
+                if offline {
+                    let (path, _commit_hash) =
+                        git::search_source_locally(INDEX_CHECKOUT_NAME, &source)?.ok_or_else(
+                            || anyhow!("Unable to find index {} locally in offline mode", self),
+                        )?;
+                    return Ok(path);
+                }
+                let pinned = git::pin(fetch_id, INDEX_CHECKOUT_NAME, source)?;
+                let path = git::commit_path(INDEX_CHECKOUT_NAME, repo, &pinned.commit_hash);
+                let mut lock = forc_util::path_lock(&path)?;
+                let _guard = lock.write()?;
+                if !path.exists() {
+                    git::fetch(fetch_id, INDEX_CHECKOUT_NAME, &pinned)?;
+                }
+                Ok(path)
+            }
+        }
+    }
+
+    /// Read all published versions of the package with the given name.
+    fn entries(&self, index_dir: &Path, name: &str) -> Result<Vec<IndexEntry>> {
+        let path = index_dir.join(name);
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("failed to find package `{name}` in index {self}"))?;
+        parse_index_entries(&contents)
+            .with_context(|| format!("failed to parse index file {}", path.display()))
+    }
 }
 
 impl source::Pin for Source {
     type Pinned = Pinned;
-    fn pin(&self, _ctx: source::PinCtx) -> anyhow::Result<(Self::Pinned, PathBuf)> {
-        bail!("registry dependencies are not yet supported");
+    fn pin(&self, ctx: source::PinCtx) -> Result<(Self::Pinned, PathBuf)> {
+        let index_dir = self
+            .index
+            .local_dir(ctx.manifest_dir(), ctx.fetch_id(), ctx.offline())?;
+        let entries = self.index.entries(&index_dir, ctx.name())?;
+        let entry = select_entry(&entries, &self.version).ok_or_else(|| {
+            anyhow!(
+                "failed to find a version of `{}` matching `{}` in index {}",
+                ctx.name(),
+                self.version,
+                self.index
+            )
+        })?;
+        let pinned = Pinned {
+            source: self.clone(),
+            version: entry.version.clone(),
+            checksum: entry.checksum.clone(),
+        };
+        let path = pkg_cache_dir(ctx.name(), &pinned.version, &pinned.checksum);
+        Ok((pinned, path))
     }
 }
 
 impl source::Fetch for Pinned {
-    fn fetch(&self, _ctx: source::PinCtx, _local: &Path) -> anyhow::Result<PackageManifestFile> {
-        bail!("registry dependencies are not yet supported");
+    fn fetch(&self, ctx: source::PinCtx, local: &Path) -> Result<PackageManifestFile> {
+        // Co-ordinate access to the registry cache directory using an advisory file lock.
+        let mut lock = forc_util::path_lock(local)?;
+        {
+            let _guard = lock.write()?;
+            if !local.exists() {
+                info!(
+                    "  {} {} {}",
+                    ansi_term::Color::Green.bold().paint("Fetching"),
+                    ansi_term::Style::new().bold().paint(ctx.name),
+                    self
+                );
+                let index_dir = self.source.index.local_dir(
+                    ctx.manifest_dir(),
+                    ctx.fetch_id(),
+                    ctx.offline(),
+                )?;
+                let entries = self.source.index.entries(&index_dir, ctx.name())?;
+                let entry = entries
+                    .iter()
+                    .find(|entry| entry.version == self.version)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "version {} of `{}` is no longer listed in index {}",
+                            self.version,
+                            ctx.name(),
+                            self.source.index
+                        )
+                    })?;
+                if entry.checksum != self.checksum {
+                    bail!(
+                        "checksum for `{} v{}` changed between lock file and index {}",
+                        ctx.name(),
+                        self.version,
+                        self.source.index
+                    );
+                }
+                let bytes = download(&index_dir, entry, ctx.offline())?;
+                verify_checksum(&bytes, &self.checksum).with_context(|| {
+                    format!("failed to verify `{} v{}`", ctx.name(), self.version)
+                })?;
+                unpack(&bytes, local)?;
+            }
+        }
+        let path = {
+            let _guard = lock.read()?;
+            manifest::find_within(local, ctx.name())
+                .ok_or_else(|| anyhow!("failed to find package `{}` in {}", ctx.name(), self))?
+        };
+        PackageManifestFile::from_file(path)
     }
 }
 
 impl source::DepPath for Pinned {
-    fn dep_path(&self, _name: &str) -> anyhow::Result<source::DependencyPath> {
-        bail!("registry dependencies are not yet supported");
+    fn dep_path(&self, name: &str) -> Result<source::DependencyPath> {
+        let path = pkg_cache_dir(name, &self.version, &self.checksum);
+        // Co-ordinate access to the registry cache directory using an advisory file lock.
+        let lock = forc_util::path_lock(&path)?;
+        let _guard = lock.read()?;
+        let path = manifest::find_within(&path, name)
+            .ok_or_else(|| anyhow!("failed to find package `{}` in {}", name, self))?;
+        Ok(source::DependencyPath::ManifestPath(path))
     }
 }
 
@@ -43,3 +301,314 @@ impl From<Pinned> for source::Pinned {
         Self::Registry(p)
     }
 }
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Whitespace is stripped so that the requirement may appear within lock file dep lines.
+        let req = self.0.to_string().replace(' ', "");
+        write!(f, "{req}")
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let req = semver::VersionReq::parse(s)
+            .map_err(|e| anyhow!("invalid version requirement {:?}: {}", s, e))?;
+        Ok(Self(req))
+    }
+}
+
+impl PartialOrd for VersionReq {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VersionReq {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+impl fmt::Display for Index {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Index::Path(path) => write!(f, "{}", path.display()),
+            Index::Git(repo) => write!(f, "{GIT_INDEX_PREFIX}{repo}"),
+        }
+    }
+}
+
+impl FromStr for Index {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix(GIT_INDEX_PREFIX) {
+            Some(repo) => Ok(Index::Git(git::Url::from_str(repo)?)),
+            None => Ok(Index::Path(PathBuf::from(s))),
+        }
+    }
+}
+
+impl fmt::Display for Pinned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // registry+<index>?<version_req>#<version>
+        write!(
+            f,
+            "{}+{}?{}#{}",
+            Self::PREFIX,
+            self.source.index,
+            self.source.version,
+            self.version
+        )
+    }
+}
+
+impl FromStr for Pinned {
+    type Err = PinnedParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // registry+<index>?<version_req>#<version>
+        let s = s.trim();
+
+        // Check for the prefix.
+        let prefix_plus = format!("{}+", Self::PREFIX);
+        if s.find(&prefix_plus) != Some(0) {
+            return Err(PinnedParseError::Prefix);
+        }
+        let s = &s[prefix_plus.len()..];
+
+        // Parse the pinned version from the end.
+        let (s, version) = s.rsplit_once('#').ok_or(PinnedParseError::Version)?;
+        let version = semver::Version::parse(version).map_err(|_| PinnedParseError::Version)?;
+
+        // Parse the index and version requirement.
+        let (index, req) = s.rsplit_once('?').ok_or(PinnedParseError::VersionReq)?;
+        let version_req = VersionReq::from_str(req).map_err(|_| PinnedParseError::VersionReq)?;
+        let index = Index::from_str(index).map_err(|_| PinnedParseError::Index)?;
+
+        let source = Source {
+            version: version_req,
+            index,
+        };
+        Ok(Self {
+            source,
+            version,
+            checksum: String::new(),
+        })
+    }
+}
+
+/// Parse the newline-delimited JSON entries of a package's index file.
+fn parse_index_entries(contents: &str) -> Result<Vec<IndexEntry>> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| serde_json::from_str(line).map_err(Into::into))
+        .collect()
+}
+
+/// Select the greatest non-yanked version that satisfies the given requirement.
+fn select_entry<'a>(entries: &'a [IndexEntry], req: &VersionReq) -> Option<&'a IndexEntry> {
+    entries
+        .iter()
+        .filter(|entry| !entry.yanked && req.0.matches(&entry.version))
+        .max_by(|a, b| a.version.cmp(&b.version))
+}
+
+/// Fetch the bytes of the archive described by the given index entry.
+fn download(index_dir: &Path, entry: &IndexEntry, offline: bool) -> Result<Vec<u8>> {
+    let url = &entry.download;
+    if url.starts_with("http://") || url.starts_with("https://") {
+        if offline {
+            bail!("Unable to download {} in offline mode", url);
+        }
+        return futures::executor::block_on(async {
+            let res = reqwest::get(url).await?;
+            if !res.status().is_success() {
+                bail!("Failed to fetch from {url:?}");
+            }
+            Ok(res.bytes().await?.to_vec())
+        });
+    }
+    // Archives within the index may only be read from within the index directory itself.
+    let read_err = || format!("failed to read archive {}", index_dir.join(url).display());
+    let index_dir = index_dir.canonicalize().with_context(read_err)?;
+    let path = index_dir.join(url).canonicalize().with_context(read_err)?;
+    if !path.starts_with(&index_dir) {
+        bail!(
+            "archive {} of {} {} is outside of the index {}",
+            path.display(),
+            entry.name,
+            entry.version,
+            index_dir.display()
+        );
+    }
+    fs::read(&path).with_context(|| format!("failed to read archive {}", path.display()))
+}
+
+/// Check that the sha256 of the given bytes matches the expected hex-encoded checksum.
+fn verify_checksum(bytes: &[u8], expected: &str) -> Result<()> {
+    let checksum = checksum(bytes);
+    if !checksum.eq_ignore_ascii_case(expected) {
+        bail!(
+            "checksum mismatch: expected {}, found {}",
+            expected,
+            checksum
+        );
+    }
+    Ok(())
+}
+
+/// The hex-encoded sha256 of the given bytes.
+pub fn checksum(bytes: &[u8]) -> String {
+    hex::encode(fuel_crypto::Hasher::hash(bytes))
+}
+
+/// Unpack the `.tar` archive into the given cache directory.
+///
+/// The archive is first unpacked to a temporary directory to avoid leaving a partially unpacked
+/// package in the cache in the case that unpacking fails.
+fn unpack(bytes: &[u8], dst: &Path) -> Result<()> {
+    let tmp = dst.with_extension("tmp");
+    if tmp.exists() {
+        let _ = fs::remove_dir_all(&tmp);
+    }
+    fs::create_dir_all(&tmp)?;
+    Archive::new(bytes).unpack(&tmp)?;
+    fs::rename(&tmp, dst)?;
+    Ok(())
+}
+
+fn registry_dir() -> PathBuf {
+    forc_util::user_forc_directory().join(REG_DIR_NAME)
+}
+
+fn cache_dir() -> PathBuf {
+    registry_dir().join(REG_CACHE_DIR_NAME)
+}
+
+/// The path at which a registry package version is cached.
+///
+/// The resulting directory is:
+///
+/// ```ignore
+/// $HOME/.forc/registry/cache/<checksum>/<name>-<version>
+/// ```
+///
+/// Keying the cache by the sha256 checksum of the package archive rather than by the index keeps
+/// the cache valid across toolchain versions, and avoids collisions between distinct local
+/// indices referred to by the same relative path.
+fn pkg_cache_dir(name: &str, version: &semver::Version, checksum: &str) -> PathBuf {
+    cache_dir()
+        .join(checksum.to_lowercase())
+        .join(format!("{name}-{version}"))
+}
+
+#[test]
+fn test_source_reg_pinned_parsing() {
+    let strings = [
+        "registry+/home/user/index?^0.1#0.1.4",
+        "registry+git+https://github.com/FuelLabs/index?>=0.1,<0.3#0.2.0",
+    ];
+
+    let expected = [
+        Pinned {
+            source: Source {
+                version: VersionReq::from_str("^0.1").unwrap(),
+                index: Index::Path(PathBuf::from("/home/user/index")),
+            },
+            version: semver::Version::new(0, 1, 4),
+            checksum: String::new(),
+        },
+        Pinned {
+            source: Source {
+                version: VersionReq::from_str(">=0.1, <0.3").unwrap(),
+                index: Index::Git(git::Url::from_str("https://github.com/FuelLabs/index").unwrap()),
+            },
+            version: semver::Version::new(0, 2, 0),
+            checksum: String::new(),
+        },
+    ];
+
+    for (&string, expected) in strings.iter().zip(&expected) {
+        let parsed = Pinned::from_str(string).unwrap();
+        assert_eq!(&parsed, expected);
+        let serialized = expected.to_string();
+        assert_eq!(&serialized, string);
+    }
+}
+
+#[test]
+fn test_source_reg_select_entry() {
+    let contents = r#"
+        {"name":"foo","version":"0.1.0","checksum":"00","download":"foo-0.1.0.tar"}
+        {"name":"foo","version":"0.1.3","checksum":"01","download":"foo-0.1.3.tar"}
+        {"name":"foo","version":"0.1.4","checksum":"02","download":"foo-0.1.4.tar","yanked":true}
+        {"name":"foo","version":"0.2.0","checksum":"03","download":"foo-0.2.0.tar"}
+    "#;
+    let entries = parse_index_entries(contents).unwrap();
+    assert_eq!(entries.len(), 4);
+
+    let select = |req: &str| {
+        let req = VersionReq::from_str(req).unwrap();
+        select_entry(&entries, &req).map(|entry| entry.version.to_string())
+    };
+    assert_eq!(select("0.1").as_deref(), Some("0.1.3"));
+    assert_eq!(select("=0.1.0").as_deref(), Some("0.1.0"));
+    assert_eq!(select("=0.1.4"), None);
+    assert_eq!(select("*").as_deref(), Some("0.2.0"));
+    assert_eq!(select("^1"), None);
+}
+
+#[test]
+fn test_source_reg_verify_checksum() {
+    let bytes = b"forc";
+    let sum = checksum(bytes);
+    assert_eq!(sum.len(), 64);
+    assert!(verify_checksum(bytes, &sum).is_ok());
+    assert!(verify_checksum(bytes, &sum.to_uppercase()).is_ok());
+    assert!(verify_checksum(b"sway", &sum).is_err());
+}
+
+#[test]
+fn test_source_reg_download_outside_index() {
+    let dir = std::env::temp_dir().join(format!("forc-pkg-reg-download-{}", std::process::id()));
+    let index_dir = dir.join("index");
+    fs::create_dir_all(&index_dir).unwrap();
+    fs::write(index_dir.join("inside.tar"), b"inside").unwrap();
+    fs::write(dir.join("outside.tar"), b"outside").unwrap();
+    let entry = |download: &str| IndexEntry {
+        name: "foo".to_string(),
+        version: semver::Version::new(0, 1, 0),
+        checksum: String::new(),
+        download: download.to_string(),
+        yanked: false,
+    };
+
+    let outside = dir.join("outside.tar").display().to_string();
+    assert_eq!(
+        download(&index_dir, &entry("inside.tar"), true).unwrap(),
+        b"inside"
+    );
+    assert!(download(&index_dir, &entry("../outside.tar"), true).is_err());
+    assert!(download(&index_dir, &entry(&outside), true).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_source_reg_env_index_is_absolute() {
+    // Test binaries run within the package root, so this is the fixture index.
+    let index = Index::from_env_str("tests/registry/index").unwrap();
+    let expected = std::env::current_dir()
+        .unwrap()
+        .join("tests/registry/index");
+    assert!(expected.is_absolute());
+    assert_eq!(index, Index::Path(expected));
+
+    // Manifest `registry` paths are kept relative to the manifest, as written.
+    let manifest_dir =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/registry/reg_consumer");
+    let index = Index::from_manifest_str(&manifest_dir, "../index").unwrap();
+    assert_eq!(index, Index::Path(PathBuf::from("../index")));
+}
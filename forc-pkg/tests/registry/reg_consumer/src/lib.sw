@@ -0,0 +1,5 @@
+library;
+
+pub fn registry_answer() -> u64 {
+    reg_lib::answer()
+}
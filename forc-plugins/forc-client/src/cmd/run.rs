@@ -33,6 +33,14 @@ pub struct Command {
     /// Only craft transaction and print it out.
     #[clap(long)]
     pub dry_run: bool,
+    /// Execute the crafted transaction one instruction at a time on the node's debugger, and
+    /// profile the gas it uses per function and source line.
+    ///
+    /// The gas used by each stack of functions is written in the folded format read by flame
+    /// graph tools, such as `inferno-flamegraph`, to `out/profile/gas.folded`. The node must be
+    /// started with debugging enabled, e.g., `fuel-core run --debug`.
+    #[clap(long, requires = "dry-run")]
+    pub profile: bool,
     /// Pretty-print the outputs from the node.
     #[clap(long = "pretty-print", short = 'r')]
     pub pretty_print: bool,
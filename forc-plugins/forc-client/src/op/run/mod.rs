@@ -9,9 +9,14 @@ use crate::{
     },
 };
 use anyhow::{anyhow, bail, Context, Result};
+use forc_debug_core::{
+    backend::NodeBackend,
+    profiler::{profile_tx, GasProfile, GasProfiler},
+    source_locations::SourceLocations,
+};
 use forc_pkg::{self as pkg, fuel_core_not_running, PackageManifestFile};
 use forc_tracing::println_warning;
-use forc_util::tx_utils::format_log_receipts;
+use forc_util::{default_output_directory, tx_utils::format_log_receipts};
 use fuel_core_client::client::FuelClient;
 use fuel_tx::{ContractId, Transaction, TransactionBuilder};
 use fuels_accounts::provider::Provider;
@@ -137,6 +142,17 @@ pub async fn run_pkg(
 
     if command.dry_run {
         info!("{:?}", tx);
+        if command.profile {
+            let receipts = profile_script(
+                node_url.as_str(),
+                tx.into(),
+                manifest,
+                compiled,
+                command.pretty_print,
+            )
+            .await?;
+            return Ok(RanScript { receipts });
+        }
         Ok(RanScript { receipts: vec![] })
     } else {
         let receipts = try_send_tx(
@@ -150,6 +166,47 @@ pub async fn run_pkg(
     }
 }
 
+/// The number of most expensive lines printed by [profile_script].
+const GAS_PROFILE_TOP_LINES: usize = 10;
+
+/// Execute `tx` on the debugger of the node at `node_url`, without committing it, and write the
+/// profile of the gas it uses to the output directory of the package.
+async fn profile_script(
+    node_url: &str,
+    tx: Transaction,
+    manifest: &PackageManifestFile,
+    compiled: &BuiltPackage,
+    pretty_print: bool,
+) -> Result<Vec<fuel_tx::Receipt>> {
+    let node_url = node_url.to_string();
+    let name = manifest.project_name().to_string();
+    let source_locations = SourceLocations::new(compiled.source_map());
+    // The debugger's client blocks on its own runtime, so it can't run on this one.
+    let (profile, receipts) = tokio::task::spawn_blocking(move || {
+        let mut backend = NodeBackend::new(&node_url)
+            .map_err(|err| anyhow!("failed to start a debugging session on {node_url}: {err}"))?;
+        let profiler = GasProfiler::new(&name, &source_locations);
+        profile_tx(&mut backend, &tx, profiler).map_err(|err| anyhow!("{err}"))
+    })
+    .await??;
+    info!("{}", format_log_receipts(&receipts, pretty_print)?);
+
+    let dir = default_output_directory(manifest.dir()).join("profile");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(GasProfile::FOLDED_FILE_NAME);
+    std::fs::write(&path, profile.to_folded())?;
+    info!(
+        "Gas profile: {} gas, written to {}",
+        profile.total(),
+        path.display()
+    );
+    for (path, line, gas) in profile.lines().into_iter().take(GAS_PROFILE_TOP_LINES) {
+        let path = path.strip_prefix(manifest.dir()).unwrap_or(path);
+        info!("  {gas:>10}  {}:{line}", path.display());
+    }
+    Ok(receipts)
+}
+
 async fn try_send_tx(
     node_url: &str,
     tx: &Transaction,
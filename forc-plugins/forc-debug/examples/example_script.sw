@@ -0,0 +1,17 @@
+script;
+
+use std::logging::log;
+
+fn factorial(n: u64) -> u64 {
+    let mut result = 1;
+    let mut counter = 0;
+    while counter < n {
+        counter = counter + 1;
+        result = result * counter;
+    }
+    return result;
+}
+
+fn main() {
+    log::<u64>(factorial(5)); // 120
+}
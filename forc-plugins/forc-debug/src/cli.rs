@@ -0,0 +1,348 @@
+//! The interactive command line debugger.
+
+use crate::{
+    backend::{DebugBackend, RunOutcome},
+    names::{self, register_name},
+    source_locations::SourceLocations,
+    variables::format_variable,
+    ContractId, Transaction,
+};
+use fuel_vm::consts::{VM_MAX_RAM, VM_REGISTER_COUNT, WORD_SIZE};
+use shellfish::{Command as ShCommand, Shell};
+use std::{error::Error, path::Path};
+
+/// The size in bytes of a VM instruction.
+const INSTRUCTION_SIZE: u64 = 4;
+
+pub struct State {
+    backend: Box<dyn DebugBackend>,
+    /// The transaction started by `start_tx` when no path is given.
+    tx: Option<Transaction>,
+    /// The source locations of the script's instructions, if known.
+    source_locations: Option<SourceLocations>,
+    /// The offset in bytes of the instruction execution stopped at, if it is stopped.
+    stopped_at: Option<u64>,
+}
+
+impl State {
+    pub fn new(backend: Box<dyn DebugBackend>) -> Self {
+        State {
+            backend,
+            tx: None,
+            source_locations: None,
+            stopped_at: None,
+        }
+    }
+
+    /// Set the transaction started by `start_tx` when no path is given.
+    pub fn with_tx(self, tx: Transaction) -> Self {
+        State {
+            tx: Some(tx),
+            ..self
+        }
+    }
+
+    /// Set the source locations used to resolve `file:line` breakpoints and to report where
+    /// execution stopped.
+    pub fn with_source_locations(self, source_locations: SourceLocations) -> Self {
+        State {
+            source_locations: Some(source_locations),
+            ..self
+        }
+    }
+}
+
+/// Run the interactive debugger until the user exits.
+pub fn start_cli(state: State) -> Result<(), Box<dyn Error>> {
+    let mut shell = Shell::new(state, ">> ");
+
+    macro_rules! command {
+        ($f:ident, $help:literal, $names:expr) => {
+            for c in $names {
+                shell
+                    .commands
+                    .insert(c, ShCommand::new($help.to_string(), $f));
+            }
+        };
+    }
+
+    command!(
+        cmd_start_tx,
+        "[path/to/tx.json] -- start a new transaction, by default the one being debugged",
+        ["n", "tx", "new_tx", "start_tx"]
+    );
+    command!(
+        cmd_reset,
+        "-- reset, removing breakpoints and other state",
+        ["reset"]
+    );
+    command!(
+        cmd_continue,
+        "-- run until next breakpoint or termination",
+        ["c", "continue"]
+    );
+    command!(
+        cmd_step,
+        "[on|off] -- turn single-stepping on or off",
+        ["s", "step"]
+    );
+    command!(
+        cmd_breakpoint,
+        "[contract_id] offset | path:line -- set a breakpoint",
+        ["b", "breakpoint"]
+    );
+    command!(
+        cmd_registers,
+        "[regname ...] -- dump registers",
+        ["r", "reg", "register", "registers"]
+    );
+    command!(cmd_memory, "[offset] limit -- dump memory", ["m", "memory"]);
+    command!(
+        cmd_variables,
+        "-- print the arguments and local variables in scope",
+        ["v", "vars", "variables", "locals"]
+    );
+
+    shell.run()?;
+    Ok(())
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ArgError {
+    #[error("Invalid argument")]
+    Invalid,
+    #[error("Not enough arguments")]
+    NotEnough,
+    #[error("Too many arguments")]
+    TooMany,
+    #[error("No code is generated for this line")]
+    UnmappedLine,
+    #[error("Execution isn't stopped in a program with source locations")]
+    NoVariables,
+}
+
+fn pretty_print_run_outcome(state: &State, outcome: &RunOutcome) {
+    for receipt in &outcome.receipts {
+        println!("Receipt: {:?}", receipt);
+    }
+    if let Some(bp) = &outcome.breakpoint {
+        println!(
+            "Stopped on breakpoint at address {} of contract {:#x}",
+            bp.pc, bp.contract
+        );
+        let location = state.source_locations.as_ref().and_then(|locations| {
+            locations.instruction_to_location((bp.pc / INSTRUCTION_SIZE) as usize)
+        });
+        if let Some((path, line_col)) = location {
+            println!(
+                "  --> {}:{}:{}",
+                path.display(),
+                line_col.line,
+                line_col.column
+            );
+        }
+    } else {
+        println!("Terminated");
+    }
+}
+
+fn cmd_start_tx(state: &mut State, mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    args.remove(0);
+    let path_to_tx_json = args.pop();
+    if !args.is_empty() {
+        return Err(Box::new(ArgError::TooMany));
+    }
+
+    let tx: Transaction = match path_to_tx_json {
+        Some(path_to_tx_json) => {
+            let tx_json = std::fs::read(path_to_tx_json)?;
+            serde_json::from_slice(&tx_json)?
+        }
+        None => state.tx.clone().ok_or(ArgError::NotEnough)?,
+    };
+    let outcome = state.backend.start_tx(&tx)?;
+    state.stopped_at = outcome.breakpoint.map(|bp| bp.pc);
+    pretty_print_run_outcome(state, &outcome);
+
+    Ok(())
+}
+
+fn cmd_reset(state: &mut State, mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    args.remove(0);
+    if !args.is_empty() {
+        return Err(Box::new(ArgError::TooMany));
+    }
+
+    state.backend.reset()?;
+    state.stopped_at = None;
+
+    Ok(())
+}
+
+fn cmd_continue(state: &mut State, mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    args.remove(0);
+    if !args.is_empty() {
+        return Err(Box::new(ArgError::TooMany));
+    }
+
+    let outcome = state.backend.continue_tx()?;
+    state.stopped_at = outcome.breakpoint.map(|bp| bp.pc);
+    pretty_print_run_outcome(state, &outcome);
+
+    Ok(())
+}
+
+fn cmd_step(state: &mut State, mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    args.remove(0);
+    if args.len() > 1 {
+        return Err(Box::new(ArgError::TooMany));
+    }
+
+    state.backend.set_single_stepping(
+        args.first()
+            .map(|v| !["off", "no", "disable"].contains(&v.as_str()))
+            .unwrap_or(true),
+    )?;
+    Ok(())
+}
+
+fn cmd_breakpoint(state: &mut State, mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    args.remove(0);
+    let offset = args.pop().ok_or_else(|| Box::new(ArgError::NotEnough))?;
+    let contract_id = args.pop();
+
+    if !args.is_empty() {
+        return Err(Box::new(ArgError::TooMany));
+    }
+
+    let offset = if let Some(offset) = parse_int(&offset) {
+        offset as u64
+    } else if let Some(offset) = parse_source_line(state, &offset)? {
+        offset
+    } else {
+        return Err(Box::new(ArgError::Invalid));
+    };
+
+    let contract = if let Some(contract_id) = contract_id {
+        if let Ok(contract_id) = contract_id.parse::<ContractId>() {
+            contract_id
+        } else {
+            return Err(Box::new(ArgError::Invalid));
+        }
+    } else {
+        ContractId::zeroed() // Current script
+    };
+
+    state.backend.set_breakpoint(contract, offset)?;
+
+    Ok(())
+}
+
+/// Resolve a `path:line` breakpoint location to its instruction offset, if the source locations
+/// are known.
+fn parse_source_line(state: &State, location: &str) -> Result<Option<u64>, ArgError> {
+    let (Some(source_locations), Some((path, line))) =
+        (&state.source_locations, location.rsplit_once(':'))
+    else {
+        return Ok(None);
+    };
+    let line = line.parse::<i64>().map_err(|_| ArgError::Invalid)?;
+    source_locations
+        .line_to_instruction(Path::new(path), line)
+        .map(|instruction| Some(instruction as u64))
+        .ok_or(ArgError::UnmappedLine)
+}
+
+fn cmd_registers(state: &mut State, mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    args.remove(0);
+
+    if args.is_empty() {
+        for r in 0..VM_REGISTER_COUNT {
+            let value = state.backend.register(r)?;
+            println!("reg[{:#x}] = {:<8} # {}", r, value, register_name(r));
+        }
+    } else {
+        for arg in &args {
+            if let Some(v) = parse_int(arg) {
+                if v < VM_REGISTER_COUNT {
+                    let value = state.backend.register(v)?;
+                    println!("reg[{:#02x}] = {:<8} # {}", v, value, register_name(v));
+                } else {
+                    println!("Register index too large {}", v);
+                    return Ok(());
+                }
+            } else if let Some(index) = names::register_index(arg) {
+                let value = state.backend.register(index)?;
+                println!("reg[{:#02x}] = {:<8} # {}", index, value, arg);
+            } else {
+                println!("Unknown register name {}", arg);
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_memory(state: &mut State, mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    args.remove(0);
+
+    let limit = args
+        .pop()
+        .map(|a| parse_int(&a).ok_or(ArgError::Invalid))
+        .transpose()?
+        .unwrap_or(WORD_SIZE * (VM_MAX_RAM as usize));
+
+    let offset = args
+        .pop()
+        .map(|a| parse_int(&a).ok_or(ArgError::Invalid))
+        .transpose()?
+        .unwrap_or(0);
+
+    if !args.is_empty() {
+        return Err(Box::new(ArgError::TooMany));
+    }
+
+    let mem = state.backend.memory(offset, limit)?;
+
+    for (i, chunk) in mem.chunks(WORD_SIZE).enumerate() {
+        print!(" {:06x}:", offset + i * WORD_SIZE);
+        for byte in chunk {
+            print!(" {:02x}", byte);
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+fn cmd_variables(state: &mut State, mut args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    args.remove(0);
+    if !args.is_empty() {
+        return Err(Box::new(ArgError::TooMany));
+    }
+
+    let (Some(source_locations), Some(pc)) = (&state.source_locations, state.stopped_at) else {
+        return Err(Box::new(ArgError::NoVariables));
+    };
+    for var in source_locations.variables_at((pc / INSTRUCTION_SIZE) as usize) {
+        match format_variable(state.backend.as_mut(), var) {
+            Ok(var) => println!("{var}"),
+            Err(err) => println!("{}: {} = <{err}>", var.name, var.ty.name),
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_int(s: &str) -> Option<usize> {
+    let (s, radix) = if let Some(stripped) = s.strip_prefix("0x") {
+        (stripped, 16)
+    } else {
+        (s, 10)
+    };
+
+    let s = s.replace('_', "");
+
+    usize::from_str_radix(&s, radix).ok()
+}
@@ -0,0 +1,21 @@
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Backend(#[from] forc_debug_core::error::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid arguments for request \"{0}\": {1}")]
+    InvalidArguments(String, serde_json::Error),
+    #[error("Failed to read {}: {}", .0.display(), .1)]
+    ReadFile(PathBuf, std::io::Error),
+    #[error("No program has been launched")]
+    NotLaunched,
+    #[error("Unsupported request \"{0}\"")]
+    UnsupportedRequest(String),
+}
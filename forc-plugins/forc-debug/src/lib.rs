@@ -1,5 +1,9 @@
+pub mod cli;
+pub mod error;
 pub mod names;
+pub mod server;
+
+pub use forc_debug_core::{backend, profiler, source_locations, variables};
 
 // Re-exports
-pub use fuel_core_client::client::{schema::RunResult, FuelClient};
-pub use fuel_vm::prelude::{ContractId, Transaction};
+pub use forc_debug_core::{ContractId, FuelClient, RunResult, Transaction};
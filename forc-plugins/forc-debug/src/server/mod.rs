@@ -0,0 +1,720 @@
+//! A [Debug Adapter Protocol](https://microsoft.github.io/debug-adapter-protocol/) server,
+//! allowing editors to debug Sway scripts executed by a fuel-core node.
+//!
+//! The server speaks DAP over stdin/stdout.  Source lines are mapped to instruction offsets, and
+//! back again, using the source map emitted by `forc build --output-debug`.
+
+pub mod protocol;
+
+use crate::{
+    backend::{DebugBackend, InterpreterBackend, NodeBackend, RunOutcome},
+    error::{Error, Result},
+    names::register_name,
+    source_locations::{canonicalize, SourceLocations},
+    variables::read_value,
+    ContractId, Transaction,
+};
+use fuel_vm::consts::VM_REGISTER_COUNT;
+use protocol::{
+    Breakpoint, Capabilities, Event, LaunchArguments, OutgoingMessage, Request, Response, Scope,
+    SetBreakpointsArguments, Source, StackFrame, Thread, Variable, VariablesArguments,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeMap,
+    io::{BufRead, Write},
+    path::PathBuf,
+};
+use sway_core::source_map::SourceMap;
+
+/// The id of the only thread, as the VM is single threaded.
+const THREAD_ID: i64 = 1;
+/// The reference of the scope containing the VM registers.
+const REGISTERS_REFERENCE: i64 = 1;
+/// The reference of the scope containing the arguments and locals of the current function.
+const LOCALS_REFERENCE: i64 = 2;
+/// The size in bytes of a VM instruction.
+const INSTRUCTION_SIZE: u64 = 4;
+
+/// The state of a launched program.
+struct Session {
+    backend: Box<dyn DebugBackend>,
+    tx: Transaction,
+    source_locations: SourceLocations,
+    stop_on_entry: bool,
+    /// The indices of the instructions with breakpoints, keyed by source file.
+    breakpoints: BTreeMap<PathBuf, Vec<u64>>,
+    /// Whether the transaction has started executing.
+    started: bool,
+    /// The offset in bytes of the instruction the VM is stopped at, if it is stopped.
+    stopped_at: Option<u64>,
+}
+
+impl Session {
+    /// Whether a breakpoint is set on the instruction at index `instruction`.
+    fn has_breakpoint(&self, instruction: u64) -> bool {
+        self.breakpoints
+            .values()
+            .flatten()
+            .any(|i| *i == instruction)
+    }
+}
+
+/// Why the VM stopped, as reported in the `stopped` event.
+enum StopReason {
+    Entry,
+    Breakpoint,
+    Step,
+}
+
+impl StopReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StopReason::Entry => "entry",
+            StopReason::Breakpoint => "breakpoint",
+            StopReason::Step => "step",
+        }
+    }
+}
+
+pub struct DapServer {
+    input: Box<dyn BufRead>,
+    output: Box<dyn Write>,
+    seq: i64,
+    session: Option<Session>,
+    next_breakpoint_id: i64,
+}
+
+impl Default for DapServer {
+    fn default() -> Self {
+        Self::new(
+            Box::new(std::io::BufReader::new(std::io::stdin())),
+            Box::new(std::io::stdout()),
+        )
+    }
+}
+
+impl DapServer {
+    pub fn new(input: Box<dyn BufRead>, output: Box<dyn Write>) -> Self {
+        DapServer {
+            input,
+            output,
+            seq: 0,
+            session: None,
+            next_breakpoint_id: 1,
+        }
+    }
+
+    /// Serve requests until the client disconnects or closes the input.
+    pub fn start(&mut self) -> Result<()> {
+        while let Some(request) = protocol::read_request(&mut self.input)? {
+            let exit = request.command == "disconnect";
+            let result = self.handle_request(&request);
+            let success = result.is_ok();
+            let response = match result {
+                Ok(body) => Response {
+                    request_seq: request.seq,
+                    success: true,
+                    command: request.command.clone(),
+                    message: None,
+                    body,
+                },
+                Err(err) => Response {
+                    request_seq: request.seq,
+                    success: false,
+                    command: request.command.clone(),
+                    message: Some(err.to_string()),
+                    body: None,
+                },
+            };
+            self.send(OutgoingMessage::Response(response))?;
+            if success {
+                if let Err(err) = self.after_response(&request.command) {
+                    self.send_event(
+                        "output",
+                        Some(json!({ "category": "stderr", "output": format!("{err}\n") })),
+                    )?;
+                }
+            }
+            if exit {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: &Request) -> Result<Option<Value>> {
+        match request.command.as_str() {
+            "initialize" => to_body(Capabilities {
+                supports_configuration_done_request: true,
+            }),
+            "launch" => {
+                self.launch(parse_arguments(request)?)?;
+                Ok(None)
+            }
+            "setBreakpoints" => {
+                let breakpoints = self.set_breakpoints(parse_arguments(request)?)?;
+                Ok(Some(json!({ "breakpoints": breakpoints })))
+            }
+            "setExceptionBreakpoints" | "configurationDone" => Ok(None),
+            "threads" => Ok(Some(json!({
+                "threads": [Thread { id: THREAD_ID, name: "main".into() }]
+            }))),
+            "stackTrace" => {
+                let stack_frames = self.stack_trace()?;
+                Ok(Some(json!({
+                    "stackFrames": stack_frames,
+                    "totalFrames": stack_frames.len(),
+                })))
+            }
+            "scopes" => Ok(Some(json!({
+                "scopes": [
+                    Scope {
+                        name: "Locals".into(),
+                        variables_reference: LOCALS_REFERENCE,
+                        expensive: false,
+                    },
+                    Scope {
+                        name: "Registers".into(),
+                        variables_reference: REGISTERS_REFERENCE,
+                        expensive: false,
+                    },
+                ]
+            }))),
+            "variables" => {
+                let variables = self.variables(parse_arguments(request)?)?;
+                Ok(Some(json!({ "variables": variables })))
+            }
+            "continue" => Ok(Some(json!({ "allThreadsContinued": true }))),
+            "next" => Ok(None),
+            "disconnect" => {
+                self.session = None;
+                Ok(None)
+            }
+            command => Err(Error::UnsupportedRequest(command.to_string())),
+        }
+    }
+
+    /// Handle the work of a request which must happen after its response has been sent, such
+    /// as resuming execution, which is reported by events.
+    fn after_response(&mut self, command: &str) -> Result<()> {
+        match command {
+            "launch" => self.send_event("initialized", None),
+            "configurationDone" => self.run(),
+            "continue" => self.resume(),
+            "next" => self.step(),
+            _ => Ok(()),
+        }
+    }
+
+    fn launch(&mut self, args: LaunchArguments) -> Result<()> {
+        let tx_json =
+            std::fs::read(&args.program).map_err(|err| Error::ReadFile(args.program, err))?;
+        let tx: Transaction = serde_json::from_slice(&tx_json)?;
+        let source_map_json =
+            std::fs::read(&args.source_map).map_err(|err| Error::ReadFile(args.source_map, err))?;
+        let source_map: SourceMap = serde_json::from_slice(&source_map_json)?;
+
+        let backend: Box<dyn DebugBackend> = if args.in_process {
+            Box::<InterpreterBackend>::default()
+        } else {
+            Box::new(NodeBackend::new(&args.api_url)?)
+        };
+        self.session = Some(Session {
+            backend,
+            tx,
+            source_locations: SourceLocations::new(&source_map),
+            stop_on_entry: args.stop_on_entry,
+            breakpoints: BTreeMap::new(),
+            started: false,
+            stopped_at: None,
+        });
+        Ok(())
+    }
+
+    fn set_breakpoints(&mut self, args: SetBreakpointsArguments) -> Result<Vec<Breakpoint>> {
+        let session = self.session.as_mut().ok_or(Error::NotLaunched)?;
+        let path = canonicalize(&args.source.path.clone().unwrap_or_default());
+
+        let mut breakpoints = Vec::new();
+        let mut instructions = Vec::new();
+        for source_breakpoint in args.breakpoints {
+            let instruction = session
+                .source_locations
+                .line_to_instruction(&path, source_breakpoint.line);
+            instructions.extend(instruction.map(|instruction| instruction as u64));
+            breakpoints.push(Breakpoint {
+                id: self.next_breakpoint_id,
+                verified: instruction.is_some(),
+                line: source_breakpoint.line,
+                source: args.source.clone(),
+                message: instruction
+                    .is_none()
+                    .then(|| "No code is generated for this line".to_string()),
+            });
+            self.next_breakpoint_id += 1;
+        }
+        let previous = session
+            .breakpoints
+            .insert(path, instructions)
+            .unwrap_or_default();
+
+        if session.started {
+            // Clear the breakpoints removed from this file.  Those which the backend can't
+            // remove are skipped when resuming, as they are no longer in `session.breakpoints`.
+            for instruction in previous {
+                if !session.has_breakpoint(instruction) {
+                    session
+                        .backend
+                        .remove_breakpoint(ContractId::zeroed(), instruction)?;
+                }
+            }
+        } else {
+            // Before the transaction has started, the session is simply reset and the
+            // breakpoints of every file are set again.
+            session.backend.reset()?;
+        }
+        for instruction in session.breakpoints.values().flatten() {
+            session
+                .backend
+                .set_breakpoint(ContractId::zeroed(), *instruction)?;
+        }
+        Ok(breakpoints)
+    }
+
+    fn stack_trace(&self) -> Result<Vec<StackFrame>> {
+        let session = self.session.as_ref().ok_or(Error::NotLaunched)?;
+        let Some(pc) = session.stopped_at else {
+            return Ok(Vec::new());
+        };
+        let location = session
+            .source_locations
+            .instruction_to_location((pc / INSTRUCTION_SIZE) as usize);
+        Ok(vec![StackFrame {
+            id: 0,
+            name: format!("pc {pc:#x}"),
+            source: location.map(|(path, _)| Source::from_path(path.to_path_buf())),
+            line: location.map_or(0, |(_, line_col)| line_col.line),
+            column: location.map_or(0, |(_, line_col)| line_col.column),
+        }])
+    }
+
+    fn variables(&mut self, args: VariablesArguments) -> Result<Vec<Variable>> {
+        let session = self.session.as_mut().ok_or(Error::NotLaunched)?;
+        match args.variables_reference {
+            REGISTERS_REFERENCE => (0..VM_REGISTER_COUNT)
+                .map(|index| {
+                    let value = session.backend.register(index)?;
+                    Ok(Variable {
+                        name: register_name(index),
+                        value: format!("{value:#x}"),
+                        ty: None,
+                        variables_reference: 0,
+                    })
+                })
+                .collect(),
+            LOCALS_REFERENCE => {
+                let Some(pc) = session.stopped_at else {
+                    return Ok(Vec::new());
+                };
+                let instruction = (pc / INSTRUCTION_SIZE) as usize;
+                Ok(session
+                    .source_locations
+                    .variables_at(instruction)
+                    .into_iter()
+                    .map(|var| Variable {
+                        name: var.name.clone(),
+                        value: read_value(session.backend.as_mut(), var)
+                            .unwrap_or_else(|err| format!("<{err}>")),
+                        ty: Some(var.ty.name.clone()),
+                        variables_reference: 0,
+                    })
+                    .collect())
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Start executing the transaction.
+    fn run(&mut self) -> Result<()> {
+        let session = self.session.as_mut().ok_or(Error::NotLaunched)?;
+        session.started = true;
+        if session.stop_on_entry {
+            session.backend.set_single_stepping(true)?;
+        }
+        let outcome = session.backend.start_tx(&session.tx)?;
+        if session.stop_on_entry {
+            self.step_to_new_line(outcome, None, StopReason::Entry)
+        } else {
+            self.run_to_breakpoint(outcome)
+        }
+    }
+
+    /// Continue executing until the next breakpoint.
+    fn resume(&mut self) -> Result<()> {
+        let session = self.session.as_mut().ok_or(Error::NotLaunched)?;
+        let outcome = session.backend.continue_tx()?;
+        self.run_to_breakpoint(outcome)
+    }
+
+    /// Keep executing, starting from `outcome`, past any removed breakpoints which the backend
+    /// couldn't clear, until the VM stops at a breakpoint which is still set.
+    fn run_to_breakpoint(&mut self, mut outcome: RunOutcome) -> Result<()> {
+        let session = self.session.as_mut().ok_or(Error::NotLaunched)?;
+        while let Some(breakpoint) = &outcome.breakpoint {
+            if session.has_breakpoint(breakpoint.pc / INSTRUCTION_SIZE) {
+                break;
+            }
+            outcome = session.backend.continue_tx()?;
+        }
+        self.handle_outcome(outcome, StopReason::Breakpoint)
+    }
+
+    /// Single-step until execution reaches a different source line.
+    fn step(&mut self) -> Result<()> {
+        let session = self.session.as_mut().ok_or(Error::NotLaunched)?;
+        let start_location = session.stopped_at.and_then(|pc| {
+            session
+                .source_locations
+                .instruction_to_location((pc / INSTRUCTION_SIZE) as usize)
+                .map(|(path, line_col)| (path.to_path_buf(), line_col.line))
+        });
+        session.backend.set_single_stepping(true)?;
+        let outcome = session.backend.continue_tx()?;
+        self.step_to_new_line(outcome, start_location, StopReason::Step)
+    }
+
+    /// Keep single-stepping, starting from `outcome`, until the VM stops at an instruction
+    /// mapped to a source line other than `start_location`, then turn single-stepping off again.
+    fn step_to_new_line(
+        &mut self,
+        mut outcome: RunOutcome,
+        start_location: Option<(PathBuf, i64)>,
+        reason: StopReason,
+    ) -> Result<()> {
+        let session = self.session.as_mut().ok_or(Error::NotLaunched)?;
+        while let Some(breakpoint) = &outcome.breakpoint {
+            let instruction = (breakpoint.pc / INSTRUCTION_SIZE) as usize;
+            let location = session.source_locations.exact_location(instruction);
+            let is_new_line = location.is_some_and(|(path, line_col)| {
+                start_location.as_ref() != Some(&(path.to_path_buf(), line_col.line))
+            });
+            if is_new_line {
+                break;
+            }
+            outcome = session.backend.continue_tx()?;
+        }
+        session.backend.set_single_stepping(false)?;
+        self.handle_outcome(outcome, reason)
+    }
+
+    /// Report the outcome of running the VM: either it stopped, or the transaction completed.
+    fn handle_outcome(&mut self, outcome: RunOutcome, reason: StopReason) -> Result<()> {
+        let session = self.session.as_mut().ok_or(Error::NotLaunched)?;
+        session.stopped_at = outcome.breakpoint.map(|breakpoint| breakpoint.pc);
+        if session.stopped_at.is_some() {
+            return self.send_event(
+                "stopped",
+                Some(json!({
+                    "reason": reason.as_str(),
+                    "threadId": THREAD_ID,
+                    "allThreadsStopped": true,
+                })),
+            );
+        }
+
+        for receipt in outcome.receipts {
+            self.send_event(
+                "output",
+                Some(json!({
+                    "category": "stdout",
+                    "output": format!("Receipt: {receipt:?}\n"),
+                })),
+            )?;
+        }
+        self.send_event("terminated", None)
+    }
+
+    fn send_event(&mut self, event: &str, body: Option<Value>) -> Result<()> {
+        self.send(OutgoingMessage::Event(Event {
+            event: event.to_string(),
+            body,
+        }))
+    }
+
+    fn send(&mut self, message: OutgoingMessage) -> Result<()> {
+        self.seq += 1;
+        protocol::write_message(&mut self.output, self.seq, &message)?;
+        Ok(())
+    }
+}
+
+fn parse_arguments<T: DeserializeOwned>(request: &Request) -> Result<T> {
+    serde_json::from_value(request.arguments.clone())
+        .map_err(|err| Error::InvalidArguments(request.command.clone(), err))
+}
+
+fn to_body(body: impl Serialize) -> Result<Option<Value>> {
+    Ok(Some(serde_json::to_value(body)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, io::Cursor, path::Path, rc::Rc};
+
+    /// The script which `example_tx.json` was compiled from.
+    const EXAMPLE_SCRIPT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/example_script.sw");
+    const EXAMPLE_TX: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/example_tx.json");
+
+    /// The source line of each instruction of the example script which is mapped to one.
+    const EXAMPLE_LINES: [(usize, usize); 12] = [
+        (4, 16),
+        (5, 16),
+        (6, 6),
+        (7, 7),
+        (8, 8),
+        (9, 8),
+        (10, 8),
+        (11, 16),
+        (12, 17),
+        (13, 9),
+        (14, 10),
+        (15, 8),
+    ];
+
+    /// A writer whose contents can still be read once the server owning it is done.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Write a source map of the example script, as `forc build --output-debug` would.
+    fn write_source_map(name: &str) -> PathBuf {
+        let text = std::fs::read_to_string(EXAMPLE_SCRIPT).unwrap();
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect::<Vec<_>>();
+        let map = EXAMPLE_LINES
+            .iter()
+            .map(|(instruction, line)| {
+                let start = line_starts[line - 1];
+                let span = json!({ "path": 0, "range": { "start": start, "end": start } });
+                (instruction.to_string(), span)
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let source_map = json!({
+            "dependency_paths": [],
+            "paths": [EXAMPLE_SCRIPT],
+            "map": map,
+        });
+        let path = std::env::temp_dir().join(format!(
+            "forc-debug-{}-{name}-source-map.json",
+            std::process::id()
+        ));
+        std::fs::write(&path, source_map.to_string()).unwrap();
+        path
+    }
+
+    fn launch_arguments(source_map: &Path, stop_on_entry: bool) -> Value {
+        json!({
+            "program": EXAMPLE_TX,
+            "sourceMap": source_map,
+            "inProcess": true,
+            "stopOnEntry": stop_on_entry,
+        })
+    }
+
+    /// Serve the `requests`, given as commands and their arguments, returning every message
+    /// sent to the client.
+    fn run_session(requests: &[(&str, Value)]) -> Vec<Value> {
+        let mut input = Vec::new();
+        for (idx, (command, arguments)) in requests.iter().enumerate() {
+            let content = json!({
+                "seq": idx + 1,
+                "type": "request",
+                "command": command,
+                "arguments": arguments,
+            })
+            .to_string();
+            write!(
+                input,
+                "Content-Length: {}\r\n\r\n{}",
+                content.len(),
+                content
+            )
+            .unwrap();
+        }
+
+        let output = SharedBuffer::default();
+        let mut server = DapServer::new(Box::new(Cursor::new(input)), Box::new(output.clone()));
+        server.start().unwrap();
+
+        let output = String::from_utf8(output.0.take()).unwrap();
+        let mut messages = Vec::new();
+        let mut rest = output.as_str();
+        while let Some((header, content)) = rest.split_once("\r\n\r\n") {
+            let len: usize = header
+                .strip_prefix("Content-Length: ")
+                .unwrap()
+                .parse()
+                .unwrap();
+            messages.push(serde_json::from_str(&content[..len]).unwrap());
+            rest = &content[len..];
+        }
+        messages
+    }
+
+    /// Describe each response and event other than output, e.g. `response next` or
+    /// `event stopped step`.
+    fn summarize(messages: &[Value]) -> Vec<String> {
+        messages
+            .iter()
+            .filter(|message| message["event"] != "output")
+            .map(|message| match message["type"].as_str().unwrap() {
+                "response" if message["success"] == true => {
+                    format!("response {}", message["command"].as_str().unwrap())
+                }
+                "response" => format!("failed {}", message["command"].as_str().unwrap()),
+                _ => match message["body"]["reason"].as_str() {
+                    Some(reason) => {
+                        format!("event {} {reason}", message["event"].as_str().unwrap())
+                    }
+                    None => format!("event {}", message["event"].as_str().unwrap()),
+                },
+            })
+            .collect()
+    }
+
+    /// The line of the top stack frame reported by each `stackTrace` response.
+    fn stack_trace_lines(messages: &[Value]) -> Vec<i64> {
+        messages
+            .iter()
+            .filter(|message| message["command"] == "stackTrace")
+            .map(|message| message["body"]["stackFrames"][0]["line"].as_i64().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_session_breakpoints() {
+        let source_map = write_source_map("breakpoints");
+        let source = json!({ "path": EXAMPLE_SCRIPT });
+        let thread = json!({ "threadId": THREAD_ID });
+        let messages = run_session(&[
+            ("initialize", json!({ "adapterID": "sway" })),
+            ("launch", launch_arguments(&source_map, false)),
+            (
+                "setBreakpoints",
+                json!({ "source": source, "breakpoints": [{ "line": 10 }, { "line": 4 }] }),
+            ),
+            ("configurationDone", Value::Null),
+            ("stackTrace", thread.clone()),
+            ("continue", thread.clone()),
+            ("stackTrace", thread.clone()),
+            // Removing the breakpoint once running lets the transaction run to completion.
+            (
+                "setBreakpoints",
+                json!({ "source": source, "breakpoints": [] }),
+            ),
+            ("continue", thread),
+            ("disconnect", Value::Null),
+        ]);
+        std::fs::remove_file(source_map).unwrap();
+
+        assert_eq!(
+            summarize(&messages),
+            [
+                "response initialize",
+                "response launch",
+                "event initialized",
+                "response setBreakpoints",
+                "response configurationDone",
+                "event stopped breakpoint",
+                "response stackTrace",
+                "response continue",
+                "event stopped breakpoint",
+                "response stackTrace",
+                "response setBreakpoints",
+                "response continue",
+                "event terminated",
+                "response disconnect",
+            ]
+        );
+
+        let breakpoints = &messages[3]["body"]["breakpoints"];
+        assert_eq!(breakpoints[0]["line"], 10);
+        assert_eq!(breakpoints[0]["verified"], true);
+        assert_eq!(breakpoints[1]["line"], 4);
+        assert_eq!(breakpoints[1]["verified"], false);
+
+        assert_eq!(stack_trace_lines(&messages), [10, 10]);
+        assert!(messages.iter().any(|message| message["event"] == "output"
+            && message["body"]["output"]
+                .as_str()
+                .unwrap()
+                .contains("ra: 120")));
+    }
+
+    #[test]
+    fn test_session_stepping() {
+        let source_map = write_source_map("stepping");
+        let thread = json!({ "threadId": THREAD_ID });
+        let messages = run_session(&[
+            ("initialize", json!({ "adapterID": "sway" })),
+            ("launch", launch_arguments(&source_map, true)),
+            ("configurationDone", Value::Null),
+            ("stackTrace", thread.clone()),
+            ("next", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("next", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("next", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("next", thread.clone()),
+            ("stackTrace", thread.clone()),
+            ("stepIn", thread.clone()),
+            ("stepOut", thread),
+            ("disconnect", Value::Null),
+        ]);
+        std::fs::remove_file(source_map).unwrap();
+
+        assert_eq!(
+            summarize(&messages),
+            [
+                "response initialize",
+                "response launch",
+                "event initialized",
+                "response configurationDone",
+                "event stopped entry",
+                "response stackTrace",
+                "response next",
+                "event stopped step",
+                "response stackTrace",
+                "response next",
+                "event stopped step",
+                "response stackTrace",
+                "response next",
+                "event stopped step",
+                "response stackTrace",
+                "response next",
+                "event stopped step",
+                "response stackTrace",
+                "failed stepIn",
+                "failed stepOut",
+                "response disconnect",
+            ]
+        );
+
+        // Stepping from the call of `factorial` through its first lines, into the loop.
+        assert_eq!(stack_trace_lines(&messages), [16, 6, 7, 8, 9]);
+    }
+}
@@ -0,0 +1,247 @@
+//! The subset of the Debug Adapter Protocol used by the server, and the framing of its messages.
+//!
+//! See <https://microsoft.github.io/debug-adapter-protocol/specification>.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+/// A request from the client.
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub seq: i64,
+    pub command: String,
+    #[serde(default)]
+    pub arguments: Value,
+}
+
+/// A response to a client request.
+#[derive(Debug, Serialize)]
+pub struct Response {
+    pub request_seq: i64,
+    pub success: bool,
+    pub command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// An event sent to the client.
+#[derive(Debug, Serialize)]
+pub struct Event {
+    pub event: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// A message sent to the client.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum OutgoingMessage {
+    Response(Response),
+    Event(Event),
+}
+
+/// A message sent to the client, along with its sequence number.
+#[derive(Debug, Serialize)]
+struct SequencedMessage<'a> {
+    seq: i64,
+    #[serde(flatten)]
+    message: &'a OutgoingMessage,
+}
+
+/// The arguments of the `launch` request.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchArguments {
+    /// The path to the JSON transaction to execute.
+    pub program: PathBuf,
+    /// The path to the JSON source map of the script, as output by `forc build --output-debug`.
+    pub source_map: PathBuf,
+    /// The URL of the fuel-core GraphQL API executing the transaction.
+    #[serde(default = "default_api_url")]
+    pub api_url: String,
+    /// Whether to execute the transaction on an in-process interpreter, rather than on the
+    /// fuel-core node at `api_url`.
+    #[serde(default)]
+    pub in_process: bool,
+    /// Whether to stop at the first instruction which maps to the source.
+    #[serde(default)]
+    pub stop_on_entry: bool,
+}
+
+fn default_api_url() -> String {
+    "http://127.0.0.1:4000/graphql".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetBreakpointsArguments {
+    pub source: Source,
+    #[serde(default)]
+    pub breakpoints: Vec<SourceBreakpoint>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceBreakpoint {
+    pub line: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariablesArguments {
+    pub variables_reference: i64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Source {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl Source {
+    pub fn from_path(path: PathBuf) -> Self {
+        Source {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+            path: Some(path),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    pub supports_configuration_done_request: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Breakpoint {
+    pub id: i64,
+    pub verified: bool,
+    pub line: i64,
+    pub source: Source,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Thread {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StackFrame {
+    pub id: i64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+    pub line: i64,
+    pub column: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Scope {
+    pub name: String,
+    pub variables_reference: i64,
+    pub expensive: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Variable {
+    pub name: String,
+    pub value: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    pub variables_reference: i64,
+}
+
+/// Read the next message from the client, returning `None` at the end of the input.
+pub fn read_request(input: &mut impl BufRead) -> io::Result<Option<Request>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length =
+                    Some(value.trim().parse::<usize>().map_err(|err| {
+                        io::Error::new(io::ErrorKind::InvalidData, err.to_string())
+                    })?);
+            }
+        }
+    }
+
+    let content_length = content_length.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "Missing Content-Length header")
+    })?;
+    let mut content = vec![0; content_length];
+    input.read_exact(&mut content)?;
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Write a message, with the sequence number `seq`, to the client.
+pub fn write_message(
+    output: &mut impl Write,
+    seq: i64,
+    message: &OutgoingMessage,
+) -> io::Result<()> {
+    let content = serde_json::to_string(&SequencedMessage { seq, message })?;
+    write!(
+        output,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    output.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_request() {
+        let content =
+            r#"{"seq":1,"type":"request","command":"initialize","arguments":{"adapterID":"sway"}}"#;
+        let message = format!("Content-Length: {}\r\n\r\n{}", content.len(), content);
+        let mut input = message.as_bytes();
+
+        let request = read_request(&mut input).unwrap().unwrap();
+        assert_eq!(request.seq, 1);
+        assert_eq!(request.command, "initialize");
+        assert_eq!(request.arguments["adapterID"], "sway");
+        assert!(read_request(&mut input).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_write_message() {
+        let mut output = Vec::new();
+        let message = OutgoingMessage::Event(Event {
+            event: "initialized".into(),
+            body: None,
+        });
+        write_message(&mut output, 3, &message).unwrap();
+
+        let content = r#"{"seq":3,"type":"event","event":"initialized"}"#;
+        let expected = format!("Content-Length: {}\r\n\r\n{}", content.len(), content);
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+}
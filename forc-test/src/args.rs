@@ -0,0 +1,733 @@
+//! The arguments of tests taking parameters: their types, as described by the ABI of the test
+//! function, parsing them from the cases of the test and encoding them into the script data the
+//! test reads them from.
+
+use anyhow::{anyhow, bail, Result};
+use fuel_abi_types::abi::program::{TypeApplication, TypeDeclaration};
+use std::{collections::HashMap, fmt};
+
+/// The type of an argument of a test, resolved from the ABI types of its parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamType {
+    Unit,
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U256,
+    B256,
+    StringArray(usize),
+    Array(Box<ParamType>, usize),
+    Tuple(Vec<ParamType>),
+    Struct {
+        name: String,
+        fields: Vec<(String, ParamType)>,
+    },
+    Enum {
+        name: String,
+        variants: Vec<(String, ParamType)>,
+    },
+}
+
+/// The value of an argument of a test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Unit,
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    /// The big-endian bytes of a `u256`.
+    U256([u8; 32]),
+    B256([u8; 32]),
+    StringArray(String),
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
+    Struct {
+        name: String,
+        fields: Vec<(String, Value)>,
+    },
+    Enum {
+        name: String,
+        variant: usize,
+        variant_name: String,
+        value: Box<Value>,
+    },
+}
+
+impl ParamType {
+    /// Resolves the types of the parameters from their ABI, substituting the generic types of
+    /// structs and enums with their type arguments.
+    pub fn from_params(params: &[TypeApplication], types: &[TypeDeclaration]) -> Result<Vec<Self>> {
+        let types = types
+            .iter()
+            .map(|decl| (decl.type_id, decl))
+            .collect::<HashMap<_, _>>();
+        params
+            .iter()
+            .map(|param| Self::from_type_application(param, &types, &HashMap::new()))
+            .collect()
+    }
+
+    fn from_type_application(
+        application: &TypeApplication,
+        types: &HashMap<usize, &TypeDeclaration>,
+        generics: &HashMap<usize, ParamType>,
+    ) -> Result<Self> {
+        let decl = types
+            .get(&application.type_id)
+            .ok_or_else(|| anyhow!("missing ABI type with id {}", application.type_id))?;
+        let type_field = decl.type_field.as_str();
+        if type_field.starts_with("generic ") {
+            return generics
+                .get(&decl.type_id)
+                .cloned()
+                .ok_or_else(|| anyhow!("unresolved generic type `{type_field}`"));
+        }
+
+        // The generic types of the declaration are bound to the type arguments of the application.
+        let type_arguments = application
+            .type_arguments
+            .iter()
+            .flatten()
+            .map(|argument| Self::from_type_application(argument, types, generics))
+            .collect::<Result<Vec<_>>>()?;
+        let generics = decl
+            .type_parameters
+            .iter()
+            .flatten()
+            .copied()
+            .zip(type_arguments)
+            .collect::<HashMap<_, _>>();
+        let components = || {
+            decl.components
+                .iter()
+                .flatten()
+                .map(|component| {
+                    Self::from_type_application(component, types, &generics)
+                        .map(|ty| (component.name.clone(), ty))
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        let ty = match type_field {
+            "()" => ParamType::Unit,
+            "bool" => ParamType::Bool,
+            "u8" => ParamType::U8,
+            "u16" => ParamType::U16,
+            "u32" => ParamType::U32,
+            "u64" => ParamType::U64,
+            "u256" => ParamType::U256,
+            "b256" => ParamType::B256,
+            _ if type_field.starts_with("str[") => {
+                let len = type_field
+                    .trim_start_matches("str[")
+                    .trim_end_matches(']')
+                    .parse()?;
+                ParamType::StringArray(len)
+            }
+            _ if type_field.starts_with('[') => {
+                let len = type_field
+                    .rsplit_once("; ")
+                    .map(|(_, len)| len.trim_end_matches(']'))
+                    .ok_or_else(|| anyhow!("invalid array type `{type_field}`"))?
+                    .parse()?;
+                let (_, elem) = components()?
+                    .pop()
+                    .ok_or_else(|| anyhow!("missing element type of `{type_field}`"))?;
+                ParamType::Array(Box::new(elem), len)
+            }
+            _ if type_field.starts_with('(') => {
+                ParamType::Tuple(components()?.into_iter().map(|(_, ty)| ty).collect())
+            }
+            _ if type_field.starts_with("struct ") => ParamType::Struct {
+                name: type_name(type_field),
+                fields: components()?,
+            },
+            _ if type_field.starts_with("enum ") => ParamType::Enum {
+                name: type_name(type_field),
+                variants: components()?,
+            },
+            _ => bail!("arguments of type `{type_field}` are not supported"),
+        };
+        Ok(ty)
+    }
+
+    /// Whether values of this type are passed to the test by value, rather than by reference.
+    fn is_copy(&self) -> bool {
+        matches!(
+            self,
+            ParamType::Unit
+                | ParamType::Bool
+                | ParamType::U8
+                | ParamType::U16
+                | ParamType::U32
+                | ParamType::U64
+        )
+    }
+
+    /// The size in bytes of the values of this type in memory.
+    fn size(&self) -> usize {
+        match self {
+            ParamType::Unit | ParamType::Bool | ParamType::U8 => 1,
+            ParamType::U16 | ParamType::U32 | ParamType::U64 => 8,
+            ParamType::U256 | ParamType::B256 => 32,
+            ParamType::StringArray(len) => word_aligned(*len),
+            ParamType::Array(elem, len) => elem.size() * len,
+            ParamType::Tuple(fields) => fields.iter().map(|ty| word_aligned(ty.size())).sum(),
+            ParamType::Struct { fields, .. } => {
+                fields.iter().map(|(_, ty)| word_aligned(ty.size())).sum()
+            }
+            ParamType::Enum { .. } => 8 + self.union_size(),
+        }
+    }
+
+    /// The size in bytes of the values of the variants of an enum, which is zero if all of its
+    /// variants are unit.
+    fn union_size(&self) -> usize {
+        match self {
+            ParamType::Enum { variants, .. }
+                if variants.iter().any(|(_, ty)| *ty != ParamType::Unit) =>
+            {
+                variants
+                    .iter()
+                    .map(|(_, ty)| word_aligned(ty.size()))
+                    .max()
+                    .unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// Parses the arguments of a case of a test, e.g., `1, true, Color::Red`, written as the values
+/// would be in Sway.
+pub fn parse_args(case: &str, params: &[ParamType]) -> Result<Vec<Value>> {
+    let mut parser = Parser::new(case);
+    let mut args = vec![];
+    for (i, ty) in params.iter().enumerate() {
+        if i > 0 {
+            parser.expect(",")?;
+        }
+        args.push(parser.value(ty)?);
+    }
+    parser.eat(",");
+    if !parser.rest().is_empty() {
+        bail!(
+            "expected {} argument(s), found `{}` after them",
+            params.len(),
+            parser.rest()
+        );
+    }
+    Ok(args)
+}
+
+/// Encodes the arguments into the script data the test reads them from.
+///
+/// A single argument is passed as a word if it is a copy type, and laid out as in memory
+/// otherwise. Multiple arguments are laid out as the fields of a struct.
+pub fn encode_args(args: &[Value], params: &[ParamType]) -> Vec<u8> {
+    let mut bytes = vec![];
+    match (args, params) {
+        ([], _) => {}
+        ([arg], [ty]) if ty.is_copy() => bytes.extend(arg.as_word().to_be_bytes()),
+        ([arg], [ty]) => arg.encode(ty, &mut bytes),
+        _ => {
+            for (arg, ty) in args.iter().zip(params) {
+                arg.encode(ty, &mut bytes);
+                pad_to_word(&mut bytes);
+            }
+        }
+    }
+    bytes
+}
+
+impl Value {
+    /// The value of a copy type as the word it is loaded into.
+    fn as_word(&self) -> u64 {
+        match self {
+            Value::Bool(value) => *value as u64,
+            Value::U8(value) => *value as u64,
+            Value::U16(value) => *value as u64,
+            Value::U32(value) => *value as u64,
+            Value::U64(value) => *value,
+            _ => 0,
+        }
+    }
+
+    /// Appends the bytes of the value as laid out in memory.
+    fn encode(&self, ty: &ParamType, bytes: &mut Vec<u8>) {
+        match (self, ty) {
+            (Value::Unit, _) => bytes.push(0),
+            (Value::Bool(_) | Value::U8(_), _) => bytes.push(self.as_word() as u8),
+            (Value::U16(_) | Value::U32(_) | Value::U64(_), _) => {
+                bytes.extend(self.as_word().to_be_bytes())
+            }
+            (Value::U256(value) | Value::B256(value), _) => bytes.extend(value),
+            (Value::StringArray(value), _) => {
+                bytes.extend(value.as_bytes());
+                pad_to_word(bytes);
+            }
+            (Value::Array(elems), ParamType::Array(elem_ty, _)) => {
+                for elem in elems {
+                    elem.encode(elem_ty, bytes);
+                }
+            }
+            (Value::Tuple(fields), ParamType::Tuple(field_tys)) => {
+                for (field, field_ty) in fields.iter().zip(field_tys) {
+                    field.encode(field_ty, bytes);
+                    pad_to_word(bytes);
+                }
+            }
+            (
+                Value::Struct { fields, .. },
+                ParamType::Struct {
+                    fields: field_tys, ..
+                },
+            ) => {
+                for ((_, field), (_, field_ty)) in fields.iter().zip(field_tys) {
+                    field.encode(field_ty, bytes);
+                    pad_to_word(bytes);
+                }
+            }
+            (Value::Enum { variant, value, .. }, ParamType::Enum { variants, .. }) => {
+                bytes.extend((*variant as u64).to_be_bytes());
+                let union_size = ty.union_size();
+                if union_size > 0 {
+                    // The value of the variant is right-aligned within the union.
+                    let variant_ty = &variants[*variant].1;
+                    bytes.resize(bytes.len() + union_size - variant_ty.size(), 0);
+                    value.encode(variant_ty, bytes);
+                }
+            }
+            _ => unreachable!("the value `{self}` is not of type `{ty:?}`"),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Unit => write!(f, "()"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::U8(value) => write!(f, "{value}"),
+            Value::U16(value) => write!(f, "{value}"),
+            Value::U32(value) => write!(f, "{value}"),
+            Value::U64(value) => write!(f, "{value}"),
+            Value::U256(value) => write!(f, "0x{}u256", hex::encode(value)),
+            Value::B256(value) => write!(f, "0x{}", hex::encode(value)),
+            Value::StringArray(value) => write!(f, "{value:?}"),
+            Value::Array(elems) => write!(f, "[{}]", Values(elems)),
+            Value::Tuple(fields) if fields.len() == 1 => write!(f, "({},)", fields[0]),
+            Value::Tuple(fields) => write!(f, "({})", Values(fields)),
+            Value::Struct { name, fields } => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}"))
+                    .collect::<Vec<_>>();
+                write!(f, "{name} {{ {} }}", fields.join(", "))
+            }
+            Value::Enum {
+                name,
+                variant_name,
+                value,
+                ..
+            } => match **value {
+                Value::Unit => write!(f, "{name}::{variant_name}"),
+                _ => write!(f, "{name}::{variant_name}({value})"),
+            },
+        }
+    }
+}
+
+/// Values separated by commas, e.g., the arguments of a test.
+pub struct Values<'a>(pub &'a [Value]);
+
+impl fmt::Display for Values<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The name of a struct or an enum from its ABI type, e.g., `Point` for `struct lib::Point`.
+fn type_name(type_field: &str) -> String {
+    let name = type_field
+        .split_once(' ')
+        .map_or(type_field, |(_, name)| name);
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+fn word_aligned(size: usize) -> usize {
+    (size + 7) - ((size + 7) % 8)
+}
+
+fn pad_to_word(bytes: &mut Vec<u8>) {
+    bytes.resize(word_aligned(bytes.len()), 0);
+}
+
+/// A parser of the values of arguments, guided by their types.
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        self.src[self.pos..].trim_start()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.pos = self.src.len() - self.rest().len();
+    }
+
+    /// Consumes `token` if the remaining text starts with it.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<()> {
+        if !self.eat(token) {
+            bail!("expected `{token}`, found `{}`", self.rest());
+        }
+        Ok(())
+    }
+
+    /// Consumes the longest prefix of the remaining text made of the characters matching `pred`.
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn ident(&mut self) -> &'a str {
+        self.take_while(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    fn value(&mut self, ty: &ParamType) -> Result<Value> {
+        let value = match ty {
+            ParamType::Unit => {
+                self.expect("(")?;
+                self.expect(")")?;
+                Value::Unit
+            }
+            ParamType::Bool => match self.ident() {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                other => bail!("expected a `bool`, found `{other}`"),
+            },
+            ParamType::U8 => Value::U8(self.uint(u8::MAX as u128)? as u8),
+            ParamType::U16 => Value::U16(self.uint(u16::MAX as u128)? as u16),
+            ParamType::U32 => Value::U32(self.uint(u32::MAX as u128)? as u32),
+            ParamType::U64 => Value::U64(self.uint(u64::MAX as u128)? as u64),
+            ParamType::U256 => Value::U256(self.big_uint()?),
+            ParamType::B256 => {
+                let literal = self.ident();
+                let bytes = literal
+                    .strip_prefix("0x")
+                    .and_then(|digits| hex::decode(digits.replace('_', "")).ok())
+                    .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                    .ok_or_else(|| anyhow!("expected a `b256`, found `{literal}`"))?;
+                Value::B256(bytes)
+            }
+            ParamType::StringArray(len) => {
+                let value = self.string()?;
+                if value.len() != *len {
+                    bail!("expected a `str[{len}]`, found {value:?}");
+                }
+                Value::StringArray(value)
+            }
+            ParamType::Array(elem_ty, len) => {
+                self.expect("[")?;
+                let elems = self.comma_separated("]", |parser, _| parser.value(elem_ty))?;
+                if elems.len() != *len {
+                    bail!("expected {len} element(s), found {}", elems.len());
+                }
+                Value::Array(elems)
+            }
+            ParamType::Tuple(field_tys) => {
+                self.expect("(")?;
+                let fields = self.comma_separated(")", |parser, i| match field_tys.get(i) {
+                    Some(field_ty) => parser.value(field_ty),
+                    None => bail!("expected {} field(s)", field_tys.len()),
+                })?;
+                if fields.len() != field_tys.len() {
+                    bail!(
+                        "expected {} field(s), found {}",
+                        field_tys.len(),
+                        fields.len()
+                    );
+                }
+                Value::Tuple(fields)
+            }
+            ParamType::Struct { name, fields } => {
+                self.path_to(name);
+                let start = self.pos;
+                if self.ident() != name {
+                    self.pos = start;
+                }
+                self.expect("{")?;
+                let mut values: Vec<Option<Value>> = vec![None; fields.len()];
+                self.comma_separated("}", |parser, _| {
+                    let field_name = parser.ident();
+                    let i = fields
+                        .iter()
+                        .position(|(name, _)| name == field_name)
+                        .ok_or_else(|| anyhow!("`{name}` has no field `{field_name}`"))?;
+                    parser.expect(":")?;
+                    values[i] = Some(parser.value(&fields[i].1)?);
+                    Ok(())
+                })?;
+                let fields = fields
+                    .iter()
+                    .zip(values)
+                    .map(|((field_name, _), value)| {
+                        value
+                            .map(|value| (field_name.clone(), value))
+                            .ok_or_else(|| anyhow!("missing field `{field_name}` of `{name}`"))
+                    })
+                    .collect::<Result<_>>()?;
+                Value::Struct {
+                    name: name.clone(),
+                    fields,
+                }
+            }
+            ParamType::Enum { name, variants } => {
+                self.path_to(name);
+                let variant_name = self.ident();
+                let variant = variants
+                    .iter()
+                    .position(|(name, _)| name == variant_name)
+                    .ok_or_else(|| anyhow!("`{name}` has no variant `{variant_name}`"))?;
+                let variant_ty = &variants[variant].1;
+                let value = if *variant_ty == ParamType::Unit && !self.rest().starts_with('(') {
+                    Value::Unit
+                } else {
+                    self.expect("(")?;
+                    let value = self.value(variant_ty)?;
+                    self.expect(")")?;
+                    value
+                };
+                Value::Enum {
+                    name: name.clone(),
+                    variant,
+                    variant_name: variant_name.to_string(),
+                    value: Box::new(value),
+                }
+            }
+        };
+        Ok(value)
+    }
+
+    /// Parses the items of a list closed by `close`, allowing a trailing comma.
+    fn comma_separated<T>(
+        &mut self,
+        close: &str,
+        mut item: impl FnMut(&mut Self, usize) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![];
+        while !self.eat(close) {
+            if !items.is_empty() {
+                self.expect(",")?;
+                if self.eat(close) {
+                    break;
+                }
+            }
+            items.push(item(self, items.len())?);
+        }
+        Ok(items)
+    }
+
+    /// Consumes the path leading to a struct or an enum named `name`, e.g., `lib::Point` or
+    /// `Color::` before a variant, if any.
+    fn path_to(&mut self, name: &str) {
+        loop {
+            let start = self.pos;
+            let segment = self.ident();
+            if segment.is_empty() || !self.eat("::") {
+                self.pos = start;
+                return;
+            }
+            if segment == name {
+                return;
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.expect("\"")?;
+        let mut value = String::new();
+        let mut chars = self.src[self.pos..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(value);
+                }
+                '\\' => match chars.next() {
+                    Some((_, '"')) => value.push('"'),
+                    Some((_, '\\')) => value.push('\\'),
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, 't')) => value.push('\t'),
+                    _ => bail!("invalid escape sequence in string"),
+                },
+                c => value.push(c),
+            }
+        }
+        bail!("unterminated string")
+    }
+
+    /// Parses an unsigned integer literal, in decimal, hexadecimal or binary, with an optional
+    /// type suffix.
+    fn uint(&mut self, max: u128) -> Result<u128> {
+        let literal = self.ident();
+        let digits = literal
+            .trim_end_matches(|c: char| c.is_ascii_digit())
+            .strip_suffix('u')
+            .filter(|_| !literal.starts_with("0x"))
+            .unwrap_or(literal)
+            .replace('_', "");
+        let value = if let Some(hex) = digits.strip_prefix("0x") {
+            u128::from_str_radix(hex, 16)
+        } else if let Some(bin) = digits.strip_prefix("0b") {
+            u128::from_str_radix(bin, 2)
+        } else {
+            digits.parse()
+        };
+        match value {
+            Ok(value) if value <= max => Ok(value),
+            _ => bail!("expected an integer of at most {max}, found `{literal}`"),
+        }
+    }
+
+    /// Parses a `u256` literal, in decimal or hexadecimal, with an optional `u256` suffix.
+    fn big_uint(&mut self) -> Result<[u8; 32]> {
+        let literal = self.ident();
+        let digits = literal.trim_end_matches("u256").replace('_', "");
+        let mut bytes = [0u8; 32];
+        let (radix, digits) = match digits.strip_prefix("0x") {
+            Some(hex) => (16, hex),
+            None => (10, digits.as_str()),
+        };
+        if digits.is_empty() {
+            bail!("expected a `u256`, found `{literal}`");
+        }
+        for c in digits.chars() {
+            let digit = c
+                .to_digit(radix)
+                .ok_or_else(|| anyhow!("expected a `u256`, found `{literal}`"))?;
+            // bytes = bytes * radix + digit
+            let mut carry = digit;
+            for byte in bytes.iter_mut().rev() {
+                let value = *byte as u32 * radix + carry;
+                *byte = value as u8;
+                carry = value >> 8;
+            }
+            if carry != 0 {
+                bail!("`{literal}` overflows a `u256`");
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point() -> ParamType {
+        ParamType::Struct {
+            name: "Point".into(),
+            fields: vec![("x".into(), ParamType::U8), ("y".into(), ParamType::U64)],
+        }
+    }
+
+    fn shape() -> ParamType {
+        ParamType::Enum {
+            name: "Shape".into(),
+            variants: vec![
+                ("Empty".into(), ParamType::Unit),
+                ("Dot".into(), point()),
+                ("Size".into(), ParamType::U32),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_and_display_args() {
+        let params = vec![
+            ParamType::U64,
+            ParamType::Bool,
+            point(),
+            shape(),
+            ParamType::Array(Box::new(ParamType::U8), 2),
+            ParamType::Tuple(vec![ParamType::StringArray(3), ParamType::U256]),
+        ];
+        let case = "0x10, true, Point { y: 2, x: 1 }, Shape::Dot(Point { x: 3, y: 4 }), \
+                    [1u8, 2], (\"abc\", 0xffu256)";
+        let args = parse_args(case, &params).unwrap();
+        assert_eq!(args[0], Value::U64(16));
+        assert_eq!(
+            Values(&args).to_string(),
+            format!(
+                "16, true, Point {{ x: 1, y: 2 }}, Shape::Dot(Point {{ x: 3, y: 4 }}), [1, 2], \
+                 (\"abc\", 0x{}ffu256)",
+                "0".repeat(62)
+            )
+        );
+        // The displayed arguments can be parsed back.
+        assert_eq!(
+            parse_args(&Values(&args).to_string(), &params).unwrap(),
+            args
+        );
+
+        assert!(parse_args("256", &[ParamType::U8]).is_err());
+        assert!(parse_args("1, 2", &[ParamType::U8]).is_err());
+        assert!(parse_args("Point { x: 1 }", &[point()]).is_err());
+        assert!(parse_args("Shape::Circle", &[shape()]).is_err());
+    }
+
+    #[test]
+    fn encode_args_as_read_by_tests() {
+        // A single copy type is passed as a word.
+        let args = parse_args("7", &[ParamType::U8]).unwrap();
+        assert_eq!(encode_args(&args, &[ParamType::U8]), 7u64.to_be_bytes());
+
+        // Multiple arguments are laid out as a struct, with the bytes at the start of their word.
+        let params = [ParamType::U8, ParamType::U16];
+        let args = parse_args("1, 2", &params).unwrap();
+        let mut expected = vec![1, 0, 0, 0, 0, 0, 0, 0];
+        expected.extend(2u64.to_be_bytes());
+        assert_eq!(encode_args(&args, &params), expected);
+
+        // The value of a variant is right-aligned within the union of the enum.
+        let args = parse_args("Shape::Size(3)", &[shape()]).unwrap();
+        let mut expected = 2u64.to_be_bytes().to_vec();
+        expected.extend([0; 8]);
+        expected.extend(3u64.to_be_bytes());
+        assert_eq!(encode_args(&args, &[shape()]), expected);
+
+        let params = [ParamType::StringArray(3)];
+        let args = parse_args("\"abc\"", &params).unwrap();
+        assert_eq!(encode_args(&args, &params), b"abc\0\0\0\0\0");
+    }
+}
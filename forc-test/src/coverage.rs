@@ -0,0 +1,343 @@
+//! Code coverage of the tests of a package, reported per Sway source line and branch.
+
+use crate::TestedPackage;
+use fuel_vm::prelude::Instruction;
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The name of the lcov tracefile written by [CoverageReport::write].
+pub const LCOV_FILE_NAME: &str = "lcov.info";
+/// The name of the HTML summary written by [CoverageReport::write].
+pub const HTML_FILE_NAME: &str = "index.html";
+
+/// The instructions of a package's bytecode executed by a test.
+#[derive(Debug, Clone, Default)]
+pub struct TestCoverage {
+    /// The number of times each instruction was executed, by its index in the bytecode.
+    executed: BTreeMap<usize, u64>,
+    /// The number of times each instruction was followed by an instruction other than the next one,
+    /// i.e., it jumped.
+    jumped: BTreeMap<usize, u64>,
+}
+
+/// The coverage of a package by its tests.
+#[derive(Debug, Clone, Default)]
+pub struct CoverageReport {
+    /// The name of the package.
+    pub name: String,
+    /// The source files of the package with instructions in its bytecode, ordered by path.
+    pub files: Vec<FileCoverage>,
+}
+
+/// The coverage of a single source file.
+#[derive(Debug, Clone)]
+pub struct FileCoverage {
+    pub path: PathBuf,
+    /// The number of times each line with instructions was executed, by line number starting at 1.
+    pub lines: BTreeMap<usize, u64>,
+    /// The conditional jumps on the lines of the file, in order of their instructions.
+    pub branches: Vec<BranchCoverage>,
+    source: String,
+}
+
+/// The coverage of the two outcomes of a conditional jump.
+#[derive(Debug, Clone)]
+pub struct BranchCoverage {
+    /// The line of the jump, starting at 1.
+    pub line: usize,
+    /// The index of the jump instruction in the bytecode.
+    pub instruction: usize,
+    /// The number of times the jump was executed.
+    pub executed: u64,
+    /// The number of times the jump was taken.
+    pub taken: u64,
+}
+
+impl TestCoverage {
+    /// Record the execution of the instruction at `ix`, following the instruction at `previous`.
+    pub(crate) fn record(&mut self, previous: Option<usize>, ix: usize) {
+        *self.executed.entry(ix).or_default() += 1;
+        if let Some(previous) = previous {
+            if previous + 1 != ix {
+                *self.jumped.entry(previous).or_default() += 1;
+            }
+        }
+    }
+
+    /// Forget the executions of the instruction at `ix`.
+    pub(crate) fn remove(&mut self, ix: usize) {
+        self.executed.remove(&ix);
+        self.jumped.remove(&ix);
+    }
+
+    /// The number of times the instruction at `ix` was executed.
+    pub fn executed(&self, ix: usize) -> u64 {
+        self.executed.get(&ix).copied().unwrap_or(0)
+    }
+
+    /// The number of times the instruction at `ix` jumped.
+    pub fn jumped(&self, ix: usize) -> u64 {
+        self.jumped.get(&ix).copied().unwrap_or(0)
+    }
+
+    /// Add the executions recorded by `other`, e.g., by another test of the same package.
+    pub fn merge(&mut self, other: &TestCoverage) {
+        for (ix, count) in &other.executed {
+            *self.executed.entry(*ix).or_default() += count;
+        }
+        for (ix, count) in &other.jumped {
+            *self.jumped.entry(*ix).or_default() += count;
+        }
+    }
+}
+
+impl CoverageReport {
+    /// Map the instructions executed by the tests of `pkg` to the lines of its source files.
+    ///
+    /// Only the files within the package directory are reported, not those of its dependencies.
+    /// Tests executed without collecting their coverage don't contribute to the report.
+    pub fn from_tested(pkg: &TestedPackage) -> anyhow::Result<CoverageReport> {
+        let mut coverage = TestCoverage::default();
+        for test_coverage in pkg.tests.iter().filter_map(|test| test.coverage.as_ref()) {
+            coverage.merge(test_coverage);
+        }
+
+        let pkg_dir = pkg.built.descriptor.manifest_file.dir();
+        let mut files: BTreeMap<PathBuf, FileCoverage> = BTreeMap::new();
+        // The file and line of each mapped instruction, if it's within the package.
+        let mut locations: BTreeMap<usize, Option<(PathBuf, usize)>> = BTreeMap::new();
+        for (ix, path, range) in pkg.built.source_map().iter() {
+            if !path.starts_with(pkg_dir) {
+                locations.insert(ix, None);
+                continue;
+            }
+            let file = match files.get_mut(&path) {
+                Some(file) => file,
+                None => {
+                    let source = fs::read_to_string(&path).map_err(|err| {
+                        anyhow::anyhow!("failed to read {}: {err}", path.display())
+                    })?;
+                    files.entry(path.clone()).or_insert(FileCoverage {
+                        path: path.clone(),
+                        lines: BTreeMap::new(),
+                        branches: vec![],
+                        source,
+                    })
+                }
+            };
+
+            let line = line_number(&file.source, range.start);
+            // A line is executed as often as its most executed instruction.
+            let line_count = file.lines.entry(line).or_default();
+            *line_count = (*line_count).max(coverage.executed(ix));
+            locations.insert(ix, Some((path, line)));
+        }
+
+        // The jumps of conditionals often aren't mapped themselves, in which case they are
+        // attributed to the nearest preceding mapped instruction, i.e., to the condition.
+        let bytecode = &pkg.built.bytecode.bytes;
+        for ix in 0..bytecode.len() / Instruction::SIZE {
+            if !is_conditional_jump(bytecode, ix) {
+                continue;
+            }
+            let Some((_, Some((path, line)))) = locations.range(..=ix).next_back() else {
+                continue;
+            };
+            if let Some(file) = files.get_mut(path) {
+                file.branches.push(BranchCoverage {
+                    line: *line,
+                    instruction: ix,
+                    executed: coverage.executed(ix),
+                    taken: coverage.jumped(ix),
+                });
+            }
+        }
+
+        Ok(CoverageReport {
+            name: pkg.built.descriptor.name.clone(),
+            files: files.into_values().collect(),
+        })
+    }
+
+    /// Write the report in the lcov tracefile format and as an HTML summary to `dir`.
+    pub fn write(&self, dir: &Path) -> anyhow::Result<()> {
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(LCOV_FILE_NAME), self.to_lcov())?;
+        fs::write(dir.join(HTML_FILE_NAME), self.to_html())?;
+        Ok(())
+    }
+
+    /// The report in the lcov tracefile format, as read by `genhtml` and most coverage services.
+    ///
+    /// Each conditional jump is a block of two branches, the jump being taken and not.
+    pub fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for file in &self.files {
+            let _ = writeln!(lcov, "TN:{}", self.name);
+            let _ = writeln!(lcov, "SF:{}", file.path.display());
+            for branch in &file.branches {
+                for (ix, count) in [branch.taken, branch.not_taken()].into_iter().enumerate() {
+                    let count = match branch.executed {
+                        0 => "-".to_string(),
+                        _ => count.to_string(),
+                    };
+                    let _ = writeln!(
+                        lcov,
+                        "BRDA:{},{},{ix},{count}",
+                        branch.line, branch.instruction
+                    );
+                }
+            }
+            let _ = writeln!(lcov, "BRF:{}", file.branches.len() * 2);
+            let _ = writeln!(lcov, "BRH:{}", file.branches_hit());
+            for (line, count) in &file.lines {
+                let _ = writeln!(lcov, "DA:{line},{count}");
+            }
+            let _ = writeln!(lcov, "LF:{}", file.lines.len());
+            let _ = writeln!(lcov, "LH:{}", file.lines_hit());
+            let _ = writeln!(lcov, "end_of_record");
+        }
+        lcov
+    }
+
+    /// A standalone HTML page summarizing the coverage of each file, followed by its source with
+    /// the executed and missed lines highlighted.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let _ = writeln!(
+            html,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Coverage of {name}</title>\n<style>\n{HTML_STYLE}</style>\n</head>\n<body>\n\
+             <h1>Coverage of {name}</h1>",
+            name = escape_html(&self.name)
+        );
+
+        let _ = writeln!(
+            html,
+            "<table>\n<tr><th>File</th><th>Lines</th><th>Branches</th></tr>"
+        );
+        let (mut lines_hit, mut lines, mut branches_hit, mut branches) = (0, 0, 0, 0);
+        for (file_ix, file) in self.files.iter().enumerate() {
+            let _ = writeln!(
+                html,
+                "<tr><td><a href=\"#file{file_ix}\">{}</a></td>{}{}</tr>",
+                escape_html(&file.path.display().to_string()),
+                ratio_cell(file.lines_hit(), file.lines.len()),
+                ratio_cell(file.branches_hit(), file.branches.len() * 2),
+            );
+            lines_hit += file.lines_hit();
+            lines += file.lines.len();
+            branches_hit += file.branches_hit();
+            branches += file.branches.len() * 2;
+        }
+        let _ = writeln!(
+            html,
+            "<tr><th>Total</th>{}{}</tr>\n</table>",
+            ratio_cell(lines_hit, lines),
+            ratio_cell(branches_hit, branches),
+        );
+
+        for (file_ix, file) in self.files.iter().enumerate() {
+            let _ = writeln!(
+                html,
+                "<h2 id=\"file{file_ix}\">{}</h2>\n<pre>",
+                escape_html(&file.path.display().to_string())
+            );
+            for (line_ix, text) in file.source.lines().enumerate() {
+                let line = line_ix + 1;
+                let (class, count) = match file.lines.get(&line) {
+                    Some(0) => ("missed", "0".to_string()),
+                    Some(count) => ("hit", count.to_string()),
+                    None => ("none", String::new()),
+                };
+                let _ = writeln!(
+                    html,
+                    "<span class=\"{class}\"><span class=\"count\">{line:>5} {count:>6}</span> {}</span>",
+                    escape_html(text)
+                );
+            }
+            let _ = writeln!(html, "</pre>");
+        }
+        let _ = writeln!(html, "</body>\n</html>");
+        html
+    }
+}
+
+impl FileCoverage {
+    /// The number of lines which were executed.
+    pub fn lines_hit(&self) -> usize {
+        self.lines.values().filter(|count| **count > 0).count()
+    }
+
+    /// The number of branches, i.e., outcomes of conditional jumps, which were taken.
+    pub fn branches_hit(&self) -> usize {
+        self.branches
+            .iter()
+            .map(|branch| usize::from(branch.taken > 0) + usize::from(branch.not_taken() > 0))
+            .sum()
+    }
+}
+
+impl BranchCoverage {
+    /// The number of times the jump wasn't taken.
+    pub fn not_taken(&self) -> u64 {
+        self.executed.saturating_sub(self.taken)
+    }
+}
+
+const HTML_STYLE: &str = "body { font-family: sans-serif; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 2px 8px; text-align: left; }
+pre { font-size: 13px; }
+.count { color: #888; }
+.hit { background: #dfd; }
+.missed { background: #fdd; }
+";
+
+/// Whether the instruction at `ix` of `bytecode` may or may not jump, depending on a condition.
+fn is_conditional_jump(bytecode: &[u8], ix: usize) -> bool {
+    let start = ix * Instruction::SIZE;
+    let Some(bytes) = bytecode.get(start..start + Instruction::SIZE) else {
+        return false;
+    };
+    let bytes: [u8; Instruction::SIZE] = bytes.try_into().expect("the size of an instruction");
+    matches!(
+        Instruction::try_from(bytes),
+        Ok(Instruction::JNE(_)
+            | Instruction::JNEI(_)
+            | Instruction::JNZI(_)
+            | Instruction::JNZF(_)
+            | Instruction::JNZB(_)
+            | Instruction::JNEF(_)
+            | Instruction::JNEB(_))
+    )
+}
+
+/// The line, starting at 1, of the byte at `offset` in `source`.
+fn line_number(source: &str, offset: usize) -> usize {
+    let offset = offset.min(source.len());
+    source.as_bytes()[..offset]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count()
+        + 1
+}
+
+fn ratio_cell(hit: usize, total: usize) -> String {
+    let percent = match total {
+        0 => 100.0,
+        _ => hit as f64 * 100.0 / total as f64,
+    };
+    format!("<td>{percent:.1}% ({hit}/{total})</td>")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,17 +1,49 @@
+use crate::coverage::TestCoverage;
 use crate::setup::TestSetup;
 use crate::TestResult;
 use crate::TEST_METADATA_SEED;
-use forc_pkg::PkgTestEntry;
-use fuel_tx::{self as tx, output::contract::Contract, Chargeable, Finalizable};
+use forc_debug_core::{
+    backend::BreakpointHit, profiler::GasProfiler, source_locations::SourceLocations,
+};
+use forc_pkg::{PkgTestEntry, TestContext};
+use fuel_tx::{
+    self as tx, field::ScriptGasLimit, output::contract::Contract, Chargeable, Finalizable,
+};
 use fuel_vm::error::InterpreterError;
 use fuel_vm::{
     self as vm,
-    checked_transaction::builder::TransactionBuilderExt,
+    checked_transaction::{builder::TransactionBuilderExt, Checked},
+    fuel_types::BlockHeight,
     interpreter::{Interpreter, NotSupportedEcal},
-    prelude::{Instruction, SecretKey},
+    prelude::{Breakpoint, Instruction, SecretKey, Word},
+    state::{DebugEval, ProgramState},
     storage::MemoryStorage,
 };
 use rand::{Rng, SeedableRng};
+use std::sync::Arc;
+
+/// The height of the block within which tests are executed, unless configured by the test.
+const TEST_BLOCK_HEIGHT: u32 = u32::MAX >> 1;
+
+/// The timestamp of the genesis block of the in-process VM, i.e., the Unix epoch in the TAI64
+/// format, from which the timestamps of the following blocks are 10 seconds apart.
+const GENESIS_TIMESTAMP: Word = (1 << 62) + 10;
+const BLOCK_INTERVAL: Word = 10;
+
+/// The index of the first instruction of the program, following the data section setup.
+// TODO: Standardize this or add metadata to bytecode.
+const PROGRAM_START_INST_OFFSET: u32 = 6;
+
+/// A test's transaction, along with the VM state and parameters it is executed with, e.g., to
+/// execute it within a debugger.
+#[derive(Debug, Clone)]
+pub struct TestTransaction {
+    pub tx: tx::Script,
+    /// The storage containing any contracts deployed for the test.
+    pub storage: MemoryStorage,
+    pub consensus_params: tx::ConsensusParameters,
+    pub block_height: BlockHeight,
+}
 
 /// An interface for executing a test within a VM [Interpreter] instance.
 #[derive(Debug)]
@@ -20,46 +52,75 @@ pub struct TestExecutor {
     tx_builder: tx::TransactionBuilder<tx::Script>,
     test_entry: PkgTestEntry,
     name: String,
+    test_offset: u32,
+    block_height: BlockHeight,
+    collect_coverage: bool,
+    /// The name of the root frame of the gas profile, and the locations of the instructions.
+    gas_profile: Option<(String, Arc<SourceLocations>)>,
 }
 
 impl TestExecutor {
+    /// Creates the executor of the test at `test_offset`, reading its arguments, if it takes any,
+    /// from `script_data`.
+    ///
+    /// The transaction executing the test is configured by the [TestContext] of the test.
     pub fn new(
         bytecode: &[u8],
         test_offset: u32,
         test_setup: TestSetup,
         test_entry: &PkgTestEntry,
         name: String,
-    ) -> Self {
+        script_data: Vec<u8>,
+    ) -> anyhow::Result<Self> {
+        let context = &test_entry.context;
+        // The storage is already at the configured block height, see `PackageTests::setup`.
         let storage = test_setup.storage().clone();
+        let block_height =
+            configured_block_height(context)?.unwrap_or_else(|| TEST_BLOCK_HEIGHT.into());
 
         // Patch the bytecode to jump to the relevant test.
         let bytecode = patch_test_bytecode(bytecode, test_offset).into_owned();
 
         // Create a transaction to execute the test function.
-        let script_input_data = vec![];
         let rng = &mut rand::rngs::StdRng::seed_from_u64(TEST_METADATA_SEED);
 
         // Prepare the transaction metadata.
         let secret_key = SecretKey::random(rng);
         let utxo_id = rng.gen();
         let amount = 1;
-        let maturity = 1.into();
+        let maturity = (*block_height).min(1).into();
         let asset_id = rng.gen();
         let tx_pointer = rng.gen();
 
-        let mut tx_builder = tx::TransactionBuilder::script(bytecode, script_input_data)
-            .add_unsigned_coin_input(
-                secret_key,
+        // The coins are owned by the sender, whose signature isn't checked so that it can be any
+        // address. Unless configured, the sender is the same for all tests.
+        let owner = context
+            .sender
+            .unwrap_or_else(|| tx::Input::owner(&secret_key.public_key()));
+        let mut coins = vec![(utxo_id, amount, asset_id)];
+        coins.extend(
+            context
+                .coins
+                .iter()
+                .map(|(amount, asset_id)| (rng.gen(), *amount, *asset_id)),
+        );
+        let mut tx_builder = tx::TransactionBuilder::script(bytecode, script_data);
+        tx_builder
+            .maturity(maturity)
+            .add_witness(tx::Witness::default());
+        for (utxo_id, amount, asset_id) in coins {
+            tx_builder.add_input(tx::Input::coin_signed(
                 utxo_id,
+                owner,
                 amount,
                 asset_id,
                 tx_pointer,
+                0,
                 0u32.into(),
-            )
-            .maturity(maturity)
-            .clone();
+            ));
+        }
 
-        let mut output_index = 1;
+        let mut output_index = tx_builder.inputs().len() as u8;
         // Insert contract ids into tx input
         for contract_id in test_setup.contract_ids() {
             tx_builder
@@ -77,6 +138,14 @@ impl TestExecutor {
                 }));
             output_index += 1;
         }
+        // Variable outputs receive the coins transferred to addresses by the test.
+        for _ in 0..context.variable_outputs {
+            tx_builder.add_output(tx::Output::variable(
+                tx::Address::zeroed(),
+                0,
+                tx::AssetId::zeroed(),
+            ));
+        }
         let consensus_params = tx_builder.get_params().clone();
 
         // Temporarily finalize to calculate `script_gas_limit`
@@ -87,24 +156,101 @@ impl TestExecutor {
         // Increase `script_gas_limit` to the maximum allowed value.
         tx_builder.script_gas_limit(consensus_params.tx_params().max_gas_per_tx - max_gas);
 
-        TestExecutor {
+        Ok(TestExecutor {
             interpreter: Interpreter::with_storage(storage, consensus_params.into()),
             tx_builder,
             test_entry: test_entry.clone(),
             name,
+            test_offset,
+            block_height,
+            collect_coverage: false,
+            gas_profile: None,
+        })
+    }
+
+    /// Record the instructions executed by the test, to report its code coverage.
+    ///
+    /// The test is then executed one instruction at a time, which is considerably slower.
+    pub fn with_coverage(mut self, collect_coverage: bool) -> Self {
+        self.collect_coverage = collect_coverage;
+        self
+    }
+
+    /// Profile the gas used by the test, attributing it to the functions and lines of the package
+    /// at `source_locations`, under a root frame named `root`.
+    ///
+    /// The test is then executed one instruction at a time, which is considerably slower.
+    pub fn with_gas_profile(mut self, root: &str, source_locations: Arc<SourceLocations>) -> Self {
+        self.gas_profile = Some((root.to_string(), source_locations));
+        self
+    }
+
+    /// The transaction executing the test, along with the state it is executed against.
+    pub fn test_transaction(&self) -> TestTransaction {
+        TestTransaction {
+            tx: self.tx_builder.clone().finalize(),
+            storage: self.interpreter.as_ref().clone(),
+            consensus_params: self.tx_builder.get_params().clone(),
+            block_height: self.block_height,
         }
     }
 
     pub fn execute(&mut self) -> anyhow::Result<TestResult> {
-        let block_height = (u32::MAX >> 1).into();
+        // The signatures of the coins aren't checked, as their owner is the configured sender.
+        let tx = self.tx_builder.finalize_checked_basic(self.block_height);
         let start = std::time::Instant::now();
-        let transition = self
-            .interpreter
-            .transact(self.tx_builder.finalize_checked(block_height))
-            .map_err(|err: InterpreterError<_>| anyhow::anyhow!(err))?;
+        let (state, receipts, coverage, gas_profile) =
+            if self.collect_coverage || self.gas_profile.is_some() {
+                let mut coverage = TestCoverage::default();
+                let mut previous = None;
+                let gas_profile = self.gas_profile.clone();
+                let mut profiler = gas_profile
+                    .as_ref()
+                    .map(|(root, source_locations)| GasProfiler::new(root, source_locations));
+                // The first instruction of the program is replaced by the jump to the test.
+                let jump_to_test = (self.test_offset != PROGRAM_START_INST_OFFSET)
+                    .then_some(PROGRAM_START_INST_OFFSET as u64 * Instruction::SIZE as u64);
+                let (state, receipts) =
+                    self.execute_single_stepping(tx, |breakpoint, remaining_gas| {
+                        let is_script = *breakpoint.contract() == tx::ContractId::zeroed();
+                        // The jump isn't the start of the function it replaced, and its gas is
+                        // charged along with that of the program setup.
+                        let is_jump_to_test = is_script && Some(breakpoint.pc()) == jump_to_test;
+                        if let Some(profiler) = profiler.as_mut().filter(|_| !is_jump_to_test) {
+                            let location = BreakpointHit {
+                                contract: *breakpoint.contract(),
+                                pc: breakpoint.pc(),
+                            };
+                            profiler.step(location, remaining_gas);
+                        }
+                        // Only the instructions of the test script itself are covered, not those
+                        // of any contracts it calls.
+                        if is_script {
+                            let ix = breakpoint.pc() as usize / Instruction::SIZE;
+                            coverage.record(previous, ix);
+                            previous = Some(ix);
+                        }
+                    })?;
+                if jump_to_test.is_some() {
+                    coverage.remove(PROGRAM_START_INST_OFFSET as usize);
+                }
+                let gas_profile = profiler
+                    .map(|profiler| profiler.finish(Some(self.interpreter.remaining_gas())));
+                let coverage = self.collect_coverage.then_some(coverage);
+                (state, receipts, coverage, gas_profile)
+            } else {
+                let transition = self
+                    .interpreter
+                    .transact(tx)
+                    .map_err(|err: InterpreterError<_>| anyhow::anyhow!(err))?;
+                (
+                    *transition.state(),
+                    transition.receipts().to_vec(),
+                    None,
+                    None,
+                )
+            };
         let duration = start.elapsed();
-        let state = *transition.state();
-        let receipts = transition.receipts().to_vec();
 
         let gas_used = *receipts
             .iter()
@@ -136,8 +282,91 @@ impl TestExecutor {
             condition,
             logs,
             gas_used,
+            coverage,
+            gas_profile,
+            args: None,
+            fuzz: None,
         })
     }
+
+    /// Execute `tx` one instruction at a time, calling `on_step` with the location of each
+    /// instruction before it is executed, and the gas remaining then.
+    ///
+    /// Returns the resulting state and receipts.
+    fn execute_single_stepping(
+        &mut self,
+        tx: Checked<tx::Script>,
+        mut on_step: impl FnMut(&Breakpoint, Word),
+    ) -> anyhow::Result<(ProgramState, Vec<tx::Receipt>)> {
+        let gas_limit = *tx.transaction().script_gas_limit();
+        self.interpreter.set_single_stepping(true);
+        let mut state = self
+            .interpreter
+            .transact(tx)
+            .map(|transition| *transition.state());
+        let (state, result) = loop {
+            match state {
+                Ok(ProgramState::RunProgram(DebugEval::Breakpoint(breakpoint))) => {
+                    on_step(&breakpoint, self.interpreter.remaining_gas());
+                    state = self.interpreter.resume();
+                }
+                Ok(ProgramState::Revert(code)) => {
+                    break (
+                        ProgramState::Revert(code),
+                        tx::ScriptExecutionResult::Revert,
+                    )
+                }
+                Ok(state) => break (state, tx::ScriptExecutionResult::Success),
+                // As when executing without interruption, a panic reverts the script.
+                Err(err) if err.instruction_result().is_some() => {
+                    break (ProgramState::Revert(0), tx::ScriptExecutionResult::Panic)
+                }
+                Err(err) => anyhow::bail!(err),
+            }
+        };
+        self.interpreter.set_single_stepping(false);
+
+        let gas_used = gas_limit.saturating_sub(self.interpreter.remaining_gas());
+        // The interpreter reports a result when first interrupted, which is replaced by the
+        // actual result.
+        let mut receipts = self
+            .interpreter
+            .receipts()
+            .iter()
+            .filter(|receipt| !matches!(receipt, tx::Receipt::ScriptResult { .. }))
+            .cloned()
+            .collect::<Vec<_>>();
+        receipts.push(tx::Receipt::script_result(result, gas_used));
+        Ok((state, receipts))
+    }
+}
+
+/// Returns the height of the block the test is configured to be executed within, either directly or
+/// by the timestamp of the block.
+///
+/// The timestamps of the blocks of the in-process VM are determined by their height, so only the
+/// timestamps of existing blocks can be configured.
+pub(crate) fn configured_block_height(
+    context: &TestContext,
+) -> anyhow::Result<Option<BlockHeight>> {
+    let height = match (context.block_height, context.timestamp) {
+        (Some(block_height), _) => block_height,
+        (None, Some(timestamp)) => {
+            let height = timestamp
+                .checked_sub(GENESIS_TIMESTAMP)
+                .filter(|delta| delta % BLOCK_INTERVAL == 0)
+                .and_then(|delta| u32::try_from(delta / BLOCK_INTERVAL).ok());
+            match height {
+                Some(height) => height,
+                None => anyhow::bail!(
+                    "timestamp {timestamp} is not the timestamp of a block: block timestamps start \
+                     at {GENESIS_TIMESTAMP} and are {BLOCK_INTERVAL} seconds apart"
+                ),
+            }
+        }
+        (None, None) => return Ok(None),
+    };
+    Ok(Some(height.into()))
 }
 
 /// Given some bytecode and an instruction offset for some test's desired entry point, patch the
@@ -158,8 +387,6 @@ impl TestExecutor {
 /// [6] <first-entry-point>           ; This is where we want to jump from to our test code!
 /// ```
 fn patch_test_bytecode(bytecode: &[u8], test_offset: u32) -> std::borrow::Cow<[u8]> {
-    // TODO: Standardize this or add metadata to bytecode.
-    const PROGRAM_START_INST_OFFSET: u32 = 6;
     const PROGRAM_START_BYTE_OFFSET: usize = PROGRAM_START_INST_OFFSET as usize * Instruction::SIZE;
 
     // If our desired entry point is the program start, no need to jump.
@@ -0,0 +1,227 @@
+//! Generating random arguments for fuzzed tests, and shrinking the arguments a test fails with to
+//! simpler ones it still fails with.
+
+use crate::args::{ParamType, Value};
+use rand::{seq::SliceRandom, Rng};
+
+/// The number of times a fuzzed test is executed, unless given by its `fuzz` argument.
+pub const DEFAULT_FUZZ_RUNS: u64 = 256;
+
+/// The maximum number of times a fuzzed test is executed while shrinking the arguments it fails
+/// with.
+pub const MAX_SHRINK_RUNS: u64 = 1024;
+
+/// The number of times a fuzzed test was executed, and the seed its arguments were generated from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzReport {
+    pub runs: u64,
+    pub seed: u64,
+}
+
+/// Generates a random value of the type.
+///
+/// Integers are biased towards the values at the edges of their range, e.g., `0` and the maximum
+/// value, which are more likely to uncover arithmetic bugs.
+pub fn random_value(ty: &ParamType, rng: &mut impl Rng) -> Value {
+    match ty {
+        ParamType::Unit => Value::Unit,
+        ParamType::Bool => Value::Bool(rng.gen()),
+        ParamType::U8 => Value::U8(random_uint(rng, u8::MAX as u64) as u8),
+        ParamType::U16 => Value::U16(random_uint(rng, u16::MAX as u64) as u16),
+        ParamType::U32 => Value::U32(random_uint(rng, u32::MAX as u64) as u32),
+        ParamType::U64 => Value::U64(random_uint(rng, u64::MAX)),
+        ParamType::U256 => Value::U256(random_bytes(rng)),
+        ParamType::B256 => Value::B256(random_bytes(rng)),
+        ParamType::StringArray(len) => Value::StringArray(
+            (0..*len)
+                .map(|_| rng.gen_range(b' '..=b'~') as char)
+                .collect(),
+        ),
+        ParamType::Array(elem_ty, len) => {
+            Value::Array((0..*len).map(|_| random_value(elem_ty, rng)).collect())
+        }
+        ParamType::Tuple(field_tys) => {
+            Value::Tuple(field_tys.iter().map(|ty| random_value(ty, rng)).collect())
+        }
+        ParamType::Struct { name, fields } => Value::Struct {
+            name: name.clone(),
+            fields: fields
+                .iter()
+                .map(|(name, ty)| (name.clone(), random_value(ty, rng)))
+                .collect(),
+        },
+        ParamType::Enum { name, variants } => {
+            let variant = rng.gen_range(0..variants.len());
+            let (variant_name, variant_ty) = &variants[variant];
+            Value::Enum {
+                name: name.clone(),
+                variant,
+                variant_name: variant_name.clone(),
+                value: Box::new(random_value(variant_ty, rng)),
+            }
+        }
+    }
+}
+
+fn random_uint(rng: &mut impl Rng, max: u64) -> u64 {
+    if rng.gen_ratio(1, 4) {
+        *[0, 1, 2, max / 2, max / 2 + 1, max - 1, max]
+            .choose(rng)
+            .expect("edge values are not empty")
+    } else {
+        rng.gen_range(0..=max)
+    }
+}
+
+fn random_bytes(rng: &mut impl Rng) -> [u8; 32] {
+    match rng.gen_range(0..8) {
+        0 => [0; 32],
+        1 => [u8::MAX; 32],
+        _ => rng.gen(),
+    }
+}
+
+/// Returns the values simpler than `value`, the simplest first, e.g., `0` and then smaller integers
+/// for an integer.
+pub fn shrink(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Unit | Value::Bool(false) => vec![],
+        Value::Bool(true) => vec![Value::Bool(false)],
+        Value::U8(n) => shrink_uint(*n as u64).map(|n| Value::U8(n as u8)).collect(),
+        Value::U16(n) => shrink_uint(*n as u64)
+            .map(|n| Value::U16(n as u16))
+            .collect(),
+        Value::U32(n) => shrink_uint(*n as u64)
+            .map(|n| Value::U32(n as u32))
+            .collect(),
+        Value::U64(n) => shrink_uint(*n).map(Value::U64).collect(),
+        Value::U256(bytes) => shrink_big_uint(bytes).map(Value::U256).collect(),
+        Value::B256(bytes) => shrink_big_uint(bytes).map(Value::B256).collect(),
+        Value::StringArray(string) => {
+            // Characters are replaced by `a`, one at a time.
+            let mut shrunk = vec![];
+            if let Some(i) = string.find(|c| c != 'a') {
+                let mut chars = string.chars().collect::<Vec<_>>();
+                chars[i] = 'a';
+                shrunk.push(Value::StringArray(chars.into_iter().collect()));
+            }
+            shrunk
+        }
+        Value::Array(elems) => shrink_each(elems).into_iter().map(Value::Array).collect(),
+        Value::Tuple(fields) => shrink_each(fields).into_iter().map(Value::Tuple).collect(),
+        Value::Struct { name, fields } => {
+            let (names, values): (Vec<_>, Vec<_>) = fields.iter().cloned().unzip();
+            shrink_each(&values)
+                .into_iter()
+                .map(|values| Value::Struct {
+                    name: name.clone(),
+                    fields: names.iter().cloned().zip(values).collect(),
+                })
+                .collect()
+        }
+        Value::Enum {
+            name,
+            variant,
+            variant_name,
+            value,
+        } => shrink(value)
+            .into_iter()
+            .map(|value| Value::Enum {
+                name: name.clone(),
+                variant: *variant,
+                variant_name: variant_name.clone(),
+                value: Box::new(value),
+            })
+            .collect(),
+    }
+}
+
+/// Returns the values simpler than the arguments, each with a single argument simplified.
+pub fn shrink_args(args: &[Value]) -> Vec<Vec<Value>> {
+    shrink_each(args)
+}
+
+fn shrink_each(values: &[Value]) -> Vec<Vec<Value>> {
+    values
+        .iter()
+        .enumerate()
+        .flat_map(|(i, value)| {
+            shrink(value).into_iter().map(move |shrunk| {
+                let mut values = values.to_vec();
+                values[i] = shrunk;
+                values
+            })
+        })
+        .collect()
+}
+
+/// Shrinks an integer to zero, and then to the integers closer and closer to it, i.e., `n / 2`,
+/// `3 * n / 4`, and so on up to `n - 1`, so that the smallest failing integer is found in few runs.
+fn shrink_uint(n: u64) -> impl Iterator<Item = u64> {
+    let mut shrunk = vec![];
+    if n > 0 {
+        shrunk.push(0);
+    }
+    shrunk.extend(
+        (1..u64::BITS)
+            .map(|k| n >> k)
+            .take_while(|delta| *delta > 0)
+            .map(|delta| n - delta),
+    );
+    shrunk.dedup();
+    shrunk.into_iter()
+}
+
+/// Shrinks big-endian bytes to zero, and to half of their value.
+fn shrink_big_uint(bytes: &[u8; 32]) -> impl Iterator<Item = [u8; 32]> {
+    let mut shrunk = vec![];
+    if bytes.iter().any(|byte| *byte != 0) {
+        shrunk.push([0; 32]);
+        let mut half = [0; 32];
+        let mut carry = 0;
+        for (half, byte) in half.iter_mut().zip(bytes) {
+            *half = (byte >> 1) | carry;
+            carry = byte << 7;
+        }
+        if half.iter().any(|byte| *byte != 0) {
+            shrunk.push(half);
+        }
+    }
+    shrunk.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn shrink_to_the_simplest_failing_value() {
+        // Shrink the arguments of a property that fails for any `x >= 100`, greedily taking the
+        // first simpler arguments it still fails with.
+        let fails = |args: &[Value]| matches!(args, [Value::U64(x), _] if *x >= 100);
+        let mut args = vec![Value::U64(123_456), Value::Bool(true)];
+        assert!(fails(&args));
+        while let Some(shrunk) = shrink_args(&args).into_iter().find(|args| fails(args)) {
+            args = shrunk;
+        }
+        assert_eq!(args, vec![Value::U64(100), Value::Bool(false)]);
+    }
+
+    #[test]
+    fn random_values_are_reproducible() {
+        let ty = ParamType::Tuple(vec![
+            ParamType::U8,
+            ParamType::B256,
+            ParamType::StringArray(4),
+        ]);
+        let values = |seed| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            (0..16)
+                .map(|_| random_value(&ty, &mut rng))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(7), values(7));
+        assert_ne!(values(7), values(8));
+    }
+}
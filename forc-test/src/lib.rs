@@ -1,24 +1,33 @@
+pub mod args;
+pub mod coverage;
 pub mod execute;
+pub mod fuzz;
 pub mod setup;
 
-use crate::execute::TestExecutor;
+use crate::args::{encode_args, parse_args, ParamType, Value};
+use crate::coverage::TestCoverage;
+use crate::execute::{configured_block_height, TestExecutor};
+use crate::fuzz::{random_value, shrink_args, FuzzReport, DEFAULT_FUZZ_RUNS, MAX_SHRINK_RUNS};
 use crate::setup::{
     ContractDeploymentSetup, ContractTestSetup, DeploymentSetup, ScriptTestSetup, TestSetup,
 };
+use forc_debug_core::{profiler::GasProfile, source_locations::SourceLocations};
 use forc_pkg as pkg;
 use fuel_abi_types::error_codes::ErrorSignal;
 use fuel_tx as tx;
 use fuel_vm::checked_transaction::builder::TransactionBuilderExt;
 use fuel_vm::{self as vm};
 use pkg::manifest::ExperimentalFlags;
-use pkg::TestPassCondition;
 use pkg::{Built, BuiltPackage};
+use pkg::{TestArgs, TestPassCondition};
 use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
 use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
 use sway_core::BuildTarget;
 use sway_types::Span;
+use vm::fuel_types::BlockHeight;
 use vm::prelude::SecretKey;
+use vm::storage::InterpreterStorage;
 
 /// The result of a `forc test` invocation.
 #[derive(Debug)]
@@ -72,6 +81,16 @@ pub struct TestResult {
     pub logs: Vec<fuel_tx::Receipt>,
     /// Gas used while executing this test.
     pub gas_used: u64,
+    /// The instructions executed by this test, if its coverage was collected.
+    pub coverage: Option<TestCoverage>,
+    /// The gas used by each function and line executed by this test, if it was profiled.
+    pub gas_profile: Option<GasProfile>,
+    /// The arguments the test was executed with, if it takes any.
+    ///
+    /// For a fuzzed test, these are the simplest arguments it was found to fail with, if any.
+    pub args: Option<Vec<Value>>,
+    /// The number of runs of a fuzzed test, and the seed its arguments were generated from.
+    pub fuzz: Option<FuzzReport>,
 }
 
 const TEST_METADATA_SEED: u64 = 0x7E57u64;
@@ -152,6 +171,21 @@ pub struct Opts {
     pub experimental: ExperimentalFlags,
 }
 
+/// The set of options provided for controlling how tests are executed.
+#[derive(Default, Clone, Debug)]
+pub struct TestRunOpts {
+    /// Collect the instructions executed by each test, to report the code coverage of the tests.
+    pub coverage: bool,
+    /// Profile the gas used by each test, per function and source line.
+    pub gas_profile: bool,
+    /// The seed the arguments of fuzzed tests are generated from, to reproduce a previous run.
+    ///
+    /// A random seed is used if none is given.
+    pub fuzz_seed: Option<u64>,
+    /// The number of times fuzzed tests are executed, unless given by their `fuzz` argument.
+    pub fuzz_runs: Option<u64>,
+}
+
 /// The set of options provided for controlling logs printed for each test.
 #[derive(Default, Clone)]
 pub struct TestPrintOpts {
@@ -192,10 +226,10 @@ impl PackageWithDeploymentToTest {
     ///
     /// For scripts deploys all contract dependencies.
     /// For contract deploys all contract dependencies and the root contract itself.
-    fn deploy(&self) -> anyhow::Result<TestSetup> {
+    fn deploy(&self, block_height: Option<BlockHeight>) -> anyhow::Result<TestSetup> {
         // Setup the interpreter for deployment.
         let params = tx::ConsensusParameters::default();
-        let storage = vm::storage::MemoryStorage::default();
+        let storage = test_storage(block_height);
         let mut interpreter: vm::prelude::Interpreter<_, _, vm::interpreter::NotSupportedEcal> =
             vm::interpreter::Interpreter::with_storage(storage, params.clone().into());
 
@@ -325,44 +359,40 @@ impl<'a> PackageTests {
         &self,
         test_runners: &rayon::ThreadPool,
         test_filter: Option<&TestFilter>,
+        test_run_opts: &TestRunOpts,
     ) -> anyhow::Result<TestedPackage> {
         let pkg_with_tests = self.built_pkg_with_tests();
-        let tests = test_runners.install(|| {
-            pkg_with_tests
-                .bytecode
-                .entries
-                .par_iter()
-                .filter_map(|entry| {
-                    if let Some(test_entry) = entry.kind.test() {
-                        // If a test filter is specified, only the tests containing the filter phrase in
-                        // their name are going to be executed.
-                        let name = entry.finalized.fn_name.clone();
-                        if let Some(filter) = test_filter {
-                            if !filter.filter(&name) {
-                                return None;
+        let source_locations = test_run_opts
+            .gas_profile
+            .then(|| Arc::new(SourceLocations::new(pkg_with_tests.source_map())));
+        let tests = test_runners
+            .install(|| {
+                pkg_with_tests
+                    .bytecode
+                    .entries
+                    .par_iter()
+                    .filter_map(|entry| {
+                        if let Some(test_entry) = entry.kind.test() {
+                            // If a test filter is specified, only the tests containing the filter phrase in
+                            // their name are going to be executed.
+                            let name = entry.finalized.fn_name.clone();
+                            if let Some(filter) = test_filter {
+                                if !filter.filter(&name) {
+                                    return None;
+                                }
                             }
+                            return Some((entry, test_entry));
                         }
-                        return Some((entry, test_entry));
-                    }
-                    None
-                })
-                .map(|(entry, test_entry)| {
-                    // Execute the test and return the result.
-                    let offset = u32::try_from(entry.finalized.imm)
-                        .expect("test instruction offset out of range");
-                    let name = entry.finalized.fn_name.clone();
-                    let test_setup = self.setup()?;
-                    TestExecutor::new(
-                        &pkg_with_tests.bytecode.bytes,
-                        offset,
-                        test_setup,
-                        test_entry,
-                        name,
-                    )
-                    .execute()
-                })
-                .collect::<anyhow::Result<_>>()
-        })?;
+                        None
+                    })
+                    .map(|(entry, test_entry)| {
+                        self.run_test(entry, test_entry, test_run_opts, source_locations.as_ref())
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()
+            })?
+            .into_iter()
+            .flatten()
+            .collect();
 
         Ok(TestedPackage {
             built: Box::new(pkg_with_tests.clone()),
@@ -370,23 +400,140 @@ impl<'a> PackageTests {
         })
     }
 
+    /// Execute the test declared by `entry`, once with each of its cases, or with as many
+    /// randomly generated arguments as it is fuzzed with, if it takes arguments.
+    ///
+    /// When a fuzzed test fails, the arguments it fails with are shrunk to the simplest arguments
+    /// it still fails with, and it is reported as executed with those.
+    fn run_test(
+        &self,
+        entry: &pkg::PkgEntry,
+        test_entry: &pkg::PkgTestEntry,
+        test_run_opts: &TestRunOpts,
+        source_locations: Option<&Arc<SourceLocations>>,
+    ) -> anyhow::Result<Vec<TestResult>> {
+        let pkg_with_tests = self.built_pkg_with_tests();
+        let execute = |args: &[Value], params: &[ParamType]| {
+            let mut executor = self
+                .test_executor(entry, test_entry, encode_args(args, params))?
+                .with_coverage(test_run_opts.coverage);
+            if let Some(source_locations) = source_locations {
+                executor = executor
+                    .with_gas_profile(&pkg_with_tests.descriptor.name, source_locations.clone());
+            }
+            let mut result = executor.execute()?;
+            result.args = (!params.is_empty()).then(|| args.to_vec());
+            Ok(result)
+        };
+
+        let name = &entry.finalized.fn_name;
+        let params = test_params(test_entry)
+            .map_err(|err| anyhow::anyhow!("cannot pass arguments to test `{name}`: {err}"))?;
+        match &test_entry.args {
+            TestArgs::None => Ok(vec![execute(&[], &[])?]),
+            TestArgs::Cases(cases) => cases
+                .iter()
+                .map(|case| {
+                    let args = parse_args(case, &params).map_err(|err| {
+                        anyhow::anyhow!("invalid case \"{case}\" of test `{name}`: {err}")
+                    })?;
+                    execute(&args, &params)
+                })
+                .collect(),
+            TestArgs::Fuzz(runs) => {
+                let runs = runs
+                    .or(test_run_opts.fuzz_runs)
+                    .unwrap_or(DEFAULT_FUZZ_RUNS)
+                    .max(1);
+                let seed = test_run_opts.fuzz_seed.unwrap_or(TEST_METADATA_SEED);
+                let rng = &mut rand::rngs::StdRng::seed_from_u64(seed);
+                let mut duration = std::time::Duration::ZERO;
+                let mut run = 0;
+                let mut result = loop {
+                    run += 1;
+                    let args = params
+                        .iter()
+                        .map(|ty| random_value(ty, rng))
+                        .collect::<Vec<_>>();
+                    let result: TestResult = execute(&args, &params)?;
+                    duration += result.duration;
+                    if !result.passed() || run == runs {
+                        break result;
+                    }
+                };
+
+                // Greedily shrink the arguments to the first simpler ones the test still fails
+                // with, until it passes with all of the simpler ones.
+                let mut shrink_runs = 0;
+                'shrink: while !result.passed() {
+                    let args = result.args.clone().unwrap_or_default();
+                    for shrunk in shrink_args(&args) {
+                        if shrink_runs == MAX_SHRINK_RUNS {
+                            break 'shrink;
+                        }
+                        shrink_runs += 1;
+                        let shrunk_result = execute(&shrunk, &params)?;
+                        duration += shrunk_result.duration;
+                        if !shrunk_result.passed() {
+                            result = shrunk_result;
+                            continue 'shrink;
+                        }
+                    }
+                    break;
+                }
+
+                if result.passed() {
+                    result.args = None;
+                }
+                result.duration = duration;
+                result.fuzz = Some(FuzzReport { runs: run, seed });
+                Ok(vec![result])
+            }
+        }
+    }
+
+    /// Create the executor for the test declared by `entry`, executed with the encoded arguments
+    /// in `script_data`.
+    fn test_executor(
+        &self,
+        entry: &pkg::PkgEntry,
+        test_entry: &pkg::PkgTestEntry,
+        script_data: Vec<u8>,
+    ) -> anyhow::Result<TestExecutor> {
+        let offset =
+            u32::try_from(entry.finalized.imm).expect("test instruction offset out of range");
+        let name = entry.finalized.fn_name.clone();
+        let block_height = configured_block_height(&test_entry.context)?;
+        let test_setup = self.setup(block_height)?;
+        TestExecutor::new(
+            &self.built_pkg_with_tests().bytecode.bytes,
+            offset,
+            test_setup,
+            test_entry,
+            name,
+            script_data,
+        )
+    }
+
     /// Setup the storage for a test and returns a contract id for testing contracts.
     ///
     /// For testing contracts, storage returned from this function contains the deployed contract.
     /// For other types, default storage is returned.
-    fn setup(&self) -> anyhow::Result<TestSetup> {
+    ///
+    /// The storage is at `block_height`, if the test configures one.
+    fn setup(&self, block_height: Option<BlockHeight>) -> anyhow::Result<TestSetup> {
         match self {
             PackageTests::Contract(contract_to_test) => {
-                let test_setup = contract_to_test.deploy()?;
+                let test_setup = contract_to_test.deploy(block_height)?;
                 Ok(test_setup)
             }
             PackageTests::Script(script_to_test) => {
-                let test_setup = script_to_test.deploy()?;
+                let test_setup = script_to_test.deploy(block_height)?;
                 Ok(test_setup)
             }
-            PackageTests::Predicate(_) | PackageTests::Library(_) => Ok(
-                TestSetup::WithoutDeployment(vm::storage::MemoryStorage::default()),
-            ),
+            PackageTests::Predicate(_) | PackageTests::Library(_) => {
+                Ok(TestSetup::WithoutDeployment(test_storage(block_height)))
+            }
         }
     }
 }
@@ -496,29 +643,77 @@ impl BuiltTests {
                     .iter()
                     .filter_map(|entry| entry.kind.test().map(|test| (entry, test)))
             })
-            .fold(TestCount::default(), |acc, (pkg_entry, _)| {
+            .fold(TestCount::default(), |acc, (pkg_entry, test_entry)| {
+                // A test is executed once for each of its cases.
+                let num_tests = match &test_entry.args {
+                    TestArgs::Cases(cases) => cases.len(),
+                    TestArgs::None | TestArgs::Fuzz(_) => 1,
+                };
                 let num_ignored = match &test_filter {
                     Some(filter) => {
                         if filter.filter(&pkg_entry.finalized.fn_name) {
                             acc.ignored
                         } else {
-                            acc.ignored + 1
+                            acc.ignored + num_tests
                         }
                     }
                     None => acc.ignored,
                 };
                 TestCount {
-                    total: acc.total + 1,
+                    total: acc.total + num_tests,
                     ignored: num_ignored,
                 }
             })
     }
 
+    /// Create the executor for the test named `test_name`, along with the package declaring it,
+    /// e.g., to execute the test within a debugger.
+    ///
+    /// A test taking arguments is executed with its first case, or with the first arguments it is
+    /// fuzzed with for the given seed.
+    pub fn test_executor(
+        &self,
+        test_name: &str,
+        fuzz_seed: Option<u64>,
+    ) -> anyhow::Result<(TestExecutor, &BuiltPackage)> {
+        let pkgs: Vec<&PackageTests> = match self {
+            BuiltTests::Package(pkg) => vec![pkg],
+            BuiltTests::Workspace(workspace) => workspace.iter().collect(),
+        };
+        let mut tests = pkgs.into_iter().flat_map(|pkg| {
+            pkg.built_pkg_with_tests()
+                .bytecode
+                .entries
+                .iter()
+                .filter(|entry| entry.finalized.fn_name == test_name)
+                .filter_map(move |entry| entry.kind.test().map(|test| (pkg, entry, test)))
+        });
+        let (pkg, entry, test_entry) = tests
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("no test named `{test_name}` was found"))?;
+        if tests.next().is_some() {
+            anyhow::bail!("more than one test named `{test_name}` was found");
+        }
+        let params = test_params(test_entry)?;
+        let args = match &test_entry.args {
+            TestArgs::None => vec![],
+            TestArgs::Cases(cases) => parse_args(&cases[0], &params)?,
+            TestArgs::Fuzz(_) => {
+                let seed = fuzz_seed.unwrap_or(TEST_METADATA_SEED);
+                let rng = &mut rand::rngs::StdRng::seed_from_u64(seed);
+                params.iter().map(|ty| random_value(ty, rng)).collect()
+            }
+        };
+        let executor = pkg.test_executor(entry, test_entry, encode_args(&args, &params))?;
+        Ok((executor, pkg.built_pkg_with_tests()))
+    }
+
     /// Run all built tests, return the result.
     pub fn run(
         self,
         test_runner_count: TestRunnerCount,
         test_filter: Option<TestFilter>,
+        test_run_opts: TestRunOpts,
     ) -> anyhow::Result<Tested> {
         let test_runners = match test_runner_count {
             TestRunnerCount::Manual(runner_count) => rayon::ThreadPoolBuilder::new()
@@ -526,7 +721,13 @@ impl BuiltTests {
                 .build(),
             TestRunnerCount::Auto => rayon::ThreadPoolBuilder::new().build(),
         }?;
-        run_tests(self, &test_runners, test_filter)
+        // The same seed is used for all tests, and reported along with the fuzzed tests so that
+        // the run can be reproduced.
+        let test_run_opts = TestRunOpts {
+            fuzz_seed: Some(test_run_opts.fuzz_seed.unwrap_or_else(rand::random)),
+            ..test_run_opts
+        };
+        run_tests(self, &test_runners, test_filter, &test_run_opts)
     }
 }
 
@@ -557,6 +758,24 @@ pub fn build(opts: Opts) -> anyhow::Result<BuiltTests> {
     BuiltTests::from_built(built, &member_contract_dependencies)
 }
 
+/// The types of the parameters of the test, resolved from their ABI.
+fn test_params(test_entry: &pkg::PkgTestEntry) -> anyhow::Result<Vec<ParamType>> {
+    ParamType::from_params(&test_entry.params, &test_entry.param_types)
+}
+
+/// The storage a test is executed with, at `block_height` if given and otherwise at the default
+/// height.
+fn test_storage(block_height: Option<BlockHeight>) -> vm::storage::MemoryStorage {
+    let storage = vm::storage::MemoryStorage::default();
+    match block_height {
+        Some(block_height) => {
+            let coinbase = storage.coinbase().expect("in-memory storage is infallible");
+            vm::storage::MemoryStorage::new(block_height, coinbase)
+        }
+        None => storage,
+    }
+}
+
 /// Deploys the provided contract and returns an interpreter instance ready to be used in test
 /// executions with deployed contract.
 fn deployment_transaction(
@@ -602,16 +821,17 @@ fn run_tests(
     built: BuiltTests,
     test_runners: &rayon::ThreadPool,
     test_filter: Option<TestFilter>,
+    test_run_opts: &TestRunOpts,
 ) -> anyhow::Result<Tested> {
     match built {
         BuiltTests::Package(pkg) => {
-            let tested_pkg = pkg.run_tests(test_runners, test_filter.as_ref())?;
+            let tested_pkg = pkg.run_tests(test_runners, test_filter.as_ref(), test_run_opts)?;
             Ok(Tested::Package(Box::new(tested_pkg)))
         }
         BuiltTests::Workspace(workspace) => {
             let tested_pkgs = workspace
                 .into_iter()
-                .map(|pkg| pkg.run_tests(test_runners, test_filter.as_ref()))
+                .map(|pkg| pkg.run_tests(test_runners, test_filter.as_ref(), test_run_opts))
                 .collect::<anyhow::Result<Vec<TestedPackage>>>()?;
             Ok(Tested::Workspace(tested_pkgs))
         }
@@ -622,7 +842,12 @@ fn run_tests(
 mod tests {
     use std::path::PathBuf;
 
-    use crate::{build, BuiltTests, Opts, TestFilter, TestResult};
+    use crate::{
+        args::{Value, Values},
+        build,
+        coverage::CoverageReport,
+        BuiltTests, Opts, TestFilter, TestResult, TestRunOpts,
+    };
 
     /// Name of the folder containing required data for tests to run, such as an example forc
     /// project.
@@ -635,6 +860,12 @@ mod tests {
     const TEST_PREDICATE_PACKAGE_NAME: &str = "test_predicate";
     /// Name of the script package in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
     const TEST_SCRIPT_PACKAGE_NAME: &str = "test_script";
+    /// Name of the contract package with tests taking arguments in the
+    /// "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_ARGS_PACKAGE_NAME: &str = "test_args";
+    /// Name of the contract package with tests configuring their transaction in the
+    /// "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_CONTEXT_PACKAGE_NAME: &str = "test_context";
 
     /// Build the tests in the test package with the given name located at
     /// "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME/TEST_LIBRARY_PACKAGE_NAME".
@@ -660,7 +891,7 @@ mod tests {
     ) -> anyhow::Result<Vec<TestResult>> {
         let built_tests = test_package_built_tests(package_name)?;
         let test_runner_count = crate::TestRunnerCount::Auto;
-        let tested = built_tests.run(test_runner_count, test_filter)?;
+        let tested = built_tests.run(test_runner_count, test_filter, Default::default())?;
         match tested {
             crate::Tested::Package(tested_pkg) => Ok(tested_pkg.tests),
             crate::Tested::Workspace(_) => {
@@ -859,4 +1090,140 @@ mod tests {
         assert_eq!(tested_predicate_test_count, 2);
         assert_eq!(tested_script_test_count, 2);
     }
+
+    #[test]
+    fn test_coverage() {
+        let built_tests = test_package_built_tests(TEST_SCRIPT_PACKAGE_NAME).unwrap();
+        let test_run_opts = TestRunOpts {
+            coverage: true,
+            ..Default::default()
+        };
+        let tested = built_tests
+            .run(crate::TestRunnerCount::Auto, None, test_run_opts)
+            .unwrap();
+        let crate::Tested::Package(tested_pkg) = tested else {
+            unreachable!("test_script is a package, not a workspace.")
+        };
+        assert!(tested_pkg.tests_passed());
+        assert!(tested_pkg.tests.iter().all(|test| test.coverage.is_some()));
+
+        let report = CoverageReport::from_tested(&tested_pkg).unwrap();
+        assert_eq!(report.files.len(), 1);
+        let file = &report.files[0];
+        assert!(file.path.ends_with("src/main.sw"));
+        // The bodies of both tests are executed.
+        assert_eq!(file.lines.get(&9), Some(&1));
+        assert_eq!(file.lines.get(&14), Some(&1));
+
+        let lcov = report.to_lcov();
+        assert!(lcov.contains("DA:9,1\n"));
+        assert!(lcov.ends_with("end_of_record\n"));
+    }
+
+    #[test]
+    fn test_gas_profile() {
+        let built_tests = test_package_built_tests(TEST_SCRIPT_PACKAGE_NAME).unwrap();
+        let test_run_opts = TestRunOpts {
+            gas_profile: true,
+            ..Default::default()
+        };
+        let tested = built_tests
+            .run(crate::TestRunnerCount::Auto, None, test_run_opts)
+            .unwrap();
+        let crate::Tested::Package(tested_pkg) = tested else {
+            unreachable!("test_script is a package, not a workspace.")
+        };
+        assert!(tested_pkg.tests_passed());
+        for test in &tested_pkg.tests {
+            let profile = test.gas_profile.as_ref().unwrap();
+            // All of the gas used by the test is attributed to the package or the test function.
+            assert_eq!(profile.total(), test.gas_used);
+            let folded = profile.to_folded();
+            let test_stack = format!("{TEST_SCRIPT_PACKAGE_NAME};{} ", test.name);
+            assert!(folded.lines().all(|line| {
+                line.starts_with(&test_stack)
+                    || line.starts_with(&format!("{TEST_SCRIPT_PACKAGE_NAME} "))
+            }));
+            assert!(folded.contains(&test_stack));
+        }
+    }
+
+    #[test]
+    fn test_cases() {
+        let built_tests = test_package_built_tests(TEST_ARGS_PACKAGE_NAME).unwrap();
+        // Each case of a test counts as a test.
+        assert_eq!(built_tests.test_count(None).total, 11);
+
+        let test_filter = TestFilter {
+            filter_phrase: "test_fuzz",
+            exact_match: false,
+        };
+        let results = test_package_test_results(TEST_ARGS_PACKAGE_NAME, None).unwrap();
+        let cases = results
+            .iter()
+            .filter(|test| !test_filter.filter(&test.name))
+            .collect::<Vec<_>>();
+        assert_eq!(cases.len(), 9);
+        assert!(cases.iter().all(|test| test.passed()));
+        let args = cases
+            .iter()
+            .filter(|test| test.name == "test_add_commutative")
+            .map(|test| Values(test.args.as_ref().unwrap()).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(args, ["1, 2", "0, 0", "255, 256"]);
+    }
+
+    #[test]
+    fn test_context() {
+        let results = test_package_test_results(TEST_CONTEXT_PACKAGE_NAME, None).unwrap();
+        assert_eq!(results.len(), 6);
+        for result in results {
+            assert!(result.passed(), "{} failed", result.name);
+        }
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let built_tests = test_package_built_tests(TEST_ARGS_PACKAGE_NAME).unwrap();
+        let test_filter = TestFilter {
+            filter_phrase: "test_fuzz",
+            exact_match: false,
+        };
+        let test_run_opts = TestRunOpts {
+            fuzz_seed: Some(42),
+            ..Default::default()
+        };
+        let tested = built_tests
+            .run(
+                crate::TestRunnerCount::Auto,
+                Some(test_filter),
+                test_run_opts,
+            )
+            .unwrap();
+        let crate::Tested::Package(tested_pkg) = tested else {
+            unreachable!("test_args is a package, not a workspace.")
+        };
+
+        let passing = tested_pkg
+            .tests
+            .iter()
+            .find(|test| test.name == "test_fuzz_passing")
+            .unwrap();
+        assert!(passing.passed());
+        assert_eq!(passing.fuzz.unwrap().runs, 64);
+        assert_eq!(passing.fuzz.unwrap().seed, 42);
+        assert!(passing.args.is_none());
+
+        // The arguments the test fails with are shrunk to the simplest ones.
+        let failing = tested_pkg
+            .tests
+            .iter()
+            .find(|test| test.name == "test_fuzz_failing")
+            .unwrap();
+        assert!(!failing.passed());
+        assert_eq!(
+            failing.args.as_deref(),
+            Some(&[Value::U64(1000), Value::U32(0)][..])
+        );
+    }
 }
@@ -0,0 +1,75 @@
+contract;
+
+abi MyContract {
+    fn test_function() -> bool;
+}
+
+impl MyContract for Contract {
+    fn test_function() -> bool {
+        true
+    }
+}
+
+struct Point {
+    x: u64,
+    y: u8,
+}
+
+enum Shape {
+    Empty: (),
+    Dot: Point,
+    Size: u32,
+}
+
+fn area(shape: Shape) -> u64 {
+    match shape {
+        Shape::Empty => 0,
+        Shape::Dot(_) => 1,
+        Shape::Size(size) => size.as_u64() * size.as_u64(),
+    }
+}
+
+#[test(case = "1, 2", case = "0, 0", case = "0xff, 0x100")]
+fn test_add_commutative(a: u64, b: u64) {
+    assert(a + b == b + a);
+}
+
+#[test(case = "7")]
+fn test_byte(byte: u8) {
+    assert(byte == 7);
+}
+
+#[test(case = "Point { x: 1, y: 2 }, true, Shape::Size(3)")]
+fn test_struct_and_enum(point: Point, flag: bool, shape: Shape) {
+    assert(point.x == 1 && point.y == 2 && flag);
+    assert(area(shape) == 9);
+}
+
+#[test(case = "Shape::Dot(Point { x: 4, y: 5 })", case = "Shape::Empty")]
+fn test_single_enum(shape: Shape) {
+    assert(area(shape) <= 1);
+}
+
+#[test(case = "\"abc\", [1, 2, 3]")]
+fn test_str_and_array(s: str[3], values: [u8; 3]) {
+    assert(values[0] + values[1] == values[2]);
+    assert(__size_of_str_array::<str[3]>() == 3);
+    let _ = s;
+}
+
+#[test(should_revert, case = "2, 1")]
+fn test_reverting_case(a: u64, b: u64) {
+    assert(a < b);
+}
+
+#[test(fuzz = "64")]
+fn test_fuzz_passing(a: u8, shape: Shape, bytes: b256) {
+    let _ = area(shape);
+    assert(a.as_u64() <= 255);
+    assert(bytes == bytes);
+}
+
+#[test(fuzz)]
+fn test_fuzz_failing(a: u64, b: u32) {
+    assert(a < 1000 || b.as_u64() > a);
+}
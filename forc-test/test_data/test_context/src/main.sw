@@ -0,0 +1,80 @@
+contract;
+
+use std::{
+    asset::transfer,
+    auth::msg_sender,
+    block::{height, timestamp},
+    call_frames::msg_asset_id,
+    constants::BASE_ASSET_ID,
+    context::msg_amount,
+};
+
+abi Wallet {
+    fn sender() -> Identity;
+    #[payable]
+    fn deposit() -> u64;
+    #[payable]
+    fn refund();
+}
+
+impl Wallet for Contract {
+    fn sender() -> Identity {
+        msg_sender().unwrap()
+    }
+
+    #[payable]
+    fn deposit() -> u64 {
+        assert(msg_asset_id() == BASE_ASSET_ID);
+        msg_amount()
+    }
+
+    #[payable]
+    fn refund() {
+        transfer(msg_sender().unwrap(), msg_asset_id(), msg_amount());
+    }
+}
+
+#[test(sender = "0x0000000000000000000000000000000000000000000000000000000000000a11")]
+fn test_sender() {
+    let wallet = abi(Wallet, CONTRACT_ID);
+    assert(wallet.sender() == Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000a11)));
+}
+
+#[test(coin = "100")]
+fn test_coin() {
+    let wallet = abi(Wallet, CONTRACT_ID);
+    let amount = wallet.deposit {
+        coins: 100,
+        asset_id: BASE_ASSET_ID.value,
+    }();
+    assert(amount == 100);
+}
+
+#[test(coin = "100", variable_outputs = "1")]
+fn test_variable_outputs() {
+    let wallet = abi(Wallet, CONTRACT_ID);
+    wallet.refund {
+        coins: 100,
+        asset_id: BASE_ASSET_ID.value,
+    }();
+}
+
+#[test(should_revert, coin = "100")]
+fn test_missing_variable_output() {
+    let wallet = abi(Wallet, CONTRACT_ID);
+    wallet.refund {
+        coins: 100,
+        asset_id: BASE_ASSET_ID.value,
+    }();
+}
+
+#[test(block_height = "1000")]
+fn test_block_height() {
+    assert(height() == 1000);
+}
+
+#[test(timestamp = "4611686018427397914")]
+fn test_timestamp() {
+    assert(height() == 1000);
+    assert(timestamp() == 4611686018427397914);
+}
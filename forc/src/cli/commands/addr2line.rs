@@ -11,7 +11,8 @@ use annotate_snippets::{AnnotationType, Slice, Snippet, SourceAnnotation};
 
 use sway_core::source_map::{LocationRange, SourceMap};
 
-/// Show location and context of an opcode address in its source file
+/// Show location and context of an opcode address in its source file, and the variables in
+/// scope there
 #[derive(Debug, Parser)]
 pub(crate) struct Command {
     /// Where to search for the project root
@@ -68,6 +69,14 @@ pub(crate) fn exec(command: Command) -> ForcResult<()> {
         let renderer = create_diagnostics_renderer();
         info!("{}", renderer.render(snippet));
 
+        let variables = sm.variables_at(command.opcode_index);
+        if !variables.is_empty() {
+            info!("Variables:");
+            for var in variables {
+                info!("  {}: {} in {}", var.name, var.ty.name, var.location);
+            }
+        }
+
         Ok(())
     } else {
         Err("Address did not map to any source code location".into())
@@ -1,10 +1,17 @@
-use crate::cli;
+use crate::{cli, cli::plugin, ops::forc_test as test_ops};
 use ansi_term::Colour;
+use anyhow::anyhow;
 use clap::Parser;
-use forc_pkg as pkg;
-use forc_test::{TestFilter, TestRunnerCount, TestedPackage};
-use forc_util::{tx_utils::format_log_receipts, ForcError, ForcResult};
-use pkg::manifest::ExperimentalFlags;
+use forc_debug_core::profiler::GasProfile;
+use forc_pkg::{manifest::ExperimentalFlags, source::IPFSNode};
+use forc_test::{
+    args::Values, coverage::CoverageReport, TestFilter, TestResult, TestRunOpts, TestRunnerCount,
+    TestedPackage,
+};
+use forc_util::{
+    default_output_directory, forc_result_bail, tx_utils::format_log_receipts, ForcError,
+    ForcResult,
+};
 use tracing::info;
 
 forc_util::cli_examples! {
@@ -13,6 +20,10 @@ forc_util::cli_examples! {
         [ Run test with a filter => "forc test $filter" ]
         [ Run test without any output => "forc test --silent" ]
         [ Run test without creating or update the lock file  => "forc test --locked" ]
+        [ Debug a test within an interactive debugger => "forc test --debug test_name" ]
+        [ Report the code coverage of the tests => "forc test --coverage" ]
+        [ Profile the gas used by the tests => "forc test --profile" ]
+        [ Reproduce the arguments of fuzzed tests => "forc test --fuzz-seed 42" ]
     }
 }
 
@@ -33,6 +44,10 @@ forc_util::cli_examples! {
 /// Upon successful compilation, test scripts are executed to their completion. A test is
 /// considered a failure in the case that a revert (`rvrt`) instruction is encountered during
 /// execution. Otherwise, it is considered a success.
+///
+/// Tests may take arguments, either given by a table of cases, e.g. `#[test(case = "1, 2")]`, or
+/// generated at random with `#[test(fuzz)]`. The arguments a fuzzed test fails with are shrunk to
+/// the simplest ones it still fails with.
 #[derive(Debug, Parser)]
 #[clap(bin_name = "forc test", version, after_help = help())]
 pub struct Command {
@@ -49,6 +64,32 @@ pub struct Command {
     /// Number of threads to utilize when running the tests. By default, this is the number of
     /// threads available in your system.
     pub test_threads: Option<usize>,
+    #[clap(long, value_name = "TEST_NAME")]
+    /// Execute the test with the given name within the interactive debugger of the `forc-debug`
+    /// plugin, rather than running the tests.
+    pub debug: Option<String>,
+    #[clap(long)]
+    /// Report the code coverage of the tests, by executing them one instruction at a time.
+    ///
+    /// The coverage of the lines and branches of each package is written in the lcov format to
+    /// `out/coverage/lcov.info`, along with an HTML summary in `out/coverage/index.html`.
+    pub coverage: bool,
+    #[clap(long)]
+    /// Profile the gas used by the tests per function and source line, by executing them one
+    /// instruction at a time.
+    ///
+    /// The gas used by each stack of functions of each package is written in the folded format
+    /// read by flame graph tools, such as `inferno-flamegraph`, to `out/profile/gas.folded`.
+    pub profile: bool,
+    #[clap(long)]
+    /// The seed the arguments of fuzzed tests are generated from, e.g. to reproduce a failure.
+    ///
+    /// By default a random seed is used, and reported along with the results of fuzzed tests.
+    pub fuzz_seed: Option<u64>,
+    #[clap(long)]
+    /// The number of times fuzzed tests are executed, unless given by their `fuzz` argument.
+    /// Defaults to 256.
+    pub fuzz_runs: Option<u64>,
 
     #[clap(long)]
     /// Experimental flag for the "new encoding" feature
@@ -68,6 +109,9 @@ pub struct TestPrintOpts {
 }
 
 pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
+    if let Some(test_name) = &cmd.debug {
+        return debug_test(&cmd, test_name);
+    }
     let test_runner_count = match cmd.test_threads {
         Some(runner_count) => TestRunnerCount::Manual(runner_count),
         None => TestRunnerCount::Auto,
@@ -79,8 +123,16 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
         filter_phrase,
         exact_match: cmd.filter_exact,
     });
-    let opts = opts_from_cmd(cmd);
-    let built_tests = forc_test::build(opts)?;
+    let test_run_opts = TestRunOpts {
+        coverage: cmd.coverage,
+        gas_profile: cmd.profile,
+        fuzz_seed: cmd.fuzz_seed,
+        fuzz_runs: cmd.fuzz_runs,
+    };
+    let experimental = ExperimentalFlags {
+        new_encoding: cmd.experimental_new_encoding,
+    };
+    let built_tests = test_ops::build(cmd.build, experimental)?;
     let start = std::time::Instant::now();
     let test_count = built_tests.test_count(test_filter.as_ref());
     let num_tests_running = test_count.total - test_count.ignored;
@@ -92,7 +144,7 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
         num_tests_ignored,
         formatted_test_count_string(&num_tests_ignored)
     );
-    let tested = built_tests.run(test_runner_count, test_filter)?;
+    let tested = built_tests.run(test_runner_count, test_filter, test_run_opts.clone())?;
     let duration = start.elapsed();
 
     // Eventually we'll print this in a fancy manner, but this will do for testing.
@@ -102,12 +154,24 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
                 let built = &pkg.built.descriptor.name;
                 info!("\n   tested -- {built}\n");
                 print_tested_pkg(pkg, &test_print_opts)?;
+                if test_run_opts.coverage {
+                    write_coverage(pkg)?;
+                }
+                if test_run_opts.gas_profile {
+                    write_gas_profile(pkg)?;
+                }
             }
             info!("\n   Finished in {:?}", duration);
             pkgs.iter().all(|pkg| pkg.tests_passed())
         }
         forc_test::Tested::Package(pkg) => {
             print_tested_pkg(&pkg, &test_print_opts)?;
+            if test_run_opts.coverage {
+                write_coverage(&pkg)?;
+            }
+            if test_run_opts.gas_profile {
+                write_gas_profile(&pkg)?;
+            }
             pkg.tests_passed()
         }
     };
@@ -121,6 +185,138 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
     }
 }
 
+/// Debug the test named `test_name` within the interactive debugger of the `forc-debug` plugin.
+fn debug_test(cmd: &Command, test_name: &str) -> ForcResult<()> {
+    if plugin::find_external_subcommand("debug").is_none() {
+        forc_result_bail!(
+            "`forc test --debug` requires the `forc-debug` plugin, which wasn't found in `PATH`"
+        );
+    }
+    let output = plugin::execute_external_subcommand(debug_args(cmd, test_name), false)?;
+    let code = output
+        .status
+        .code()
+        .ok_or_else(|| anyhow!("plugin exit status unknown"))?;
+    std::process::exit(code);
+}
+
+/// The arguments of `forc debug test`, to debug the test named `test_name` built with the options
+/// `forc test` was given.
+fn debug_args(cmd: &Command, test_name: &str) -> Vec<String> {
+    let build = &cmd.build;
+    let flags = [
+        ("--offline", build.pkg.offline),
+        ("--terse", build.pkg.terse),
+        ("--locked", build.pkg.locked),
+        (
+            "--json-abi-with-callpaths",
+            build.pkg.json_abi_with_callpaths,
+        ),
+        ("--ast", build.print.ast),
+        ("--finalized-asm", build.print.finalized_asm),
+        ("--intermediate-asm", build.print.intermediate_asm),
+        ("--ir", build.print.ir),
+        ("--time-phases", build.print.time_phases),
+        ("--reverse-order", build.print.reverse_order),
+        ("--json-abi", build.minify.json_abi),
+        ("--json-storage-slots", build.minify.json_storage_slots),
+        ("--release", build.profile.release),
+        ("--error-on-warnings", build.profile.error_on_warnings),
+        ("--experimental-new-encoding", cmd.experimental_new_encoding),
+    ];
+    let ipfs_node = build.pkg.ipfs_node.as_ref().map(|node| match node {
+        IPFSNode::Local => "LOCAL".to_string(),
+        IPFSNode::WithUrl(url) => url.clone(),
+    });
+    let options = [
+        ("--path", build.pkg.path.clone()),
+        ("--output-directory", build.pkg.output_directory.clone()),
+        ("--ipfs-node", ipfs_node),
+        ("--dca-graph", build.print.dca_graph.clone()),
+        (
+            "--dca-graph-url-format",
+            build.print.dca_graph_url_format.clone(),
+        ),
+        ("--metrics-outfile", build.print.metrics_outfile.clone()),
+        ("--output-bin", build.output.bin_file.clone()),
+        ("--output-debug", build.output.debug_file.clone()),
+        ("--build-profile", build.profile.build_profile.clone()),
+        ("--build-target", Some(build.build_target.to_string())),
+        ("--fuzz-seed", cmd.fuzz_seed.map(|seed| seed.to_string())),
+    ];
+
+    let mut args = vec![
+        "debug".to_string(),
+        "test".to_string(),
+        test_name.to_string(),
+    ];
+    args.extend(
+        flags
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(flag, _)| flag.to_string()),
+    );
+    for (option, value) in options {
+        if let Some(value) = value {
+            args.extend([option.to_string(), value]);
+        }
+    }
+    args
+}
+
+/// Write the coverage report of `pkg` to its output directory and print a summary.
+fn write_coverage(pkg: &TestedPackage) -> ForcResult<()> {
+    let report = CoverageReport::from_tested(pkg)?;
+    let dir = default_output_directory(pkg.built.descriptor.manifest_file.dir()).join("coverage");
+    report.write(&dir)?;
+
+    let lines_hit: usize = report.files.iter().map(|file| file.lines_hit()).sum();
+    let lines: usize = report.files.iter().map(|file| file.lines.len()).sum();
+    let branches_hit: usize = report.files.iter().map(|file| file.branches_hit()).sum();
+    let branches: usize = report
+        .files
+        .iter()
+        .map(|file| file.branches.len() * 2)
+        .sum();
+    info!(
+        "   Coverage: {lines_hit}/{lines} lines, {branches_hit}/{branches} branches, written to {}",
+        dir.display()
+    );
+    Ok(())
+}
+
+/// The number of most expensive lines printed by [write_gas_profile].
+const GAS_PROFILE_TOP_LINES: usize = 10;
+
+/// Write the gas profile of the tests of `pkg` to its output directory and print the lines using
+/// the most gas.
+fn write_gas_profile(pkg: &TestedPackage) -> ForcResult<()> {
+    let mut profile = GasProfile::default();
+    for test_profile in pkg
+        .tests
+        .iter()
+        .filter_map(|test| test.gas_profile.as_ref())
+    {
+        profile.merge(test_profile);
+    }
+    let dir = default_output_directory(pkg.built.descriptor.manifest_file.dir()).join("profile");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(GasProfile::FOLDED_FILE_NAME);
+    std::fs::write(&path, profile.to_folded())?;
+
+    info!(
+        "   Gas profile: {} gas, written to {}",
+        profile.total(),
+        path.display()
+    );
+    let pkg_dir = pkg.built.descriptor.manifest_file.dir();
+    for (path, line, gas) in profile.lines().into_iter().take(GAS_PROFILE_TOP_LINES) {
+        let path = path.strip_prefix(pkg_dir).unwrap_or(path);
+        info!("      {gas:>10}  {}:{line}", path.display());
+    }
+    Ok(())
+}
+
 fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> ForcResult<()> {
     let succeeded = pkg.tests.iter().filter(|t| t.passed()).count();
     let failed = pkg.tests.len() - succeeded;
@@ -131,10 +327,17 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
             true => ("ok", Colour::Green),
             false => ("FAILED", Colour::Red),
         };
+        let fuzz = match &test.fuzz {
+            Some(fuzz) if fuzz.runs == 1 => "1 run, ".to_string(),
+            Some(fuzz) => format!("{} runs, ", fuzz.runs),
+            None => String::new(),
+        };
         info!(
-            "      test {} ... {} ({:?}, {} gas)",
+            "      test {}{} ... {} ({}{:?}, {} gas)",
             test.name,
+            formatted_test_args(test),
             color.paint(state),
+            fuzz,
             test.duration,
             test.gas_used
         );
@@ -165,9 +368,18 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
             let logs = &failed_test.logs;
             let formatted_logs = format_log_receipts(logs, test_print_opts.pretty_print)?;
             info!(
-                "      - test {}, {:?}:{} ",
-                failed_test_name, path, line_number
+                "      - test {}{}, {:?}:{} ",
+                failed_test_name,
+                formatted_test_args(failed_test),
+                path,
+                line_number
             );
+            if let Some(fuzz) = &failed_test.fuzz {
+                info!(
+                    "        fuzz seed: {}, rerun with `--fuzz-seed {}` to reproduce",
+                    fuzz.seed, fuzz.seed
+                );
+            }
             if let Some(revert_code) = failed_test.revert_code() {
                 // If we have a revert_code, try to get a known error signal
                 let mut failed_info_str = format!("        revert code: {revert_code:x}");
@@ -199,41 +411,11 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
     Ok(())
 }
 
-fn opts_from_cmd(cmd: Command) -> forc_test::Opts {
-    forc_test::Opts {
-        pkg: pkg::PkgOpts {
-            path: cmd.build.pkg.path,
-            offline: cmd.build.pkg.offline,
-            terse: cmd.build.pkg.terse,
-            locked: cmd.build.pkg.locked,
-            output_directory: cmd.build.pkg.output_directory,
-            json_abi_with_callpaths: cmd.build.pkg.json_abi_with_callpaths,
-            ipfs_node: cmd.build.pkg.ipfs_node.unwrap_or_default(),
-        },
-        print: pkg::PrintOpts {
-            ast: cmd.build.print.ast,
-            dca_graph: cmd.build.print.dca_graph,
-            dca_graph_url_format: cmd.build.print.dca_graph_url_format,
-            finalized_asm: cmd.build.print.finalized_asm,
-            intermediate_asm: cmd.build.print.intermediate_asm,
-            ir: cmd.build.print.ir,
-            reverse_order: cmd.build.print.reverse_order,
-        },
-        time_phases: cmd.build.print.time_phases,
-        metrics_outfile: cmd.build.print.metrics_outfile,
-        minify: pkg::MinifyOpts {
-            json_abi: cmd.build.minify.json_abi,
-            json_storage_slots: cmd.build.minify.json_storage_slots,
-        },
-        build_profile: cmd.build.profile.build_profile,
-        release: cmd.build.profile.release,
-        error_on_warnings: cmd.build.profile.error_on_warnings,
-        binary_outfile: cmd.build.output.bin_file,
-        debug_outfile: cmd.build.output.debug_file,
-        build_target: cmd.build.build_target,
-        experimental: ExperimentalFlags {
-            new_encoding: cmd.experimental_new_encoding,
-        },
+/// The arguments the test was executed with, e.g. ` (1, 2)`, if it takes any.
+fn formatted_test_args(test: &TestResult) -> String {
+    match &test.args {
+        Some(args) => format!(" ({})", Values(args)),
+        None => String::new(),
     }
 }
 
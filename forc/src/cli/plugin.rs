@@ -48,7 +48,7 @@ pub(crate) fn execute_external_subcommand(
 }
 
 /// Find an exe called `forc-<cmd>` and return its path.
-fn find_external_subcommand(cmd: &str) -> Option<PathBuf> {
+pub(crate) fn find_external_subcommand(cmd: &str) -> Option<PathBuf> {
     let command_exe = format!("forc-{}{}", cmd, env::consts::EXE_SUFFIX);
     search_directories()
         .iter()
@@ -0,0 +1,46 @@
+use crate::cli::shared::Build;
+use forc_pkg as pkg;
+use forc_util::ForcResult;
+use pkg::manifest::ExperimentalFlags;
+
+pub fn build(build: Build, experimental: ExperimentalFlags) -> ForcResult<forc_test::BuiltTests> {
+    let opts = opts_from_build(build, experimental);
+    let built_tests = forc_test::build(opts)?;
+    Ok(built_tests)
+}
+
+fn opts_from_build(build: Build, experimental: ExperimentalFlags) -> forc_test::Opts {
+    forc_test::Opts {
+        pkg: pkg::PkgOpts {
+            path: build.pkg.path,
+            offline: build.pkg.offline,
+            terse: build.pkg.terse,
+            locked: build.pkg.locked,
+            output_directory: build.pkg.output_directory,
+            json_abi_with_callpaths: build.pkg.json_abi_with_callpaths,
+            ipfs_node: build.pkg.ipfs_node.unwrap_or_default(),
+        },
+        print: pkg::PrintOpts {
+            ast: build.print.ast,
+            dca_graph: build.print.dca_graph,
+            dca_graph_url_format: build.print.dca_graph_url_format,
+            finalized_asm: build.print.finalized_asm,
+            intermediate_asm: build.print.intermediate_asm,
+            ir: build.print.ir,
+            reverse_order: build.print.reverse_order,
+        },
+        time_phases: build.print.time_phases,
+        metrics_outfile: build.print.metrics_outfile,
+        minify: pkg::MinifyOpts {
+            json_abi: build.minify.json_abi,
+            json_storage_slots: build.minify.json_storage_slots,
+        },
+        build_profile: build.profile.build_profile,
+        release: build.profile.release,
+        error_on_warnings: build.profile.error_on_warnings,
+        binary_outfile: build.output.bin_file,
+        debug_outfile: build.output.debug_file,
+        build_target: build.build_target,
+        experimental,
+    }
+}
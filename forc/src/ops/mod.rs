@@ -5,4 +5,5 @@ pub mod forc_contract_id;
 pub mod forc_init;
 pub mod forc_predicate_root;
 pub mod forc_template;
+pub mod forc_test;
 pub mod forc_update;
@@ -82,6 +82,12 @@ pub enum Expr {
         star_token: StarToken,
         expr: Box<Expr>,
     },
+    /// The `?` operator, returning early from the function with the error of a `Result`, or the
+    /// `None` of an `Option`.
+    Try {
+        expr: Box<Expr>,
+        question_mark_token: QuestionMarkToken,
+    },
     Not {
         bang_token: BangToken,
         expr: Box<Expr>,
@@ -192,6 +198,12 @@ pub enum Expr {
     Continue {
         continue_token: ContinueToken,
     },
+    /// A closure, e.g., `|x: u64| x + 1`, which captures the values of the variables it uses.
+    Closure {
+        params: ClosureParams,
+        return_type_opt: Option<(RightArrowToken, Ty)>,
+        body: Box<Expr>,
+    },
 }
 
 impl Spanned for Expr {
@@ -242,6 +254,10 @@ impl Spanned for Expr {
                 expr,
             } => Span::join(ampersand_token.span(), expr.span()),
             Expr::Deref { star_token, expr } => Span::join(star_token.span(), expr.span()),
+            Expr::Try {
+                expr,
+                question_mark_token,
+            } => Span::join(expr.span(), question_mark_token.span()),
             Expr::Not { bang_token, expr } => Span::join(bang_token.span(), expr.span()),
             Expr::Pow { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::Mul { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
@@ -267,6 +283,7 @@ impl Spanned for Expr {
             } => Span::join(assignable.span(), expr.span()),
             Expr::Break { break_token } => break_token.span(),
             Expr::Continue { continue_token } => continue_token.span(),
+            Expr::Closure { params, body, .. } => Span::join(params.span(), body.span()),
         }
     }
 }
@@ -428,6 +445,45 @@ impl Spanned for CodeBlockContents {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub enum ClosureParams {
+    /// The `||` of a closure without parameters.
+    Empty(DoublePipeToken),
+    Params {
+        open_pipe_token: PipeToken,
+        params: Punctuated<ClosureParam, CommaToken>,
+        close_pipe_token: PipeToken,
+    },
+}
+
+impl Spanned for ClosureParams {
+    fn span(&self) -> Span {
+        match self {
+            ClosureParams::Empty(double_pipe_token) => double_pipe_token.span(),
+            ClosureParams::Params {
+                open_pipe_token,
+                close_pipe_token,
+                ..
+            } => Span::join(open_pipe_token.span(), close_pipe_token.span()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ClosureParam {
+    pub name: Ident,
+    pub ty_opt: Option<(ColonToken, Ty)>,
+}
+
+impl Spanned for ClosureParam {
+    fn span(&self) -> Span {
+        match &self.ty_opt {
+            None => self.name.span(),
+            Some((_colon_token, ty)) => Span::join(self.name.span(), ty.span()),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ExprStructField {
     pub field_name: Ident,
@@ -523,6 +579,7 @@ impl Expr {
             | Expr::TupleFieldProjection { .. }
             | Expr::Ref { .. }
             | Expr::Deref { .. }
+            | Expr::Try { .. }
             | Expr::Not { .. }
             | Expr::Mul { .. }
             | Expr::Div { .. }
@@ -545,7 +602,8 @@ impl Expr {
             | Expr::LogicalOr { .. }
             | Expr::Reassignment { .. }
             | Expr::Break { .. }
-            | Expr::Continue { .. } => false,
+            | Expr::Continue { .. }
+            | Expr::Closure { .. } => false,
         }
     }
 }
@@ -232,3 +232,4 @@ define_token!(DoublePipeToken, "`||`", [Pipe, Pipe], [Pipe]);
 define_token!(UnderscoreToken, "`_`", [Underscore], [Underscore]);
 define_token!(HashToken, "`#`", [Sharp], []);
 define_token!(HashBangToken, "`#!`", [Sharp, Bang], []);
+define_token!(QuestionMarkToken, "`?`", [QuestionMark], []);
@@ -26,6 +26,12 @@ pub enum Ty {
         ampersand_token: AmpersandToken,
         ty: Box<Ty>,
     },
+    /// The type of a function parameter that is a closure or a function, e.g., `fn(u64) -> bool`.
+    Fn {
+        fn_token: FnToken,
+        params: Parens<Punctuated<Ty, CommaToken>>,
+        return_type_opt: Option<(RightArrowToken, Box<Ty>)>,
+    },
 }
 
 impl Spanned for Ty {
@@ -43,6 +49,17 @@ impl Spanned for Ty {
                 ampersand_token,
                 ty,
             } => Span::join(ampersand_token.span(), ty.span()),
+            Ty::Fn {
+                fn_token,
+                params,
+                return_type_opt,
+            } => {
+                let end = match return_type_opt {
+                    Some((_right_arrow_token, ty)) => ty.span(),
+                    None => params.span(),
+                };
+                Span::join(fn_token.span(), end)
+            }
         }
     }
 }
@@ -871,6 +871,7 @@ impl TypeInfo {
             Ref(ty) => {
                 format!("__ref {}", ty.abi_str(ctx, type_engine, decl_engine)) // TODO-IG: No references in ABIs according to the RFC. Or we want to have them?
             }
+            Closure(_) => "closure".into(),
         }
     }
 }
@@ -902,7 +903,7 @@ fn call_path_display(ctx: &mut AbiContext, call_path: &CallPath) -> String {
 }
 
 impl TyFunctionDecl {
-    pub(self) fn generate_abi_function(
+    pub fn generate_abi_function(
         &self,
         ctx: &mut AbiContext,
         type_engine: &TypeEngine,
@@ -1,7 +1,11 @@
 use super::instruction_set::InstructionSet;
 use super::ToMidenBytecode;
 use super::{
-    fuel::{checks, data_section::DataSection},
+    fuel::{
+        checks,
+        data_section::DataSection,
+        debug_info::{self, FunctionDebugInfo},
+    },
     ProgramABI, ProgramKind,
 };
 use crate::asm_lang::allocated_ops::{AllocatedOp, AllocatedOpcode};
@@ -26,6 +30,8 @@ pub struct FinalizedAsm {
     pub program_kind: ProgramKind,
     pub entries: Vec<FinalizedEntry>,
     pub abi: Option<ProgramABI>,
+    /// The variables of the functions, whose locations are recorded in the source map.
+    pub(crate) debug_info: Vec<FunctionDebugInfo<usize>>,
 }
 
 #[derive(Clone, Debug)]
@@ -60,6 +66,7 @@ impl FinalizedAsm {
                 handler,
                 ops,
                 &mut self.data_section,
+                &self.debug_info,
                 source_map,
                 source_engine,
             ),
@@ -101,6 +108,7 @@ fn to_bytecode_mut(
     handler: &Handler,
     ops: &[AllocatedOp],
     data_section: &mut DataSection,
+    functions_debug_info: &[FunctionDebugInfo<usize>],
     source_map: &mut SourceMap,
     source_engine: &SourceEngine,
 ) -> Result<CompiledBytecode, ErrorEmitted> {
@@ -133,7 +141,9 @@ fn to_bytecode_mut(
     let mut buf = vec![0; (ops.len() * 4) + 4];
 
     let mut half_word_ix = 0;
+    let mut op_offsets = Vec::with_capacity(ops.len());
     for op in ops.iter() {
+        op_offsets.push(half_word_ix);
         let span = op.owning_span.clone();
         let op = op.to_fuel_asm(offset_to_data_section_in_bytes, data_section);
         match op {
@@ -160,6 +170,15 @@ fn to_bytecode_mut(
         }
     }
 
+    debug_info::insert_functions(
+        source_map,
+        source_engine,
+        functions_debug_info,
+        ops,
+        &op_offsets,
+        half_word_ix,
+    );
+
     let config_offsets = data_section
         .config_map
         .iter()
@@ -0,0 +1,262 @@
+//! The ranges of functions and the locations of their variables for the debug info.
+//!
+//! While compiling a function we know where each of its locals is on the stack, and which argument
+//! register each argument is copied from, but not which opcodes will access them.  These are only
+//! known once the registers are allocated and the program is laid out, when the functions and the
+//! locations are resolved and recorded in the [SourceMap].
+
+use crate::{
+    asm_lang::{
+        allocated_ops::{AllocatedOp, AllocatedOpcode, AllocatedRegister},
+        ConstantRegister,
+    },
+    metadata::DebugTypeNames,
+    source_map::{
+        DebugField, DebugFunction, DebugType, DebugTypeKind, DebugVariable, SourceMap,
+        VariableKind, VariableLocation,
+    },
+};
+
+use sway_ir::{Context, Type, TypeContent};
+use sway_types::{SourceEngine, Span};
+
+use super::compiler_constants;
+
+/// The variables of a function, and where it starts, identified by labels until the program is
+/// laid out and by opcode indices thereafter.
+#[derive(Clone, Debug)]
+pub struct FunctionDebugInfo<L> {
+    /// The name of the function, as declared in the source.
+    pub(crate) name: String,
+    pub(crate) decl: Option<Span>,
+    pub(crate) start: L,
+    /// The start of the body, after the arguments are copied and the locals are allocated.
+    pub(crate) body_start: L,
+    pub(crate) variables: Vec<VariableDebugInfo>,
+}
+
+#[derive(Clone, Debug)]
+pub struct VariableDebugInfo {
+    pub(crate) name: String,
+    pub(crate) kind: VariableKind,
+    pub(crate) ty: DebugType,
+    pub(crate) storage: VariableStorage,
+    pub(crate) decl: Option<Span>,
+    pub(crate) scope: Option<Span>,
+}
+
+#[derive(Clone, Debug)]
+pub enum VariableStorage {
+    /// On the stack, at an offset in bytes from the locals base register.
+    Stack(u64),
+    /// In the register the argument is copied to, either from the `arg_reg` register or from the
+    /// word at `word_offset` from the address it holds.  If `by_reference` the register holds the
+    /// address of the value rather than the value itself.
+    Argument {
+        arg_reg: ConstantRegister,
+        word_offset: Option<u64>,
+        by_reference: bool,
+    },
+}
+
+/// Describes an IR type for the debug info, using the names of the source type and of its
+/// components when they are known.
+pub(crate) fn debug_type(context: &Context, ty: Type, names: Option<&DebugTypeNames>) -> DebugType {
+    let field = |idx: usize, offset: u64, field_ty: Type| {
+        let (name, names) = match names.and_then(|names| names.components.get(idx)) {
+            Some((name, names)) => (name.clone(), Some(names)),
+            None => (idx.to_string(), None),
+        };
+        DebugField {
+            name,
+            offset,
+            ty: debug_type(context, field_ty, names),
+        }
+    };
+
+    let kind = match ty.get_content(context) {
+        TypeContent::Unit => DebugTypeKind::Unit,
+        TypeContent::Bool => DebugTypeKind::Bool,
+        TypeContent::Uint(_) => DebugTypeKind::Uint,
+        TypeContent::B256 => DebugTypeKind::B256,
+        TypeContent::StringArray(_) => DebugTypeKind::StringArray,
+        TypeContent::StringSlice | TypeContent::Slice => DebugTypeKind::Slice,
+        TypeContent::Array(element, length) => DebugTypeKind::Array {
+            element: Box::new(debug_type(
+                context,
+                *element,
+                names
+                    .and_then(|names| names.components.first())
+                    .map(|(_, names)| names),
+            )),
+            length: *length,
+        },
+        TypeContent::Struct(_) if ty.is_enum(context) => {
+            // The variants are in a union following the tag.
+            let (union_offset, union_ty) = ty.get_struct_field_offset_and_type(context, 1).unwrap();
+            let variants = (0..union_ty.get_field_types(context).len())
+                .map(|idx| {
+                    let (offset, variant_ty) = union_ty
+                        .get_union_field_offset_and_type(context, idx as u64)
+                        .unwrap();
+                    field(idx, union_offset + offset, variant_ty)
+                })
+                .collect();
+            DebugTypeKind::Enum(variants)
+        }
+        TypeContent::Struct(fields) => DebugTypeKind::Struct(
+            (0..fields.len())
+                .map(|idx| {
+                    let (offset, field_ty) = ty
+                        .get_struct_field_offset_and_type(context, idx as u64)
+                        .unwrap();
+                    field(idx, offset, field_ty)
+                })
+                .collect(),
+        ),
+        TypeContent::Union(_) | TypeContent::Pointer(_) => DebugTypeKind::Opaque,
+    };
+
+    DebugType {
+        name: names.map_or_else(|| ty.as_string(context), |names| names.name.clone()),
+        size: ty.size(context).in_bytes(),
+        kind,
+    }
+}
+
+/// The name of an IR function as declared in the source.  Functions other than entries are named
+/// with a unique suffix, e.g., `add_3` for `add`, which is removed.
+pub(crate) fn source_function_name(ir_name: &str, is_entry: bool) -> String {
+    if is_entry {
+        return ir_name.to_string();
+    }
+    match ir_name.rsplit_once('_') {
+        Some((name, suffix))
+            if !name.is_empty()
+                && !suffix.is_empty()
+                && suffix.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            name.to_string()
+        }
+        _ => ir_name.to_string(),
+    }
+}
+
+/// Records the ranges of `functions` and the locations of their variables in the `source_map`.
+///
+/// `op_offsets` are the opcode indices of `ops` and `program_end` is the index past the last
+/// opcode.  A function ends where the next one starts.
+pub(crate) fn insert_functions(
+    source_map: &mut SourceMap,
+    source_engine: &SourceEngine,
+    functions: &[FunctionDebugInfo<usize>],
+    ops: &[AllocatedOp],
+    op_offsets: &[usize],
+    program_end: usize,
+) {
+    let mut starts = functions.iter().map(|func| func.start).collect::<Vec<_>>();
+    starts.sort_unstable();
+
+    for func in functions {
+        let end = starts
+            .iter()
+            .copied()
+            .find(|start| *start > func.start)
+            .unwrap_or(program_end);
+        let first_op = op_offsets.partition_point(|offset| *offset < func.start);
+        let body_op = op_offsets.partition_point(|offset| *offset < func.body_start);
+        let end_op = op_offsets.partition_point(|offset| *offset < end);
+        source_map.insert_function(
+            source_engine,
+            DebugFunction {
+                name: func.name.clone(),
+                start_pc: func.start,
+                end_pc: end,
+                decl: None,
+            },
+            func.decl.as_ref(),
+        );
+
+        for var in &func.variables {
+            let (location, end_pc) = match &var.storage {
+                VariableStorage::Stack(offset) => (
+                    VariableLocation::Memory {
+                        base: compiler_constants::LOCALS_BASE,
+                        offset: *offset,
+                    },
+                    end,
+                ),
+                VariableStorage::Argument {
+                    arg_reg,
+                    word_offset,
+                    by_reference,
+                } => {
+                    // The copy may have been optimised away if the argument is unused.
+                    let Some((copy_op, reg)) = (first_op..body_op).find_map(|idx| {
+                        arg_copy_destination(&ops[idx].opcode, arg_reg, *word_offset)
+                            .map(|reg| (idx, reg))
+                    }) else {
+                        continue;
+                    };
+                    // The register holds the argument until it is reused, or until the caller's
+                    // registers are restored.
+                    let end_pc = (copy_op + 1..end_op)
+                        .find(|idx| match &ops[*idx].opcode {
+                            AllocatedOpcode::POPH(_) | AllocatedOpcode::POPL(_) => true,
+                            AllocatedOpcode::PSHH(_) | AllocatedOpcode::PSHL(_) => false,
+                            opcode => opcode.def_registers().contains(&reg),
+                        })
+                        .map_or(end, |idx| op_offsets[idx]);
+                    let reg = reg.to_reg_id().to_u8();
+                    let location = if *by_reference {
+                        VariableLocation::Memory {
+                            base: reg,
+                            offset: 0,
+                        }
+                    } else {
+                        VariableLocation::Register(reg)
+                    };
+                    (location, end_pc)
+                }
+            };
+            if end_pc <= func.body_start {
+                continue;
+            }
+
+            source_map.insert_variable(
+                source_engine,
+                DebugVariable {
+                    name: var.name.clone(),
+                    kind: var.kind,
+                    ty: var.ty.clone(),
+                    location,
+                    start_pc: func.body_start,
+                    end_pc,
+                    decl: None,
+                    scope: None,
+                },
+                var.decl.as_ref(),
+                var.scope.as_ref(),
+            );
+        }
+    }
+}
+
+/// The register an argument is copied to by `opcode`, if it copies the argument.
+fn arg_copy_destination<'a>(
+    opcode: &'a AllocatedOpcode,
+    arg_reg: &ConstantRegister,
+    word_offset: Option<u64>,
+) -> Option<&'a AllocatedRegister> {
+    match (opcode, word_offset) {
+        (AllocatedOpcode::MOVE(dst, AllocatedRegister::Constant(src)), None) if src == arg_reg => {
+            Some(dst)
+        }
+        (AllocatedOpcode::LW(dst, AllocatedRegister::Constant(src), imm), Some(word_offset))
+            if src == arg_reg && u64::from(imm.value) == word_offset =>
+        {
+            Some(dst)
+        }
+        _otherwise => None,
+    }
+}
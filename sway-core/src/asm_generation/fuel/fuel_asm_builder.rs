@@ -6,6 +6,7 @@ use crate::{
             abstract_instruction_set::AbstractInstructionSet,
             compiler_constants,
             data_section::{DataId, DataSection, Entry},
+            debug_info::FunctionDebugInfo,
             register_sequencer::RegisterSequencer,
         },
         ProgramKind,
@@ -72,6 +73,9 @@ pub struct FuelAsmBuilder<'ir, 'eng> {
     pub(super) entries: Vec<(Function, Label, Vec<Op>, Option<DeclRefFunction>)>,
     pub(super) non_entries: Vec<Vec<Op>>,
 
+    // The variables of all the functions, for the debug info.
+    pub(super) debug_info: Vec<FunctionDebugInfo<Label>>,
+
     // In progress VM bytecode ops.
     pub(super) cur_bytecode: Vec<Op>,
 }
@@ -86,6 +90,7 @@ pub type FuelAsmBuilderResult = (
         Option<DeclRefFunction>,
     )>,
     Vec<AbstractInstructionSet>,
+    Vec<FunctionDebugInfo<Label>>,
 );
 
 impl<'ir, 'eng> AsmBuilder for FuelAsmBuilder<'ir, 'eng> {
@@ -128,6 +133,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             md_mgr: MetadataManager::default(),
             entries: Vec::new(),
             non_entries: Vec::new(),
+            debug_info: Vec::new(),
             cur_bytecode: Vec::new(),
         }
     }
@@ -148,6 +154,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
                 .into_iter()
                 .map(|ops| AbstractInstructionSet { ops })
                 .collect(),
+            self.debug_info.clone(),
         ))
     }
 
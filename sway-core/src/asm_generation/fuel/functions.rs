@@ -1,7 +1,15 @@
 use crate::{
     asm_generation::{
         from_ir::*,
-        fuel::{compiler_constants, data_section::Entry, fuel_asm_builder::FuelAsmBuilder},
+        fuel::{
+            compiler_constants,
+            data_section::Entry,
+            debug_info::{
+                debug_type, source_function_name, FunctionDebugInfo, VariableDebugInfo,
+                VariableStorage,
+            },
+            fuel_asm_builder::FuelAsmBuilder,
+        },
         ProgramKind,
     },
     asm_lang::{
@@ -11,6 +19,7 @@ use crate::{
     },
     decl_engine::DeclRef,
     fuel_prelude::fuel_asm::GTFArgs,
+    source_map::VariableKind,
 };
 
 use sway_ir::*;
@@ -257,7 +266,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         let locals_alloc_result = self.alloc_locals(function);
 
         if func_is_entry {
-            self.compile_external_args(function)
+            self.compile_external_args(function, test_decl_index.is_some())
                 .map_err(|e| handler.emit_err(e))?
         } else {
             // Make copies of the arg registers.
@@ -287,6 +296,17 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
 
         self.init_locals(locals_alloc_result);
 
+        // The variables are all in place once the body starts, at the entry block.
+        let variables = self.variables_debug_info(function, func_is_entry);
+        let body_start = self.block_to_label(&function.get_entry_block(self.context));
+        self.debug_info.push(FunctionDebugInfo {
+            name: source_function_name(function.get_name(self.context), func_is_entry),
+            decl: self.md_mgr.md_to_span(self.context, md),
+            start: start_label,
+            body_start,
+            variables,
+        });
+
         // Compile instructions. Traverse the IR blocks in reverse post order. This guarantees that
         // each block is processed after all its CFG predecessors have been processed.
         let po = sway_ir::dominator::compute_post_order(self.context, &function);
@@ -405,8 +425,92 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         }
     }
 
+    /// Describes the arguments and stack allocated locals of `function` for the debug info.  The
+    /// arguments of entry functions are decoded from the transaction and aren't described.
+    fn variables_debug_info(
+        &mut self,
+        function: Function,
+        func_is_entry: bool,
+    ) -> Vec<VariableDebugInfo> {
+        let mut variables = Vec::new();
+
+        if !func_is_entry {
+            let num_args = function.num_args(self.context);
+            for (idx, (_, arg_val)) in function.args_iter(self.context).enumerate() {
+                let md_idx = arg_val.get_metadata(self.context);
+                let Some(debug_var) = self.md_mgr.md_to_debug_variable(self.context, md_idx) else {
+                    continue;
+                };
+                let arg_ty = arg_val.get_type(self.context).unwrap();
+                let (ty, by_reference) = match arg_ty.get_pointee_type(self.context) {
+                    Some(pointee_ty) => (pointee_ty, true),
+                    None => (arg_ty, !self.is_copy_type(&arg_ty)),
+                };
+                // See `compile_fn_call_args()` for where the arguments are passed.
+                let (arg_reg, word_offset) = if num_args <= NUM_ARG_REGISTERS as usize
+                    || idx < NUM_ARG_REGISTERS as usize - 1
+                {
+                    (ConstantRegister::ARG_REGS[idx], None)
+                } else {
+                    (
+                        ConstantRegister::ARG_REGS[NUM_ARG_REGISTERS as usize - 1],
+                        Some(idx as u64 + 1 - NUM_ARG_REGISTERS as u64),
+                    )
+                };
+                variables.push(VariableDebugInfo {
+                    name: debug_var.name,
+                    kind: VariableKind::Argument,
+                    ty: debug_type(self.context, ty, Some(&debug_var.type_names)),
+                    storage: VariableStorage::Argument {
+                        arg_reg,
+                        word_offset,
+                        by_reference,
+                    },
+                    decl: self.md_mgr.md_to_span(self.context, md_idx),
+                    scope: debug_var.scope,
+                });
+            }
+        }
+
+        // Locals in the data section are constants, which have no runtime location.
+        for (_, ptr) in function.locals_iter(self.context) {
+            let Some(Storage::Stack(stack_base_words)) = self.ptr_map.get(ptr) else {
+                continue;
+            };
+            let md_idx = ptr.get_metadata(self.context);
+            let Some(debug_var) = self.md_mgr.md_to_debug_variable(self.context, md_idx) else {
+                continue;
+            };
+            variables.push(VariableDebugInfo {
+                name: debug_var.name,
+                kind: VariableKind::Local,
+                ty: debug_type(
+                    self.context,
+                    ptr.get_inner_type(self.context),
+                    Some(&debug_var.type_names),
+                ),
+                storage: VariableStorage::Stack(stack_base_words * 8),
+                decl: self.md_mgr.md_to_span(self.context, md_idx),
+                scope: debug_var.scope,
+            });
+        }
+
+        variables
+    }
+
     // Handle loading the arguments of a contract call
-    fn compile_external_args(&mut self, function: Function) -> Result<(), CompileError> {
+    fn compile_external_args(
+        &mut self,
+        function: Function,
+        is_test: bool,
+    ) -> Result<(), CompileError> {
+        // Tests are executed as scripts whatever the kind of the program, so their arguments are
+        // read from the script data.
+        let program_kind = if is_test {
+            ProgramKind::Script
+        } else {
+            self.program_kind
+        };
         match function.args_iter(self.context).count() {
             // Nothing to do if there are no arguments
             0 => Ok(()),
@@ -416,13 +520,13 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             1 => {
                 let (_, val) = function.args_iter(self.context).next().unwrap();
                 let single_arg_reg = self.reg_seqr.next();
-                match self.program_kind {
+                match program_kind {
                     ProgramKind::Contract => {
                         self.read_args_base_from_frame(&single_arg_reg);
                     }
                     ProgramKind::Library => {} // Nothing to do here
                     ProgramKind::Script | ProgramKind::Predicate => {
-                        if let ProgramKind::Predicate = self.program_kind {
+                        if let ProgramKind::Predicate = program_kind {
                             self.read_args_base_from_predicate_data(&single_arg_reg);
                         } else {
                             self.read_args_base_from_script_data(&single_arg_reg);
@@ -453,7 +557,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             // Otherwise, the args are bundled together and pointed to by the base register.
             _ => {
                 let args_base_reg = self.reg_seqr.next();
-                match self.program_kind {
+                match program_kind {
                     ProgramKind::Contract => self.read_args_base_from_frame(&args_base_reg),
                     ProgramKind::Library => return Ok(()), // Nothing to do here
                     ProgramKind::Predicate => {
@@ -5,6 +5,7 @@ pub(crate) mod register_allocator;
 pub(super) mod abstract_instruction_set;
 pub(super) mod allocated_abstract_instruction_set;
 pub(super) mod checks;
+pub(super) mod debug_info;
 pub(super) mod fuel_asm_builder;
 pub(super) mod register_sequencer;
 
@@ -5,7 +5,7 @@ mod r#final;
 use super::fuel::{
     abstract_instruction_set::AbstractInstructionSet,
     allocated_abstract_instruction_set::AllocatedAbstractInstructionSet, data_section::DataSection,
-    register_sequencer::RegisterSequencer,
+    debug_info::FunctionDebugInfo, register_sequencer::RegisterSequencer,
 };
 
 use crate::{
@@ -36,6 +36,7 @@ pub(super) struct AbstractProgram {
     entries: Vec<AbstractEntry>,
     non_entries: Vec<AbstractInstructionSet>,
     reg_seqr: RegisterSequencer,
+    debug_info: Vec<FunctionDebugInfo<Label>>,
 }
 
 /// The entry point of an abstract program.
@@ -55,6 +56,7 @@ pub(super) struct AllocatedProgram {
     prologue: AllocatedAbstractInstructionSet,
     functions: Vec<AllocatedAbstractInstructionSet>,
     entries: Vec<(SelectorOpt, Label, FnName, Option<DeclRefFunction>)>,
+    debug_info: Vec<FunctionDebugInfo<Label>>,
 }
 
 /// A FinalProgram represents code which may be serialized to VM bytecode.
@@ -64,6 +66,7 @@ pub(super) enum FinalProgram {
         data_section: DataSection,
         ops: Vec<AllocatedOp>,
         entries: Vec<(SelectorOpt, ImmOffset, FnName, Option<DeclRefFunction>)>,
+        debug_info: Vec<FunctionDebugInfo<usize>>,
     },
     Evm {
         ops: Vec<etk_asm::ops::AbstractOp>,
@@ -6,11 +6,12 @@ use crate::{
         allocated_abstract_instruction_set::AllocatedAbstractInstructionSet,
         compiler_constants,
         data_section::{DataSection, Entry},
+        debug_info::FunctionDebugInfo,
         register_sequencer::RegisterSequencer,
     },
     asm_lang::{
         allocated_ops::{AllocatedOpcode, AllocatedRegister},
-        AllocatedAbstractOp, ConstantRegister, ControlFlowOp, VirtualImmediate12,
+        AllocatedAbstractOp, ConstantRegister, ControlFlowOp, Label, VirtualImmediate12,
         VirtualImmediate18,
     },
 };
@@ -26,6 +27,7 @@ impl AbstractProgram {
         entries: Vec<AbstractEntry>,
         non_entries: Vec<AbstractInstructionSet>,
         reg_seqr: RegisterSequencer,
+        debug_info: Vec<FunctionDebugInfo<Label>>,
     ) -> Self {
         AbstractProgram {
             kind,
@@ -33,6 +35,7 @@ impl AbstractProgram {
             entries,
             non_entries,
             reg_seqr,
+            debug_info,
         }
     }
 
@@ -87,6 +90,7 @@ impl AbstractProgram {
             prologue,
             functions,
             entries,
+            debug_info: self.debug_info,
         })
     }
 
@@ -1,6 +1,9 @@
 use super::{AllocatedProgram, FinalProgram};
 
-use crate::asm_generation::fuel::allocated_abstract_instruction_set::AllocatedAbstractInstructionSet;
+use crate::asm_generation::fuel::{
+    allocated_abstract_instruction_set::AllocatedAbstractInstructionSet,
+    debug_info::FunctionDebugInfo,
+};
 
 impl AllocatedProgram {
     pub(crate) fn into_final_program(mut self) -> Result<FinalProgram, crate::CompileError> {
@@ -16,6 +19,23 @@ impl AllocatedProgram {
             abstract_ops.realize_labels(&mut self.data_section)?;
         let ops = realized_ops.pad_to_even();
 
+        // Resolve where the functions and their bodies start.  Functions whose labels were
+        // optimised away aren't described.
+        let debug_info = self
+            .debug_info
+            .into_iter()
+            .filter_map(|func| {
+                let offset = |label| label_offsets.get(label).map(|block| block.offs as usize);
+                Some(FunctionDebugInfo {
+                    name: func.name,
+                    decl: func.decl,
+                    start: offset(&func.start)?,
+                    body_start: offset(&func.body_start)?,
+                    variables: func.variables,
+                })
+            })
+            .collect();
+
         // Collect the entry point offsets.
         let entries = self
             .entries
@@ -34,6 +54,7 @@ impl AllocatedProgram {
             data_section: self.data_section,
             ops,
             entries,
+            debug_info,
         })
     }
 }
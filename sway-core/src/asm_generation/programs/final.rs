@@ -15,6 +15,7 @@ impl FinalProgram {
                 data_section,
                 ops,
                 entries,
+                debug_info,
             } => FinalizedAsm {
                 data_section,
                 program_section: InstructionSet::Fuel { ops },
@@ -29,6 +30,7 @@ impl FinalProgram {
                     })
                     .collect(),
                 abi: None,
+                debug_info,
             },
             FinalProgram::Evm { ops, abi } => FinalizedAsm {
                 data_section: DataSection {
@@ -38,6 +40,7 @@ impl FinalProgram {
                 program_kind: super::ProgramKind::Script,
                 entries: vec![],
                 abi: Some(ProgramABI::Evm(abi)),
+                debug_info: vec![],
             },
             FinalProgram::MidenVM { ops } => FinalizedAsm {
                 data_section: DataSection {
@@ -48,6 +51,7 @@ impl FinalProgram {
                 program_kind: super::ProgramKind::Script,
                 entries: vec![],
                 abi: None, /* TODO? */
+                debug_info: vec![],
             },
         }
     }
@@ -36,6 +36,10 @@ pub enum BuildTarget {
 pub enum OptLevel {
     Opt0,
     Opt1,
+    /// Iterates a richer pipeline to a fixed point, inlining eagerly to reduce gas.
+    Opt2,
+    /// Iterates a richer pipeline to a fixed point, avoiding transforms which grow bytecode size.
+    OptSize,
 }
 
 /// Configuration for the overall build and compilation process.
@@ -1859,7 +1859,142 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             exp.span.clone(),
             options,
         ),
+        Closure { fn_ref, captures } => connect_closure(
+            engines,
+            fn_ref,
+            captures,
+            graph,
+            leaves,
+            exit_node,
+            label,
+            tree_type,
+            expression_span,
+            options,
+        ),
+        ClosureCall { closure, arguments } => connect_closure_call(
+            engines, closure, arguments, graph, leaves, exit_node, tree_type, options,
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn connect_closure<'eng: 'cfg, 'cfg>(
+    engines: &'eng Engines,
+    fn_ref: &DeclRefFunction,
+    captures: &[ty::TyExpression],
+    graph: &mut ControlFlowGraph<'cfg>,
+    leaves: &[NodeIndex],
+    exit_node: Option<NodeIndex>,
+    label: &'static str,
+    tree_type: &TreeType,
+    expression_span: Span,
+    options: NodeConnectionOptions,
+) -> Result<Vec<NodeIndex>, CompileError> {
+    let decl_engine = engines.de();
+    let fn_decl = decl_engine.get_function(fn_ref);
+
+    // a function used as a value is only referenced, it gets called by the closure calls
+    if fn_decl.span != expression_span {
+        if let Some(fn_namespace_entry) = graph.namespace.get_function(&fn_decl).cloned() {
+            for leaf in leaves {
+                graph.add_edge(*leaf, fn_namespace_entry.entry_point, label.into());
+            }
+        }
+        return Ok(leaves.to_vec());
+    }
+
+    let entry = graph.add_node("closure entry".into());
+    let exit = graph.add_node("closure exit".into());
+    for leaf in leaves {
+        graph.add_edge(*leaf, entry, label.into());
+    }
+
+    let mut current_leaf = vec![entry];
+    for capture in captures {
+        current_leaf = connect_expression(
+            engines,
+            &capture.expression,
+            graph,
+            &current_leaf,
+            exit_node,
+            "closure capture",
+            tree_type,
+            capture.span.clone(),
+            options,
+        )?;
+    }
+
+    // the captured variables are used under their own names in the body, so only the
+    // parameters of the closure are new variables
+    graph.namespace.push_code_block();
+    for param in fn_decl.parameters.iter().skip(captures.len()) {
+        let param_node = graph.add_node(ControlFlowGraphNode::FunctionParameter {
+            param_name: param.name.clone(),
+            is_self: false,
+        });
+        for leaf in current_leaf.iter() {
+            graph.add_edge(*leaf, param_node, "".into());
+        }
+        graph.namespace.insert_variable(
+            param.name.clone(),
+            VariableNamespaceEntry {
+                variable_decl_ix: param_node,
+            },
+        );
+    }
+    let (body_leaves, _exit_node) = depth_first_insertion_code_block(
+        engines,
+        &fn_decl.body,
+        graph,
+        &current_leaf,
+        Some(exit),
+        tree_type,
+        options,
+    )?;
+    graph.namespace.pop_code_block();
+
+    for leaf in body_leaves {
+        graph.add_edge(leaf, exit, "".into());
+    }
+    Ok(vec![exit])
+}
+
+#[allow(clippy::too_many_arguments)]
+fn connect_closure_call<'eng: 'cfg, 'cfg>(
+    engines: &'eng Engines,
+    closure: &ty::TyExpression,
+    arguments: &[ty::TyExpression],
+    graph: &mut ControlFlowGraph<'cfg>,
+    leaves: &[NodeIndex],
+    exit_node: Option<NodeIndex>,
+    tree_type: &TreeType,
+    options: NodeConnectionOptions,
+) -> Result<Vec<NodeIndex>, CompileError> {
+    let mut current_leaf = connect_expression(
+        engines,
+        &closure.expression,
+        graph,
+        leaves,
+        exit_node,
+        "closure call",
+        tree_type,
+        closure.span.clone(),
+        options,
+    )?;
+    for arg in arguments {
+        current_leaf = connect_expression(
+            engines,
+            &arg.expression,
+            graph,
+            &current_leaf,
+            exit_node,
+            "arg eval",
+            tree_type,
+            arg.span.clone(),
+            options,
+        )?;
     }
+    Ok(current_leaf)
 }
 
 fn connect_intrinsic_function<'eng: 'cfg, 'cfg>(
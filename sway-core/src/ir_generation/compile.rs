@@ -314,6 +314,7 @@ pub(super) fn compile_function(
     ast_fn_decl: &ty::TyFunctionDecl,
     logged_types_map: &HashMap<TypeId, LogId>,
     messages_types_map: &HashMap<TypeId, MessageId>,
+    closures: &[(TypeId, Function)],
     is_entry: bool,
     test_decl_ref: Option<DeclRefFunction>,
 ) -> Result<Option<Function>, Vec<CompileError>> {
@@ -332,6 +333,7 @@ pub(super) fn compile_function(
             None,
             logged_types_map,
             messages_types_map,
+            closures,
             test_decl_ref,
         )
         .map(Some)
@@ -359,6 +361,7 @@ pub(super) fn compile_entry_function(
         &ast_fn_decl,
         logged_types_map,
         messages_types_map,
+        &[],
         is_entry,
         test_decl_ref,
     )
@@ -403,6 +406,7 @@ fn compile_fn(
     selector: Option<[u8; 4]>,
     logged_types_map: &HashMap<TypeId, LogId>,
     messages_types_map: &HashMap<TypeId, MessageId>,
+    closures: &[(TypeId, Function)],
     test_decl_ref: Option<DeclRefFunction>,
 ) -> Result<Function, Vec<CompileError>> {
     let type_engine = engines.te();
@@ -448,8 +452,19 @@ fn compile_fn(
                         .is_reference
                         .then(|| Type::new_ptr(context, ty))
                         .unwrap_or(ty),
-                    // Convert the span to a metadata index.
-                    md_mgr.span_to_md(context, &param.name.span()),
+                    // Convert the span to a metadata index, along with the description of the
+                    // argument for the debug info.
+                    {
+                        let name_md_idx = md_mgr.span_to_md(context, &param.name.span());
+                        let debug_var_md_idx = md_mgr.debug_variable_to_md(
+                            context,
+                            engines,
+                            param.name.as_str(),
+                            param.type_argument.type_id,
+                            Some(&body.whole_block_span),
+                        );
+                        md_combine(context, &name_md_idx, &debug_var_md_idx)
+                    },
                 )
             })
         })
@@ -498,6 +513,7 @@ fn compile_fn(
         func,
         logged_types_map,
         messages_types_map,
+        closures,
     );
     let mut ret_val = compiler.compile_code_block_to_value(context, md_mgr, body)?;
 
@@ -584,6 +600,7 @@ fn compile_abi_method(
         Some(selector),
         logged_types_map,
         messages_types_map,
+        &[],
         None,
     )
 }
@@ -674,7 +674,9 @@ fn const_eval_typed_expr(
         | ty::TyExpressionVariant::Break
         | ty::TyExpressionVariant::Continue
         | ty::TyExpressionVariant::WhileLoop { .. }
-        | ty::TyExpressionVariant::ForLoop { .. } => {
+        | ty::TyExpressionVariant::ForLoop { .. }
+        | ty::TyExpressionVariant::Closure { .. }
+        | ty::TyExpressionVariant::ClosureCall { .. } => {
             return Err(ConstEvalError::CannotBeEvaluatedToConst {
                 span: expr.span.clone(),
             })
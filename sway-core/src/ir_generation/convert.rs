@@ -159,6 +159,17 @@ fn convert_resolved_type(
             convert_resolved_typeid(type_engine, decl_engine, context, &ty.type_id, span)?
         }
         TypeInfo::Ref(_) => Type::get_uint64(context),
+        // The value of a closure is the aggregate of the values it captures.
+        TypeInfo::Closure(closure) => match closure.captures() {
+            Some([]) => Type::get_unit(context),
+            Some(captures) => create_tuple_aggregate(
+                type_engine,
+                decl_engine,
+                context,
+                captures.iter().map(|capture| capture.type_id).collect(),
+            )?,
+            None => reject_type!("Generic closure"),
+        },
 
         // Unsupported types which shouldn't exist in the AST after type checking and
         // monomorphisation.
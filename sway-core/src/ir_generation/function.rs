@@ -6,6 +6,7 @@ use super::{
     types::*,
 };
 use crate::{
+    decl_engine::DeclRefFunction,
     engine_threading::*,
     ir_generation::const_eval::{
         compile_constant_expression, compile_constant_expression_to_constant,
@@ -21,7 +22,7 @@ use crate::{
 use indexmap::IndexMap;
 use sway_ast::intrinsics::Intrinsic;
 use sway_error::error::CompileError;
-use sway_ir::{Context, *};
+use sway_ir::{metadata::combine as md_combine, Context, *};
 use sway_types::{
     constants,
     ident::Ident,
@@ -94,11 +95,18 @@ pub(crate) struct FnCompiler<'eng> {
     block_to_continue_to: Option<Block>,
     current_fn_param: Option<ty::TyFunctionParameter>,
     lexical_map: LexicalMap,
+    // The spans of the blocks being compiled, innermost last, which are the scopes of the
+    // variables they declare.
+    block_spans: Vec<Span>,
     recreated_fns: HashMap<(Span, Vec<TypeId>, Vec<TypeId>), Function>,
     // This is a map from the type IDs of a logged type and the ID of the corresponding log
     logged_types_map: HashMap<TypeId, LogId>,
     // This is a map from the type IDs of a message data type and the ID of the corresponding smo
     messages_types_map: HashMap<TypeId, MessageId>,
+    // The types of the closures created by this function and its callers, and the functions that
+    // hold their bodies.  Every closure has a distinct type, so calling a closure is a call of the
+    // function found by its type.
+    closures: Vec<(TypeId, Function)>,
 }
 
 impl<'eng> FnCompiler<'eng> {
@@ -110,6 +118,7 @@ impl<'eng> FnCompiler<'eng> {
         function: Function,
         logged_types_map: &HashMap<TypeId, LogId>,
         messages_types_map: &HashMap<TypeId, MessageId>,
+        closures: &[(TypeId, Function)],
     ) -> Self {
         let lexical_map = LexicalMap::from_iter(
             function
@@ -124,10 +133,12 @@ impl<'eng> FnCompiler<'eng> {
             block_to_break_to: None,
             block_to_continue_to: None,
             lexical_map,
+            block_spans: Vec::new(),
             recreated_fns: HashMap::new(),
             current_fn_param: None,
             logged_types_map: logged_types_map.clone(),
             messages_types_map: messages_types_map.clone(),
+            closures: closures.to_vec(),
         }
     }
 
@@ -156,7 +167,8 @@ impl<'eng> FnCompiler<'eng> {
         md_mgr: &mut MetadataManager,
         ast_block: &ty::TyCodeBlock,
     ) -> Result<TerminatorValue, Vec<CompileError>> {
-        self.compile_with_new_scope(|fn_compiler| {
+        self.block_spans.push(ast_block.whole_block_span.clone());
+        let result = self.compile_with_new_scope(|fn_compiler| {
             let mut errors = vec![];
 
             let mut ast_nodes = ast_block.contents.iter();
@@ -180,7 +192,9 @@ impl<'eng> FnCompiler<'eng> {
             } else {
                 Ok(v)
             }
-        })
+        });
+        self.block_spans.pop();
+        result
     }
 
     fn compile_ast_node(
@@ -639,6 +653,18 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::Deref(exp) => {
                 self.compile_deref(context, md_mgr, exp, span_md_idx)
             }
+            ty::TyExpressionVariant::Closure { fn_ref, captures } => self.compile_closure(
+                context,
+                md_mgr,
+                fn_ref,
+                captures,
+                ast_expr.return_type,
+                &ast_expr.span,
+                span_md_idx,
+            ),
+            ty::TyExpressionVariant::ClosureCall { closure, arguments } => {
+                self.compile_closure_call(context, md_mgr, closure, arguments, span_md_idx)
+            }
         }
     }
 
@@ -1730,6 +1756,41 @@ impl<'eng> FnCompiler<'eng> {
         // Eventually we need to Do It Properly and inline into the AST only when necessary, and
         // compile the standard library to an actual module.
 
+        // The arguments are compiled first, so that the closures among them are known when
+        // compiling the callee.
+        let mut args = Vec::with_capacity(ast_args.len());
+        for ((_, expr), param) in ast_args.iter().zip(callee.parameters.iter()) {
+            self.current_fn_param = Some(param.clone());
+            let arg =
+                return_on_termination_or_extract!(if param.is_reference && param.is_mutable {
+                    self.compile_expression_to_ptr(context, md_mgr, expr)
+                } else {
+                    self.compile_expression_to_value(context, md_mgr, expr)
+                }?);
+            self.current_fn_param = None;
+            args.push(arg);
+        }
+
+        let new_callee = self.compile_fn_instance(context, md_mgr, callee)?;
+
+        // Now actually call the new function.
+        let val = self
+            .current_block
+            .append(context)
+            .call(new_callee, &args)
+            .add_metadatum(context, span_md_idx);
+
+        Ok(TerminatorValue::new(val, context))
+    }
+
+    /// Returns the instance of `callee` for the types of its parameters, compiling it on its first
+    /// use, either by a call or as a function value.
+    fn compile_fn_instance(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        callee: &ty::TyFunctionDecl,
+    ) -> Result<Function, CompileError> {
         // Get the callee from the cache if we've already compiled it.  We can't insert it with
         // .entry() since `compile_function()` returns a Result we need to handle.  The key to our
         // cache, to uniquely identify a function instance, is the span and the type IDs of any
@@ -1744,58 +1805,167 @@ impl<'eng> FnCompiler<'eng> {
                 .collect(),
             callee.type_parameters.iter().map(|tp| tp.type_id).collect(),
         );
-        let new_callee = match self.recreated_fns.get(&fn_key).copied() {
-            Some(func) => func,
-            None => {
-                let callee_fn_decl = ty::TyFunctionDecl {
-                    type_parameters: Vec::new(),
-                    name: Ident::new(Span::from_string(format!(
-                        "{}_{}",
-                        callee.name,
-                        context.get_unique_id()
-                    ))),
-                    parameters: callee.parameters.clone(),
-                    ..callee.clone()
-                };
-                let is_entry = false;
-                let new_func = compile_function(
-                    self.engines,
-                    context,
-                    md_mgr,
-                    self.module,
-                    &callee_fn_decl,
-                    &self.logged_types_map,
-                    &self.messages_types_map,
-                    is_entry,
-                    None,
+        if let Some(func) = self.recreated_fns.get(&fn_key).copied() {
+            return Ok(func);
+        }
+        let callee_fn_decl = ty::TyFunctionDecl {
+            type_parameters: Vec::new(),
+            name: Ident::new(Span::from_string(format!(
+                "{}_{}",
+                callee.name,
+                context.get_unique_id()
+            ))),
+            parameters: callee.parameters.clone(),
+            ..callee.clone()
+        };
+        let is_entry = false;
+        let new_func = compile_function(
+            self.engines,
+            context,
+            md_mgr,
+            self.module,
+            &callee_fn_decl,
+            &self.logged_types_map,
+            &self.messages_types_map,
+            &self.closures,
+            is_entry,
+            None,
+        )
+        .map_err(|mut x| x.pop().unwrap())?
+        .unwrap();
+        self.recreated_fns.insert(fn_key, new_func);
+        Ok(new_func)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn compile_closure(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        fn_ref: &DeclRefFunction,
+        captures: &[ty::TyExpression],
+        closure_type: TypeId,
+        span: &Span,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<TerminatorValue, CompileError> {
+        // The value of the closure is the aggregate of the captured values.
+        let closure_val = return_on_termination_or_extract!(self.compile_tuple_expr(
+            context,
+            md_mgr,
+            captures,
+            span_md_idx
+        )?);
+
+        // A function used as a value is compiled once for all its uses, like a called one is.
+        // The body of a closure is compiled into a function of its own, which takes the captured
+        // values before the arguments.
+        let fn_decl = self.engines.de().get_function(fn_ref);
+        let closure_fn = if fn_decl.span != *span {
+            self.compile_fn_instance(context, md_mgr, &fn_decl)?
+        } else {
+            let closure_fn_decl = ty::TyFunctionDecl {
+                name: Ident::new(Span::from_string(format!(
+                    "{}_{}",
+                    fn_decl.name,
+                    context.get_unique_id()
+                ))),
+                ..(*fn_decl).clone()
+            };
+            let is_entry = false;
+            compile_function(
+                self.engines,
+                context,
+                md_mgr,
+                self.module,
+                &closure_fn_decl,
+                &self.logged_types_map,
+                &self.messages_types_map,
+                &self.closures,
+                is_entry,
+                None,
+            )
+            .map_err(|mut x| x.pop().unwrap())?
+            .ok_or_else(|| CompileError::Internal("Closure cannot be generic.", fn_decl.span()))?
+        };
+        self.closures.push((closure_type, closure_fn));
+
+        Ok(TerminatorValue::new(closure_val, context))
+    }
+
+    fn compile_closure_call(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        closure: &ty::TyExpression,
+        ast_args: &[ty::TyExpression],
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<TerminatorValue, CompileError> {
+        let type_engine = self.engines.te();
+        let closure_type = type_engine.get(closure.return_type);
+        let closure_fn = self
+            .closures
+            .iter()
+            .find(|(type_id, _)| type_engine.get(*type_id).eq(&closure_type, self.engines))
+            .map(|(_, closure_fn)| *closure_fn)
+            .ok_or_else(|| {
+                CompileError::Internal(
+                    "Called closure has not been compiled.",
+                    closure.span.clone(),
                 )
-                .map_err(|mut x| x.pop().unwrap())?
-                .unwrap();
-                self.recreated_fns.insert(fn_key, new_func);
-                new_func
-            }
+            })?;
+        let num_captures = match &*closure_type {
+            TypeInfo::Closure(closure_type) => closure_type.captures().map_or(0, |c| c.len()),
+            _ => 0,
         };
 
-        // Now actually call the new function.
-        let mut args = Vec::with_capacity(ast_args.len());
-        for ((_, expr), param) in ast_args.iter().zip(callee.parameters.iter()) {
-            self.current_fn_param = Some(param.clone());
-            let arg =
-                return_on_termination_or_extract!(if param.is_reference && param.is_mutable {
-                    self.compile_expression_to_ptr(context, md_mgr, expr)
-                } else {
-                    self.compile_expression_to_value(context, md_mgr, expr)
-                }?);
-            self.current_fn_param = None;
-            args.push(arg);
+        // The captured values are passed to the function of the closure before the arguments.
+        let mut args = Vec::with_capacity(num_captures + ast_args.len());
+        if num_captures > 0 {
+            let closure_ptr = return_on_termination_or_extract!(
+                self.compile_expression_to_ptr(context, md_mgr, closure)?
+            );
+            let aggregate_type = convert_resolved_typeid(
+                type_engine,
+                self.engines.de(),
+                context,
+                &closure.return_type,
+                &closure.span,
+            )?;
+            for idx in 0..num_captures as u64 {
+                let field_type = aggregate_type.get_field_type(context, idx).ok_or_else(|| {
+                    CompileError::Internal(
+                        "Invalid (non-aggregate?) closure type.",
+                        closure.span.clone(),
+                    )
+                })?;
+                let field_ptr = self
+                    .current_block
+                    .append(context)
+                    .get_elem_ptr_with_idx(closure_ptr, field_type, idx)
+                    .add_metadatum(context, span_md_idx);
+                let field_val = self
+                    .current_block
+                    .append(context)
+                    .load(field_ptr)
+                    .add_metadatum(context, span_md_idx);
+                args.push(field_val);
+            }
+        } else {
+            return_on_termination_or_extract!(
+                self.compile_expression_to_value(context, md_mgr, closure)?
+            );
+        }
+        for arg in ast_args {
+            args.push(return_on_termination_or_extract!(
+                self.compile_expression_to_value(context, md_mgr, arg)?
+            ));
         }
 
         let val = self
             .current_block
             .append(context)
-            .call(new_callee, &args)
+            .call(closure_fn, &args)
             .add_metadatum(context, span_md_idx);
-
         Ok(TerminatorValue::new(val, context))
     }
 
@@ -2169,6 +2339,8 @@ impl<'eng> FnCompiler<'eng> {
             .function
             .new_local_var(context, local_name.clone(), return_type, None, mutable)
             .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+        let local_md_idx = self.local_var_md(context, md_mgr, name, body.return_type);
+        local_var.set_metadata(context, local_md_idx);
 
         // We can have empty aggregates, especially arrays, which shouldn't be initialized, but
         // otherwise use a store.
@@ -2187,6 +2359,29 @@ impl<'eng> FnCompiler<'eng> {
         Ok(None)
     }
 
+    /// The metadata for a local variable declared as `name`, describing it in the debug info
+    /// unless it is unnamed or generated by the compiler.
+    fn local_var_md(
+        &self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        name: &Ident,
+        type_id: TypeId,
+    ) -> Option<MetadataIndex> {
+        let name_md_idx = md_mgr.span_to_md(context, &name.span());
+        if name.as_str() == "_" || name.as_str().starts_with("__") {
+            return name_md_idx;
+        }
+        let debug_var_md_idx = md_mgr.debug_variable_to_md(
+            context,
+            self.engines,
+            name.as_str(),
+            type_id,
+            self.block_spans.last(),
+        );
+        md_combine(context, &name_md_idx, &debug_var_md_idx)
+    }
+
     fn compile_const_decl(
         &mut self,
         context: &mut Context,
@@ -2243,6 +2438,9 @@ impl<'eng> FnCompiler<'eng> {
                     .map_err(|ir_error| {
                         CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
                     })?;
+                let local_md_idx =
+                    self.local_var_md(context, md_mgr, &call_path.suffix, value.return_type);
+                local_var.set_metadata(context, local_md_idx);
 
                 // We can have empty aggregates, especially arrays, which shouldn't be initialised, but
                 // otherwise use a store.
@@ -246,6 +246,21 @@ pub struct ReassignmentExpression {
     pub rhs: Box<Expression>,
 }
 
+#[derive(Debug, Clone)]
+pub struct ClosureExpression {
+    pub parameters: Vec<ClosureParameter>,
+    /// The return type of the closure, which is inferred if it is not annotated.
+    pub return_type: TypeArgument,
+    pub body: CodeBlock,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClosureParameter {
+    pub name: Ident,
+    /// The type of the parameter, which is inferred if it is not annotated.
+    pub type_argument: TypeArgument,
+}
+
 #[derive(Debug, Clone)]
 pub enum ExpressionKind {
     /// A malformed expression.
@@ -323,6 +338,8 @@ pub enum ExpressionKind {
     Return(Box<Expression>),
     Ref(Box<Expression>),
     Deref(Box<Expression>),
+    /// A closure, e.g., `|x: u64| x + 1`.
+    Closure(Box<ClosureExpression>),
 }
 
 #[derive(Debug, Clone)]
@@ -332,6 +332,39 @@ impl TyFunctionDecl {
         }
     }
 
+    /// Replaces the types `fn(A) -> R` of the parameters that take closures with placeholders,
+    /// which are inferred from the closures the function is called with. Like type parameters,
+    /// these parameters make the function generic, and it is instantiated for every closure type.
+    pub(crate) fn instantiate_closure_parameters(&mut self, engines: &Engines) {
+        let type_engine = engines.te();
+        let (generic_types, placeholder_types): (Vec<_>, Vec<_>) = self
+            .parameters
+            .iter()
+            .filter_map(
+                |param| match &*type_engine.get(param.type_argument.type_id) {
+                    TypeInfo::Closure(closure)
+                        if matches!(closure.kind, ClosureTypeKind::Generic) =>
+                    {
+                        let placeholder = TypeInfo::Closure(Box::new(ClosureType {
+                            kind: ClosureTypeKind::Placeholder,
+                            ..(**closure).clone()
+                        }));
+                        let placeholder =
+                            type_engine.insert(engines, placeholder, closure.span.source_id());
+                        Some((param.type_argument.type_id, placeholder))
+                    }
+                    _ => None,
+                },
+            )
+            .unzip();
+        if generic_types.is_empty() {
+            return;
+        }
+        let type_mapping =
+            TypeSubstMap::from_type_parameters_and_type_arguments(generic_types, placeholder_types);
+        self.subst(&type_mapping, engines);
+    }
+
     pub fn to_fn_selector_value_untruncated(
         &self,
         handler: &Handler,
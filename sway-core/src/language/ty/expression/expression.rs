@@ -312,6 +312,21 @@ impl CollectTypesMetadata for TyExpression {
             Reassignment(reassignment) => {
                 res.append(&mut reassignment.rhs.collect_types_metadata(handler, ctx)?);
             }
+            Closure { fn_ref, captures } => {
+                for capture in captures.iter() {
+                    res.append(&mut capture.collect_types_metadata(handler, ctx)?);
+                }
+                let function_decl = decl_engine.get_function(fn_ref);
+                for content in function_decl.body.contents.iter() {
+                    res.append(&mut content.collect_types_metadata(handler, ctx)?);
+                }
+            }
+            ClosureCall { closure, arguments } => {
+                res.append(&mut closure.collect_types_metadata(handler, ctx)?);
+                for arg in arguments.iter() {
+                    res.append(&mut arg.collect_types_metadata(handler, ctx)?);
+                }
+            }
         }
         Ok(res)
     }
@@ -416,6 +431,17 @@ impl DeterministicallyAborts for TyExpression {
             // gets executed.
             Return(_) => true,
             Ref(exp) | Deref(exp) => exp.deterministically_aborts(decl_engine, check_call_body),
+            // Creating a closure does not run its body, and what a call of a closure does is not
+            // known until the function it is passed to is instantiated.
+            Closure { captures, .. } => captures
+                .iter()
+                .any(|x| x.deterministically_aborts(decl_engine, check_call_body)),
+            ClosureCall { closure, arguments } => {
+                closure.deterministically_aborts(decl_engine, check_call_body)
+                    || arguments
+                        .iter()
+                        .any(|x| x.deterministically_aborts(decl_engine, check_call_body))
+            }
         }
     }
 }
@@ -163,6 +163,17 @@ pub enum TyExpressionVariant {
     Return(Box<TyExpression>),
     Ref(Box<TyExpression>),
     Deref(Box<TyExpression>),
+    /// A closure, whose value holds the values of the `captures`. The body of the closure is the
+    /// body of `fn_ref`, which takes the captured values before the arguments of the closure.
+    Closure {
+        fn_ref: DeclRefFunction,
+        captures: Vec<TyExpression>,
+    },
+    /// A call of a closure, or of a function used as a value.
+    ClosureCall {
+        closure: Box<TyExpression>,
+        arguments: Vec<TyExpression>,
+    },
 }
 
 impl EqWithEngines for TyExpressionVariant {}
@@ -421,6 +432,26 @@ impl PartialEqWithEngines for TyExpressionVariant {
                     condition: r_condition,
                 },
             ) => l_body.eq(r_body, engines) && l_condition.eq(r_condition, engines),
+            (
+                Self::Closure {
+                    fn_ref: l_fn_ref,
+                    captures: l_captures,
+                },
+                Self::Closure {
+                    fn_ref: r_fn_ref,
+                    captures: r_captures,
+                },
+            ) => l_fn_ref.eq(r_fn_ref, engines) && l_captures.eq(r_captures, engines),
+            (
+                Self::ClosureCall {
+                    closure: l_closure,
+                    arguments: l_arguments,
+                },
+                Self::ClosureCall {
+                    closure: r_closure,
+                    arguments: r_arguments,
+                },
+            ) => (**l_closure).eq(&**r_closure, engines) && l_arguments.eq(r_arguments, engines),
             (l, r) => std::mem::discriminant(l) == std::mem::discriminant(r),
         }
     }
@@ -627,6 +658,14 @@ impl HashWithEngines for TyExpressionVariant {
             Self::Ref(exp) | Self::Deref(exp) => {
                 exp.hash(state, engines);
             }
+            Self::Closure { fn_ref, captures } => {
+                fn_ref.hash(state, engines);
+                captures.hash(state, engines);
+            }
+            Self::ClosureCall { closure, arguments } => {
+                closure.hash(state, engines);
+                arguments.hash(state, engines);
+            }
         }
     }
 }
@@ -779,6 +818,21 @@ impl SubstTypes for TyExpressionVariant {
             Reassignment(reassignment) => reassignment.subst(type_mapping, engines),
             ImplicitReturn(expr) | Return(expr) => expr.subst(type_mapping, engines),
             Ref(exp) | Deref(exp) => exp.subst(type_mapping, engines),
+            Closure { fn_ref, captures } => {
+                let new_decl_ref = fn_ref
+                    .clone()
+                    .subst_types_and_insert_new_with_parent(type_mapping, engines);
+                fn_ref.replace_id(*new_decl_ref.id());
+                captures
+                    .iter_mut()
+                    .for_each(|x| x.subst(type_mapping, engines));
+            }
+            ClosureCall { closure, arguments } => {
+                closure.subst(type_mapping, engines);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.subst(type_mapping, engines));
+            }
         }
     }
 }
@@ -924,6 +978,26 @@ impl ReplaceDecls for TyExpressionVariant {
                     expr.replace_decls(decl_mapping, handler, ctx)?
                 }
                 Ref(exp) | Deref(exp) => exp.replace_decls(decl_mapping, handler, ctx)?,
+                Closure {
+                    ref mut fn_ref,
+                    captures,
+                } => {
+                    let new_decl_ref = fn_ref.clone().replace_decls_and_insert_new_with_parent(
+                        decl_mapping,
+                        handler,
+                        ctx,
+                    )?;
+                    fn_ref.replace_id(*new_decl_ref.id());
+                    captures.iter_mut().for_each(|x| {
+                        x.replace_decls(decl_mapping, handler, ctx).ok();
+                    });
+                }
+                ClosureCall { closure, arguments } => {
+                    closure.replace_decls(decl_mapping, handler, ctx)?;
+                    arguments.iter_mut().for_each(|x| {
+                        x.replace_decls(decl_mapping, handler, ctx).ok();
+                    });
+                }
             }
 
             Ok(())
@@ -1044,6 +1118,20 @@ impl TypeCheckAnalysis for TyExpressionVariant {
             TyExpressionVariant::Ref(exp) | TyExpressionVariant::Deref(exp) => {
                 exp.type_check_analyze(handler, ctx)?;
             }
+            TyExpressionVariant::Closure { fn_ref, captures } => {
+                for capture in captures.iter() {
+                    capture.type_check_analyze(handler, ctx)?
+                }
+                // The body of a closure is analyzed as a part of the function it is defined in.
+                let fn_decl = ctx.engines.de().get_function(fn_ref);
+                fn_decl.body.type_check_analyze(handler, ctx)?;
+            }
+            TyExpressionVariant::ClosureCall { closure, arguments } => {
+                closure.type_check_analyze(handler, ctx)?;
+                for arg in arguments.iter() {
+                    arg.type_check_analyze(handler, ctx)?
+                }
+            }
         }
         Ok(())
     }
@@ -1185,6 +1273,17 @@ impl TypeCheckFinalization for TyExpressionVariant {
                 TyExpressionVariant::Ref(exp) | TyExpressionVariant::Deref(exp) => {
                     exp.type_check_finalize(handler, ctx)?;
                 }
+                TyExpressionVariant::Closure { captures, .. } => {
+                    for capture in captures.iter_mut() {
+                        capture.type_check_finalize(handler, ctx)?;
+                    }
+                }
+                TyExpressionVariant::ClosureCall { closure, arguments } => {
+                    closure.type_check_finalize(handler, ctx)?;
+                    for arg in arguments.iter_mut() {
+                        arg.type_check_finalize(handler, ctx)?;
+                    }
+                }
             }
             Ok(())
         })
@@ -1293,6 +1392,15 @@ impl UpdateConstantExpression for TyExpressionVariant {
                 expr.update_constant_expression(engines, implementing_type)
             }
             Ref(exp) | Deref(exp) => exp.update_constant_expression(engines, implementing_type),
+            Closure { captures, .. } => captures
+                .iter_mut()
+                .for_each(|x| x.update_constant_expression(engines, implementing_type)),
+            ClosureCall { closure, arguments } => {
+                closure.update_constant_expression(engines, implementing_type);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.update_constant_expression(engines, implementing_type));
+            }
         }
     }
 }
@@ -1465,6 +1573,10 @@ impl DebugWithEngines for TyExpressionVariant {
             TyExpressionVariant::Deref(exp) => {
                 format!("*({:?})", engines.help_out(&**exp))
             }
+            TyExpressionVariant::Closure { .. } => "closure".to_string(),
+            TyExpressionVariant::ClosureCall { closure, .. } => {
+                format!("call of {:?}", engines.help_out(&**closure))
+            }
         };
         write!(f, "{s}")
     }
@@ -40,10 +40,10 @@ use std::sync::Arc;
 use sway_ast::AttributeDecl;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::{
-    create_o1_pass_group, register_known_passes, Context, Kind, Module, PassGroup, PassManager,
-    ARGDEMOTION_NAME, CONSTDEMOTION_NAME, DCE_NAME, INLINE_MODULE_NAME, MEM2REG_NAME,
-    MEMCPYOPT_NAME, MISCDEMOTION_NAME, MODULEPRINTER_NAME, RETDEMOTION_NAME, SIMPLIFYCFG_NAME,
-    SROA_NAME,
+    create_o1_pass_group, create_o2_cleanup_pass_group, create_o2_pass_group, create_os_pass_group,
+    register_known_passes, Context, Kind, Module, PassGroup, PassManager, ARGDEMOTION_NAME,
+    CONSTDEMOTION_NAME, DCE_NAME, INLINE_MODULE_NAME, MEM2REG_NAME, MEMCPYOPT_NAME,
+    MISCDEMOTION_NAME, MODULEPRINTER_NAME, RETDEMOTION_NAME, SIMPLIFYCFG_NAME, SROA_NAME,
 };
 use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
 use sway_types::SourceEngine;
@@ -832,6 +832,12 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         OptLevel::Opt1 => {
             pass_group.append_group(create_o1_pass_group());
         }
+        OptLevel::Opt2 => {
+            pass_group.append_group(create_o2_pass_group());
+        }
+        OptLevel::OptSize => {
+            pass_group.append_group(create_os_pass_group());
+        }
         OptLevel::Opt0 => {
             // Inlining is necessary until #4899 is resolved.
             pass_group.append_pass(INLINE_MODULE_NAME);
@@ -862,6 +868,9 @@ pub(crate) fn compile_ast_to_ir_to_asm(
                 pass_group.append_pass(MEM2REG_NAME);
                 pass_group.append_pass(DCE_NAME);
             }
+            OptLevel::Opt2 | OptLevel::OptSize => {
+                pass_group.append_group(create_o2_cleanup_pass_group());
+            }
             OptLevel::Opt0 => {}
         }
     }
@@ -1,6 +1,8 @@
 use crate::{
     decl_engine::DeclId,
+    engine_threading::Engines,
     language::{ty::TyFunctionDecl, Inline, Purity},
+    type_system::{TypeId, TypeInfo},
 };
 
 use sway_ir::{Context, MetadataIndex, Metadatum, Value};
@@ -24,6 +26,7 @@ pub(crate) struct MetadataManager {
     md_inline_cache: HashMap<MetadataIndex, Inline>,
     md_test_decl_index_cache: HashMap<MetadataIndex, DeclId<TyFunctionDecl>>,
     md_config_const_name_cache: HashMap<MetadataIndex, Rc<str>>,
+    md_debug_var_cache: HashMap<MetadataIndex, DebugVariableMd>,
 
     span_md_cache: HashMap<Span, MetadataIndex>,
     file_loc_md_cache: HashMap<SourceId, MetadataIndex>,
@@ -31,6 +34,25 @@ pub(crate) struct MetadataManager {
     inline_md_cache: HashMap<Inline, MetadataIndex>,
     test_decl_index_md_cache: HashMap<DeclId<TyFunctionDecl>, MetadataIndex>,
     config_const_name_md_cache: HashMap<Rc<str>, MetadataIndex>,
+    debug_type_md_cache: HashMap<TypeId, MetadataIndex>,
+}
+
+/// The source level names of a type and of its components, which IR types don't record.
+#[derive(Clone, Debug)]
+pub(crate) struct DebugTypeNames {
+    pub(crate) name: String,
+    /// The names of the struct fields, tuple elements or enum variants, along with their types, in
+    /// the order of the components of the IR type.  Arrays have a single component, `element`.
+    pub(crate) components: Vec<(String, DebugTypeNames)>,
+}
+
+/// A variable described in the debug info.
+#[derive(Clone, Debug)]
+pub(crate) struct DebugVariableMd {
+    pub(crate) name: String,
+    pub(crate) type_names: DebugTypeNames,
+    /// The block in which the variable is visible.
+    pub(crate) scope: Option<Span>,
 }
 
 #[derive(Clone, Copy)]
@@ -204,6 +226,60 @@ impl MetadataManager {
         })
     }
 
+    pub(crate) fn md_to_debug_variable(
+        &mut self,
+        context: &Context,
+        md_idx: Option<MetadataIndex>,
+    ) -> Option<DebugVariableMd> {
+        Self::for_each_md_idx(context, md_idx, |md_idx| {
+            self.md_debug_var_cache.get(&md_idx).cloned().or_else(|| {
+                // Create a new debug variable and save it in the cache.
+                let Metadatum::Struct(tag, fields) = md_idx.get_content(context) else {
+                    return None;
+                };
+                if tag != "debug_var" || !(2..=3).contains(&fields.len()) {
+                    return None;
+                }
+                let name = fields[0].unwrap_string()?.to_owned();
+                let type_names = Self::md_to_debug_type_names(context, fields[1].unwrap_index()?)?;
+                let scope = fields
+                    .get(2)
+                    .and_then(|scope| self.md_to_span(context, scope.unwrap_index()));
+                let var = DebugVariableMd {
+                    name,
+                    type_names,
+                    scope,
+                };
+
+                self.md_debug_var_cache.insert(md_idx, var.clone());
+
+                Some(var)
+            })
+        })
+    }
+
+    fn md_to_debug_type_names(context: &Context, md_idx: MetadataIndex) -> Option<DebugTypeNames> {
+        let Metadatum::Struct(tag, fields) = md_idx.get_content(context) else {
+            return None;
+        };
+        if tag != "debug_type" || fields.is_empty() {
+            return None;
+        }
+        let name = fields[0].unwrap_string()?.to_owned();
+        let components = fields[1..]
+            .iter()
+            .map(|field| {
+                let component = field.unwrap_index()?.get_content(context);
+                let component = component.unwrap_struct("debug_component", 2)?;
+                Some((
+                    component[0].unwrap_string()?.to_owned(),
+                    Self::md_to_debug_type_names(context, component[1].unwrap_index()?)?,
+                ))
+            })
+            .collect::<Option<_>>()?;
+        Some(DebugTypeNames { name, components })
+    }
+
     pub(crate) fn val_to_span(&mut self, context: &Context, value: Value) -> Option<Span> {
         self.md_to_span(context, value.get_metadata(context))
     }
@@ -313,6 +389,80 @@ impl MetadataManager {
         )
     }
 
+    /// Describes a variable of type `type_id` named `name` for the debug info, visible in the
+    /// `scope` block.
+    pub(crate) fn debug_variable_to_md(
+        &mut self,
+        context: &mut Context,
+        engines: &Engines,
+        name: &str,
+        type_id: TypeId,
+        scope: Option<&Span>,
+    ) -> Option<MetadataIndex> {
+        let type_md_idx = self.debug_type_to_md(context, engines, type_id);
+        let mut fields = vec![
+            Metadatum::String(name.to_owned()),
+            Metadatum::Index(type_md_idx),
+        ];
+        if let Some(scope_md_idx) = scope.and_then(|scope| self.span_to_md(context, scope)) {
+            fields.push(Metadatum::Index(scope_md_idx));
+        }
+        Some(MetadataIndex::new_struct(context, "debug_var", fields))
+    }
+
+    fn debug_type_to_md(
+        &mut self,
+        context: &mut Context,
+        engines: &Engines,
+        type_id: TypeId,
+    ) -> MetadataIndex {
+        if let Some(md_idx) = self.debug_type_md_cache.get(&type_id) {
+            return *md_idx;
+        }
+
+        let decl_engine = engines.de();
+        let components = match &*engines.te().get_unaliased(type_id) {
+            TypeInfo::Struct(decl_ref) => decl_engine
+                .get_struct(decl_ref)
+                .fields
+                .iter()
+                .map(|field| (field.name.to_string(), field.type_argument.type_id))
+                .collect(),
+            TypeInfo::Enum(decl_ref) => decl_engine
+                .get_enum(decl_ref)
+                .variants
+                .iter()
+                .map(|variant| (variant.name.to_string(), variant.type_argument.type_id))
+                .collect(),
+            TypeInfo::Tuple(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(idx, field)| (idx.to_string(), field.type_id))
+                .collect(),
+            TypeInfo::Array(element, _) => vec![("element".to_owned(), element.type_id)],
+            _otherwise => Vec::new(),
+        };
+
+        // Create new metadata, with the components' names first.
+        let mut fields = vec![Metadatum::String(engines.help_out(type_id).to_string())];
+        for (name, component_type_id) in components {
+            let component_type_md_idx = self.debug_type_to_md(context, engines, component_type_id);
+            fields.push(Metadatum::Index(MetadataIndex::new_struct(
+                context,
+                "debug_component",
+                vec![
+                    Metadatum::String(name),
+                    Metadatum::Index(component_type_md_idx),
+                ],
+            )));
+        }
+        let md_idx = MetadataIndex::new_struct(context, "debug_type", fields);
+
+        self.debug_type_md_cache.insert(type_id, md_idx);
+
+        md_idx
+    }
+
     fn file_location_to_md(
         &mut self,
         context: &mut Context,
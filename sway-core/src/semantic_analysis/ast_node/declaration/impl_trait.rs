@@ -507,9 +507,10 @@ impl TyImplTrait {
                         };
 
                     // In case there was any issue processing the dependency graph, then lets just
-                    // process them in the original order.
+                    // process them in the original order. Edges go from callers to callees, so
+                    // the sorted nodes are reversed to have callees type checked first.
                     let ordered_node_indices: Vec<_> = match ordered_node_indices_opt {
-                        Some(value) => value.iter().map(|n| n.index()).collect(),
+                        Some(value) => value.iter().rev().map(|n| n.index()).collect(),
                         None => (0..new_items.len()).collect(),
                     };
 
@@ -1,3 +1,4 @@
+mod closure;
 mod constant_expression;
 mod enum_instantiation;
 mod function_application;
@@ -12,7 +13,7 @@ mod unsafe_downcast;
 use self::constant_expression::instantiate_constant_expression;
 
 pub(crate) use self::{
-    enum_instantiation::*, function_application::*, if_expression::*, lazy_operator::*,
+    closure::*, enum_instantiation::*, function_application::*, if_expression::*, lazy_operator::*,
     method_application::*, struct_field_access::*, struct_instantiation::*, tuple_index_access::*,
     unsafe_downcast::*,
 };
@@ -419,6 +420,9 @@ impl ty::TyExpression {
             ExpressionKind::Deref(expr) => {
                 Self::type_check_deref(handler, ctx.by_ref(), expr, span)
             }
+            ExpressionKind::Closure(closure) => {
+                instantiate_closure(handler, ctx.by_ref(), closure, span)
+            }
         };
         let mut typed_expression = match res {
             Ok(r) => r,
@@ -541,6 +545,15 @@ impl ty::TyExpression {
                     span,
                 }
             }
+            // Only functions that are not generic can be used as values.
+            Some(ty::TyDecl::FunctionDecl(ty::FunctionDecl { decl_id, .. }))
+                if decl_engine
+                    .get_function(&decl_id)
+                    .type_parameters
+                    .is_empty() =>
+            {
+                instantiate_function_value(&ctx, decl_id, span)
+            }
             Some(a) => {
                 let err = handler.emit_err(CompileError::NotAVariable {
                     name: name.clone(),
@@ -567,6 +580,11 @@ impl ty::TyExpression {
         arguments: Vec<Expression>,
         span: Span,
     ) -> Result<ty::TyExpression, ErrorEmitted> {
+        // Is it a call of a closure held by a variable?
+        if let Some(name) = called_closure_variable(&ctx, &call_path_binding) {
+            return instantiate_closure_call(handler, ctx, name, arguments, span);
+        }
+
         // Grab the fn declaration.
         let (fn_ref, _, _): (DeclRefFunction, _, _) =
             TypeBinding::type_check(&mut call_path_binding, handler, ctx.by_ref())?;
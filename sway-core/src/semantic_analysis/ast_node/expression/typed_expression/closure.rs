@@ -0,0 +1,461 @@
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
+use sway_types::{Ident, Span, Spanned};
+
+use crate::{
+    decl_engine::{DeclEngineInsert, DeclId, DeclRef},
+    language::{
+        parsed::{ClosureExpression, ClosureParameter, Expression},
+        ty::{self, TyAstNodeContent, TyCodeBlock},
+        CallPath, Visibility,
+    },
+    semantic_analysis::{type_check_context::EnforceTypeArguments, *},
+    transform,
+    type_system::*,
+};
+
+const UNIFY_ARGS_HELP_TEXT: &str =
+    "The argument that has been provided to this closure's type does \
+not match the type of the parameter in the closure's type.";
+
+/// Type checks a closure, e.g., `|x: u64| x + y`.
+///
+/// The body of the closure becomes the body of a function, which takes the values of the variables
+/// the closure captures, followed by the parameters of the closure. The captured variables are
+/// immutable within the closure, and the closure holds copies of their values.
+pub(crate) fn instantiate_closure(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    closure: Box<ClosureExpression>,
+    span: Span,
+) -> Result<ty::TyExpression, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let decl_engine = ctx.engines.de();
+    let engines = ctx.engines();
+
+    let ClosureExpression {
+        parameters,
+        mut return_type,
+        body,
+    } = *closure;
+
+    // Types that are not annotated are taken from the type that the closure is expected to have,
+    // e.g., when it is the argument of a function with a parameter of type `fn(u64) -> bool`.
+    let expected = match &*type_engine.get(ctx.type_annotation()) {
+        TypeInfo::Closure(expected) if expected.parameters.len() == parameters.len() => {
+            Some(expected.clone())
+        }
+        _ => None,
+    };
+    let parameters = parameters
+        .into_iter()
+        .enumerate()
+        .map(
+            |(
+                i,
+                ClosureParameter {
+                    name,
+                    mut type_argument,
+                },
+            )| {
+                let expected = expected.as_ref().map(|expected| &expected.parameters[i]);
+                resolve_or_infer_type(handler, ctx.by_ref(), &mut type_argument, expected);
+                ty::TyFunctionParameter {
+                    name,
+                    is_reference: false,
+                    is_mutable: false,
+                    mutability_span: Span::dummy(),
+                    type_argument,
+                }
+            },
+        )
+        .collect::<Vec<_>>();
+    let expected_return_type = expected.as_ref().map(|expected| &expected.return_type);
+    resolve_or_infer_type(
+        handler,
+        ctx.by_ref(),
+        &mut return_type,
+        expected_return_type,
+    );
+
+    // Every variable in scope could be captured by the closure.
+    let outer_variables = ctx
+        .namespace
+        .module()
+        .current_items()
+        .symbols()
+        .values()
+        .filter_map(|decl| match decl {
+            ty::TyDecl::VariableDecl(decl) => Some(ty::TyFunctionParameter {
+                name: decl.name.clone(),
+                is_reference: false,
+                is_mutable: false,
+                mutability_span: Span::dummy(),
+                type_argument: TypeArgument {
+                    type_id: decl.return_type,
+                    initial_type_id: decl.return_type,
+                    span: decl.name.span(),
+                    call_path_tree: None,
+                },
+            }),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut closure_namespace = ctx.namespace.clone();
+    let typed_body = ctx
+        .by_ref()
+        .with_const_shadowing_mode(ConstShadowingMode::Sequential)
+        .scoped(&mut closure_namespace, |mut ctx| {
+            for variable in outer_variables.iter().chain(parameters.iter()) {
+                variable.insert_into_namespace(handler, ctx.by_ref());
+            }
+
+            let mut ctx = ctx
+                .by_ref()
+                .with_help_text(
+                    "Closure body's return type does not match up with its return type annotation.",
+                )
+                .with_type_annotation(return_type.type_id)
+                .with_function_type_annotation(return_type.type_id);
+
+            let mut typed_body = TyCodeBlock::type_check(handler, ctx.by_ref(), &body)
+                .unwrap_or_else(|_err| TyCodeBlock::default());
+
+            let mut unification_ctx = TypeCheckUnificationContext::new(ctx.engines, ctx);
+            typed_body.type_check_unify(handler, &mut unification_ctx)?;
+
+            Ok(typed_body)
+        })?;
+
+    // The closure captures the variables in scope that its body uses, in the order of their first
+    // use.
+    let mut used_names = vec![];
+    for node in typed_body.contents.iter() {
+        collect_variable_names_in_node(&node.content, &mut used_names);
+    }
+    let mut captured_variables: Vec<ty::TyFunctionParameter> = vec![];
+    for name in used_names {
+        let is_captured = captured_variables.iter().any(|p| p.name == name)
+            || parameters.iter().any(|p| p.name == name);
+        if is_captured {
+            continue;
+        }
+        if let Some(variable) = outer_variables.iter().find(|v| v.name == name) {
+            captured_variables.push(variable.clone());
+        }
+    }
+    let captures = captured_variables
+        .iter()
+        .map(|variable| {
+            ty::TyExpression::type_check_variable_expression(
+                handler,
+                ctx.by_ref(),
+                variable.name.clone(),
+                span.clone(),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let closure_type = ClosureType {
+        parameters: parameters.iter().map(|p| p.type_argument.clone()).collect(),
+        return_type: return_type.clone(),
+        kind: ClosureTypeKind::Closure {
+            captures: captured_variables
+                .iter()
+                .map(|p| p.type_argument.clone())
+                .collect(),
+        },
+        span: span.clone(),
+    };
+
+    let name = Ident::new_with_override("closure".into(), span.clone());
+    let fn_decl = ty::TyFunctionDecl {
+        call_path: CallPath::from(name.clone()).to_fullpath(ctx.namespace),
+        name,
+        body: typed_body,
+        parameters: captured_variables.into_iter().chain(parameters).collect(),
+        implementing_type: None,
+        implementing_for_typeid: None,
+        span: span.clone(),
+        attributes: transform::AttributesMap::default(),
+        type_parameters: vec![],
+        return_type,
+        visibility: Visibility::Private,
+        is_contract_call: false,
+        purity: ctx.purity(),
+        where_clause: vec![],
+        is_trait_method_dummy: false,
+    };
+
+    Ok(ty::TyExpression {
+        expression: ty::TyExpressionVariant::Closure {
+            fn_ref: decl_engine.insert(fn_decl),
+            captures,
+        },
+        return_type: type_engine.insert(
+            engines,
+            TypeInfo::Closure(Box::new(closure_type)),
+            span.source_id(),
+        ),
+        span,
+    })
+}
+
+/// Type checks the use of a function as a value, which is a closure that captures nothing.
+///
+/// The closure refers to the declaration of the function itself, whose span is not the span of
+/// the expression, so that the function is compiled once for all of its uses.
+pub(crate) fn instantiate_function_value(
+    ctx: &TypeCheckContext,
+    decl_id: DeclId<ty::TyFunctionDecl>,
+    span: Span,
+) -> ty::TyExpression {
+    let engines = ctx.engines();
+    let fn_decl = engines.de().get_function(&decl_id);
+    let closure_type = ClosureType {
+        parameters: fn_decl
+            .parameters
+            .iter()
+            .map(|p| p.type_argument.clone())
+            .collect(),
+        return_type: fn_decl.return_type.clone(),
+        kind: ClosureTypeKind::Closure { captures: vec![] },
+        span: fn_decl.span.clone(),
+    };
+    ty::TyExpression {
+        expression: ty::TyExpressionVariant::Closure {
+            fn_ref: DeclRef::new(fn_decl.name.clone(), decl_id, fn_decl.span.clone()),
+            captures: vec![],
+        },
+        return_type: engines.te().insert(
+            engines,
+            TypeInfo::Closure(Box::new(closure_type)),
+            span.source_id(),
+        ),
+        span,
+    }
+}
+
+/// Returns the name of the called variable if `call_path_binding` calls a closure held by a
+/// local variable, e.g., `f` in `f(x)`.
+pub(crate) fn called_closure_variable(
+    ctx: &TypeCheckContext,
+    call_path_binding: &TypeBinding<CallPath>,
+) -> Option<Ident> {
+    let call_path = &call_path_binding.inner;
+    if !call_path.prefixes.is_empty()
+        || call_path.is_absolute
+        || !matches!(&call_path_binding.type_arguments, TypeArgs::Regular(args) if args.is_empty())
+    {
+        return None;
+    }
+    match ctx.namespace.resolve_symbol(
+        &Handler::default(),
+        ctx.engines(),
+        &call_path.suffix,
+        ctx.self_type(),
+    ) {
+        Ok(ty::TyDecl::VariableDecl(decl))
+            if matches!(
+                &*ctx.engines.te().get(decl.return_type),
+                TypeInfo::Closure(_)
+            ) =>
+        {
+            Some(call_path.suffix.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Type checks a call of the closure held by the variable `name`, e.g., `f(x)`.
+pub(crate) fn instantiate_closure_call(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    name: Ident,
+    arguments: Vec<Expression>,
+    span: Span,
+) -> Result<ty::TyExpression, ErrorEmitted> {
+    let engines = ctx.engines();
+
+    let closure = ty::TyExpression::type_check_variable_expression(
+        handler,
+        ctx.by_ref(),
+        name.clone(),
+        name.span(),
+    )?;
+    let closure_type = match &*engines.te().get(closure.return_type) {
+        TypeInfo::Closure(closure_type) => closure_type.clone(),
+        _ => {
+            return Err(handler.emit_err(CompileError::Internal(
+                "Called variable is expected to hold a closure.",
+                span,
+            )))
+        }
+    };
+
+    let expected = closure_type.parameters.len();
+    let received = arguments.len();
+    if expected != received {
+        let err = if expected < received {
+            CompileError::TooManyArgumentsForFunction {
+                span,
+                method_name: name,
+                dot_syntax_used: false,
+                expected,
+                received,
+            }
+        } else {
+            CompileError::TooFewArgumentsForFunction {
+                span,
+                method_name: name,
+                dot_syntax_used: false,
+                expected,
+                received,
+            }
+        };
+        return Err(handler.emit_err(err));
+    }
+
+    let arguments = arguments
+        .into_iter()
+        .zip(closure_type.parameters.iter())
+        .map(|(arg, param)| {
+            let ctx = ctx
+                .by_ref()
+                .with_help_text(UNIFY_ARGS_HELP_TEXT)
+                .with_type_annotation(param.type_id);
+            ty::TyExpression::type_check(handler, ctx, arg.clone())
+                .unwrap_or_else(|err| ty::TyExpression::error(err, arg.span(), engines))
+        })
+        .collect();
+
+    Ok(ty::TyExpression {
+        expression: ty::TyExpressionVariant::ClosureCall {
+            closure: Box::new(closure),
+            arguments,
+        },
+        return_type: closure_type.return_type.type_id,
+        span,
+    })
+}
+
+/// Resolves the type of a parameter or of the return value of a closure, or takes it from the
+/// `expected` type if it is not annotated.
+fn resolve_or_infer_type(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    type_argument: &mut TypeArgument,
+    expected: Option<&TypeArgument>,
+) {
+    let engines = ctx.engines();
+    let type_engine = engines.te();
+    let is_annotated = !matches!(&*type_engine.get(type_argument.type_id), TypeInfo::Unknown);
+    type_argument.type_id = match expected {
+        Some(expected) if !is_annotated => expected.type_id,
+        _ => ctx
+            .resolve_type(
+                handler,
+                type_argument.type_id,
+                &type_argument.span,
+                EnforceTypeArguments::Yes,
+                None,
+            )
+            .unwrap_or_else(|err| type_engine.insert(engines, TypeInfo::ErrorRecovery(err), None)),
+    };
+}
+
+fn collect_variable_names_in_node(node: &TyAstNodeContent, names: &mut Vec<Ident>) {
+    match node {
+        TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(decl)) => {
+            collect_variable_names(&decl.body, names)
+        }
+        TyAstNodeContent::Expression(expr) => collect_variable_names(expr, names),
+        TyAstNodeContent::Declaration(_)
+        | TyAstNodeContent::SideEffect(_)
+        | TyAstNodeContent::Error(..) => {}
+    }
+}
+
+/// Collects the names of the variables used in `expr`, in the order of their use.
+fn collect_variable_names(expr: &ty::TyExpression, names: &mut Vec<Ident>) {
+    use ty::TyExpressionVariant::*;
+    let exprs: Vec<&ty::TyExpression> = match &expr.expression {
+        VariableExpression { name, .. } => {
+            names.push(name.clone());
+            vec![]
+        }
+        FunctionApplication {
+            arguments,
+            contract_call_params,
+            ..
+        } => contract_call_params
+            .values()
+            .chain(arguments.iter().map(|(_, arg)| arg))
+            .collect(),
+        LazyOperator { lhs, rhs, .. } => vec![lhs, rhs],
+        Tuple { fields } => fields.iter().collect(),
+        Array { contents, .. } => contents.iter().collect(),
+        ArrayIndex { prefix, index } => vec![prefix, index],
+        StructExpression { fields, .. } => fields.iter().map(|field| &field.value).collect(),
+        CodeBlock(block) => {
+            for node in block.contents.iter() {
+                collect_variable_names_in_node(&node.content, names);
+            }
+            vec![]
+        }
+        WhileLoop { condition, body } => {
+            collect_variable_names(condition, names);
+            for node in body.contents.iter() {
+                collect_variable_names_in_node(&node.content, names);
+            }
+            vec![]
+        }
+        MatchExp { desugared, .. } | ForLoop { desugared } => vec![desugared],
+        IfExp {
+            condition,
+            then,
+            r#else,
+        } => [condition, then]
+            .into_iter()
+            .chain(r#else.iter())
+            .map(|expr| &**expr)
+            .collect(),
+        AsmExpression { registers, .. } => registers
+            .iter()
+            .filter_map(|register| register.initializer.as_ref())
+            .collect(),
+        StructFieldAccess { prefix, .. } | TupleElemAccess { prefix, .. } => vec![prefix],
+        EnumInstantiation { contents, .. } => contents.iter().map(|expr| &**expr).collect(),
+        AbiCast { address, .. } => vec![address],
+        IntrinsicFunction(kind) => kind.arguments.iter().collect(),
+        EnumTag { exp } | UnsafeDowncast { exp, .. } => vec![exp],
+        Reassignment(reassignment) => reassignment
+            .lhs_indices
+            .iter()
+            .filter_map(|index| match index {
+                ty::ProjectionKind::ArrayIndex { index, .. } => Some(&**index),
+                _ => None,
+            })
+            .chain(std::iter::once(&reassignment.rhs))
+            .collect(),
+        ImplicitReturn(exp) | Return(exp) | Ref(exp) | Deref(exp) => vec![exp],
+        // The variables used by a nested closure are a part of its captures.
+        Closure { captures, .. } => captures.iter().collect(),
+        ClosureCall { closure, arguments } => std::iter::once(&**closure)
+            .chain(arguments.iter())
+            .collect(),
+        Literal(_)
+        | ConstantExpression { .. }
+        | FunctionParameter
+        | StorageAccess(_)
+        | AbiName(_)
+        | Break
+        | Continue => vec![],
+    };
+    for expr in exprs {
+        collect_variable_names(expr, names);
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     language::{ty, *},
     semantic_analysis::{ast_node::*, TypeCheckContext},
 };
+use ast_node::typed_expression::instantiate_function_value;
 use indexmap::IndexMap;
 use sway_error::error::CompileError;
 use sway_types::Spanned;
@@ -23,17 +24,18 @@ pub(crate) fn instantiate_function_application(
 ) -> Result<ty::TyExpression, ErrorEmitted> {
     let decl_engine = ctx.engines.de();
 
-    let mut function_decl = (*decl_engine.get_function(&function_decl_ref)).clone();
-
-    if arguments.is_none() {
-        return Err(
-            handler.emit_err(CompileError::MissingParenthesesForFunction {
-                method_name: call_path_binding.inner.suffix.clone(),
-                span: call_path_binding.inner.span(),
-            }),
+    let Some(arguments) = arguments else {
+        return instantiate_function_path(
+            handler,
+            &ctx,
+            &function_decl_ref,
+            &call_path_binding,
+            span,
         );
-    }
-    let arguments = arguments.unwrap_or_default();
+    };
+
+    let mut function_decl = (*decl_engine.get_function(&function_decl_ref)).clone();
+    function_decl.instantiate_closure_parameters(ctx.engines());
 
     // 'purity' is that of the callee, 'opts.purity' of the caller.
     if !ctx.purity().can_call(function_decl.purity) {
@@ -96,6 +98,31 @@ pub(crate) fn instantiate_function_application(
     Ok(exp)
 }
 
+/// Type checks a path to a function without arguments, which is a function value if the function
+/// is not generic.
+fn instantiate_function_path(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    function_decl_ref: &DeclRefFunction,
+    call_path_binding: &TypeBinding<CallPath>,
+    span: Span,
+) -> Result<ty::TyExpression, ErrorEmitted> {
+    let function_decl = ctx.engines.de().get_function(function_decl_ref);
+    if !function_decl.type_parameters.is_empty() {
+        return Err(
+            handler.emit_err(CompileError::MissingParenthesesForFunction {
+                method_name: call_path_binding.inner.suffix.clone(),
+                span: call_path_binding.inner.span(),
+            }),
+        );
+    }
+    Ok(instantiate_function_value(
+        ctx,
+        *function_decl_ref.id(),
+        span,
+    ))
+}
+
 /// Type checks the arguments.
 fn type_check_arguments(
     handler: &Handler,
@@ -114,18 +141,46 @@ fn type_check_arguments(
     }
 
     handler.scope(|handler| {
-        let typed_arguments = arguments
-            .into_iter()
-            .zip(parameters)
-            .map(|(arg, param)| {
+        // Closures are type checked after the other arguments, so that the types of their
+        // parameters can be inferred from the other arguments, e.g., the `T` of `f: fn(T) -> U`
+        // from `v: Vec<T>`.
+        let mut typed_arguments: Vec<Option<ty::TyExpression>> = vec![None; arguments.len()];
+        for closures in [false, true] {
+            if closures && typed_arguments.iter().any(Option::is_none) {
+                // The errors are reported when the arguments and parameters are unified later.
+                for (arg, param) in typed_arguments.iter().zip(parameters) {
+                    if let Some(arg) = arg {
+                        engines.te().unify(
+                            &Handler::default(),
+                            engines,
+                            arg.return_type,
+                            param.type_argument.type_id,
+                            &arg.span,
+                            UNIFY_ARGS_HELP_TEXT,
+                            None,
+                        );
+                    }
+                }
+            }
+            for ((arg, param), typed_arg) in arguments
+                .iter()
+                .zip(parameters)
+                .zip(typed_arguments.iter_mut())
+            {
+                if matches!(arg.kind, parsed::ExpressionKind::Closure(_)) != closures {
+                    continue;
+                }
                 let ctx = ctx
                     .by_ref()
                     .with_help_text(UNIFY_ARGS_HELP_TEXT)
                     .with_type_annotation(param.type_argument.type_id);
-                ty::TyExpression::type_check(handler, ctx, arg.clone())
-                    .unwrap_or_else(|err| ty::TyExpression::error(err, arg.span(), engines))
-            })
-            .collect();
+                *typed_arg = Some(
+                    ty::TyExpression::type_check(handler, ctx, arg.clone())
+                        .unwrap_or_else(|err| ty::TyExpression::error(err, arg.span(), engines)),
+                );
+            }
+        }
+        let typed_arguments = typed_arguments.into_iter().flatten().collect();
 
         Ok(typed_arguments)
     })
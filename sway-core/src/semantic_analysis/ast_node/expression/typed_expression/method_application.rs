@@ -37,8 +37,21 @@ pub(crate) fn type_check_method_application(
     let engines = ctx.engines();
 
     // type check the function arguments
+    //
+    // Closures are type checked once the method is known, so that the types of their parameters
+    // can be inferred from the types of the parameters of the method.
     let mut args_buf = VecDeque::new();
-    for arg in &arguments {
+    let mut closure_args = vec![];
+    for (index, arg) in arguments.iter().enumerate() {
+        if let ExpressionKind::Closure(_) = arg.kind {
+            closure_args.push((index, arg.clone()));
+            args_buf.push_back(ty::TyExpression {
+                expression: ty::TyExpressionVariant::Tuple { fields: vec![] },
+                return_type: type_engine.insert(engines, TypeInfo::Unknown, None),
+                span: arg.span(),
+            });
+            continue;
+        }
         let ctx = ctx
             .by_ref()
             .with_help_text("")
@@ -291,6 +304,10 @@ pub(crate) fn type_check_method_application(
 
     // build the function selector
     let selector = if method.is_contract_call {
+        closure_args = closure_args
+            .into_iter()
+            .filter_map(|(index, arg)| Some((index.checked_sub(1)?, arg)))
+            .collect();
         let contract_caller = args_buf.pop_front();
         let contract_address = match contract_caller
             .clone()
@@ -361,7 +378,7 @@ pub(crate) fn type_check_method_application(
         span,
     };
 
-    monomorphize_method_application(&mut fn_app, handler, ctx)?;
+    monomorphize_method_application(&mut fn_app, closure_args, handler, ctx)?;
 
     if let ty::TyExpressionVariant::FunctionApplication { ref fn_ref, .. } = &fn_app {
         let method = decl_engine.get_function(fn_ref);
@@ -547,6 +564,7 @@ pub(crate) fn resolve_method_name(
 
 pub(crate) fn monomorphize_method_application(
     expr: &mut ty::TyExpressionVariant,
+    closure_args: Vec<(usize, Expression)>,
     handler: &Handler,
     mut ctx: TypeCheckContext,
 ) -> Result<(), ErrorEmitted> {
@@ -575,6 +593,23 @@ pub(crate) fn monomorphize_method_application(
         *arguments =
             unify_arguments_and_parameters(handler, ctx.by_ref(), arguments, &method.parameters)?;
 
+        // type check the closures, which are unified with the types of the parameters as a part
+        // of their type checking
+        for (index, closure) in closure_args {
+            let Some(param) = method.parameters.get(index) else {
+                continue;
+            };
+            let ctx = ctx
+                .by_ref()
+                .with_help_text("")
+                .with_type_annotation(param.type_argument.type_id);
+            let typed_closure = ty::TyExpression::type_check(handler, ctx, closure.clone())
+                .unwrap_or_else(|err| ty::TyExpression::error(err, closure.span(), engines));
+            if let Some((_, arg)) = arguments.get_mut(index) {
+                *arg = typed_closure;
+            }
+        }
+
         // unify method return type with current ctx.type_annotation().
         handler.scope(|handler| {
             type_engine.unify_with_generic(
@@ -654,6 +689,7 @@ pub(crate) fn monomorphize_method(
         EnforceTypeArguments::No,
         &decl_ref.span(),
     )?;
+    func_decl.instantiate_closure_parameters(engines);
 
     if let Some(implementing_type) = &func_decl.implementing_type {
         func_decl
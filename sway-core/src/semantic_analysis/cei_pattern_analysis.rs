@@ -314,6 +314,22 @@ fn analyze_expression(
             None => HashSet::new(),
         },
         MatchExp { desugared, .. } => analyze_expression(engines, desugared, block_name, warnings),
+        // the effects of the body of a closure are attributed to the closure itself, as it is
+        // not known statically where the closure gets called
+        Closure { fn_ref, captures } => {
+            let fn_effs = effects_of_codeblock(engines, &decl_engine.get_function(fn_ref).body);
+            let captures_effs =
+                analyze_expressions(engines, captures.iter().collect(), block_name, warnings);
+            set_union(fn_effs, captures_effs)
+        }
+        ClosureCall { closure, arguments } => analyze_expressions(
+            engines,
+            std::iter::once(&**closure)
+                .chain(arguments.iter())
+                .collect(),
+            block_name,
+            warnings,
+        ),
         IfExp {
             condition,
             then,
@@ -574,6 +590,16 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
             .cloned()
             .collect(),
         ForLoop { desugared } => effects_of_expression(engines, desugared),
+        Closure { fn_ref, captures } => {
+            let mut effs = effects_of_codeblock(engines, &decl_engine.get_function(fn_ref).body);
+            effs.extend(effects_of_expressions(engines, captures));
+            effs
+        }
+        ClosureCall { closure, arguments } => {
+            let mut effs = effects_of_expression(engines, closure);
+            effs.extend(effects_of_expressions(engines, arguments));
+            effs
+        }
         FunctionApplication {
             fn_ref,
             arguments,
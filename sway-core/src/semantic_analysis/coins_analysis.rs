@@ -65,7 +65,8 @@ pub fn possibly_nonzero_u64_expression(
         | TupleElemAccess { .. }
         | StorageAccess(_)
         | WhileLoop { .. }
-        | ForLoop { .. } => true,
+        | ForLoop { .. }
+        | ClosureCall { .. } => true,
         // The following expression variants are unreachable, because of the type system
         // but we still consider these as non-zero to be on the safe side
         LazyOperator { .. }
@@ -82,6 +83,7 @@ pub fn possibly_nonzero_u64_expression(
         | Break
         | Continue
         | Reassignment(_)
+        | Closure { .. }
         | ImplicitReturn(_)
         | Return(_)
         | Ref(_)
@@ -668,6 +668,18 @@ impl Dependencies {
             ExpressionKind::Ref(expr) | ExpressionKind::Deref(expr) => {
                 self.gather_from_expr(engines, expr)
             }
+            ExpressionKind::Closure(closure) => {
+                let ClosureExpression {
+                    parameters,
+                    return_type,
+                    body,
+                } = &**closure;
+                self.gather_from_iter(parameters.iter(), |deps, param| {
+                    deps.gather_from_type_argument(engines, &param.type_argument)
+                })
+                .gather_from_type_argument(engines, return_type)
+                .gather_from_block(engines, body)
+            }
         }
     }
 
@@ -793,6 +805,11 @@ impl Dependencies {
                 |deps, variant| deps.gather_from_type_argument(engines, &variant.type_argument),
             ),
             TypeInfo::Alias { ty, .. } => self.gather_from_type_argument(engines, ty),
+            TypeInfo::Closure(closure) => self
+                .gather_from_iter(closure.parameters.iter(), |deps, param| {
+                    deps.gather_from_type_argument(engines, param)
+                })
+                .gather_from_type_argument(engines, &closure.return_type),
             _ => self,
         }
     }
@@ -1006,6 +1023,7 @@ fn type_info_name(type_info: &TypeInfo) -> String {
         TypeInfo::Alias { .. } => "alias",
         TypeInfo::TraitType { .. } => "trait type",
         TypeInfo::Ref(..) => "reference type",
+        TypeInfo::Closure(..) => "closure",
     }
     .to_string()
 }
@@ -568,13 +568,30 @@ impl<'a> TypeCheckContext<'a> {
                 name,
                 trait_type_id,
             } => {
-                let item_ref = self.namespace.get_root_trait_item_for_type(
-                    handler,
-                    self.engines,
-                    &name,
-                    trait_type_id,
-                    None,
-                )?;
+                // While type-checking a trait declaration the items of `Self` are only known to
+                // the current module, so look them up there if the root module does not have them.
+                let item_ref = self
+                    .namespace
+                    .get_root_trait_item_for_type(
+                        &Handler::default(),
+                        self.engines,
+                        &name,
+                        trait_type_id,
+                        None,
+                    )
+                    .or_else(|_| {
+                        self.namespace
+                            .module()
+                            .current_items()
+                            .implemented_traits
+                            .get_trait_item_for_type(
+                                handler,
+                                self.engines,
+                                &name,
+                                trait_type_id,
+                                None,
+                            )
+                    })?;
                 if let TyTraitItem::Type(type_ref) = item_ref {
                     let type_decl = self.engines.de().get_type(type_ref.id());
                     if let Some(ty) = &type_decl.ty {
@@ -609,6 +626,37 @@ impl<'a> TypeCheckContext<'a> {
                     .te()
                     .insert(self.engines, TypeInfo::Ref(ty.clone()), None)
             }
+            TypeInfo::Closure(mut closure) => {
+                for ty in closure
+                    .parameters
+                    .iter_mut()
+                    .chain(std::iter::once(&mut closure.return_type))
+                {
+                    ty.type_id = self
+                        .resolve(
+                            handler,
+                            ty.type_id,
+                            span,
+                            enforce_type_arguments,
+                            None,
+                            mod_path,
+                        )
+                        .unwrap_or_else(|err| {
+                            self.engines.te().insert(
+                                self.engines,
+                                TypeInfo::ErrorRecovery(err),
+                                None,
+                            )
+                        });
+                }
+
+                let source_id = closure.span.source_id().cloned();
+                self.engines.te().insert(
+                    self.engines,
+                    TypeInfo::Closure(closure),
+                    source_id.as_ref(),
+                )
+            }
             _ => type_id,
         };
 
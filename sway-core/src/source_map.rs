@@ -21,6 +21,12 @@ pub struct SourceMap {
     paths: Vec<PathBuf>,
     /// Mapping from opcode index to source location
     map: HashMap<usize, SourceMapSpan>,
+    /// Locations of the program's variables.
+    #[serde(default)]
+    variables: Vec<DebugVariable>,
+    /// The opcodes of the program's functions, ordered by their start.
+    #[serde(default)]
+    functions: Vec<DebugFunction>,
 }
 impl SourceMap {
     pub fn new() -> Self {
@@ -39,47 +45,152 @@ impl SourceMap {
     }
 
     pub fn insert(&mut self, source_engine: &SourceEngine, pc: usize, span: &Span) {
-        if let Some(source_id) = span.source_id() {
-            let path = source_engine.get_path(source_id);
-            let path_index = self
-                .paths
-                .iter()
-                .position(|p| *p == *path)
-                .unwrap_or_else(|| {
-                    self.paths.push((*path).to_owned());
-                    self.paths.len() - 1
-                });
-            self.map.insert(
-                pc,
-                SourceMapSpan {
-                    path: PathIndex(path_index),
-                    range: LocationRange {
-                        start: span.start(),
-                        end: span.end(),
-                    },
-                },
-            );
+        if let Some(sms) = self.source_map_span(source_engine, span) {
+            self.map.insert(pc, sms);
         }
     }
 
-    /// Inverse source mapping
+    fn source_map_span(
+        &mut self,
+        source_engine: &SourceEngine,
+        span: &Span,
+    ) -> Option<SourceMapSpan> {
+        let source_id = span.source_id()?;
+        let path = source_engine.get_path(source_id);
+        let path_index = self
+            .paths
+            .iter()
+            .position(|p| *p == *path)
+            .unwrap_or_else(|| {
+                self.paths.push((*path).to_owned());
+                self.paths.len() - 1
+            });
+        Some(SourceMapSpan {
+            path: PathIndex(path_index),
+            range: LocationRange {
+                start: span.start(),
+                end: span.end(),
+            },
+        })
+    }
+
+    /// Inserts the location of a variable, declared at `decl` and visible in `scope`.
+    pub fn insert_variable(
+        &mut self,
+        source_engine: &SourceEngine,
+        variable: DebugVariable,
+        decl: Option<&Span>,
+        scope: Option<&Span>,
+    ) {
+        let decl = decl.and_then(|span| self.source_map_span(source_engine, span));
+        let scope = scope.and_then(|span| self.source_map_span(source_engine, span));
+        self.variables.push(DebugVariable {
+            decl,
+            scope,
+            ..variable
+        });
+    }
+
+    /// Inserts the range of opcodes of a function, declared at `decl`.
+    pub fn insert_function(
+        &mut self,
+        source_engine: &SourceEngine,
+        function: DebugFunction,
+        decl: Option<&Span>,
+    ) {
+        let decl = decl.and_then(|span| self.source_map_span(source_engine, span));
+        let idx = self
+            .functions
+            .partition_point(|other| other.start_pc <= function.start_pc);
+        self.functions
+            .insert(idx, DebugFunction { decl, ..function });
+    }
+
+    /// The function whose opcodes include the opcode index `pc`.
+    pub fn function_at(&self, pc: usize) -> Option<&DebugFunction> {
+        let idx = self.functions.partition_point(|func| func.start_pc <= pc);
+        self.functions
+            .get(idx.checked_sub(1)?)
+            .filter(|func| pc < func.end_pc)
+    }
+
     pub fn addr_to_span(&self, pc: usize) -> Option<(PathBuf, LocationRange)> {
-        self.map.get(&pc).map(|sms| {
-            let p = &self.paths[sms.path.0];
-            for dep in &self.dependency_paths {
-                if p.starts_with(dep.file_name().unwrap()) {
-                    let mut path = home_dir().expect("Could not get homedir").join(".forc");
-
-                    if let Some(dp) = dep.parent() {
-                        path = path.join(dp);
+        self.map
+            .get(&pc)
+            .map(|sms| (self.resolve_path(sms.path), sms.range))
+    }
+
+    /// Iterate over every mapped opcode index in ascending order, along with its source location.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, PathBuf, LocationRange)> + '_ {
+        let mut pcs = self.map.keys().copied().collect::<Vec<_>>();
+        pcs.sort_unstable();
+        pcs.into_iter().map(|pc| {
+            let sms = &self.map[&pc];
+            (pc, self.resolve_path(sms.path), sms.range)
+        })
+    }
+
+    /// The variables visible at an opcode index, whose locations are known there.
+    ///
+    /// A variable is visible when the source location of the opcode, or of the nearest preceding
+    /// mapped opcode, is within its scope and after its declaration.  Of several variables with
+    /// the same name only the most recently declared, shadowing the others, is returned.
+    /// Arguments come first, followed by the locals in order of declaration.
+    pub fn variables_at(&self, pc: usize) -> Vec<&DebugVariable> {
+        let here = (0..=pc).rev().find_map(|pc| self.map.get(&pc));
+        let mut visible: Vec<&DebugVariable> = Vec::new();
+        for var in self
+            .variables
+            .iter()
+            .filter(|var| var.start_pc <= pc && pc < var.end_pc)
+        {
+            if let Some(here) = here {
+                let in_scope = match &var.scope {
+                    Some(scope) => {
+                        scope.path == here.path
+                            && scope.range.start <= here.range.start
+                            && here.range.start < scope.range.end
                     }
+                    None => true,
+                };
+                let declared = match &var.decl {
+                    Some(decl) => decl.path != here.path || decl.range.start <= here.range.start,
+                    None => true,
+                };
+                if !in_scope || !declared {
+                    continue;
+                }
+            }
+            match visible.iter_mut().find(|other| other.name == var.name) {
+                Some(other) if decl_start(other) <= decl_start(var) => *other = var,
+                Some(_) => (),
+                None => visible.push(var),
+            }
+        }
+        visible.sort_by_key(|var| (var.kind, decl_start(var)));
+        visible
+    }
+
+    /// Resolve the path of a variable's declaration or scope.
+    pub fn span_path(&self, span: &SourceMapSpan) -> PathBuf {
+        self.resolve_path(span.path)
+    }
 
-                    return (path.join(p), sms.range);
+    fn resolve_path(&self, path_index: PathIndex) -> PathBuf {
+        let p = &self.paths[path_index.0];
+        for dep in &self.dependency_paths {
+            if p.starts_with(dep.file_name().unwrap()) {
+                let mut path = home_dir().expect("Could not get homedir").join(".forc");
+
+                if let Some(dp) = dep.parent() {
+                    path = path.join(dp);
                 }
+
+                return path.join(p);
             }
+        }
 
-            (p.to_owned(), sms.range)
-        })
+        p.to_owned()
     }
 }
 
@@ -94,3 +205,106 @@ pub struct LocationRange {
     pub start: usize,
     pub end: usize,
 }
+
+fn decl_start(var: &DebugVariable) -> usize {
+    var.decl.as_ref().map_or(0, |decl| decl.range.start)
+}
+
+/// A function, and the range of opcodes it was compiled to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugFunction {
+    /// The name of the function, as declared in the source.
+    pub name: String,
+    /// The index of the first opcode of the function.
+    pub start_pc: usize,
+    /// The opcode index past the last of the function.
+    pub end_pc: usize,
+    /// The declaration of the function.
+    pub decl: Option<SourceMapSpan>,
+}
+
+/// A variable, and where its value is found over a range of opcodes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugVariable {
+    pub name: String,
+    pub kind: VariableKind,
+    pub ty: DebugType,
+    pub location: VariableLocation,
+    /// The first opcode index at which the location holds the variable.
+    pub start_pc: usize,
+    /// The opcode index past the last at which the location holds the variable.
+    pub end_pc: usize,
+    /// The name of the variable at its declaration.
+    pub decl: Option<SourceMapSpan>,
+    /// The block in which the variable is visible.
+    pub scope: Option<SourceMapSpan>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum VariableKind {
+    Argument,
+    Local,
+}
+
+/// Where the value of a variable is found while a program runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VariableLocation {
+    /// In a register, for values which fit in a word.
+    Register(u8),
+    /// In memory, at an offset in bytes from the address held in a register.  Locals on the stack
+    /// are found relative to the base of the function's locals, in the `$$locbase` register.
+    Memory { base: u8, offset: u64 },
+}
+
+impl std::fmt::Display for VariableLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VariableLocation::Register(reg) => write!(f, "reg[{reg:#x}]"),
+            VariableLocation::Memory { base, offset } => {
+                write!(f, "mem[reg[{base:#x}] + {offset}]")
+            }
+        }
+    }
+}
+
+/// The type of a variable, describing how to read its value from memory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DebugType {
+    /// The name of the type, as written in the source.
+    pub name: String,
+    /// The size of a value of the type in bytes.
+    pub size: u64,
+    pub kind: DebugTypeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DebugTypeKind {
+    Unit,
+    Bool,
+    /// An unsigned integer, stored big-endian in `size` bytes.
+    Uint,
+    B256,
+    StringArray,
+    /// A pointer and a length, e.g., a string slice.
+    Slice,
+    /// A struct or tuple, whose fields are stored at an offset from the start of the value.
+    Struct(Vec<DebugField>),
+    /// An enum, whose variant index is stored in the first word and whose variant values are stored
+    /// at an offset from the start of the value.
+    Enum(Vec<DebugField>),
+    Array {
+        element: Box<DebugType>,
+        length: u64,
+    },
+    /// A value whose contents aren't described, e.g., a raw pointer.
+    Opaque,
+}
+
+/// A field of a struct or tuple, or a variant of an enum.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DebugField {
+    pub name: String,
+    /// The offset in bytes of the field from the start of its parent value.
+    pub offset: u64,
+    pub ty: DebugType,
+}
@@ -15,15 +15,17 @@ use itertools::Itertools;
 use sway_ast::{
     attribute::Annotated,
     expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant},
+    keywords::QuestionMarkToken,
     ty::TyTupleDescriptor,
-    AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
-    CommaToken, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor,
-    FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition, IfExpr, Instruction,
-    Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemKind,
-    ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemTypeAlias, ItemUse, LitInt, LitIntType,
-    MatchBranchKind, Module, ModuleKind, Parens, PathExpr, PathExprSegment, PathType,
-    PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated, QualifiedPathRoot,
-    Statement, StatementLet, Submodule, TraitType, Traits, Ty, TypeField, UseTree, WhereClause,
+    AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, ClosureParam,
+    ClosureParams, CodeBlockContents, CommaToken, DoubleColonToken, Expr, ExprArrayDescriptor,
+    ExprStructField, ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParams,
+    IfCondition, IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst,
+    ItemEnum, ItemFn, ItemImpl, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTraitItem,
+    ItemTypeAlias, ItemUse, LitInt, LitIntType, MatchBranchKind, Module, ModuleKind, Parens,
+    PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern, PatternStructField, PubToken,
+    Punctuated, QualifiedPathRoot, Statement, StatementLet, Submodule, TraitType, Traits, Ty,
+    TypeField, UseTree, WhereClause,
 };
 use sway_error::convert_parse_tree_error::ConvertParseTreeError;
 use sway_error::handler::{ErrorEmitted, Handler};
@@ -1404,6 +1406,10 @@ fn ty_to_type_info(
             let type_argument = ty_to_type_argument(context, handler, engines, *ty)?;
             TypeInfo::Ref(type_argument)
         }
+        Ty::Fn { .. } => {
+            let error = ConvertParseTreeError::FnTypesNotSupportedHere { span: ty.span() };
+            return Err(handler.emit_err(error.into()));
+        }
     };
     Ok(type_info)
 }
@@ -2164,6 +2170,10 @@ fn expr_to_expression(
             )?)),
             span,
         },
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => try_expr_to_expression(context, handler, engines, *expr, question_mark_token, span)?,
         Expr::Not { bang_token, expr } => {
             let expr = expr_to_expression(context, handler, engines, *expr)?;
             op_call("not", bang_token.span(), span, &[expr])?
@@ -2383,10 +2393,84 @@ fn expr_to_expression(
             kind: ExpressionKind::Continue,
             span,
         },
+        // The closure is returned directly, without any temporaries, as the stack frame of this
+        // function is already very large.
+        Expr::Closure { .. } => return closure_to_expression(context, handler, engines, expr),
     };
     Ok(expression)
 }
 
+fn closure_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    expr: Expr,
+) -> Result<Expression, ErrorEmitted> {
+    let span = expr.span();
+    let Expr::Closure {
+        params,
+        return_type_opt,
+        body,
+    } = expr
+    else {
+        unreachable!("only closure expressions are converted to closures");
+    };
+    let params = match params {
+        ClosureParams::Empty(_) => Vec::new(),
+        ClosureParams::Params { params, .. } => params.into_iter().collect(),
+    };
+    let parameters = params
+        .into_iter()
+        .map(|ClosureParam { name, ty_opt }| {
+            let type_argument = match ty_opt {
+                Some((_colon_token, ty)) => ty_to_type_argument(context, handler, engines, ty)?,
+                None => unknown_type_argument(engines, name.span()),
+            };
+            Ok(ClosureParameter {
+                name,
+                type_argument,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let return_type = match return_type_opt {
+        Some((_right_arrow_token, ty)) => ty_to_type_argument(context, handler, engines, ty)?,
+        None => unknown_type_argument(engines, body.span()),
+    };
+    let body = match *body {
+        Expr::Block(braced_code_block_contents) => braced_code_block_contents_to_code_block(
+            context,
+            handler,
+            engines,
+            braced_code_block_contents,
+        )?,
+        body => {
+            let whole_block_span = body.span();
+            CodeBlock {
+                contents: vec![expr_to_ast_node(context, handler, engines, body, false)?],
+                whole_block_span,
+            }
+        }
+    };
+    Ok(Expression {
+        kind: ExpressionKind::Closure(Box::new(ClosureExpression {
+            parameters,
+            return_type,
+            body,
+        })),
+        span,
+    })
+}
+
+fn unknown_type_argument(engines: &Engines, span: Span) -> TypeArgument {
+    let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
+    TypeArgument {
+        type_id,
+        initial_type_id: type_id,
+        span,
+        call_path_tree: None,
+    }
+}
+
 fn op_call(
     name: &'static str,
     op_span: Span,
@@ -2553,11 +2637,66 @@ fn fn_arg_to_function_parameter(
         is_reference: reference.is_some(),
         is_mutable: mutable.is_some(),
         mutability_span,
-        type_argument: ty_to_type_argument(context, handler, engines, fn_arg.ty)?,
+        type_argument: fn_arg_ty_to_type_argument(context, handler, engines, fn_arg.ty)?,
     };
     Ok(function_parameter)
 }
 
+/// Function parameters are the only place where a function type, e.g., `fn(u64) -> bool`, can be
+/// used, and it stands for the type of any closure with that signature.
+fn fn_arg_ty_to_type_argument(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    ty: Ty,
+) -> Result<TypeArgument, ErrorEmitted> {
+    let span = ty.span();
+    let (params, return_type_opt) = match ty {
+        Ty::Fn {
+            params,
+            return_type_opt,
+            ..
+        } => (params, return_type_opt),
+        ty => return ty_to_type_argument(context, handler, engines, ty),
+    };
+    let parameters = params
+        .into_inner()
+        .into_iter()
+        .map(|ty| ty_to_type_argument(context, handler, engines, ty))
+        .collect::<Result<Vec<_>, _>>()?;
+    let return_type = match return_type_opt {
+        Some((_right_arrow_token, ty)) => ty_to_type_argument(context, handler, engines, *ty)?,
+        None => {
+            let type_id =
+                engines
+                    .te()
+                    .insert(engines, TypeInfo::Tuple(Vec::new()), span.source_id());
+            TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: span.clone(),
+                call_path_tree: None,
+            }
+        }
+    };
+    let type_id = engines.te().insert(
+        engines,
+        TypeInfo::Closure(Box::new(ClosureType {
+            parameters,
+            return_type,
+            kind: ClosureTypeKind::Generic,
+            span: span.clone(),
+        })),
+        span.source_id(),
+    );
+    Ok(TypeArgument {
+        type_id,
+        initial_type_id: type_id,
+        span,
+        call_path_tree: None,
+    })
+}
+
 fn expr_to_length(
     context: &mut Context,
     handler: &Handler,
@@ -3117,6 +3256,120 @@ fn for_expr_to_expression(
     })
 }
 
+fn try_expr_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    expr: Expr,
+    question_mark_token: QuestionMarkToken,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    // Desugar `expr?` into:
+    //    {
+    //        let __try_value = expr;
+    //        if __try_value.is_residual() {
+    //            return __try_value.into_residual();
+    //        }
+    //        __try_value.unwrap()
+    //    }
+    //
+    // where `is_residual`, `into_residual` and `unwrap` are implemented by both `Option` and
+    // `Result`. The early return is type checked against the return type of the function, and the
+    // methods use the span of the `?`, so that errors point to it.
+    let question_mark_span = question_mark_token.span();
+    let try_value_ident = Ident::new_no_span("__try_value".into());
+    let try_value_expr = Expression {
+        kind: ExpressionKind::Variable(try_value_ident.clone()),
+        span: Span::dummy(),
+    };
+    let method_call = |method_name: &str| Expression {
+        kind: ExpressionKind::MethodApplication(Box::new(MethodApplicationExpression {
+            arguments: vec![try_value_expr.clone()],
+            method_name_binding: TypeBinding {
+                inner: MethodName::FromModule {
+                    method_name: Ident::new_with_override(
+                        method_name.into(),
+                        question_mark_span.clone(),
+                    ),
+                },
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: question_mark_span.clone(),
+            },
+            contract_call_params: vec![],
+        })),
+        span: question_mark_span.clone(),
+    };
+
+    //    let __try_value = expr;
+    let try_value_decl = engines.pe().insert(VariableDeclaration {
+        type_ascription: {
+            let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
+            TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: try_value_ident.span(),
+                call_path_tree: None,
+            }
+        },
+        name: try_value_ident,
+        is_mutable: false,
+        body: expr_to_expression(context, handler, engines, expr)?,
+    });
+
+    //    if __try_value.is_residual() {
+    //        return __try_value.into_residual();
+    //    }
+    let return_residual = Expression {
+        kind: ExpressionKind::If(IfExpression {
+            condition: Box::new(method_call("is_residual")),
+            then: Box::new(Expression {
+                kind: ExpressionKind::CodeBlock(CodeBlock {
+                    contents: vec![AstNode {
+                        content: AstNodeContent::Expression(Expression {
+                            kind: ExpressionKind::Return(Box::new(method_call("into_residual"))),
+                            span: question_mark_span.clone(),
+                        }),
+                        span: question_mark_span.clone(),
+                    }],
+                    whole_block_span: Span::dummy(),
+                }),
+                span: Span::dummy(),
+            }),
+            r#else: None,
+        }),
+        span: Span::dummy(),
+    };
+
+    //    __try_value.unwrap()
+    let output = Expression {
+        kind: ExpressionKind::ImplicitReturn(Box::new(method_call("unwrap"))),
+        span: question_mark_span.clone(),
+    };
+
+    Ok(Expression {
+        kind: ExpressionKind::CodeBlock(CodeBlock {
+            contents: vec![
+                AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        try_value_decl,
+                    )),
+                    span: Span::dummy(),
+                },
+                AstNode {
+                    content: AstNodeContent::Expression(return_residual),
+                    span: Span::dummy(),
+                },
+                AstNode {
+                    content: AstNodeContent::Expression(output),
+                    span: question_mark_span,
+                },
+            ],
+            whole_block_span: span.clone(),
+        }),
+        span,
+    })
+}
+
 /// Determine if the path is in absolute form, e.g., `::foo::bar`.
 ///
 /// Throws an error when given `<Foo as Bar>::baz`.
@@ -4088,6 +4341,7 @@ fn ty_to_type_parameter(
         Ty::Ptr { .. } => panic!("__ptr types are not allowed in this position"),
         Ty::Slice { .. } => panic!("__slice types are not allowed in this position"),
         Ty::Ref { .. } => panic!("ref types are not allowed in this position"),
+        Ty::Fn { .. } => panic!("fn types are not allowed in this position"),
     };
     let custom_type = type_engine.insert(
         engines,
@@ -0,0 +1,158 @@
+use crate::{engine_threading::*, type_system::priv_prelude::*};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+use sway_types::{Span, Spanned};
+
+/// The type of a closure, or of a function parameter that takes a closure.
+///
+/// Every closure has a type of its own, identified by the span of the closure. Functions with a
+/// parameter of type `fn(A) -> R` are instantiated for each closure they are called with, so that
+/// calling the closure is always a static call to the function holding its body.
+#[derive(Debug, Clone)]
+pub struct ClosureType {
+    pub parameters: Vec<TypeArgument>,
+    pub return_type: TypeArgument,
+    pub kind: ClosureTypeKind,
+    /// The span of the closure, or of the `fn(A) -> R` type of the parameter.
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum ClosureTypeKind {
+    /// The type `fn(A) -> R` of a function parameter, which stands for any closure with that
+    /// signature, like a type parameter does for any type.
+    Generic,
+    /// The type of the parameter of a function instantiated at a call site, which is inferred from
+    /// the closure passed as the argument, like a [TypeInfo::Placeholder] is.
+    Placeholder,
+    /// The type of a closure, or of a function used as a value. The value of a closure holds the
+    /// values it captures, which its function takes before the arguments.
+    Closure { captures: Vec<TypeArgument> },
+}
+
+impl ClosureType {
+    /// Returns the types of the captured values, if the type is the type of a closure.
+    pub fn captures(&self) -> Option<&[TypeArgument]> {
+        match &self.kind {
+            ClosureTypeKind::Closure { captures } => Some(captures),
+            ClosureTypeKind::Generic | ClosureTypeKind::Placeholder => None,
+        }
+    }
+}
+
+impl Spanned for ClosureType {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl HashWithEngines for ClosureType {
+    fn hash<H: Hasher>(&self, state: &mut H, engines: &Engines) {
+        let ClosureType {
+            parameters,
+            return_type,
+            kind,
+            span,
+        } = self;
+        parameters.hash(state, engines);
+        return_type.hash(state, engines);
+        std::mem::discriminant(kind).hash(state);
+        if let ClosureTypeKind::Closure { captures } = kind {
+            captures.hash(state, engines);
+        }
+        span.hash(state);
+    }
+}
+
+impl EqWithEngines for ClosureType {}
+impl PartialEqWithEngines for ClosureType {
+    fn eq(&self, other: &Self, engines: &Engines) -> bool {
+        let kinds_eq = match (&self.kind, &other.kind) {
+            (ClosureTypeKind::Generic, ClosureTypeKind::Generic)
+            | (ClosureTypeKind::Placeholder, ClosureTypeKind::Placeholder) => true,
+            (
+                ClosureTypeKind::Closure { captures: l },
+                ClosureTypeKind::Closure { captures: r },
+            ) => l.eq(r, engines),
+            _ => false,
+        };
+        kinds_eq
+            && self.span == other.span
+            && self.parameters.eq(&other.parameters, engines)
+            && self.return_type.eq(&other.return_type, engines)
+    }
+}
+
+impl OrdWithEngines for ClosureType {
+    fn cmp(&self, other: &Self, engines: &Engines) -> Ordering {
+        let kind_cmp = match (&self.kind, &other.kind) {
+            (
+                ClosureTypeKind::Closure { captures: l },
+                ClosureTypeKind::Closure { captures: r },
+            ) => l.cmp(r, engines),
+            (l, r) => kind_discriminant(l).cmp(&kind_discriminant(r)),
+        };
+        self.parameters
+            .cmp(&other.parameters, engines)
+            .then_with(|| self.return_type.cmp(&other.return_type, engines))
+            .then(kind_cmp)
+            .then_with(|| self.span.cmp(&other.span))
+    }
+}
+
+fn kind_discriminant(kind: &ClosureTypeKind) -> u8 {
+    match kind {
+        ClosureTypeKind::Generic => 0,
+        ClosureTypeKind::Placeholder => 1,
+        ClosureTypeKind::Closure { .. } => 2,
+    }
+}
+
+impl DisplayWithEngines for ClosureType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>, engines: &Engines) -> fmt::Result {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|parameter| format!("{}", engines.help_out(parameter)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "fn({parameters})")?;
+        if !engines.te().get(self.return_type.type_id).is_unit() {
+            write!(f, " -> {}", engines.help_out(&self.return_type))?;
+        }
+        Ok(())
+    }
+}
+
+impl DebugWithEngines for ClosureType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>, engines: &Engines) -> fmt::Result {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|parameter| format!("{:?}", engines.help_out(parameter)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "fn({parameters}) -> {:?}",
+            engines.help_out(&self.return_type)
+        )
+    }
+}
+
+impl SubstTypes for ClosureType {
+    fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: &Engines) {
+        self.parameters
+            .iter_mut()
+            .for_each(|parameter| parameter.subst(type_mapping, engines));
+        self.return_type.subst(type_mapping, engines);
+        if let ClosureTypeKind::Closure { captures } = &mut self.kind {
+            captures
+                .iter_mut()
+                .for_each(|capture| capture.subst(type_mapping, engines));
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub(crate) mod binding;
+pub(crate) mod closure;
 pub(crate) mod create_type_id;
 pub(crate) mod length;
 pub(crate) mod trait_constraint;
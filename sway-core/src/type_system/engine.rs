@@ -288,7 +288,8 @@ impl TypeEngine {
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
             | TypeInfo::Alias { .. }
-            | TypeInfo::TraitType { .. } => false,
+            | TypeInfo::TraitType { .. }
+            | TypeInfo::Closure(_) => false,
             TypeInfo::Numeric => true,
         }
     }
@@ -343,7 +344,8 @@ impl TypeEngine {
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
             | TypeInfo::Alias { .. }
-            | TypeInfo::TraitType { .. } => {}
+            | TypeInfo::TraitType { .. }
+            | TypeInfo::Closure(_) => {}
             TypeInfo::Numeric => {
                 self.unify(
                     handler,
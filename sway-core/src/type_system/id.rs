@@ -396,6 +396,24 @@ impl TypeId {
                         .extract_any_including_self(engines, filter_fn, vec![], depth + 1),
                 );
             }
+            TypeInfo::Closure(closure) => {
+                for ty in closure
+                    .parameters
+                    .iter()
+                    .chain(std::iter::once(&closure.return_type))
+                    .chain(closure.captures().unwrap_or_default())
+                {
+                    extend(
+                        &mut found,
+                        ty.type_id.extract_any_including_self(
+                            engines,
+                            filter_fn,
+                            vec![],
+                            depth + 1,
+                        ),
+                    );
+                }
+            }
         }
         found
     }
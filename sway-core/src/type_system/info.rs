@@ -185,6 +185,8 @@ pub enum TypeInfo {
         trait_type_id: TypeId,
     },
     Ref(TypeArgument),
+    /// The type of a closure, or of a function parameter that takes a closure.
+    Closure(Box<ClosureType>),
 }
 
 impl HashWithEngines for TypeInfo {
@@ -266,6 +268,9 @@ impl HashWithEngines for TypeInfo {
             TypeInfo::Ref(ty) => {
                 ty.hash(state, engines);
             }
+            TypeInfo::Closure(closure) => {
+                closure.hash(state, engines);
+            }
             TypeInfo::StringSlice
             | TypeInfo::Numeric
             | TypeInfo::Boolean
@@ -404,6 +409,7 @@ impl PartialEqWithEngines for TypeInfo {
                         .get(l_ty.type_id)
                         .eq(&type_engine.get(r_ty.type_id), engines)
             }
+            (Self::Closure(l), Self::Closure(r)) => l.eq(r, engines),
 
             (l, r) => l.discriminant_value() == r.discriminant_value(),
         }
@@ -519,6 +525,7 @@ impl OrdWithEngines for TypeInfo {
             (Self::Ref(l_ty), Self::Ref(r_ty)) => type_engine
                 .get(l_ty.type_id)
                 .cmp(&type_engine.get(r_ty.type_id), engines),
+            (Self::Closure(l), Self::Closure(r)) => l.cmp(r, engines),
 
             (l, r) => l.discriminant_value().cmp(&r.discriminant_value()),
         }
@@ -596,6 +603,7 @@ impl DisplayWithEngines for TypeInfo {
             Ref(ty) => {
                 format!("&{}", engines.help_out(ty))
             }
+            Closure(closure) => format!("{}", engines.help_out(closure)),
         };
         write!(f, "{s}")
     }
@@ -699,6 +707,7 @@ impl DebugWithEngines for TypeInfo {
             Ref(ty) => {
                 format!("&{:?}", engines.help_out(ty))
             }
+            Closure(closure) => format!("{:?}", engines.help_out(closure)),
         };
         write!(f, "{s}")
     }
@@ -737,6 +746,7 @@ impl TypeInfo {
             TypeInfo::StringSlice => 23,
             TypeInfo::TraitType { .. } => 24,
             TypeInfo::Ref { .. } => 25,
+            TypeInfo::Closure(_) => 26,
         }
     }
 
@@ -1155,7 +1165,8 @@ impl TypeInfo {
             | TypeInfo::TypeParam(_)
             | TypeInfo::Alias { .. }
             | TypeInfo::TraitType { .. }
-            | TypeInfo::Ref(_) => {
+            | TypeInfo::Ref(_)
+            | TypeInfo::Closure(_) => {
                 Err(handler.emit_err(CompileError::TypeArgumentsNotAllowed { span: span.clone() }))
             }
         }
@@ -1192,7 +1203,8 @@ impl TypeInfo {
             | TypeInfo::Storage { .. }
             | TypeInfo::Placeholder(_)
             | TypeInfo::TypeParam(_)
-            | TypeInfo::TraitType { .. } => Err(handler.emit_err(CompileError::Unimplemented(
+            | TypeInfo::TraitType { .. }
+            | TypeInfo::Closure(_) => Err(handler.emit_err(CompileError::Unimplemented(
                 "Matching on this type is currently not supported.",
                 span.clone(),
             ))),
@@ -1241,7 +1253,8 @@ impl TypeInfo {
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Storage { .. }
             | TypeInfo::Placeholder(_)
-            | TypeInfo::TypeParam(_) => Err(handler.emit_err(CompileError::Unimplemented(
+            | TypeInfo::TypeParam(_)
+            | TypeInfo::Closure(_) => Err(handler.emit_err(CompileError::Unimplemented(
                 "implementing traits on this type is unsupported right now",
                 span.clone(),
             ))),
@@ -1284,7 +1297,8 @@ impl TypeInfo {
             | TypeInfo::Placeholder(_)
             | TypeInfo::TypeParam(_)
             | TypeInfo::Alias { .. }
-            | TypeInfo::Ref(_) => true,
+            | TypeInfo::Ref(_)
+            | TypeInfo::Closure(_) => true,
         }
     }
 
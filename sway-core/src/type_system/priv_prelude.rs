@@ -12,7 +12,10 @@ pub(crate) use super::{
 
 pub use super::{
     ast_elements::{
-        length::Length, trait_constraint::TraitConstraint, type_argument::TypeArgument,
+        closure::{ClosureType, ClosureTypeKind},
+        length::Length,
+        trait_constraint::TraitConstraint,
+        type_argument::TypeArgument,
         type_parameter::TypeParameter,
     },
     engine::TypeEngine,
@@ -475,6 +475,42 @@ impl TypeSubstMap {
                 ty.type_id = type_id;
                 type_engine.insert(engines, TypeInfo::Ref(ty.clone()), ty.span.source_id())
             }),
+            TypeInfo::Closure(mut closure) => {
+                if !matches!(closure.kind, ClosureTypeKind::Closure { .. }) {
+                    if let Some(type_id) = iter_for_match(engines, self, &type_info) {
+                        return Some(type_id);
+                    }
+                }
+                let mut need_to_create_new = false;
+                for ty in closure.parameters.iter_mut() {
+                    if let Some(type_id) = self.find_match(ty.type_id, engines) {
+                        need_to_create_new = true;
+                        ty.type_id = type_id;
+                    }
+                }
+                if let Some(type_id) = self.find_match(closure.return_type.type_id, engines) {
+                    need_to_create_new = true;
+                    closure.return_type.type_id = type_id;
+                }
+                if let ClosureTypeKind::Closure { captures } = &mut closure.kind {
+                    for ty in captures.iter_mut() {
+                        if let Some(type_id) = self.find_match(ty.type_id, engines) {
+                            need_to_create_new = true;
+                            ty.type_id = type_id;
+                        }
+                    }
+                }
+                if need_to_create_new {
+                    let source_id = closure.span.source_id().cloned();
+                    Some(type_engine.insert(
+                        engines,
+                        TypeInfo::Closure(closure),
+                        source_id.as_ref(),
+                    ))
+                } else {
+                    None
+                }
+            }
             TypeInfo::Unknown
             | TypeInfo::StringArray(..)
             | TypeInfo::StringSlice
@@ -263,6 +263,9 @@ impl<'a> Unifier<'a> {
             (Ref(r), Ref(e)) => {
                 self.unify_type_arguments_in_parents(handler, received, expected, span, r, e)
             }
+            (Closure(r), Closure(e)) if r.parameters.len() == e.parameters.len() => {
+                self.unify_closures(handler, received, expected, span, r, e)
+            }
 
             // If no previous attempts to unify were successful, raise an error.
             (TypeInfo::ErrorRecovery(_), _) => (),
@@ -286,6 +289,57 @@ impl<'a> Unifier<'a> {
         OccursCheck::new(self.engines).check(generic, other)
     }
 
+    fn unify_closures(
+        &self,
+        handler: &Handler,
+        received: TypeId,
+        expected: TypeId,
+        span: &Span,
+        r: &ClosureType,
+        e: &ClosureType,
+    ) {
+        for (rp, ep) in r.parameters.iter().zip(e.parameters.iter()) {
+            self.unify(handler, rp.type_id, ep.type_id, span);
+        }
+        self.unify(handler, r.return_type.type_id, e.return_type.type_id, span);
+
+        // The type of a parameter instantiated at a call site is inferred from the closure that is
+        // passed as the argument. Other closure types are only equal to themselves.
+        match (&r.kind, &e.kind) {
+            (_, ClosureTypeKind::Placeholder) => self.replace_expected_with_received(
+                expected,
+                &TypeInfo::Closure(Box::new(r.clone())),
+                span,
+            ),
+            (ClosureTypeKind::Placeholder, _) => self.replace_received_with_expected(
+                received,
+                &TypeInfo::Closure(Box::new(e.clone())),
+                span,
+            ),
+            (
+                ClosureTypeKind::Closure { captures: rcs },
+                ClosureTypeKind::Closure { captures: ecs },
+            ) if r.span == e.span && rcs.len() == ecs.len() => {
+                for (rc, ec) in rcs.iter().zip(ecs.iter()) {
+                    self.unify(handler, rc.type_id, ec.type_id, span);
+                }
+            }
+            (ClosureTypeKind::Generic, ClosureTypeKind::Generic) if r.span == e.span => {}
+            _ => {
+                let (received, expected) = self.assign_args(received, expected);
+                handler.emit_err(
+                    TypeError::MismatchedType {
+                        expected,
+                        received,
+                        help_text: self.help_text.clone(),
+                        span: span.clone(),
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
     fn unify_strs(
         &self,
         handler: &Handler,
@@ -433,6 +433,29 @@ impl<'a> UnifyCheck<'a> {
                     (ErrorRecovery(_), _) => true,
                     (_, ErrorRecovery(_)) => true,
 
+                    // any closure can be coerced into a closure parameter type with a compatible
+                    // signature
+                    (Closure(l), Closure(r)) => {
+                        let l_types = l.parameters.iter().map(|x| x.type_id).collect::<Vec<_>>();
+                        let r_types = r.parameters.iter().map(|x| x.type_id).collect::<Vec<_>>();
+                        let kinds_match = match (&l.kind, &r.kind) {
+                            (_, ClosureTypeKind::Generic | ClosureTypeKind::Placeholder)
+                            | (ClosureTypeKind::Placeholder, _) => true,
+                            (
+                                ClosureTypeKind::Closure { captures: lcs },
+                                ClosureTypeKind::Closure { captures: rcs },
+                            ) => {
+                                let lcs = lcs.iter().map(|x| x.type_id).collect::<Vec<_>>();
+                                let rcs = rcs.iter().map(|x| x.type_id).collect::<Vec<_>>();
+                                l.span == r.span && self.check_multiple(&lcs, &rcs)
+                            }
+                            (ClosureTypeKind::Generic, ClosureTypeKind::Closure { .. }) => false,
+                        };
+                        kinds_match
+                            && self.check_multiple(&l_types, &r_types)
+                            && self.check_inner(l.return_type.type_id, r.return_type.type_id)
+                    }
+
                     (a, b) => a.eq(b, self.engines),
                 }
             }
@@ -67,6 +67,8 @@ pub enum ConvertParseTreeError {
     ConstructorPatternSubPatterns { span: Span },
     #[error("paths are not supported in this position")]
     PathsNotSupportedHere { span: Span },
+    #[error("function types are only supported as the types of function parameters")]
+    FnTypesNotSupportedHere { span: Span },
     #[error("Fully specified types are not supported in this position. Try importing the type and referring to it here.")]
     FullySpecifiedTypesNotSupported { span: Span },
     #[error("ContractCaller requires exactly one generic argument")]
@@ -156,6 +158,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::ConstructorPatternOneArg { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternSubPatterns { span } => span.clone(),
             ConvertParseTreeError::PathsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::FnTypesNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::FullySpecifiedTypesNotSupported { span } => span.clone(),
             ConvertParseTreeError::ContractCallerOneGenericArg { span } => span.clone(),
             ConvertParseTreeError::ContractCallerNamedTypeGenericArg { span } => span.clone(),
@@ -4,3 +4,5 @@ pub mod dominator;
 pub use dominator::*;
 pub mod memory_utils;
 pub use memory_utils::*;
+pub mod loops;
+pub use loops::*;
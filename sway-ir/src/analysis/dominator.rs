@@ -270,3 +270,18 @@ pub fn print_dom_fronts(context: &Context, func_name: &str, dom_fronts: &DomFron
     }
     res
 }
+
+/// Does `dominator` dominate `dominatee`?  A block dominates itself.
+///
+/// Blocks which are unreachable, and hence not in the dominator tree, don't dominate and aren't
+/// dominated by any block.
+pub fn dominates(dom_tree: &DomTree, dominator: Block, dominatee: Block) -> bool {
+    let mut runner = Some(dominatee);
+    while let Some(block) = runner {
+        if block == dominator {
+            return dom_tree.contains_key(&block);
+        }
+        runner = dom_tree.get(&block).and_then(|node| node.parent);
+    }
+    false
+}
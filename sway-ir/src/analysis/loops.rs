@@ -0,0 +1,199 @@
+//! Natural loop analysis.
+//!
+//! A natural loop is identified by a back edge, an edge from a block (the latch) to a block
+//! which dominates it (the header).  The loop consists of the header and all the blocks which
+//! can reach the latch without passing through the header.  Loops sharing a header are merged.
+
+use rustc_hash::FxHashMap;
+use std::fmt::Write;
+use sway_types::{FxIndexMap, FxIndexSet};
+
+use crate::{
+    block::Block, dominates, AnalysisResult, AnalysisResultT, AnalysisResults, BranchToWithArgs,
+    Context, DomTree, Function, IrError, Pass, PassMutability, ScopedPass, DOMINATORS_NAME,
+};
+
+/// A natural loop in the CFG.
+pub struct Loop {
+    /// The single entry block of the loop, which dominates all of its blocks.
+    pub header: Block,
+    /// All the blocks in the loop, including the header and those of any nested loops.
+    pub blocks: FxIndexSet<Block>,
+    /// The blocks within the loop which branch back to the header.
+    pub latches: Vec<Block>,
+    /// The index of the immediately enclosing loop in the [LoopNest].
+    pub parent: Option<usize>,
+    /// The indices of the loops immediately nested within this loop in the [LoopNest].
+    pub children: Vec<usize>,
+}
+
+impl Loop {
+    pub fn contains(&self, block: &Block) -> bool {
+        self.blocks.contains(block)
+    }
+
+    /// The predecessors of the header which are not within the loop.
+    pub fn entering_blocks(&self, context: &Context) -> Vec<Block> {
+        self.header
+            .pred_iter(context)
+            .filter(|pred| !self.contains(pred))
+            .copied()
+            .collect()
+    }
+
+    /// The blocks within the loop which have a successor outside of the loop.
+    pub fn exiting_blocks(&self, context: &Context) -> Vec<Block> {
+        self.blocks
+            .iter()
+            .filter(|block| {
+                block
+                    .successors(context)
+                    .iter()
+                    .any(|BranchToWithArgs { block: succ, .. }| !self.contains(succ))
+            })
+            .copied()
+            .collect()
+    }
+
+    /// The preheader of the loop, if it has one: the only block entering the loop, whose only
+    /// successor is the header.
+    pub fn preheader(&self, context: &Context) -> Option<Block> {
+        match self.entering_blocks(context)[..] {
+            [pred] if pred.successors(context).len() == 1 => Some(pred),
+            _ => None,
+        }
+    }
+}
+
+/// All the natural loops in a function.
+///
+/// The loops are ordered so that nested loops always precede their enclosing loops.
+#[derive(Default)]
+pub struct LoopNest {
+    pub loops: Vec<Loop>,
+    /// Map from each block within a loop to the index of the innermost loop containing it.
+    pub block_to_loop: FxHashMap<Block, usize>,
+}
+impl AnalysisResultT for LoopNest {}
+
+impl LoopNest {
+    /// The innermost loop containing `block`, if any.
+    pub fn innermost_loop(&self, block: &Block) -> Option<&Loop> {
+        self.block_to_loop.get(block).map(|idx| &self.loops[*idx])
+    }
+
+    /// The number of loops containing `block`.
+    pub fn loop_depth(&self, block: &Block) -> usize {
+        let mut depth = 0;
+        let mut idx = self.block_to_loop.get(block).copied();
+        while let Some(loop_idx) = idx {
+            depth += 1;
+            idx = self.loops[loop_idx].parent;
+        }
+        depth
+    }
+}
+
+pub const LOOPS_NAME: &str = "loops";
+
+pub fn create_loops_pass() -> Pass {
+    Pass {
+        name: LOOPS_NAME,
+        descr: "Natural loop nest computation",
+        deps: vec![DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Analysis(compute_loops_pass)),
+    }
+}
+
+pub fn compute_loops_pass(
+    context: &Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<AnalysisResult, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+    Ok(Box::new(compute_loops(context, dom_tree)))
+}
+
+/// Compute the natural loops of the CFG, given its dominator tree.
+pub fn compute_loops(context: &Context, dom_tree: &DomTree) -> LoopNest {
+    // Find the back edges, grouping the latches by their header.
+    let mut latches = FxIndexMap::<Block, Vec<Block>>::default();
+    for block in dom_tree.keys() {
+        for BranchToWithArgs { block: succ, .. } in block.successors(context) {
+            if dominates(dom_tree, succ, *block) {
+                latches.entry(succ).or_default().push(*block);
+            }
+        }
+    }
+
+    // Collect the body of each loop by walking backwards from its latches up to the header.
+    let mut loops = latches
+        .into_iter()
+        .map(|(header, latches)| {
+            let mut blocks = FxIndexSet::default();
+            blocks.insert(header);
+            let mut worklist = latches.clone();
+            while let Some(block) = worklist.pop() {
+                if blocks.insert(block) {
+                    worklist.extend(
+                        block
+                            .pred_iter(context)
+                            .filter(|pred| dom_tree.contains_key(*pred)),
+                    );
+                }
+            }
+            Loop {
+                header,
+                blocks,
+                latches,
+                parent: None,
+                children: Vec::new(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Natural loops are either disjoint or nested, and a nested loop is strictly smaller than
+    // its enclosing loop.  Sorting by size puts inner loops first.
+    loops.sort_by_key(|lp| lp.blocks.len());
+
+    // The parent of each loop is the smallest larger loop containing its header.
+    for idx in 0..loops.len() {
+        let header = loops[idx].header;
+        if let Some(parent) = (idx + 1..loops.len()).find(|other| loops[*other].contains(&header)) {
+            loops[idx].parent = Some(parent);
+            loops[parent].children.push(idx);
+        }
+    }
+
+    // Visiting the smallest loops first means each block is mapped to its innermost loop.
+    let mut block_to_loop = FxHashMap::default();
+    for (idx, lp) in loops.iter().enumerate() {
+        for block in &lp.blocks {
+            block_to_loop.entry(*block).or_insert(idx);
+        }
+    }
+
+    LoopNest {
+        loops,
+        block_to_loop,
+    }
+}
+
+/// Print the loop nest information.
+pub fn print_loops(context: &Context, func_name: &str, loop_nest: &LoopNest) -> String {
+    let mut res = format!("Loops for {func_name}:\n");
+    for lp in &loop_nest.loops {
+        let _ = write!(
+            res,
+            "\t{} (depth {}):",
+            lp.header.get_label(context),
+            loop_nest.loop_depth(&lp.header)
+        );
+        for block in &lp.blocks {
+            res += " ";
+            res += &block.get_label(context);
+        }
+        res += "\n";
+    }
+    res
+}
@@ -443,6 +443,7 @@ impl Function {
                 old_var_content.initializer,
                 old_var_content.mutable,
             );
+            new_var.set_metadata(context, old_var_content.metadata);
             var_map.insert(old_var, new_var);
         }
         var_map
@@ -37,6 +37,12 @@ pub mod sroa;
 pub use sroa::*;
 pub mod fn_dedup;
 pub use fn_dedup::*;
+pub mod gvn;
+pub use gvn::*;
+pub mod licm;
+pub use licm::*;
+pub mod sccp;
+pub use sccp::*;
 
 mod target_fuel;
 
@@ -11,7 +11,8 @@ use crate::{
     function::Function,
     instruction::InstOp,
     value::ValueDatum,
-    AnalysisResults, BranchToWithArgs, Instruction, Pass, PassMutability, Predicate, ScopedPass,
+    AnalysisResults, BinaryOpKind, BranchToWithArgs, Instruction, Pass, PassMutability, Predicate,
+    ScopedPass, UnaryOpKind,
 };
 
 pub const CONSTCOMBINE_NAME: &str = "constcombine";
@@ -130,37 +131,7 @@ fn combine_cmp(context: &mut Context, function: &Function) -> bool {
                 }) if val1.is_constant(context) && val2.is_constant(context) => {
                     let val1 = val1.get_constant(context).unwrap();
                     let val2 = val2.get_constant(context).unwrap();
-
-                    use ConstantValue::*;
-                    match pred {
-                        Predicate::Equal => Some((inst_val, block, val1.eq(context, val2))),
-                        Predicate::GreaterThan => {
-                            let r = match (&val1.value, &val2.value) {
-                                (Uint(val1), Uint(val2)) => val1 > val2,
-                                (U256(val1), U256(val2)) => val1 > val2,
-                                (B256(val1), B256(val2)) => val1 > val2,
-                                _ => {
-                                    unreachable!(
-                                        "Type checker allowed non integer value for GreaterThan"
-                                    )
-                                }
-                            };
-                            Some((inst_val, block, r))
-                        }
-                        Predicate::LessThan => {
-                            let r = match (&val1.value, &val2.value) {
-                                (Uint(val1), Uint(val2)) => val1 < val2,
-                                (U256(val1), U256(val2)) => val1 < val2,
-                                (B256(val1), B256(val2)) => val1 < val2,
-                                _ => {
-                                    unreachable!(
-                                        "Type checker allowed non integer value for GreaterThan"
-                                    )
-                                }
-                            };
-                            Some((inst_val, block, r))
-                        }
-                    }
+                    Some((inst_val, block, fold_cmp(context, pred, val1, val2)))
                 }
                 _ => None,
             },
@@ -188,45 +159,7 @@ fn combine_binary_op(context: &mut Context, function: &Function) -> bool {
                 }) if arg1.is_constant(context) && arg2.is_constant(context) => {
                     let val1 = arg1.get_constant(context).unwrap();
                     let val2 = arg2.get_constant(context).unwrap();
-                    use crate::BinaryOpKind::*;
-                    use ConstantValue::*;
-                    let v = match (op, &val1.value, &val2.value) {
-                        (Add, Uint(l), Uint(r)) => l.checked_add(*r).map(Uint),
-                        (Add, U256(l), U256(r)) => l.checked_add(r).map(U256),
-
-                        (Sub, Uint(l), Uint(r)) => l.checked_sub(*r).map(Uint),
-                        (Sub, U256(l), U256(r)) => l.checked_sub(r).map(U256),
-
-                        (Mul, Uint(l), Uint(r)) => l.checked_mul(*r).map(Uint),
-                        (Mul, U256(l), U256(r)) => l.checked_mul(r).map(U256),
-
-                        (Div, Uint(l), Uint(r)) => l.checked_div(*r).map(Uint),
-                        (Div, U256(l), U256(r)) => l.checked_div(r).map(U256),
-
-                        (And, Uint(l), Uint(r)) => Some(Uint(l & r)),
-                        (And, U256(l), U256(r)) => Some(U256(l & r)),
-
-                        (Or, Uint(l), Uint(r)) => Some(Uint(l | r)),
-                        (Or, U256(l), U256(r)) => Some(U256(l | r)),
-
-                        (Xor, Uint(l), Uint(r)) => Some(Uint(l ^ r)),
-                        (Xor, U256(l), U256(r)) => Some(U256(l ^ r)),
-
-                        (Mod, Uint(l), Uint(r)) => Some(Uint(l % r)),
-                        (Mod, U256(l), U256(r)) => Some(U256(l % r)),
-
-                        (Rsh, Uint(l), Uint(r)) => u32::try_from(*r)
-                            .ok()
-                            .and_then(|r| l.checked_shr(r).map(Uint)),
-                        (Rsh, U256(l), Uint(r)) => Some(U256(l.shr(r))),
-
-                        (Lsh, Uint(l), Uint(r)) => u32::try_from(*r)
-                            .ok()
-                            .and_then(|r| l.checked_shl(r).map(Uint)),
-                        (Lsh, U256(l), Uint(r)) => l.checked_shl(r).map(U256),
-                        _ => None,
-                    };
-                    v.map(|value| (inst_val, block, Constant { ty: val1.ty, value }))
+                    fold_binary_op(op, val1, val2).map(|c| (inst_val, block, c))
                 }
                 _ => None,
             },
@@ -250,23 +183,7 @@ fn combine_unary_op(context: &mut Context, function: &Function) -> bool {
                     ..
                 }) if arg.is_constant(context) => {
                     let val = arg.get_constant(context).unwrap();
-                    use crate::UnaryOpKind::*;
-                    use ConstantValue::*;
-                    let v = match (op, &val.value) {
-                        (Not, Uint(v)) => val.ty.get_uint_width(context).and_then(|width| {
-                            let max = match width {
-                                8 => u8::MAX as u64,
-                                16 => u16::MAX as u64,
-                                32 => u32::MAX as u64,
-                                64 => u64::MAX,
-                                _ => return None,
-                            };
-                            Some(Uint((!v) & max))
-                        }),
-                        (Not, U256(v)) => Some(U256(!v)),
-                        _ => None,
-                    };
-                    v.map(|value| (inst_val, block, Constant { ty: val.ty, value }))
+                    fold_unary_op(context, op, val).map(|c| (inst_val, block, c))
                 }
                 _ => None,
             },
@@ -280,6 +197,109 @@ fn combine_unary_op(context: &mut Context, function: &Function) -> bool {
     })
 }
 
+/// Evaluate a comparison of two constants.
+pub(crate) fn fold_cmp(
+    context: &Context,
+    pred: &Predicate,
+    val1: &Constant,
+    val2: &Constant,
+) -> bool {
+    use ConstantValue::*;
+    match pred {
+        Predicate::Equal => val1.eq(context, val2),
+        Predicate::GreaterThan => match (&val1.value, &val2.value) {
+            (Uint(val1), Uint(val2)) => val1 > val2,
+            (U256(val1), U256(val2)) => val1 > val2,
+            (B256(val1), B256(val2)) => val1 > val2,
+            _ => {
+                unreachable!("Type checker allowed non integer value for GreaterThan")
+            }
+        },
+        Predicate::LessThan => match (&val1.value, &val2.value) {
+            (Uint(val1), Uint(val2)) => val1 < val2,
+            (U256(val1), U256(val2)) => val1 < val2,
+            (B256(val1), B256(val2)) => val1 < val2,
+            _ => {
+                unreachable!("Type checker allowed non integer value for GreaterThan")
+            }
+        },
+    }
+}
+
+/// Evaluate a binary operation on two constants, returning `None` if it can't be evaluated at
+/// compile time, e.g., because it would overflow.
+pub(crate) fn fold_binary_op(
+    op: &BinaryOpKind,
+    val1: &Constant,
+    val2: &Constant,
+) -> Option<Constant> {
+    use crate::BinaryOpKind::*;
+    use ConstantValue::*;
+    let v = match (op, &val1.value, &val2.value) {
+        (Add, Uint(l), Uint(r)) => l.checked_add(*r).map(Uint),
+        (Add, U256(l), U256(r)) => l.checked_add(r).map(U256),
+
+        (Sub, Uint(l), Uint(r)) => l.checked_sub(*r).map(Uint),
+        (Sub, U256(l), U256(r)) => l.checked_sub(r).map(U256),
+
+        (Mul, Uint(l), Uint(r)) => l.checked_mul(*r).map(Uint),
+        (Mul, U256(l), U256(r)) => l.checked_mul(r).map(U256),
+
+        (Div, Uint(l), Uint(r)) => l.checked_div(*r).map(Uint),
+        (Div, U256(l), U256(r)) => l.checked_div(r).map(U256),
+
+        (And, Uint(l), Uint(r)) => Some(Uint(l & r)),
+        (And, U256(l), U256(r)) => Some(U256(l & r)),
+
+        (Or, Uint(l), Uint(r)) => Some(Uint(l | r)),
+        (Or, U256(l), U256(r)) => Some(U256(l | r)),
+
+        (Xor, Uint(l), Uint(r)) => Some(Uint(l ^ r)),
+        (Xor, U256(l), U256(r)) => Some(U256(l ^ r)),
+
+        (Mod, Uint(l), Uint(r)) => l.checked_rem(*r).map(Uint),
+        (Mod, U256(l), U256(r)) => l.checked_rem(r).map(U256),
+
+        (Rsh, Uint(l), Uint(r)) => u32::try_from(*r)
+            .ok()
+            .and_then(|r| l.checked_shr(r).map(Uint)),
+        (Rsh, U256(l), Uint(r)) => Some(U256(l.shr(r))),
+
+        (Lsh, Uint(l), Uint(r)) => u32::try_from(*r)
+            .ok()
+            .and_then(|r| l.checked_shl(r).map(Uint)),
+        (Lsh, U256(l), Uint(r)) => l.checked_shl(r).map(U256),
+        _ => None,
+    };
+    v.map(|value| Constant { ty: val1.ty, value })
+}
+
+/// Evaluate a unary operation on a constant, returning `None` if it can't be evaluated at compile
+/// time.
+pub(crate) fn fold_unary_op(
+    context: &Context,
+    op: &UnaryOpKind,
+    val: &Constant,
+) -> Option<Constant> {
+    use crate::UnaryOpKind::*;
+    use ConstantValue::*;
+    let v = match (op, &val.value) {
+        (Not, Uint(v)) => val.ty.get_uint_width(context).and_then(|width| {
+            let max = match width {
+                8 => u8::MAX as u64,
+                16 => u16::MAX as u64,
+                32 => u32::MAX as u64,
+                64 => u64::MAX,
+                _ => return None,
+            };
+            Some(Uint((!v) & max))
+        }),
+        (Not, U256(v)) => Some(U256(!v)),
+        _ => None,
+    };
+    v.map(|value| Constant { ty: val.ty, value })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::optimize::tests::*;
@@ -0,0 +1,331 @@
+//! ## Global Value Numbering
+//!
+//! This optimization removes redundant computations, i.e., instructions which compute a value
+//! already computed by a dominating instruction. It is a dominator based value numbering:
+//!   1. The dominator tree is walked in preorder, maintaining a scoped table of the available
+//!      pure expressions (arithmetic, comparisons, casts, `get_local` and `get_elem_ptr`).
+//!   2. An instruction matching an available expression is replaced by the dominating value.
+//!   3. Memory reads (`load` and `state_load_word`) are only numbered within a block, and are
+//!      invalidated by any instruction which may write to the memory (or storage) they read.
+
+use rustc_hash::FxHashMap;
+use sway_types::FxIndexSet;
+
+use crate::{
+    get_stored_ptr_values, get_symbols, AnalysisResults, BinaryOpKind, Block, Context, DomTree,
+    FuelVmInstruction, Function, InstOp, Instruction, IrError, LocalVar, Pass, PassMutability,
+    Predicate, ScopedPass, Symbol, Type, UnaryOpKind, Value, ValueDatum, DOMINATORS_NAME,
+};
+
+pub const GVN_NAME: &str = "gvn";
+
+pub fn create_gvn_pass() -> Pass {
+    Pass {
+        name: GVN_NAME,
+        descr: "Global value numbering / common subexpression elimination.",
+        deps: vec![DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(gvn)),
+    }
+}
+
+/// An operand of a numbered expression.
+///
+/// Integer and boolean constants are compared by value, since each use of a constant is usually
+/// a distinct [Value].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Operand {
+    Value(Value),
+    Const(Type, u64),
+}
+
+impl Operand {
+    fn new(context: &Context, replacements: &FxHashMap<Value, Value>, val: Value) -> Self {
+        let val = replacements.get(&val).copied().unwrap_or(val);
+        match &context.values[val.0].value {
+            ValueDatum::Constant(c) => match c.value {
+                crate::ConstantValue::Uint(n) => Operand::Const(c.ty, n),
+                crate::ConstantValue::Bool(b) => Operand::Const(c.ty, b as u64),
+                _ => Operand::Value(val),
+            },
+            _ => Operand::Value(val),
+        }
+    }
+
+    /// A total order on operands, used to canonicalize commutative expressions.
+    fn order_key(&self) -> (u8, usize, u64, u64) {
+        match self {
+            Operand::Value(v) => {
+                let (idx, gen) = v.0.into_raw_parts();
+                (0, idx, gen, 0)
+            }
+            Operand::Const(ty, n) => {
+                let (idx, gen) = ty.0.into_raw_parts();
+                (1, idx, gen, *n)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum CastKind {
+    BitCast,
+    CastPtr,
+    IntToPtr,
+    PtrToInt,
+}
+
+/// A pure expression, which computes the same value wherever it is evaluated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum ExprKey {
+    Unary(UnaryOpKind, Operand),
+    Binary(BinaryOpKind, Operand, Operand),
+    Cmp(Predicate, Operand, Operand),
+    Cast(CastKind, Operand, Type),
+    GetLocal(LocalVar),
+    GetElemPtr(Operand, Type, Vec<Operand>),
+}
+
+/// An expression reading memory or storage, which is only valid until it may be clobbered.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum MemKey {
+    Load(Operand),
+    StateLoadWord(Operand),
+}
+
+fn expr_key(
+    context: &Context,
+    replacements: &FxHashMap<Value, Value>,
+    op: &InstOp,
+) -> Option<ExprKey> {
+    let operand = |val: &Value| Operand::new(context, replacements, *val);
+    let ordered = |a: Operand, b: Operand| {
+        if a.order_key() <= b.order_key() {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+    Some(match op {
+        InstOp::UnaryOp { op, arg } => ExprKey::Unary(*op, operand(arg)),
+        InstOp::BinaryOp { op, arg1, arg2 } => {
+            let (arg1, arg2) = (operand(arg1), operand(arg2));
+            let (arg1, arg2) = match op {
+                BinaryOpKind::Add
+                | BinaryOpKind::Mul
+                | BinaryOpKind::And
+                | BinaryOpKind::Or
+                | BinaryOpKind::Xor => ordered(arg1, arg2),
+                _ => (arg1, arg2),
+            };
+            ExprKey::Binary(*op, arg1, arg2)
+        }
+        InstOp::Cmp(pred, lhs, rhs) => {
+            let (lhs, rhs) = (operand(lhs), operand(rhs));
+            let (lhs, rhs) = match pred {
+                Predicate::Equal => ordered(lhs, rhs),
+                _ => (lhs, rhs),
+            };
+            ExprKey::Cmp(*pred, lhs, rhs)
+        }
+        InstOp::BitCast(val, ty) => ExprKey::Cast(CastKind::BitCast, operand(val), *ty),
+        InstOp::CastPtr(val, ty) => ExprKey::Cast(CastKind::CastPtr, operand(val), *ty),
+        InstOp::IntToPtr(val, ty) => ExprKey::Cast(CastKind::IntToPtr, operand(val), *ty),
+        InstOp::PtrToInt(val, ty) => ExprKey::Cast(CastKind::PtrToInt, operand(val), *ty),
+        InstOp::GetLocal(local) => ExprKey::GetLocal(*local),
+        InstOp::GetElemPtr {
+            base,
+            elem_ptr_ty,
+            indices,
+        } => ExprKey::GetElemPtr(
+            operand(base),
+            *elem_ptr_ty,
+            indices.iter().map(operand).collect(),
+        ),
+        _ => return None,
+    })
+}
+
+/// A memory read available within the current block.
+struct AvailableRead {
+    key: MemKey,
+    value: Value,
+    /// The symbols which may be read. Empty if they could not be determined.
+    symbols: FxIndexSet<Symbol>,
+}
+
+impl AvailableRead {
+    /// Could a write to a pointer referring to `stored` symbols clobber this read?
+    ///
+    /// Reads or writes through pointers which cannot be resolved to symbols, or which are
+    /// derived from pointer arguments (that may alias each other), are conservatively assumed
+    /// to clobber each other.
+    fn may_be_clobbered_by(&self, stored: &FxIndexSet<Symbol>) -> bool {
+        let is_unknown = |syms: &FxIndexSet<Symbol>| {
+            syms.is_empty() || syms.iter().any(|sym| matches!(sym, Symbol::Arg(_)))
+        };
+        is_unknown(&self.symbols)
+            || is_unknown(stored)
+            || self.symbols.iter().any(|sym| stored.contains(sym))
+    }
+}
+
+/// Remove the memory reads in `reads` which may be clobbered by the instruction `inst`.
+fn invalidate_reads(context: &Context, reads: &mut Vec<AvailableRead>, inst: Value) {
+    let op = &inst.get_instruction(context).unwrap().op;
+    match op {
+        // These may read or write to any memory and storage.
+        InstOp::AsmBlock(..) | InstOp::Call(..) | InstOp::ContractCall { .. } => {
+            reads.clear();
+            return;
+        }
+        // These write to storage.
+        InstOp::FuelVm(
+            FuelVmInstruction::StateClear { .. }
+            | FuelVmInstruction::StateStoreQuadWord { .. }
+            | FuelVmInstruction::StateStoreWord { .. },
+        ) => reads.retain(|read| !matches!(read.key, MemKey::StateLoadWord(_))),
+        _ => (),
+    }
+    for ptr in get_stored_ptr_values(context, inst) {
+        let stored = get_symbols(context, ptr);
+        reads.retain(|read| !read.may_be_clobbered_by(&stored));
+    }
+}
+
+/// Perform value numbering over the function, returning true if it was modified.
+pub fn gvn(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+
+    // Map from redundant instructions to the dominating values which replace them.
+    let mut replacements = FxHashMap::<Value, Value>::default();
+    let mut redundant = Vec::<(Block, Value)>::new();
+
+    // The pure expressions available in the current block, from its dominators.
+    let mut available = FxHashMap::<ExprKey, Value>::default();
+
+    // Walk the dominator tree in preorder. Each stack entry records the keys added to
+    // `available` by the block, which are removed once all of its children are processed.
+    enum Visit {
+        Enter(Block),
+        Exit(Vec<ExprKey>),
+    }
+    let mut stack = vec![Visit::Enter(function.get_entry_block(context))];
+    while let Some(visit) = stack.pop() {
+        let block = match visit {
+            Visit::Enter(block) => block,
+            Visit::Exit(added) => {
+                for key in added {
+                    available.remove(&key);
+                }
+                continue;
+            }
+        };
+
+        let mut added = Vec::new();
+        let mut reads = Vec::<AvailableRead>::new();
+        for inst in block.instruction_iter(context) {
+            let Some(Instruction { op, .. }) = inst.get_instruction(context) else {
+                continue;
+            };
+
+            if let Some(key) = expr_key(context, &replacements, op) {
+                match available.get(&key) {
+                    Some(value) => {
+                        replacements.insert(inst, *value);
+                        redundant.push((block, inst));
+                    }
+                    None => {
+                        available.insert(key.clone(), inst);
+                        added.push(key);
+                    }
+                }
+                continue;
+            }
+
+            let (mem_key, ptr) = match op {
+                InstOp::Load(ptr) => (
+                    MemKey::Load(Operand::new(context, &replacements, *ptr)),
+                    *ptr,
+                ),
+                InstOp::FuelVm(FuelVmInstruction::StateLoadWord(key)) => (
+                    MemKey::StateLoadWord(Operand::new(context, &replacements, *key)),
+                    *key,
+                ),
+                _ => {
+                    invalidate_reads(context, &mut reads, inst);
+                    continue;
+                }
+            };
+            match reads.iter().find(|read| read.key == mem_key) {
+                Some(read) => {
+                    replacements.insert(inst, read.value);
+                    redundant.push((block, inst));
+                }
+                None => reads.push(AvailableRead {
+                    key: mem_key,
+                    value: inst,
+                    symbols: get_symbols(context, ptr),
+                }),
+            }
+        }
+
+        stack.push(Visit::Exit(added));
+        if let Some(node) = dom_tree.get(&block) {
+            stack.extend(node.children.iter().map(|child| Visit::Enter(*child)));
+        }
+    }
+
+    if replacements.is_empty() {
+        return Ok(false);
+    }
+
+    function.replace_values(context, &replacements, None);
+    for (block, inst) in redundant {
+        block.remove_instruction(context, inst);
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::optimize::tests::*;
+
+    #[test]
+    fn gvn_keeps_non_commutative_operands() {
+        assert_optimization(
+            &["gvn"],
+            "entry fn main(a: u64, b: u64) -> u64 {
+                entry(a: u64, b: u64):
+                v0 = sub a, b
+                v1 = sub b, a, !0
+                v2 = mul v0, v1
+                ret u64 v2
+            }",
+            None::<Vec<&str>>,
+        );
+    }
+    #[test]
+    fn gvn_keeps_loads_across_stores() {
+        assert_optimization(
+            &["gvn"],
+            "entry fn main() -> u64 {
+                local u64 x
+
+                entry():
+                v0 = get_local ptr u64, x
+                v1 = load v0
+                v2 = const u64 1
+                v3 = add v1, v2
+                store v3 to v0
+                v4 = load v0, !0
+                ret u64 v4
+            }",
+            None::<Vec<&str>>,
+        );
+    }
+}
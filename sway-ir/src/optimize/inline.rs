@@ -43,6 +43,17 @@ pub fn create_inline_in_module_pass() -> Pass {
     }
 }
 
+pub const INLINE_MODULE_AGGRESSIVE_NAME: &str = "inline_module_aggressive";
+
+pub fn create_inline_in_module_aggressive_pass() -> Pass {
+    Pass {
+        name: INLINE_MODULE_AGGRESSIVE_NAME,
+        descr: "inline function calls in a module, favouring gas over code size.",
+        deps: vec![],
+        runner: ScopedPass::ModulePass(PassMutability::Transform(inline_in_module_aggressive)),
+    }
+}
+
 /// This is a copy of sway_core::inline::Inline.
 /// TODO: Reuse: Depend on sway_core? Move it to sway_types?
 #[derive(Debug)]
@@ -87,6 +98,26 @@ pub fn inline_in_module(
     context: &mut Context,
     _: &AnalysisResults,
     module: Module,
+) -> Result<bool, IrError> {
+    // Functions this small are unlikely to be larger than the call overhead.
+    const MAX_INLINE_INSTRS_COUNT: usize = 4;
+    inline_in_module_with_max_instrs(context, module, MAX_INLINE_INSTRS_COUNT)
+}
+
+pub fn inline_in_module_aggressive(
+    context: &mut Context,
+    _: &AnalysisResults,
+    module: Module,
+) -> Result<bool, IrError> {
+    // Inlining larger functions saves the call overhead at runtime at the cost of code size.
+    const MAX_INLINE_INSTRS_COUNT: usize = 16;
+    inline_in_module_with_max_instrs(context, module, MAX_INLINE_INSTRS_COUNT)
+}
+
+fn inline_in_module_with_max_instrs(
+    context: &mut Context,
+    module: Module,
+    max_inline_instrs_count: usize,
 ) -> Result<bool, IrError> {
     // Inspect ALL calls and count how often each function is called.
     let call_counts: HashMap<Function, u64> =
@@ -127,8 +158,7 @@ pub fn inline_in_module(
         }
 
         // If the function is (still) small then also inline it.
-        const MAX_INLINE_INSTRS_COUNT: usize = 4;
-        if func.num_instructions(ctx) <= MAX_INLINE_INSTRS_COUNT {
+        if func.num_instructions(ctx) <= max_inline_instrs_count {
             return true;
         }
 
@@ -0,0 +1,384 @@
+//! ## Loop Invariant Code Motion
+//!
+//! This optimization hoists instructions which compute the same value on every iteration of a
+//! loop out of the loop and into its preheader, creating a preheader if necessary.
+//!
+//! Pure instructions whose operands are all defined outside of the loop are hoisted, as are loads
+//! from invariant pointers to memory which isn't written to within the loop.  Instructions which
+//! may fail at runtime (e.g., overflowing arithmetic or loads from arbitrary pointers) are only
+//! hoisted if they are guaranteed to execute whenever the loop is entered, so that a loop which
+//! would have exited before reaching them doesn't now fail.
+//!
+//! Loops are visited innermost first, so that instructions hoisted out of a nested loop may then
+//! be hoisted out of its enclosing loop.
+
+use rustc_hash::FxHashSet;
+use sway_types::FxIndexSet;
+
+use crate::{
+    dominates, get_stored_ptr_values, get_symbols, AnalysisResults, BinaryOpKind, Block,
+    BranchToWithArgs, Context, DomTree, Function, InstOp, IrError, Loop, LoopNest, Pass,
+    PassMutability, ScopedPass, Symbol, Value, ValueDatum, DOMINATORS_NAME, LOOPS_NAME,
+};
+
+pub const LICM_NAME: &str = "licm";
+
+pub fn create_licm_pass() -> Pass {
+    Pass {
+        name: LICM_NAME,
+        descr: "Loop invariant code motion.",
+        deps: vec![DOMINATORS_NAME, LOOPS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(licm)),
+    }
+}
+
+/// The memory written to within a loop.
+enum LoopWrites {
+    /// The memory written to can't be determined, so any memory may be written to.
+    Unknown,
+    /// Only the memory of these symbols may be written to.
+    Symbols(FxIndexSet<Symbol>),
+}
+
+impl LoopWrites {
+    fn compute(context: &Context, blocks: &FxIndexSet<Block>) -> Self {
+        let mut symbols = FxIndexSet::default();
+        for inst in blocks
+            .iter()
+            .flat_map(|block| block.instruction_iter(context))
+        {
+            if matches!(
+                inst.get_instruction(context).unwrap().op,
+                InstOp::AsmBlock(..) | InstOp::Call(..) | InstOp::ContractCall { .. }
+            ) {
+                return LoopWrites::Unknown;
+            }
+            for ptr in get_stored_ptr_values(context, inst) {
+                let stored = get_symbols(context, ptr);
+                if is_unknown(&stored) {
+                    return LoopWrites::Unknown;
+                }
+                symbols.extend(stored);
+            }
+        }
+        LoopWrites::Symbols(symbols)
+    }
+
+    /// Is the memory pointed to by `ptr` unmodified within the loop?
+    fn is_unmodified(&self, context: &Context, ptr: Value) -> bool {
+        match self {
+            LoopWrites::Unknown => false,
+            LoopWrites::Symbols(stored) if stored.is_empty() => true,
+            LoopWrites::Symbols(stored) => {
+                let read = get_symbols(context, ptr);
+                !is_unknown(&read) && !read.iter().any(|sym| stored.contains(sym))
+            }
+        }
+    }
+}
+
+/// Symbols which are empty (unresolved) or derived from pointer arguments (which may alias each
+/// other) don't tell us which memory is accessed.
+fn is_unknown(symbols: &FxIndexSet<Symbol>) -> bool {
+    symbols.is_empty() || symbols.iter().any(|sym| matches!(sym, Symbol::Arg(_)))
+}
+
+/// Is `ptr` always a valid pointer to a local variable, i.e., is it safe to load from it?
+fn is_local_ptr(context: &Context, ptr: Value) -> bool {
+    match ptr.get_instruction(context).map(|inst| &inst.op) {
+        Some(InstOp::GetLocal(_)) => true,
+        Some(InstOp::GetElemPtr { base, indices, .. }) => {
+            indices.iter().all(|idx| idx.is_constant(context)) && is_local_ptr(context, *base)
+        }
+        _ => false,
+    }
+}
+
+/// Is `val` defined outside of the loop made up of `blocks`?
+fn is_invariant(context: &Context, blocks: &FxIndexSet<Block>, val: Value) -> bool {
+    match &context.values[val.0].value {
+        ValueDatum::Instruction(inst) => !blocks.contains(&inst.parent),
+        ValueDatum::Argument(arg) => !blocks.contains(&arg.block),
+        ValueDatum::Configurable(_) | ValueDatum::Constant(_) => true,
+    }
+}
+
+/// How may `inst` be hoisted?
+enum Hoistable {
+    /// It may always be hoisted.
+    Always,
+    /// It may only be hoisted if it is guaranteed to execute.
+    IfExecuted,
+    /// It may not be hoisted.
+    Never,
+}
+
+fn hoistable(context: &Context, writes: &LoopWrites, inst: Value) -> Hoistable {
+    match &inst.get_instruction(context).unwrap().op {
+        InstOp::BinaryOp { op, .. } => match op {
+            // These may overflow or divide by zero.
+            BinaryOpKind::Add
+            | BinaryOpKind::Sub
+            | BinaryOpKind::Mul
+            | BinaryOpKind::Div
+            | BinaryOpKind::Mod => Hoistable::IfExecuted,
+            BinaryOpKind::And
+            | BinaryOpKind::Or
+            | BinaryOpKind::Xor
+            | BinaryOpKind::Lsh
+            | BinaryOpKind::Rsh => Hoistable::Always,
+        },
+        InstOp::UnaryOp { .. }
+        | InstOp::Cmp(..)
+        | InstOp::BitCast(..)
+        | InstOp::CastPtr(..)
+        | InstOp::IntToPtr(..)
+        | InstOp::PtrToInt(..)
+        | InstOp::GetLocal(_)
+        | InstOp::GetElemPtr { .. } => Hoistable::Always,
+        InstOp::Load(ptr) if writes.is_unmodified(context, *ptr) => {
+            if is_local_ptr(context, *ptr) {
+                Hoistable::Always
+            } else {
+                Hoistable::IfExecuted
+            }
+        }
+        _ => Hoistable::Never,
+    }
+}
+
+/// Get the preheader of the loop, creating one if the loop doesn't have one.
+///
+/// Returns `None` if a preheader can't be created, e.g., if the header is the function entry.
+fn get_or_create_preheader(context: &mut Context, function: Function, lp: &Loop) -> Option<Block> {
+    if let Some(preheader) = lp.preheader(context) {
+        return Some(preheader);
+    }
+
+    let header = lp.header;
+    let entering = lp.entering_blocks(context);
+    // A block which branches to the header twice, e.g., with a `cbr` to it on both edges, may
+    // pass different arguments on each edge, which can't both be forwarded to a preheader.
+    let branches_twice = |block: &Block| {
+        block
+            .successors(context)
+            .iter()
+            .filter(|BranchToWithArgs { block: succ, .. }| *succ == header)
+            .count()
+            > 1
+    };
+    if entering.is_empty() || entering.iter().any(branches_twice) {
+        return None;
+    }
+
+    // The preheader is placed before the header so that the hoisted definitions precede their
+    // uses within the function.  It takes the same arguments as the header and forwards them.
+    let preheader = function.create_block_before(context, &header, None).ok()?;
+    let arg_tys = header
+        .arg_iter(context)
+        .map(|arg| arg.get_type(context).unwrap())
+        .collect::<Vec<_>>();
+    let args = arg_tys
+        .into_iter()
+        .map(|ty| {
+            let idx = preheader.new_arg(context, ty);
+            preheader.get_arg(context, idx).unwrap()
+        })
+        .collect();
+    for pred in entering {
+        let params = pred.get_succ_params(context, &header);
+        pred.replace_successor(context, header, preheader, params);
+    }
+    preheader.append(context).branch(header, args);
+
+    Some(preheader)
+}
+
+/// Move `inst` to the end of `preheader`, before its terminator.
+fn hoist(context: &mut Context, inst: Value, preheader: Block) {
+    let from_block = inst.get_instruction(context).unwrap().parent;
+    from_block.remove_instruction(context, inst);
+    let instructions = &mut context.blocks[preheader.0].instructions;
+    instructions.insert(instructions.len() - 1, inst);
+    inst.get_instruction_mut(context).unwrap().parent = preheader;
+}
+
+/// Hoist loop invariant instructions out of every loop, returning true if the function was
+/// modified.
+pub fn licm(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+    let loop_nest: &LoopNest = analyses.get_analysis_result(function);
+
+    // The blocks of each loop, which grow as preheaders are created for the loops they contain.
+    let mut loop_blocks = loop_nest
+        .loops
+        .iter()
+        .map(|lp| lp.blocks.clone())
+        .collect::<Vec<_>>();
+
+    let mut modified = false;
+    for (loop_idx, lp) in loop_nest.loops.iter().enumerate() {
+        let blocks = &loop_blocks[loop_idx];
+
+        // Collect the candidates in the order of the function's blocks, so that hoisted
+        // definitions precede any of their uses which are also hoisted.
+        let candidates = function
+            .block_iter(context)
+            .filter(|block| blocks.contains(block))
+            .flat_map(|block| block.instruction_iter(context))
+            .collect::<Vec<_>>();
+
+        // An instruction is guaranteed to execute if its block dominates every block which may
+        // leave the loop, whether by branching out of it or by returning or reverting.  Nothing
+        // is guaranteed to execute in a loop which never exits, and blocks added since the
+        // dominator tree was computed are conservatively not.
+        let exits = blocks
+            .iter()
+            .filter(|block| {
+                let succs = block.successors(context);
+                succs.is_empty()
+                    || succs
+                        .iter()
+                        .any(|BranchToWithArgs { block: succ, .. }| !blocks.contains(succ))
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        let is_executed = |block: Block| {
+            !exits.is_empty() && exits.iter().all(|exit| dominates(dom_tree, block, *exit))
+        };
+
+        let writes = LoopWrites::compute(context, blocks);
+
+        // Hoisting an instruction may make its users invariant, so iterate until no more are
+        // found.
+        let mut to_hoist = Vec::new();
+        let mut hoisted = FxHashSet::default();
+        loop {
+            let mut found = false;
+            for inst in &candidates {
+                if hoisted.contains(inst) {
+                    continue;
+                }
+                let ins = inst.get_instruction(context).unwrap();
+                let may_hoist = match hoistable(context, &writes, *inst) {
+                    Hoistable::Always => true,
+                    Hoistable::IfExecuted => is_executed(ins.parent),
+                    Hoistable::Never => false,
+                };
+                if may_hoist
+                    && ins
+                        .op
+                        .get_operands()
+                        .into_iter()
+                        .all(|opd| hoisted.contains(&opd) || is_invariant(context, blocks, opd))
+                {
+                    hoisted.insert(*inst);
+                    to_hoist.push(*inst);
+                    found = true;
+                }
+            }
+            if !found {
+                break;
+            }
+        }
+
+        if to_hoist.is_empty() {
+            continue;
+        }
+        let Some(preheader) = get_or_create_preheader(context, function, lp) else {
+            continue;
+        };
+
+        // A new preheader is within every enclosing loop.
+        let mut parent = lp.parent;
+        while let Some(parent_idx) = parent {
+            loop_blocks[parent_idx].insert(preheader);
+            parent = loop_nest.loops[parent_idx].parent;
+        }
+
+        for inst in to_hoist {
+            hoist(context, inst, preheader);
+        }
+        modified = true;
+    }
+
+    Ok(modified)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::optimize::tests::*;
+
+    #[test]
+    fn licm_keeps_loads_of_modified_memory() {
+        assert_optimization(
+            &["licm"],
+            "entry fn main() -> u64 {
+                local u64 x
+
+                entry():
+                v0 = get_local ptr u64, x
+                br header()
+
+                header():
+                v1 = load v0, !0
+                v2 = const u64 1
+                v3 = add v1, v2
+                store v3 to v0
+                v4 = const u64 10
+                v5 = cmp lt v3 v4
+                cbr v5, header(), exit()
+
+                exit():
+                ret u64 v3
+            }",
+            None::<Vec<&str>>,
+        );
+    }
+
+    #[test]
+    fn licm_keeps_unexecuted_arithmetic() {
+        assert_optimization(
+            &["licm"],
+            "entry fn main(a: u64, b: u64, c: bool) -> u64 {
+                entry(a: u64, b: u64, c: bool):
+                br header()
+
+                header():
+                cbr c, body(), exit()
+
+                body():
+                v0 = add a, b, !0
+                br header()
+
+                exit():
+                v1 = const u64 0
+                ret u64 v1
+            }",
+            None::<Vec<&str>>,
+        );
+    }
+
+    #[test]
+    fn licm_keeps_unexecuted_arithmetic_without_exits() {
+        assert_optimization(
+            &["licm"],
+            "entry fn main(a: u64, b: u64, c: bool) -> u64 {
+                entry(a: u64, b: u64, c: bool):
+                br header()
+
+                header():
+                cbr c, body(), header()
+
+                body():
+                v0 = add a, b, !0
+                br header()
+            }",
+            None::<Vec<&str>>,
+        );
+    }
+}
@@ -399,6 +399,7 @@ fn local_copy_prop(
         escaped_symbols: &EscapedSymbols,
         inst: Value,
         src_val_ptr: Value,
+        available_copies: &FxHashSet<Value>,
         dest_to_copies: &FxIndexMap<Symbol, FxIndexSet<Value>>,
         replacements: &mut FxHashMap<Value, Replacement>,
     ) -> bool {
@@ -408,10 +409,12 @@ fn local_copy_prop(
             if escaped_symbols.contains(&src_sym) {
                 return false;
             }
+            // A `memcpy` killed through its source symbol is still in `dest_to_copies`.
             for memcpy in dest_to_copies
                 .get(&src_sym)
                 .iter()
                 .flat_map(|set| set.iter())
+                .filter(|memcpy| available_copies.contains(memcpy))
             {
                 let (dst_ptr_memcpy, src_ptr_memcpy, copy_len) =
                     deconstruct_memcpy(context, *memcpy);
@@ -557,6 +560,7 @@ fn local_copy_prop(
                             escaped_symbols,
                             inst,
                             *src_val_ptr,
+                            &available_copies,
                             &dest_to_copies,
                             &mut replacements,
                         );
@@ -581,6 +585,7 @@ fn local_copy_prop(
                             escaped_symbols,
                             inst,
                             src_val_ptr,
+                            &available_copies,
                             &dest_to_copies,
                             &mut replacements,
                         ) {
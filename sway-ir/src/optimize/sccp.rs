@@ -0,0 +1,506 @@
+//! ## Sparse Conditional Constant Propagation
+//!
+//! This optimization finds the values which are constant, assuming that only the blocks which
+//! can be reached by branches with (possibly constant) conditions are executed, following
+//! "Constant Propagation with Conditional Branches" -- Mark N. Wegman and F. Kenneth Zadeck.
+//!
+//! Unlike `constcombine`, which only folds instructions with constant operands, constants are
+//! propagated through block arguments, and through the blocks which are only reachable when a
+//! condition has a particular value.  Found constants replace their values, branches on constant
+//! conditions become unconditional, and the blocks which are never reached are removed.
+//!
+//! The interprocedural variant first replaces any function argument, for which every call site in
+//! the module passes the same constant, with that constant.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use sway_types::FxIndexMap;
+
+use crate::{
+    fold_binary_op, fold_cmp, fold_unary_op, AnalysisResults, Block, BranchToWithArgs, Constant,
+    ConstantValue, Context, Function, InstOp, Instruction, IrError, Module, Pass, PassMutability,
+    ScopedPass, Value, ValueDatum,
+};
+
+pub const SCCP_NAME: &str = "sccp";
+
+pub fn create_sccp_pass() -> Pass {
+    Pass {
+        name: SCCP_NAME,
+        descr: "Sparse conditional constant propagation.",
+        deps: vec![],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(sccp)),
+    }
+}
+
+pub const IPSCCP_NAME: &str = "ipsccp";
+
+pub fn create_ipsccp_pass() -> Pass {
+    Pass {
+        name: IPSCCP_NAME,
+        descr: "Sparse conditional constant propagation, including into called functions.",
+        deps: vec![],
+        runner: ScopedPass::ModulePass(PassMutability::Transform(ipsccp)),
+    }
+}
+
+/// The lattice of values: each value starts as `Undefined` and may only be lowered, first to a
+/// `Constant` and then to `Overdefined`.
+#[derive(Clone, Debug)]
+enum LatticeValue {
+    /// No evidence has been found for the value yet.
+    Undefined,
+    /// The value is always this constant.
+    Constant(Constant),
+    /// The value may not be constant.
+    Overdefined,
+}
+
+impl LatticeValue {
+    fn from_value(context: &Context, val: Value) -> Self {
+        match val.get_constant(context) {
+            Some(Constant {
+                value: ConstantValue::Undef,
+                ..
+            })
+            | None => LatticeValue::Overdefined,
+            Some(c) => LatticeValue::Constant(c.clone()),
+        }
+    }
+
+    /// The greatest lower bound of `self` and `other`.
+    fn meet(self, context: &Context, other: &LatticeValue) -> LatticeValue {
+        match (self, other) {
+            (LatticeValue::Undefined, other) => other.clone(),
+            (this, LatticeValue::Undefined) => this,
+            (LatticeValue::Constant(c1), LatticeValue::Constant(c2)) if c1.eq(context, c2) => {
+                LatticeValue::Constant(c1)
+            }
+            _ => LatticeValue::Overdefined,
+        }
+    }
+
+    fn is_same(&self, context: &Context, other: &LatticeValue) -> bool {
+        match (self, other) {
+            (LatticeValue::Undefined, LatticeValue::Undefined)
+            | (LatticeValue::Overdefined, LatticeValue::Overdefined) => true,
+            (LatticeValue::Constant(c1), LatticeValue::Constant(c2)) => c1.eq(context, c2),
+            _ => false,
+        }
+    }
+}
+
+struct Solver<'a, 'eng> {
+    context: &'a Context<'eng>,
+    entry: Block,
+    lattice: FxHashMap<Value, LatticeValue>,
+    executable_edges: FxHashSet<(Block, Block)>,
+    executable_blocks: FxHashSet<Block>,
+    /// Map from each value to the blocks which must be revisited when it changes: those of the
+    /// instructions using it, or for branch arguments, those of the block arguments.
+    users: FxHashMap<Value, Vec<Block>>,
+    worklist: Vec<Block>,
+}
+
+impl<'a, 'eng> Solver<'a, 'eng> {
+    fn new(context: &'a Context<'eng>, function: Function) -> Self {
+        let mut users = FxHashMap::<Value, Vec<Block>>::default();
+        for (block, inst) in function.instruction_iter(context) {
+            let op = &inst.get_instruction(context).unwrap().op;
+            match op {
+                InstOp::Branch(_) | InstOp::ConditionalBranch { .. } => {
+                    if let InstOp::ConditionalBranch { cond_value, .. } = op {
+                        users.entry(*cond_value).or_default().push(block);
+                    }
+                    for BranchToWithArgs { block: succ, args } in block.successors(context) {
+                        for arg in args {
+                            users.entry(arg).or_default().push(succ);
+                        }
+                    }
+                }
+                _ => {
+                    for opd in op.get_operands() {
+                        users.entry(opd).or_default().push(block);
+                    }
+                }
+            }
+        }
+
+        let entry = function.get_entry_block(context);
+        let mut lattice = FxHashMap::default();
+        for arg in entry.arg_iter(context) {
+            lattice.insert(*arg, LatticeValue::Overdefined);
+        }
+
+        Solver {
+            context,
+            entry,
+            lattice,
+            executable_edges: FxHashSet::default(),
+            executable_blocks: FxHashSet::from_iter([entry]),
+            users,
+            worklist: vec![entry],
+        }
+    }
+
+    fn get(&self, val: Value) -> LatticeValue {
+        match &self.context.values[val.0].value {
+            ValueDatum::Constant(_) | ValueDatum::Configurable(_) => {
+                LatticeValue::from_value(self.context, val)
+            }
+            _ => self
+                .lattice
+                .get(&val)
+                .cloned()
+                .unwrap_or(LatticeValue::Undefined),
+        }
+    }
+
+    /// Lower `val` to `new`, scheduling its users to be revisited if it changed.
+    fn update(&mut self, val: Value, new: LatticeValue) {
+        let old = self.get(val);
+        let new = old.clone().meet(self.context, &new);
+        if new.is_same(self.context, &old) {
+            return;
+        }
+        self.lattice.insert(val, new);
+        if let Some(users) = self.users.get(&val) {
+            self.worklist.extend(
+                users
+                    .iter()
+                    .filter(|block| self.executable_blocks.contains(block)),
+            );
+        }
+    }
+
+    fn mark_edge_executable(&mut self, from: Block, to: Block) {
+        if self.executable_edges.insert((from, to)) {
+            self.executable_blocks.insert(to);
+            self.worklist.push(to);
+        }
+    }
+
+    fn solve(&mut self, function: Function) {
+        loop {
+            while let Some(block) = self.worklist.pop() {
+                self.visit_block(block);
+            }
+
+            // A condition which is still undefined would leave both of its successors
+            // unreachable.  It shouldn't happen in valid IR, but in case it does we treat it as
+            // unknown rather than remove blocks which may actually be executed.
+            let undefined_conds = function
+                .block_iter(self.context)
+                .filter(|block| self.executable_blocks.contains(block))
+                .filter_map(|block| match block.get_terminator(self.context) {
+                    Some(Instruction {
+                        op: InstOp::ConditionalBranch { cond_value, .. },
+                        ..
+                    }) if matches!(self.get(*cond_value), LatticeValue::Undefined) => {
+                        Some((block, *cond_value))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if undefined_conds.is_empty() {
+                break;
+            }
+            for (block, cond_value) in undefined_conds {
+                self.lattice.insert(cond_value, LatticeValue::Overdefined);
+                self.worklist.push(block);
+            }
+        }
+    }
+
+    fn visit_block(&mut self, block: Block) {
+        let context = self.context;
+
+        // Block arguments are the meet of the values passed along every executable edge.
+        if block != self.entry {
+            for (idx, arg) in block.arg_iter(context).enumerate() {
+                let mut new = LatticeValue::Undefined;
+                for pred in block.pred_iter(context) {
+                    if !self.executable_edges.contains(&(*pred, block)) {
+                        continue;
+                    }
+                    for BranchToWithArgs { block: succ, args } in pred.successors(context) {
+                        if succ == block {
+                            new = new.meet(context, &self.get(args[idx]));
+                        }
+                    }
+                }
+                self.update(*arg, new);
+            }
+        }
+
+        for inst in block.instruction_iter(context) {
+            let op = &inst.get_instruction(context).unwrap().op;
+            let new = match op {
+                InstOp::UnaryOp { op, arg } => match self.get(*arg) {
+                    LatticeValue::Constant(c) => fold_unary_op(context, op, &c)
+                        .map_or(LatticeValue::Overdefined, LatticeValue::Constant),
+                    other => other,
+                },
+                InstOp::BinaryOp { op, arg1, arg2 } => match (self.get(*arg1), self.get(*arg2)) {
+                    (LatticeValue::Constant(c1), LatticeValue::Constant(c2)) => {
+                        fold_binary_op(op, &c1, &c2)
+                            .map_or(LatticeValue::Overdefined, LatticeValue::Constant)
+                    }
+                    (LatticeValue::Overdefined, _) | (_, LatticeValue::Overdefined) => {
+                        LatticeValue::Overdefined
+                    }
+                    _ => LatticeValue::Undefined,
+                },
+                InstOp::Cmp(pred, lhs, rhs) => match (self.get(*lhs), self.get(*rhs)) {
+                    (LatticeValue::Constant(c1), LatticeValue::Constant(c2)) => {
+                        LatticeValue::Constant(Constant::new_bool(
+                            context,
+                            fold_cmp(context, pred, &c1, &c2),
+                        ))
+                    }
+                    (LatticeValue::Overdefined, _) | (_, LatticeValue::Overdefined) => {
+                        LatticeValue::Overdefined
+                    }
+                    _ => LatticeValue::Undefined,
+                },
+                InstOp::ConditionalBranch {
+                    cond_value,
+                    true_block,
+                    false_block,
+                } => {
+                    let taken = match self.get(*cond_value) {
+                        LatticeValue::Undefined => vec![],
+                        LatticeValue::Constant(Constant {
+                            value: ConstantValue::Bool(true),
+                            ..
+                        }) => vec![true_block.block],
+                        LatticeValue::Constant(Constant {
+                            value: ConstantValue::Bool(false),
+                            ..
+                        }) => vec![false_block.block],
+                        _ => vec![true_block.block, false_block.block],
+                    };
+                    for succ in taken {
+                        self.mark_edge_executable(block, succ);
+                    }
+                    continue;
+                }
+                InstOp::Branch(BranchToWithArgs { block: succ, .. }) => {
+                    self.mark_edge_executable(block, *succ);
+                    continue;
+                }
+                _ => LatticeValue::Overdefined,
+            };
+            self.update(inst, new);
+        }
+    }
+}
+
+/// Propagate constants through the function, returning true if it was modified.
+pub fn sccp(
+    context: &mut Context,
+    _: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let mut solver = Solver::new(context, function);
+    solver.solve(function);
+    let Solver {
+        lattice,
+        executable_blocks,
+        ..
+    } = solver;
+
+    let mut modified = false;
+
+    // Replace the values found to be constant, removing the instructions which computed them.
+    // Block arguments aren't removed, so are only replaced while they're still used.
+    let used = function
+        .instruction_iter(context)
+        .flat_map(|(_, inst)| inst.get_instruction(context).unwrap().op.get_operands())
+        .collect::<FxHashSet<_>>();
+    let mut replacements = FxIndexMap::default();
+    let mut to_remove = Vec::new();
+    for block in function.block_iter(context) {
+        if !executable_blocks.contains(&block) {
+            continue;
+        }
+        let args = block.arg_iter(context).copied();
+        for val in args.chain(block.instruction_iter(context)) {
+            let Some(LatticeValue::Constant(c)) = lattice.get(&val) else {
+                continue;
+            };
+            if let Some(Instruction { op, .. }) = val.get_instruction(context) {
+                if !matches!(
+                    op,
+                    InstOp::UnaryOp { .. } | InstOp::BinaryOp { .. } | InstOp::Cmp(..)
+                ) {
+                    continue;
+                }
+                to_remove.push((block, val));
+            } else if !used.contains(&val) {
+                continue;
+            }
+            replacements.insert(val, c.clone());
+        }
+    }
+    if !replacements.is_empty() {
+        let replacements = replacements
+            .into_iter()
+            .map(|(val, c)| (val, Value::new_constant(context, c)))
+            .collect();
+        function.replace_values(context, &replacements, None);
+        for (block, inst) in to_remove {
+            block.remove_instruction(context, inst);
+        }
+        modified = true;
+    }
+
+    // Replace branches on constant conditions with unconditional branches.
+    for block in function.block_iter(context) {
+        if !executable_blocks.contains(&block) {
+            continue;
+        }
+        let Some(Instruction {
+            op:
+                InstOp::ConditionalBranch {
+                    cond_value,
+                    true_block,
+                    false_block,
+                },
+            ..
+        }) = block.get_terminator(context)
+        else {
+            continue;
+        };
+        let (taken, not_taken) = match cond_value.get_constant(context) {
+            Some(Constant {
+                value: ConstantValue::Bool(true),
+                ..
+            }) => (true_block.clone(), false_block.clone()),
+            Some(Constant {
+                value: ConstantValue::Bool(false),
+                ..
+            }) => (false_block.clone(), true_block.clone()),
+            _ => continue,
+        };
+        if taken.block != not_taken.block {
+            not_taken.block.remove_pred(context, &block);
+        }
+        let cbr = block.instruction_iter(context).next_back().unwrap();
+        cbr.replace(
+            context,
+            ValueDatum::Instruction(Instruction {
+                op: InstOp::Branch(taken),
+                parent: block,
+            }),
+        );
+        modified = true;
+    }
+
+    // Remove the blocks which are never executed.
+    for block in function.block_iter(context) {
+        if !executable_blocks.contains(&block) {
+            for BranchToWithArgs { block: succ, .. } in block.successors(context) {
+                succ.remove_pred(context, &block);
+            }
+            function.remove_block(context, &block)?;
+            modified = true;
+        }
+    }
+
+    Ok(modified)
+}
+
+/// Propagate constant arguments into called functions, and then constants through every function
+/// in the module, returning true if it was modified.
+pub fn ipsccp(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    module: Module,
+) -> Result<bool, IrError> {
+    let mut modified = propagate_constant_args(context, module);
+    for function in module.function_iter(context) {
+        modified |= sccp(context, analyses, function)?;
+    }
+    Ok(modified)
+}
+
+/// Replace the uses of each function argument, for which every call in the module passes the same
+/// constant, with that constant.
+fn propagate_constant_args(context: &mut Context, module: Module) -> bool {
+    // The meet of the arguments passed by every call to each function.
+    let mut call_args = FxIndexMap::<Function, Vec<LatticeValue>>::default();
+    for function in module.function_iter(context) {
+        for (_, inst) in function.instruction_iter(context) {
+            let Some(Instruction {
+                op: InstOp::Call(callee, args),
+                ..
+            }) = inst.get_instruction(context)
+            else {
+                continue;
+            };
+            let arg_vals = call_args
+                .entry(*callee)
+                .or_insert_with(|| vec![LatticeValue::Undefined; args.len()]);
+            for (arg_val, arg) in arg_vals.iter_mut().zip(args) {
+                let new = std::mem::replace(arg_val, LatticeValue::Undefined)
+                    .meet(context, &LatticeValue::from_value(context, *arg));
+                *arg_val = new;
+            }
+        }
+    }
+
+    let mut modified = false;
+    for (callee, arg_vals) in call_args {
+        // Entry functions may also be called externally, with any arguments.
+        if callee.is_entry(context) {
+            continue;
+        }
+        let mut replacements = FxIndexMap::default();
+        for ((_, arg), arg_val) in callee.args_iter(context).zip(arg_vals) {
+            if let LatticeValue::Constant(c) = arg_val {
+                replacements.insert(*arg, c);
+            }
+        }
+
+        // Only replace the arguments which are still used, so that we reach a fixed point.
+        let used = callee
+            .instruction_iter(context)
+            .flat_map(|(_, inst)| inst.get_instruction(context).unwrap().op.get_operands())
+            .filter(|opd| replacements.contains_key(opd))
+            .collect::<FxHashSet<_>>();
+        let replacements = replacements
+            .into_iter()
+            .filter(|(arg, _)| used.contains(arg))
+            .map(|(arg, c)| (arg, Value::new_constant(context, c)))
+            .collect::<FxHashMap<_, _>>();
+        if !replacements.is_empty() {
+            callee.replace_values(context, &replacements, None);
+            modified = true;
+        }
+    }
+    modified
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::optimize::tests::*;
+
+    #[test]
+    fn sccp_keeps_non_constant_block_args() {
+        assert_optimization(
+            &["sccp"],
+            "entry fn main(c: bool) -> u64 {
+                entry(c: bool):
+                v0 = const u64 1
+                v1 = const u64 2
+                cbr c, block0(v0), block0(v1)
+
+                block0(v2: u64):
+                v3 = const u64 3
+                v4 = add v2, v3, !0
+                ret u64 v4
+            }",
+            None::<Vec<&str>>,
+        );
+    }
+}
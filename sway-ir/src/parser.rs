@@ -116,9 +116,10 @@ mod ir_builder {
                     (ty, name, mdi)
                 }
 
-            rule fn_local() -> (IrAstTy, String, Option<IrAstOperation>, bool)
-                = "local" _ m:("mut" _)? ty:ast_ty() name:id() init:fn_local_init()? {
-                    (ty, name, init, m.is_some())
+            rule fn_local() -> IrAstLocal
+                = "local" _ m:("mut" _)? ty:ast_ty() name:id() init:fn_local_init()?
+                        metadata:comma_metadata_idx()? {
+                    (ty, name, init, m.is_some(), metadata)
                 }
 
             rule fn_local_init() -> IrAstOperation
@@ -663,6 +664,15 @@ mod ir_builder {
         metadata: Vec<(MdIdxRef, IrMetadatum)>,
     }
 
+    /// A local's type, name, initializer, mutability and metadata.
+    type IrAstLocal = (
+        IrAstTy,
+        String,
+        Option<IrAstOperation>,
+        bool,
+        Option<MdIdxRef>,
+    );
+
     #[derive(Debug)]
     struct IrAstFnDecl {
         name: String,
@@ -670,7 +680,7 @@ mod ir_builder {
         ret_type: IrAstTy,
         is_public: bool,
         metadata: Option<MdIdxRef>,
-        locals: Vec<(IrAstTy, String, Option<IrAstOperation>, bool)>,
+        locals: Vec<IrAstLocal>,
         blocks: Vec<IrAstBlock>,
         selector: Option<[u8; 4]>,
         is_entry: bool,
@@ -982,7 +992,7 @@ mod ir_builder {
             // config variables as they are globally available
             let mut arg_map = self.configs_map.clone();
             let mut local_map = HashMap::<String, LocalVar>::new();
-            for (ty, name, initializer, mutable) in fn_decl.locals {
+            for (ty, name, initializer, mutable, md_idx) in fn_decl.locals {
                 let initializer = initializer.map(|const_init| {
                     if let IrAstOperation::Const(val_ty, val) = const_init {
                         val.value.as_constant(context, val_ty)
@@ -991,10 +1001,10 @@ mod ir_builder {
                     }
                 });
                 let ty = ty.to_ir_type(context);
-                local_map.insert(
-                    name.clone(),
-                    func.new_local_var(context, name, ty, initializer, mutable)?,
-                );
+                let local_var =
+                    func.new_local_var(context, name.clone(), ty, initializer, mutable)?;
+                local_var.set_metadata(context, convert_md_idx(&md_idx));
+                local_map.insert(name, local_var);
             }
 
             // The entry block is already created, we don't want to recreate it.
@@ -1,12 +1,14 @@
 use crate::{
     create_arg_demotion_pass, create_const_combine_pass, create_const_demotion_pass,
     create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_escaped_symbols_pass,
-    create_fn_dedup_pass, create_func_dce_pass, create_inline_in_main_pass,
-    create_inline_in_module_pass, create_mem2reg_pass, create_memcpyopt_pass,
+    create_fn_dedup_pass, create_func_dce_pass, create_gvn_pass, create_inline_in_main_pass,
+    create_inline_in_module_aggressive_pass, create_inline_in_module_pass, create_ipsccp_pass,
+    create_licm_pass, create_loops_pass, create_mem2reg_pass, create_memcpyopt_pass,
     create_misc_demotion_pass, create_module_printer_pass, create_module_verifier_pass,
-    create_postorder_pass, create_ret_demotion_pass, create_simplify_cfg_pass, create_sroa_pass,
-    Context, Function, IrError, Module, CONSTCOMBINE_NAME, DCE_NAME, FNDEDUP_NAME, FUNC_DCE_NAME,
-    INLINE_MODULE_NAME, MEM2REG_NAME, SIMPLIFYCFG_NAME,
+    create_postorder_pass, create_ret_demotion_pass, create_sccp_pass, create_simplify_cfg_pass,
+    create_sroa_pass, Context, Function, IrError, Module, CONSTCOMBINE_NAME, DCE_NAME,
+    FNDEDUP_NAME, FUNC_DCE_NAME, GVN_NAME, INLINE_MODULE_AGGRESSIVE_NAME, INLINE_MODULE_NAME,
+    IPSCCP_NAME, LICM_NAME, MEM2REG_NAME, MEMCPYOPT_NAME, SIMPLIFYCFG_NAME, SROA_NAME,
 };
 use downcast_rs::{impl_downcast, Downcast};
 use rustc_hash::FxHashMap;
@@ -235,8 +237,21 @@ impl PassManager {
     /// Run the passes specified in `config`.
     pub fn run(&mut self, ir: &mut Context, passes: &PassGroup) -> Result<bool, IrError> {
         let mut modified = false;
-        for pass in passes.flatten_pass_group() {
-            modified |= self.actually_run(ir, pass)?;
+        for pass_or_group in &passes.0 {
+            modified |= match pass_or_group {
+                PassOrGroup::Pass(pass) => self.actually_run(ir, pass)?,
+                PassOrGroup::Group(pg) => self.run(ir, pg)?,
+                PassOrGroup::FixedPoint(pg, max_iterations) => {
+                    let mut group_modified = false;
+                    for _ in 0..*max_iterations {
+                        if !self.run(ir, pg)? {
+                            break;
+                        }
+                        group_modified = true;
+                    }
+                    group_modified
+                }
+            };
         }
         Ok(modified)
     }
@@ -267,24 +282,12 @@ pub struct PassGroup(Vec<PassOrGroup>);
 pub enum PassOrGroup {
     Pass(&'static str),
     Group(PassGroup),
+    /// A group which is run repeatedly until it no longer modifies the IR, or until it has been
+    /// run the given maximum number of times.
+    FixedPoint(PassGroup, usize),
 }
 
 impl PassGroup {
-    // Flatten a group of passes into an ordered list.
-    fn flatten_pass_group(&self) -> Vec<&'static str> {
-        let mut output = Vec::<&str>::new();
-        fn inner(output: &mut Vec<&str>, input: &PassGroup) {
-            for pass_or_group in &input.0 {
-                match pass_or_group {
-                    PassOrGroup::Pass(pass) => output.push(pass),
-                    PassOrGroup::Group(pg) => inner(output, pg),
-                }
-            }
-        }
-        inner(&mut output, self);
-        output
-    }
-
     /// Append a pass to this group.
     pub fn append_pass(&mut self, pass: &'static str) {
         self.0.push(PassOrGroup::Pass(pass));
@@ -294,6 +297,12 @@ impl PassGroup {
     pub fn append_group(&mut self, group: PassGroup) {
         self.0.push(PassOrGroup::Group(group));
     }
+
+    /// Append a pass group which is run until it reaches a fixed point, i.e., until none of its
+    /// passes modify the IR, or until it has been run `max_iterations` times.
+    pub fn append_fixed_point_group(&mut self, group: PassGroup, max_iterations: usize) {
+        self.0.push(PassOrGroup::FixedPoint(group, max_iterations));
+    }
 }
 
 /// A convenience utility to register known passes.
@@ -302,6 +311,7 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_postorder_pass());
     pm.register(create_dominators_pass());
     pm.register(create_dom_fronts_pass());
+    pm.register(create_loops_pass());
     pm.register(create_escaped_symbols_pass());
     pm.register(create_module_printer_pass());
     pm.register(create_module_verifier_pass());
@@ -310,8 +320,13 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_mem2reg_pass());
     pm.register(create_sroa_pass());
     pm.register(create_inline_in_module_pass());
+    pm.register(create_inline_in_module_aggressive_pass());
     pm.register(create_inline_in_main_pass());
     pm.register(create_const_combine_pass());
+    pm.register(create_sccp_pass());
+    pm.register(create_ipsccp_pass());
+    pm.register(create_gvn_pass());
+    pm.register(create_licm_pass());
     pm.register(create_simplify_cfg_pass());
     pm.register(create_func_dce_pass());
     pm.register(create_dce_pass());
@@ -339,6 +354,72 @@ pub fn create_o1_pass_group() -> PassGroup {
     o1
 }
 
+/// The maximum number of times the fixed point groups of the `-O2` and `-Os` pipelines are run.
+const MAX_FIXED_POINT_ITERATIONS: usize = 8;
+
+/// Create the group of simplifications which are iterated to a fixed point by the `-O2` and `-Os`
+/// pipelines.
+fn create_simplification_pass_group() -> PassGroup {
+    let mut simplify = PassGroup::default();
+    simplify.append_pass(CONSTCOMBINE_NAME);
+    simplify.append_pass(IPSCCP_NAME);
+    simplify.append_pass(GVN_NAME);
+    simplify.append_pass(SIMPLIFYCFG_NAME);
+    simplify.append_pass(MEM2REG_NAME);
+    simplify.append_pass(DCE_NAME);
+    simplify
+}
+
+pub fn create_o2_pass_group() -> PassGroup {
+    // Like `-O1` but inlines more eagerly, trading bytecode size for gas, and iterates the
+    // simplifications, along with hoisting loop invariant code, to a fixed point.
+    let mut o2 = PassGroup::default();
+    o2.append_pass(MEM2REG_NAME);
+    o2.append_pass(INLINE_MODULE_AGGRESSIVE_NAME);
+    o2.append_pass(FNDEDUP_NAME);
+    let mut simplify = create_simplification_pass_group();
+    simplify.append_pass(LICM_NAME);
+    o2.append_fixed_point_group(simplify, MAX_FIXED_POINT_ITERATIONS);
+    o2.append_pass(FUNC_DCE_NAME);
+    o2.append_pass(DCE_NAME);
+
+    o2
+}
+
+pub fn create_os_pass_group() -> PassGroup {
+    // Like `-O2` but only inlines when it is unlikely to grow the bytecode, and deduplicates
+    // functions which become identical after simplification.
+    let mut os = PassGroup::default();
+    os.append_pass(MEM2REG_NAME);
+    os.append_pass(INLINE_MODULE_NAME);
+    let mut simplify = create_simplification_pass_group();
+    simplify.append_pass(FNDEDUP_NAME);
+    os.append_fixed_point_group(simplify, MAX_FIXED_POINT_ITERATIONS);
+    os.append_pass(FUNC_DCE_NAME);
+    os.append_pass(DCE_NAME);
+
+    os
+}
+
+/// Create the group of passes run by the `-O2` and `-Os` pipelines after the target specific
+/// demotions, to clean up the temporaries they introduce.
+pub fn create_o2_cleanup_pass_group() -> PassGroup {
+    let mut cleanup = PassGroup::default();
+    cleanup.append_pass(SROA_NAME);
+    cleanup.append_pass(MEM2REG_NAME);
+    cleanup.append_pass(MEMCPYOPT_NAME);
+    cleanup.append_pass(CONSTCOMBINE_NAME);
+    cleanup.append_pass(GVN_NAME);
+    cleanup.append_pass(SIMPLIFYCFG_NAME);
+    cleanup.append_pass(DCE_NAME);
+
+    let mut group = PassGroup::default();
+    group.append_fixed_point_group(cleanup, MAX_FIXED_POINT_ITERATIONS);
+    group.append_pass(FUNC_DCE_NAME);
+
+    group
+}
+
 /// Utility to insert a pass after every pass in the given group
 pub fn insert_after_each(pg: PassGroup, pass: &'static str) -> PassGroup {
     PassGroup(
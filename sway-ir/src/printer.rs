@@ -292,7 +292,8 @@ fn function_to_doc<'a>(
                                     "local {mut_str}{} {name}",
                                     var.get_inner_type(context).as_string(context)
                                 ))
-                                .append(init_doc),
+                                .append(init_doc)
+                                .append(md_namer.md_idx_to_doc(context, &var_content.metadata)),
                             )
                         })
                         .collect(),
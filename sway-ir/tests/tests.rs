@@ -3,9 +3,10 @@ use std::path::PathBuf;
 use sway_ir::{
     create_arg_demotion_pass, create_const_combine_pass, create_const_demotion_pass,
     create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_escaped_symbols_pass,
-    create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass, create_postorder_pass,
-    create_ret_demotion_pass, create_simplify_cfg_pass, optimize as opt, register_known_passes,
-    Context, ExperimentalFlags, PassGroup, PassManager, DCE_NAME, MEM2REG_NAME, SROA_NAME,
+    create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass, create_o2_pass_group,
+    create_postorder_pass, create_ret_demotion_pass, create_simplify_cfg_pass, optimize as opt,
+    register_known_passes, Context, ExperimentalFlags, PassGroup, PassManager, DCE_NAME, GVN_NAME,
+    IPSCCP_NAME, LICM_NAME, MEM2REG_NAME, SCCP_NAME, SROA_NAME,
 };
 use sway_types::SourceEngine;
 
@@ -269,6 +270,60 @@ fn sroa() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn gvn() {
+    run_tests("gvn", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        register_known_passes(&mut pass_mgr);
+        pass_group.append_pass(GVN_NAME);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn licm() {
+    run_tests("licm", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        register_known_passes(&mut pass_mgr);
+        pass_group.append_pass(LICM_NAME);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn sccp() {
+    run_tests("sccp", |first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        register_known_passes(&mut pass_mgr);
+        if first_line.contains("ipsccp") {
+            pass_group.append_pass(IPSCCP_NAME);
+        } else {
+            pass_group.append_pass(SCCP_NAME);
+        }
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn fixed_point() {
+    run_tests("fixed_point", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        register_known_passes(&mut pass_mgr);
+        pass_mgr.run(ir, &create_o2_pass_group()).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[test]
 fn serialize() {
     // This isn't running a pass, it's just confirming that the IR can be loaded and printed, and
@@ -38,4 +38,208 @@ pub trait Iterator {
     /// assert_eq!(None, iter.next());
     /// ```
     fn next(ref mut self) -> Option<Self::Item>;
+} {
+    /// Calls `f` on each remaining element of the iterator.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(Self::Item)] - The closure to call on each element.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(1);
+    ///     vec.push(2);
+    ///
+    ///     vec.iter().for_each(|x| log(x));
+    /// }
+    /// ```
+    fn for_each(ref mut self, f: fn(Self::Item)) {
+        let mut next = self.next();
+        while next.is_some() {
+            f(next.unwrap());
+            next = self.next();
+        }
+    }
+
+    /// Folds every remaining element into an accumulator by applying `f`,
+    /// starting from `init`.
+    ///
+    /// # Arguments
+    ///
+    /// * `init`: [B] - The initial value of the accumulator.
+    /// * `f`: [fn(B, Self::Item) -> B] - The closure combining the accumulator and an element.
+    ///
+    /// # Returns
+    ///
+    /// * [B] - The final value of the accumulator.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(1);
+    ///     vec.push(2);
+    ///
+    ///     let sum = vec.iter().fold(0, |acc, x| acc + x);
+    ///     assert(sum == 3);
+    /// }
+    /// ```
+    fn fold<B>(ref mut self, init: B, f: fn(B, Self::Item) -> B) -> B {
+        let mut acc = init;
+        let mut next = self.next();
+        while next.is_some() {
+            acc = f(acc, next.unwrap());
+            next = self.next();
+        }
+        acc
+    }
+
+    /// Returns whether `f` returns `true` for any remaining element.
+    ///
+    /// Stops at the first element for which `f` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(Self::Item) -> bool] - The predicate to test each element with.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if any element satisfies `f`, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(1);
+    ///     vec.push(2);
+    ///
+    ///     assert(vec.iter().any(|x| x == 2));
+    /// }
+    /// ```
+    fn any(ref mut self, f: fn(Self::Item) -> bool) -> bool {
+        let mut next = self.next();
+        while next.is_some() {
+            if f(next.unwrap()) {
+                return true;
+            }
+            next = self.next();
+        }
+        false
+    }
+
+    /// Returns whether `f` returns `true` for every remaining element.
+    ///
+    /// Stops at the first element for which `f` returns `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(Self::Item) -> bool] - The predicate to test each element with.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if all elements satisfy `f`, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(1);
+    ///     vec.push(2);
+    ///
+    ///     assert(vec.iter().all(|x| x > 0));
+    /// }
+    /// ```
+    fn all(ref mut self, f: fn(Self::Item) -> bool) -> bool {
+        let mut next = self.next();
+        while next.is_some() {
+            if !f(next.unwrap()) {
+                return false;
+            }
+            next = self.next();
+        }
+        true
+    }
+
+    /// Returns the first remaining element for which `f` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(Self::Item) -> bool] - The predicate to test each element with.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Self::Item>] - The first matching element, or `None` if no element matches.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(1);
+    ///     vec.push(2);
+    ///
+    ///     assert(vec.iter().find(|x| x > 1).unwrap() == 2);
+    /// }
+    /// ```
+    fn find(ref mut self, f: fn(Self::Item) -> bool) -> Option<Self::Item> {
+        let mut next = self.next();
+        while next.is_some() {
+            if f(next.unwrap()) {
+                return next;
+            }
+            next = self.next();
+        }
+        next
+    }
+
+    /// Returns the number of remaining elements for which `f` returns `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(Self::Item) -> bool] - The predicate to test each element with.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of matching elements.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(1);
+    ///     vec.push(2);
+    ///
+    ///     assert(vec.iter().count_where(|x| x > 1) == 1);
+    /// }
+    /// ```
+    fn count_where(ref mut self, f: fn(Self::Item) -> bool) -> u64 {
+        let mut count = 0;
+        let mut next = self.next();
+        while next.is_some() {
+            if f(next.unwrap()) {
+                count += 1;
+            }
+            next = self.next();
+        }
+        count
+    }
 }
@@ -72,6 +72,21 @@
 //! `Ok(v)`  : `Result::Ok`
 //! `Some(v)`: `Option::Some`
 //! `ok_or`  : `Option::ok_or`
+//!
+//! # Propagating `None`
+//!
+//! The `?` operator extracts the contained value in an `Option<T>` when it
+//! is the `Some` variant. If the `Option` is `None`, it returns `None` early
+//! from the function, which must return an `Option`.
+//!
+//! ```
+//! fn add_last_two(stack: Vec<u64>) -> Option<u64> {
+//!     Some(stack.get(stack.len() - 1)? + stack.get(stack.len() - 2)?)
+//! }
+//! ```
+//!
+//! `is_residual`  : `Option::is_residual`
+//! `into_residual`: `Option::into_residual`
 library;
 
 use ::result::Result;
@@ -218,6 +233,62 @@ impl<T> Option<T> {
         }
     }
 
+    /// Returns whether the `?` operator returns the option early from the function, i.e., whether
+    /// the option is `None`.
+    ///
+    /// # Additional Information
+    ///
+    /// This method is used by the `?` operator, along with `into_residual` and `unwrap`.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the option is `None`, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let x: Option<u32> = Some(2);
+    ///     assert(!x.is_residual());
+    ///
+    ///     let x: Option<u32> = None;
+    ///     assert(x.is_residual());
+    /// }
+    /// ```
+    pub fn is_residual(self) -> bool {
+        self.is_none()
+    }
+
+    /// Converts the `None` option into an option of any other value type, for the `?` operator to
+    /// return it early from a function.
+    ///
+    /// # Additional Information
+    ///
+    /// This method is used by the `?` operator, along with `is_residual` and `unwrap`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<U>] - `None`.
+    ///
+    /// # Reverts
+    ///
+    /// * Reverts if the `Option` is the `Some` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() -> Option<bool> {
+    ///     let x: Option<u64> = None;
+    ///     x.into_residual()
+    /// }
+    /// ```
+    pub fn into_residual<U>(self) -> Option<U> {
+        match self {
+            Self::None => Option::None,
+            _ => revert(0),
+        }
+    }
+
     // Transforming contained values
     //
     /// Transforms the `Option<T>` into a `Result<T, E>`, mapping `Some(v)` to
@@ -261,4 +332,60 @@ impl<T> Option<T> {
             Self::None => Result::Err(err),
         }
     }
+
+    /// Maps an `Option<T>` to `Option<U>` by applying `f` to the contained
+    /// value, leaving `None` untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(T) -> U] - The closure to apply to the contained value.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<U>] - `Some(f(v))` if the option is `Some(v)`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let x = Some(42);
+    ///     assert(x.map(|v| v + 1).unwrap() == 43);
+    ///
+    ///     let x: Option<u64> = None;
+    ///     assert(x.map(|v| v + 1).is_none());
+    /// }
+    /// ```
+    pub fn map<U>(self, f: fn(T) -> U) -> Option<U> {
+        match self {
+            Self::Some(v) => Option::Some(f(v)),
+            Self::None => Option::None,
+        }
+    }
+
+    /// Returns `None` if the option is `None`, otherwise calls `f` with the
+    /// contained value and returns the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(T) -> Option<U>] - The closure to apply to the contained value.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<U>] - `f(v)` if the option is `Some(v)`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let x = Some(42);
+    ///     assert(x.and_then(|v| if v > 10 { Some(v) } else { None }).unwrap() == 42);
+    ///     assert(x.and_then(|v| if v > 50 { Some(v) } else { None }).is_none());
+    /// }
+    /// ```
+    pub fn and_then<U>(self, f: fn(T) -> Option<U>) -> Option<U> {
+        match self {
+            Self::Some(v) => f(v),
+            Self::None => Option::None,
+        }
+    }
 }
@@ -53,6 +53,21 @@
 //!
 //! `unwrap`   : `Result::unwrap`
 //! `unwrap_or`: `Result::unwrap_or`
+//!
+//! ### Propagating errors
+//!
+//! The `?` operator extracts the contained value in a `Result<T,E>` when it is
+//! the `Ok` variant. If the `Result` is `Err`, it returns the error early from
+//! the function, which must return a `Result` with the same error type.
+//!
+//! ```
+//! fn parse_versions(first: u8, second: u8) -> Result<(Version, Version), VersionError> {
+//!     Ok((parse_version(first)?, parse_version(second)?))
+//! }
+//! ```
+//!
+//! `is_residual`  : `Result::is_residual`
+//! `into_residual`: `Result::into_residual`
 library;
 
 use ::revert::revert;
@@ -203,6 +218,72 @@ impl<T, E> Result<T, E> {
         }
     }
 
+    /// Returns whether the `?` operator returns the result early from the function, i.e., whether
+    /// the result is `Err`.
+    ///
+    /// # Additional Information
+    ///
+    /// This method is used by the `?` operator, along with `into_residual` and `unwrap`.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the result is `Err`, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// enum Error {
+    ///     NotFound,
+    ///     Invalid,
+    /// }
+    ///
+    /// fn foo() {
+    ///     let x: Result<u64, Error> = Result::Ok(42);
+    ///     assert(!x.is_residual());
+    ///
+    ///     let y: Result<u64, Error> = Result::Err(Error::NotFound);
+    ///     assert(y.is_residual());
+    /// }
+    /// ```
+    pub fn is_residual(self) -> bool {
+        self.is_err()
+    }
+
+    /// Converts the `Err` result into a result of any other value type, for the `?` operator to
+    /// return it early from a function.
+    ///
+    /// # Additional Information
+    ///
+    /// This method is used by the `?` operator, along with `is_residual` and `unwrap`.
+    ///
+    /// # Returns
+    ///
+    /// * [Result<U, E>] - The `Err` containing the error of the result.
+    ///
+    /// # Reverts
+    ///
+    /// * Reverts if the `Result` is the `Ok` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// enum Error {
+    ///     NotFound,
+    ///     Invalid,
+    /// }
+    ///
+    /// fn foo() -> Result<bool, Error> {
+    ///     let x: Result<u64, Error> = Result::Err(Error::NotFound);
+    ///     x.into_residual()
+    /// }
+    /// ```
+    pub fn into_residual<U>(self) -> Result<U, E> {
+        match self {
+            Self::Err(error) => Result::Err(error),
+            _ => revert(0),
+        }
+    }
+
     // TODO: Implement the following transforms when Option and Result can
     // import one another:
     // - `ok(self) -> Option<T>`
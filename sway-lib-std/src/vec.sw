@@ -595,6 +595,90 @@ impl<T> Vec<T> {
             index: 0,
         }
     }
+
+    /// Creates a new vector by applying `f` to each element of this vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(T) -> U] - The closure to apply to each element.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<U>] - A vector holding the results of `f`, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(5);
+    ///     vec.push(10);
+    ///
+    ///     let doubled = vec.map(|x| x * 2);
+    ///
+    ///     assert(doubled.get(0).unwrap() == 10);
+    ///     assert(doubled.get(1).unwrap() == 20);
+    /// }
+    /// ```
+    pub fn map<U>(self, f: fn(T) -> U) -> Vec<U> {
+        let mut result = Vec::with_capacity(self.len);
+        let mut i = 0;
+        while i < self.len {
+            result.push(f(self.buf.ptr().add::<T>(i).read::<T>()));
+            i += 1;
+        }
+        result
+    }
+
+    /// Sorts the vector in ascending order of the key returned by `f`.
+    ///
+    /// # Additional Information
+    ///
+    /// The sort is stable: elements with equal keys keep their relative order.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [fn(T) -> K] - The closure returning the key to sort each element by.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push((3, 30));
+    ///     vec.push((1, 10));
+    ///     vec.push((2, 20));
+    ///
+    ///     vec.sort_by_key(|p| p.0);
+    ///
+    ///     assert(vec.get(0).unwrap().1 == 10);
+    ///     assert(vec.get(1).unwrap().1 == 20);
+    ///     assert(vec.get(2).unwrap().1 == 30);
+    /// }
+    /// ```
+    pub fn sort_by_key<K>(ref mut self, f: fn(T) -> K)
+    where
+        K: Ord,
+    {
+        let mut i = 1;
+        while i < self.len {
+            let mut j = i;
+            while j > 0 {
+                let prev = self.buf.ptr().add::<T>(j - 1).read::<T>();
+                let current = self.buf.ptr().add::<T>(j).read::<T>();
+                if !(f(current) < f(prev)) {
+                    break;
+                }
+                self.swap(j - 1, j);
+                j -= 1;
+            }
+            i += 1;
+        }
+    }
 }
 
 impl<T> AsRawSlice for Vec<T> {
@@ -0,0 +1,266 @@
+//! Incoming and outgoing calls of functions and ABI or trait methods.
+//!
+//! Calls are found in the typed tokens of the program: each call is the token of the name of the
+//! called function, within the body of the calling function.
+
+use crate::{
+    core::{
+        session::Session,
+        token::{Token, TokenIdent, TypedAstToken},
+    },
+    utils::document::{get_url_from_path, is_within},
+};
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Position, Range,
+    SymbolKind, Url,
+};
+use std::sync::Arc;
+use sway_core::{language::ty, transform, Engines};
+use sway_types::{Ident, Span, Spanned};
+
+/// Returns the function declared or called at the given position.
+pub fn prepare_call_hierarchy(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<Vec<CallHierarchyItem>> {
+    let engines = session.engines.read();
+    let (decl_ident, function) = function_at_position(&session, &engines, url, position)?;
+    Some(vec![function.call_hierarchy_item(
+        &session,
+        &engines,
+        &decl_ident,
+    )?])
+}
+
+/// Returns the functions calling the function of `item`, along with the ranges of the calls
+/// within each of them.
+pub fn incoming_calls(
+    session: Arc<Session>,
+    url: &Url,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let engines = session.engines.read();
+    let (decl_ident, _) =
+        function_at_position(&session, &engines, url, item.selection_range.start)?;
+    let functions = function_declarations(&session, &engines);
+
+    let mut calls: Vec<(&TokenIdent, CallHierarchyIncomingCall)> = vec![];
+    for entry in session.token_map().iter() {
+        let (ident, token) = entry.pair();
+        if !is_call(token) || token.declared_token_ident(&engines).as_ref() != Some(&decl_ident) {
+            continue;
+        }
+        // Calls outside of any function, e.g., in the initializer of a constant, are skipped.
+        let Some((caller_ident, _, caller)) = functions.iter().find(|(caller_ident, body, _)| {
+            caller_ident.path == ident.path && is_within(&ident.range, body)
+        }) else {
+            continue;
+        };
+        match calls.iter_mut().find(|(ident, _)| *ident == caller_ident) {
+            Some((_, call)) => call.from_ranges.push(ident.range),
+            None => {
+                let Some(from) = caller.call_hierarchy_item(&session, &engines, caller_ident)
+                else {
+                    continue;
+                };
+                calls.push((
+                    caller_ident,
+                    CallHierarchyIncomingCall {
+                        from,
+                        from_ranges: vec![ident.range],
+                    },
+                ));
+            }
+        }
+    }
+
+    let mut calls: Vec<_> = calls.into_iter().map(|(_, call)| call).collect();
+    calls.sort_by(|a, b| item_order(&a.from).cmp(&item_order(&b.from)));
+    calls
+        .iter_mut()
+        .for_each(|call| call.from_ranges.sort_by_key(|range| range.start));
+    Some(calls)
+}
+
+/// Returns the functions called by the function of `item`, along with the ranges of the calls
+/// to each of them.
+pub fn outgoing_calls(
+    session: Arc<Session>,
+    url: &Url,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let engines = session.engines.read();
+    let (_, function) = function_at_position(&session, &engines, url, item.selection_range.start)?;
+    let body = TokenIdent::new(&Ident::new(function.span.clone()), engines.se()).range;
+
+    let mut calls: Vec<(TokenIdent, CallHierarchyOutgoingCall)> = vec![];
+    for entry in session.token_map().tokens_for_file(url) {
+        let (ident, token) = entry.pair();
+        if !is_call(token) || !is_within(&ident.range, &body) {
+            continue;
+        }
+        let Some(callee_ident) = token.declared_token_ident(&engines) else {
+            continue;
+        };
+        match calls.iter_mut().find(|(ident, _)| *ident == callee_ident) {
+            Some((_, call)) => call.from_ranges.push(ident.range),
+            None => {
+                let Some(to) = session
+                    .token_map()
+                    .try_get(&callee_ident)
+                    .try_unwrap()
+                    .and_then(|callee| Function::from_token(&engines, callee.value()))
+                    .and_then(|callee| {
+                        callee.call_hierarchy_item(&session, &engines, &callee_ident)
+                    })
+                else {
+                    continue;
+                };
+                calls.push((
+                    callee_ident,
+                    CallHierarchyOutgoingCall {
+                        to,
+                        from_ranges: vec![ident.range],
+                    },
+                ));
+            }
+        }
+    }
+
+    let mut calls: Vec<_> = calls.into_iter().map(|(_, call)| call).collect();
+    calls
+        .iter_mut()
+        .for_each(|call| call.from_ranges.sort_by_key(|range| range.start));
+    calls.sort_by_key(|call| call.from_ranges.first().map(|range| range.start));
+    Some(calls)
+}
+
+/// A declaration of a function, or of a method of an ABI or trait.
+struct Function {
+    name: Ident,
+    span: Span,
+    is_method: bool,
+    attributes: transform::AttributesMap,
+}
+
+impl Function {
+    fn from_token(engines: &Engines, token: &Token) -> Option<Function> {
+        match token.typed.as_ref()? {
+            TypedAstToken::TypedFunctionDeclaration(fn_decl) => Some(Self::from_fn_decl(fn_decl)),
+            TypedAstToken::TypedDeclaration(ty::TyDecl::FunctionDecl(ty::FunctionDecl {
+                decl_id,
+                ..
+            })) => Some(Self::from_fn_decl(&engines.de().get_function(decl_id))),
+            TypedAstToken::TypedTraitFn(trait_fn) => Some(Function {
+                name: trait_fn.name.clone(),
+                span: trait_fn.span(),
+                is_method: true,
+                attributes: trait_fn.attributes.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn from_fn_decl(fn_decl: &ty::TyFunctionDecl) -> Function {
+        Function {
+            name: fn_decl.name.clone(),
+            span: fn_decl.span.clone(),
+            is_method: fn_decl.implementing_type.is_some(),
+            attributes: fn_decl.attributes.clone(),
+        }
+    }
+
+    /// Returns the item of the function, whose name is `decl_ident`.
+    ///
+    /// The storage access of the function, if any, is its detail.
+    fn call_hierarchy_item(
+        &self,
+        session: &Session,
+        engines: &Engines,
+        decl_ident: &TokenIdent,
+    ) -> Option<CallHierarchyItem> {
+        let url = get_url_from_path(decl_ident.path.as_ref()?).ok()?;
+        let storage = self
+            .attributes
+            .get(&transform::AttributeKind::Storage)
+            .and_then(|attributes| attributes.last())
+            .map(|attribute| {
+                let args = attribute
+                    .args
+                    .iter()
+                    .map(|arg| arg.name.as_str())
+                    .collect::<Vec<_>>();
+                format!("#[storage({})]", args.join(", "))
+            });
+        Some(CallHierarchyItem {
+            name: self.name.as_str().to_string(),
+            kind: match self.is_method {
+                true => SymbolKind::METHOD,
+                false => SymbolKind::FUNCTION,
+            },
+            tags: None,
+            detail: storage,
+            uri: session.sync.to_workspace_url(url)?,
+            range: TokenIdent::new(&Ident::new(self.span.clone()), engines.se()).range,
+            selection_range: decl_ident.range,
+            data: None,
+        })
+    }
+}
+
+/// Returns the declaration of the function declared or called at the given position, along
+/// with the ident of its name.
+fn function_at_position(
+    session: &Session,
+    engines: &Engines,
+    url: &Url,
+    position: Position,
+) -> Option<(TokenIdent, Function)> {
+    let decl_ident = session
+        .token_map()
+        .token_at_position(url, position)?
+        .declared_token_ident(engines)?;
+    let function = session
+        .token_map()
+        .try_get(&decl_ident)
+        .try_unwrap()
+        .and_then(|decl| Function::from_token(engines, decl.value()))?;
+    Some((decl_ident, function))
+}
+
+/// Returns the idents of the names of the functions, the ranges of their declarations, and the
+/// functions.
+fn function_declarations(
+    session: &Session,
+    engines: &Engines,
+) -> Vec<(TokenIdent, Range, Function)> {
+    session
+        .token_map()
+        .iter()
+        .filter_map(|entry| {
+            let function = Function::from_token(engines, entry.value())?;
+            // Other tokens, e.g., the type parameters of functions, refer to their declarations.
+            if TokenIdent::new(&function.name, engines.se()) != *entry.key() {
+                return None;
+            }
+            let range = TokenIdent::new(&Ident::new(function.span.clone()), engines.se()).range;
+            Some((entry.key().clone(), range, function))
+        })
+        .collect()
+}
+
+/// Whether the token is the name of a function in a call to it.
+fn is_call(token: &Token) -> bool {
+    matches!(
+        &token.typed,
+        Some(TypedAstToken::TypedExpression(ty::TyExpression {
+            expression: ty::TyExpressionVariant::FunctionApplication { .. },
+            ..
+        }))
+    )
+}
+
+fn item_order(item: &CallHierarchyItem) -> (&str, Position) {
+    (item.uri.as_str(), item.selection_range.start)
+}
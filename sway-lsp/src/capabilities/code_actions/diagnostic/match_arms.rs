@@ -0,0 +1,70 @@
+use super::quick_fix;
+use crate::capabilities::{
+    code_actions::{
+        common::generate_impl::TAB, indentation_at, CodeActionContext, CODE_ACTION_MATCH_ARMS_TITLE,
+    },
+    diagnostic::DiagnosticData,
+};
+use lsp_types::{CodeActionOrCommand, Range, TextEdit};
+use std::collections::HashMap;
+
+/// Returns a [CodeActionOrCommand] for adding the arms a non-exhaustive match expression is missing.
+pub(crate) fn match_arms_code_action(
+    ctx: &CodeActionContext,
+    diagnostics: &mut impl Iterator<Item = (Range, DiagnosticData)>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (range, patterns) =
+        diagnostics.find_map(|(range, diag)| Some((range, diag.missing_match_patterns?)))?;
+
+    // Ranges of numbers, e.g. `[MIN...4]`, are not valid patterns, so use a catch-all arm instead.
+    let patterns = if patterns.iter().any(|pattern| pattern.contains("...")) {
+        vec!["_".to_string()]
+    } else {
+        patterns
+    };
+
+    // The match expression ends with the closing brace of its arms.
+    let text = ctx.document.get_text();
+    let end = ctx.document.position_to_offset(range.end).min(text.len());
+    let closing_brace = text[..end].rfind('}')?;
+    let mut text_edits = vec![];
+
+    // The last arm needs a trailing comma to be followed by the new ones.
+    let last_arm_end = text[..closing_brace].trim_end().len();
+    if !text[..last_arm_end].ends_with([',', '{', '}']) {
+        let position = ctx.document.offset_to_position(last_arm_end);
+        text_edits.push(TextEdit {
+            range: Range::new(position, position),
+            new_text: ",".to_string(),
+        });
+    }
+
+    let line_start = text[..closing_brace].rfind('\n').map_or(0, |i| i + 1);
+    let (offset, new_text) = if text[line_start..closing_brace].trim().is_empty() {
+        // The closing brace is on its own line, so add each arm on a new line above it.
+        let indentation = format!("{}{TAB}", indentation_at(&text, closing_brace));
+        let arms = patterns
+            .iter()
+            .map(|pattern| format!("{indentation}{pattern} => {{}},\n"))
+            .collect::<String>();
+        (line_start, arms)
+    } else {
+        let arms = patterns
+            .iter()
+            .map(|pattern| format!("{pattern} => {{}}, "))
+            .collect::<String>();
+        (closing_brace, arms)
+    };
+    let position = ctx.document.offset_to_position(offset);
+    text_edits.push(TextEdit {
+        range: Range::new(position, position),
+        new_text,
+    });
+    let changes = HashMap::from([(ctx.uri.clone(), text_edits)]);
+
+    Some(vec![quick_fix(
+        ctx,
+        CODE_ACTION_MATCH_ARMS_TITLE.to_string(),
+        changes,
+    )])
+}
@@ -0,0 +1,168 @@
+use super::quick_fix;
+use crate::{
+    capabilities::{
+        code_actions::{
+            common::generate_impl::TAB, indentation_at, CodeActionContext,
+            CODE_ACTION_MISSING_METHODS_TITLE,
+        },
+        diagnostic::DiagnosticData,
+    },
+    core::token::{AstToken, TypedAstToken},
+    utils::document::is_within,
+};
+use lsp_types::{CodeActionOrCommand, Range, TextEdit};
+use std::collections::HashMap;
+use sway_core::{
+    language::{
+        parsed::Declaration,
+        ty::{self, TyDecl, TyTraitFn},
+    },
+    transform::AttributeKind,
+    Engines,
+};
+
+/// Returns a [CodeActionOrCommand] for adding stubs of the methods an implementation of a trait or
+/// an ABI is missing.
+pub(crate) fn missing_methods_code_action(
+    ctx: &CodeActionContext,
+    diagnostics: &mut impl Iterator<Item = (Range, DiagnosticData)>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (range, missing_methods) =
+        diagnostics.find_map(|(range, diag)| Some((range, diag.missing_interface_methods?)))?;
+
+    // The diagnostic spans the whole `impl` block, the first name in which is that of the
+    // implemented trait or ABI.
+    let interface_name = ctx
+        .tokens
+        .tokens_for_file(ctx.temp_uri)
+        .filter(|item| {
+            matches!(
+                item.value().parsed,
+                AstToken::Declaration(Declaration::ImplTrait(_))
+            ) && is_within(&item.key().range, &range)
+        })
+        .min_by_key(|item| item.key().range.start)?
+        .key()
+        .name
+        .clone();
+    let trait_fns = interface_methods(ctx, &interface_name)?;
+
+    // Add the stubs before the closing brace of the `impl` block.
+    let text = ctx.document.get_text();
+    let end = ctx.document.position_to_offset(range.end).min(text.len());
+    let closing_brace = text[..end].rfind('}')?;
+    let impl_indentation = indentation_at(&text, ctx.document.position_to_offset(range.start));
+    let indentation = format!("{impl_indentation}{TAB}");
+    let stubs = trait_fns
+        .iter()
+        .filter(|trait_fn| missing_methods.contains(&trait_fn.name.to_string()))
+        .map(|trait_fn| method_stub(ctx.engines, trait_fn, &indentation))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if stubs.is_empty() {
+        return None;
+    }
+
+    // Separate the stubs from the existing items with an empty line.
+    let separator = if text[..closing_brace].trim_end().ends_with('{') {
+        ""
+    } else {
+        "\n"
+    };
+    let line_start = text[..closing_brace].rfind('\n').map_or(0, |i| i + 1);
+    let (offset, new_text) = if text[line_start..closing_brace].trim().is_empty() {
+        (line_start, format!("{separator}{stubs}"))
+    } else {
+        (
+            closing_brace,
+            format!("\n{separator}{stubs}{impl_indentation}"),
+        )
+    };
+    let position = ctx.document.offset_to_position(offset);
+    let text_edit = TextEdit {
+        range: Range::new(position, position),
+        new_text,
+    };
+    let changes = HashMap::from([(ctx.uri.clone(), vec![text_edit])]);
+
+    Some(vec![quick_fix(
+        ctx,
+        CODE_ACTION_MISSING_METHODS_TITLE.to_string(),
+        changes,
+    )])
+}
+
+/// Returns the methods declared by the trait or ABI of the given name.
+fn interface_methods(ctx: &CodeActionContext, name: &String) -> Option<Vec<TyTraitFn>> {
+    let interface_surface = ctx.tokens.tokens_for_name(name).find_map(|item| {
+        if item.value().declared_token_ident(ctx.engines).as_ref() != Some(item.key()) {
+            return None;
+        }
+        match &item.value().typed {
+            Some(TypedAstToken::TypedDeclaration(TyDecl::TraitDecl(decl))) => Some(
+                ctx.engines
+                    .de()
+                    .get_trait(&decl.decl_id)
+                    .interface_surface
+                    .clone(),
+            ),
+            Some(TypedAstToken::TypedDeclaration(TyDecl::AbiDecl(decl))) => Some(
+                ctx.engines
+                    .de()
+                    .get_abi(&decl.decl_id)
+                    .interface_surface
+                    .clone(),
+            ),
+            _ => None,
+        }
+    })?;
+    Some(
+        interface_surface
+            .iter()
+            .filter_map(|item| match item {
+                ty::TyTraitInterfaceItem::TraitFn(decl_ref) => {
+                    Some((*ctx.engines.de().get_trait_fn(decl_ref)).clone())
+                }
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Returns the stub of a method implementing the given trait function, with an empty body.
+fn method_stub(engines: &Engines, trait_fn: &TyTraitFn, indentation: &str) -> String {
+    let attributes = trait_fn
+        .attributes
+        .iter()
+        .filter(|(kind, _)| !matches!(kind, AttributeKind::Doc | AttributeKind::DocComment))
+        .flat_map(|(_, attrs)| attrs.iter())
+        .map(|attr| format!("{indentation}{}\n", attr.span.as_str()))
+        .collect::<String>();
+    let params = trait_fn
+        .parameters
+        .iter()
+        .map(|param| {
+            let reference = if param.is_reference { "ref " } else { "" };
+            let mutable = if param.is_mutable { "mut " } else { "" };
+            if param.name.as_str() == "self" {
+                format!("{reference}{mutable}self")
+            } else {
+                format!(
+                    "{reference}{mutable}{}: {}",
+                    param.name,
+                    param.type_argument.span.as_str()
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = if engines.te().get(trait_fn.return_type.type_id).is_unit() {
+        String::new()
+    } else {
+        format!(" -> {}", trait_fn.return_type.span.as_str())
+    };
+    format!(
+        "{attributes}{indentation}fn {}({params}){return_type} {{\n{indentation}}}\n",
+        trait_fn.name
+    )
+}
@@ -1,11 +1,34 @@
 mod auto_import;
+mod match_arms;
+mod missing_methods;
+mod mutability;
+mod payable;
 mod qualify;
+mod storage_attribute;
+mod unused_import;
+mod unused_variable;
 
-use crate::capabilities::{code_actions::CodeActionContext, diagnostic::DiagnosticData};
-use lsp_types::CodeActionOrCommand;
+use crate::{
+    capabilities::{code_actions::CodeActionContext, diagnostic::DiagnosticData},
+    core::token::get_range_from_span,
+};
+use lsp_types::{
+    CodeAction as LspCodeAction, CodeActionKind, CodeActionOrCommand, Position, Range, TextEdit,
+    Url, WorkspaceEdit,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use sway_types::Span;
 
 use self::auto_import::import_code_action;
+use self::match_arms::match_arms_code_action;
+use self::missing_methods::missing_methods_code_action;
+use self::mutability::mutability_code_action;
+use self::payable::payable_code_action;
 use self::qualify::qualify_code_action;
+use self::storage_attribute::storage_attribute_code_action;
+use self::unused_import::unused_import_code_action;
+use self::unused_variable::unused_variable_code_action;
 
 /// Returns a list of [CodeActionOrCommand] based on the relavent compiler diagnostics.
 pub(crate) fn code_actions(ctx: &CodeActionContext) -> Option<Vec<CodeActionOrCommand>> {
@@ -22,8 +45,67 @@ pub(crate) fn code_actions(ctx: &CodeActionContext) -> Option<Vec<CodeActionOrCo
     import_code_action(ctx, &mut diagnostics_with_data.clone())
         .into_iter()
         .chain(qualify_code_action(ctx, &mut diagnostics_with_data.clone()))
+        .chain(storage_attribute_code_action(
+            ctx,
+            &mut diagnostics_with_data.clone(),
+        ))
+        .chain(match_arms_code_action(
+            ctx,
+            &mut diagnostics_with_data.clone(),
+        ))
+        .chain(missing_methods_code_action(
+            ctx,
+            &mut diagnostics_with_data.clone(),
+        ))
+        .chain(payable_code_action(ctx, &mut diagnostics_with_data.clone()))
+        .chain(mutability_code_action(
+            ctx,
+            &mut diagnostics_with_data.clone(),
+        ))
+        .chain(unused_variable_code_action(
+            ctx,
+            &mut diagnostics_with_data.clone(),
+        ))
+        .chain(unused_import_code_action(
+            ctx,
+            &mut diagnostics_with_data.clone(),
+        ))
         .reduce(|mut combined, mut curr| {
             combined.append(&mut curr);
             combined
         })
 }
+
+/// Returns a quick fix [CodeActionOrCommand] with the given title that applies the given changes.
+fn quick_fix(
+    ctx: &CodeActionContext,
+    title: String,
+    changes: HashMap<Url, Vec<TextEdit>>,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(LspCodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        data: Some(Value::String(ctx.uri.to_string())),
+        ..Default::default()
+    })
+}
+
+/// Returns a [TextEdit] inserting `text` as a new line above the line of the span, with the
+/// same indentation.
+fn insert_line_above(span: &Span, text: &str) -> TextEdit {
+    let src = span.src();
+    let line_start = src[..span.start()].rfind('\n').map_or(0, |i| i + 1);
+    let indentation: String = src[line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect();
+    let line = get_range_from_span(span).start.line;
+    TextEdit {
+        range: Range::new(Position::new(line, 0), Position::new(line, 0)),
+        new_text: format!("{indentation}{text}\n"),
+    }
+}
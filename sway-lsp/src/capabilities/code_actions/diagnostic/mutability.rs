@@ -0,0 +1,68 @@
+use super::quick_fix;
+use crate::{
+    capabilities::{
+        code_actions::{CodeActionContext, CODE_ACTION_MUTABLE_TITLE},
+        diagnostic::DiagnosticData,
+    },
+    core::token::{get_range_from_span, TokenIdent, TypedAstToken},
+    utils::document::is_within,
+};
+use lsp_types::{CodeActionOrCommand, Range, TextEdit};
+use std::collections::HashMap;
+use sway_core::language::ty::{TyDecl, TyVariableDecl, VariableMutability};
+use sway_types::Spanned;
+
+/// Returns a [CodeActionOrCommand] for declaring a variable that is mutated as mutable.
+pub(crate) fn mutability_code_action(
+    ctx: &CodeActionContext,
+    diagnostics: &mut impl Iterator<Item = (Range, DiagnosticData)>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (range, variable_name) =
+        diagnostics.find_map(|(range, diag)| Some((range, diag.immutable_variable_name?)))?;
+
+    // Find the declaration of the variable referred to within the diagnostic. The assignment has
+    // no type information when it failed to type check, in which case the closest declaration of
+    // the variable before it is used.
+    let var_decl = ctx
+        .tokens
+        .tokens_for_file(ctx.temp_uri)
+        .filter(|item| item.key().name == variable_name && is_within(&item.key().range, &range))
+        .filter_map(|item| item.value().declared_token_ident(ctx.engines))
+        .find_map(|ident| variable_decl(ctx, &ident))
+        .or_else(|| {
+            ctx.tokens
+                .tokens_for_file(ctx.temp_uri)
+                .filter(|item| {
+                    item.key().name == variable_name && item.key().range.start < range.start
+                })
+                .filter_map(|item| Some((item.key().range.start, variable_decl(ctx, item.key())?)))
+                .max_by_key(|(position, _)| *position)
+                .map(|(_, var_decl)| var_decl)
+        })?;
+    if var_decl.mutability != VariableMutability::Immutable {
+        return None;
+    }
+
+    let position = get_range_from_span(&var_decl.name.span()).start;
+    let text_edit = TextEdit {
+        range: Range::new(position, position),
+        new_text: "mut ".to_string(),
+    };
+    let changes = HashMap::from([(ctx.uri.clone(), vec![text_edit])]);
+
+    Some(vec![quick_fix(
+        ctx,
+        format!("{CODE_ACTION_MUTABLE_TITLE} `{variable_name}`"),
+        changes,
+    )])
+}
+
+/// Returns the variable declaration of the ident, if it declares one.
+fn variable_decl(ctx: &CodeActionContext, ident: &TokenIdent) -> Option<TyVariableDecl> {
+    match &ctx.tokens.try_get(ident).try_unwrap()?.value().typed {
+        Some(TypedAstToken::TypedDeclaration(TyDecl::VariableDecl(var_decl))) => {
+            Some((**var_decl).clone())
+        }
+        _ => None,
+    }
+}
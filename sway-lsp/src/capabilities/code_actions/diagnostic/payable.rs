@@ -0,0 +1,76 @@
+use super::{insert_line_above, quick_fix};
+use crate::{
+    capabilities::{
+        code_actions::{workspace_url, CodeActionContext, CODE_ACTION_ADD_ATTRIBUTE_TITLE},
+        diagnostic::DiagnosticData,
+        implementation::implementation_idents,
+    },
+    core::token::TypedAstToken,
+    utils::document::is_within,
+};
+use lsp_types::{CodeActionOrCommand, Range, TextEdit, Url};
+use std::{collections::HashMap, iter};
+use sway_core::transform::AttributeKind;
+use sway_types::Spanned;
+
+/// Returns a [CodeActionOrCommand] for adding the `#[payable]` attribute to a contract method and
+/// its implementations.
+pub(crate) fn payable_code_action(
+    ctx: &CodeActionContext,
+    diagnostics: &mut impl Iterator<Item = (Range, DiagnosticData)>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (range, fn_name) =
+        diagnostics.find_map(|(range, diag)| Some((range, diag.non_payable_method?)))?;
+
+    // Find the declaration of the method called or implemented within the diagnostic. The call
+    // site has no type information when the expression failed to type check, in which case the
+    // method is only resolved by name if a single trait or ABI declares it.
+    let decl_ident =
+        ctx.tokens
+            .tokens_for_file(ctx.temp_uri)
+            .filter(|item| item.key().name == fn_name && is_within(&item.key().range, &range))
+            .find_map(|item| item.value().declared_token_ident(ctx.engines))
+            .or_else(|| {
+                let mut trait_fns = ctx.tokens.tokens_for_name(&fn_name).filter(|item| {
+                    matches!(item.value().typed, Some(TypedAstToken::TypedTraitFn(_)))
+                });
+                let ident = trait_fns.next()?.key().clone();
+                trait_fns.next().is_none().then_some(ident)
+            })?;
+    let implementations =
+        implementation_idents(ctx.tokens, ctx.engines, &decl_ident).unwrap_or_default();
+
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    for ident in iter::once(decl_ident).chain(implementations) {
+        let Some(item) = ctx.tokens.try_get(&ident).try_unwrap() else {
+            continue;
+        };
+        let (name, attributes) = match &item.value().typed {
+            Some(TypedAstToken::TypedTraitFn(trait_fn)) => {
+                (trait_fn.name.clone(), trait_fn.attributes.clone())
+            }
+            Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) => {
+                (fn_decl.name.clone(), fn_decl.attributes.clone())
+            }
+            _ => continue,
+        };
+        if attributes.contains_key(&AttributeKind::Payable) {
+            continue;
+        }
+        if let Some(url) = workspace_url(ctx, &ident) {
+            changes
+                .entry(url)
+                .or_default()
+                .push(insert_line_above(&name.span(), "#[payable]"));
+        }
+    }
+    if changes.is_empty() {
+        return None;
+    }
+
+    Some(vec![quick_fix(
+        ctx,
+        format!("{CODE_ACTION_ADD_ATTRIBUTE_TITLE} `#[payable]`"),
+        changes,
+    )])
+}
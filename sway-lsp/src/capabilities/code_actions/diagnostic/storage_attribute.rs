@@ -0,0 +1,66 @@
+use super::{insert_line_above, quick_fix};
+use crate::{
+    capabilities::{
+        code_actions::{CodeActionContext, CODE_ACTION_ADD_ATTRIBUTE_TITLE},
+        diagnostic::DiagnosticData,
+    },
+    core::token::{get_range_from_span, TypedAstToken},
+};
+use lsp_types::{CodeActionOrCommand, Position, Range, TextEdit};
+use std::collections::HashMap;
+use sway_core::{language::ty::TyFunctionDecl, transform::AttributeKind};
+use sway_types::Spanned;
+
+/// Returns a [CodeActionOrCommand] for giving the function surrounding a storage access the storage
+/// attribute it is missing.
+pub(crate) fn storage_attribute_code_action(
+    ctx: &CodeActionContext,
+    diagnostics: &mut impl Iterator<Item = (Range, DiagnosticData)>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (range, storage_access) =
+        diagnostics.find_map(|(range, diag)| Some((range, diag.missing_storage_access?)))?;
+    let fn_decl = enclosing_function(ctx, range.start)?;
+
+    // Replace the arguments of an existing storage attribute, otherwise add a new one.
+    let existing_args = fn_decl
+        .attributes
+        .get(&AttributeKind::Storage)
+        .and_then(|attrs| attrs.last())
+        .filter(|attr| !attr.args.is_empty())
+        .map(|attr| attr.args.clone());
+    let text_edit = match existing_args {
+        Some(args) => TextEdit {
+            range: Range::new(
+                get_range_from_span(&args.first()?.span).start,
+                get_range_from_span(&args.last()?.span).end,
+            ),
+            new_text: storage_access.clone(),
+        },
+        None => insert_line_above(
+            &fn_decl.name.span(),
+            &format!("#[storage({storage_access})]"),
+        ),
+    };
+    let changes = HashMap::from([(ctx.uri.clone(), vec![text_edit])]);
+
+    Some(vec![quick_fix(
+        ctx,
+        format!("{CODE_ACTION_ADD_ATTRIBUTE_TITLE} `#[storage({storage_access})]`"),
+        changes,
+    )])
+}
+
+/// Returns the innermost function declaration containing the position.
+fn enclosing_function(ctx: &CodeActionContext, position: Position) -> Option<TyFunctionDecl> {
+    ctx.tokens
+        .tokens_for_file(ctx.temp_uri)
+        .filter_map(|item| match &item.value().typed {
+            Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) => Some(fn_decl.clone()),
+            _ => None,
+        })
+        .filter(|fn_decl| {
+            let range = get_range_from_span(&fn_decl.span);
+            range.start <= position && position <= range.end
+        })
+        .min_by_key(|fn_decl| fn_decl.span.end() - fn_decl.span.start())
+}
@@ -0,0 +1,121 @@
+use super::quick_fix;
+use crate::{
+    capabilities::{
+        code_actions::{CodeActionContext, CODE_ACTION_REMOVE_UNUSED_IMPORT_TITLE},
+        diagnostic::DiagnosticData,
+    },
+    core::token::{get_range_from_span, TypedAstToken},
+};
+use lsp_types::{CodeActionOrCommand, Range, TextEdit};
+use std::collections::HashMap;
+use sway_core::language::parsed::ImportType;
+use sway_types::{Span, Spanned};
+
+/// Returns a [CodeActionOrCommand] for removing an imported item that is never used.
+///
+/// The item is removed from the `use` statement along with its separating comma, or the whole
+/// statement is removed if it imports nothing else.
+pub(crate) fn unused_import_code_action(
+    ctx: &CodeActionContext,
+    diagnostics: &mut impl Iterator<Item = (Range, DiagnosticData)>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (range, name) =
+        diagnostics.find_map(|(range, diag)| Some((range, diag.unused_import_name?)))?;
+
+    let use_statement = match &ctx
+        .tokens
+        .token_at_position(ctx.temp_uri, range.start)?
+        .value()
+        .typed
+    {
+        Some(TypedAstToken::TypedUseStatement(use_statement)) => use_statement.clone(),
+        _ => return None,
+    };
+    let ImportType::Item(item) = &use_statement.import_type else {
+        return None;
+    };
+
+    let text = ctx.document.get_text();
+    let offset = |span: &Span, at_end: bool| {
+        let range = get_range_from_span(span);
+        ctx.document
+            .position_to_offset(if at_end { range.end } else { range.start })
+    };
+    let statement_start = offset(&use_statement.span, false);
+    let statement_end = offset(&use_statement.span, true);
+    let statement = &text[statement_start..statement_end];
+    let alias_or_item = use_statement.alias.as_ref().unwrap_or(item);
+    let (start, end) = removed_import(
+        statement,
+        offset(&item.span(), false) - statement_start,
+        offset(&alias_or_item.span(), true) - statement_start,
+    );
+
+    let text_edit = TextEdit {
+        range: if (start, end) == (0, statement.len()) {
+            ctx.whole_lines_range(statement_start, statement_end)
+        } else {
+            Range::new(
+                ctx.document.offset_to_position(statement_start + start),
+                ctx.document.offset_to_position(statement_start + end),
+            )
+        },
+        new_text: String::new(),
+    };
+    Some(vec![quick_fix(
+        ctx,
+        format!("{CODE_ACTION_REMOVE_UNUSED_IMPORT_TITLE} `{name}`"),
+        HashMap::from([(ctx.uri.clone(), vec![text_edit])]),
+    )])
+}
+
+/// Returns the byte range of the `use` statement to remove along with the imported item between
+/// `start` and `end`, which is the whole statement if the item is the only one it imports.
+fn removed_import(statement: &str, mut start: usize, mut end: usize) -> (usize, usize) {
+    loop {
+        start = path_start(statement, start);
+        let before = statement[..start].trim_end();
+        let after = statement[end..].trim_start();
+        let trailing_comma = after.starts_with(',');
+        let rest = if trailing_comma {
+            after[1..].trim_start()
+        } else {
+            after
+        };
+
+        if !rest.starts_with('}') {
+            if trailing_comma {
+                // Followed by another item, which takes its place.
+                return (start, statement.len() - rest.len());
+            }
+            return (0, statement.len());
+        }
+        if before.ends_with(',') {
+            // The last item of a group, the comma in front of it is removed instead of the
+            // trailing one.
+            return if trailing_comma {
+                (before.len(), statement.len() - after.len() + 1)
+            } else {
+                (before.len() - 1, end)
+            };
+        }
+        if !before.ends_with('{') {
+            return (0, statement.len());
+        }
+        // The only item of a group, which is removed as a whole, e.g. `b::{C}` in `a::{b::{C}, D}`.
+        start = before.len() - 1;
+        end = statement.len() - rest.len() + 1;
+    }
+}
+
+/// Returns the start of the path leading to the item starting at `start`, e.g. of `b::C` in
+/// `a::{b::C, D}`.
+fn path_start(statement: &str, mut start: usize) -> usize {
+    while let Some(before) = statement[..start].trim_end().strip_suffix("::") {
+        start = before
+            .trim_end()
+            .trim_end_matches(|c: char| c.is_alphanumeric() || c == '_')
+            .len();
+    }
+    start
+}
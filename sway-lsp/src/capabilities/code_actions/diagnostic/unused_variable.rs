@@ -0,0 +1,65 @@
+use super::quick_fix;
+use crate::{
+    capabilities::{
+        code_actions::{
+            CodeActionContext, CODE_ACTION_REMOVE_UNUSED_TITLE, CODE_ACTION_RENAME_TITLE,
+        },
+        diagnostic::DiagnosticData,
+    },
+    core::token::{get_range_from_span, TypedAstToken},
+};
+use lsp_types::{CodeActionOrCommand, Range, TextEdit};
+use std::collections::HashMap;
+use sway_core::language::ty::TyDecl;
+use sway_types::Spanned;
+
+/// Returns a list of [CodeActionOrCommand] for removing a variable flagged as unused by the
+/// dead-code analysis, or for prefixing its name with an underscore to silence the warning.
+pub(crate) fn unused_variable_code_action(
+    ctx: &CodeActionContext,
+    diagnostics: &mut impl Iterator<Item = (Range, DiagnosticData)>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (range, variable_name) =
+        diagnostics.find_map(|(range, diag)| Some((range, diag.unused_declaration_name?)))?;
+
+    // Only variables are handled, the warning is also emitted for other unused declarations.
+    let var_decl = match &ctx
+        .tokens
+        .token_at_position(ctx.temp_uri, range.start)?
+        .value()
+        .typed
+    {
+        Some(TypedAstToken::TypedDeclaration(TyDecl::VariableDecl(var_decl)))
+            if var_decl.name.as_str() == variable_name =>
+        {
+            var_decl.clone()
+        }
+        _ => return None,
+    };
+
+    let mut actions = vec![];
+    if let Some(statement_range) = ctx.let_statement_range(&var_decl) {
+        let text_edit = TextEdit {
+            range: statement_range,
+            new_text: String::new(),
+        };
+        actions.push(quick_fix(
+            ctx,
+            format!("{CODE_ACTION_REMOVE_UNUSED_TITLE} `{variable_name}`"),
+            HashMap::from([(ctx.uri.clone(), vec![text_edit])]),
+        ));
+    }
+
+    let position = get_range_from_span(&var_decl.name.span()).start;
+    let text_edit = TextEdit {
+        range: Range::new(position, position),
+        new_text: "_".to_string(),
+    };
+    actions.push(quick_fix(
+        ctx,
+        format!("{CODE_ACTION_RENAME_TITLE} `_{variable_name}`"),
+        HashMap::from([(ctx.uri.clone(), vec![text_edit])]),
+    ));
+
+    Some(actions)
+}
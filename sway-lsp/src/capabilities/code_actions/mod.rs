@@ -5,17 +5,23 @@ pub mod diagnostic;
 pub mod enum_decl;
 pub mod enum_variant;
 pub mod function_decl;
+pub mod refactor;
 pub mod storage_field;
 pub mod struct_decl;
 pub mod struct_field;
 pub mod trait_fn;
 
-use crate::core::{
-    session::Session,
-    token::{Token, TypedAstToken},
-    token_map::TokenMap,
-};
 pub use crate::error::DocumentError;
+use crate::{
+    core::{
+        document::TextDocument,
+        session::Session,
+        sync::SyncWorkspace,
+        token::{get_range_from_span, Token, TokenIdent, TypedAstToken},
+        token_map::TokenMap,
+    },
+    utils::document::get_url_from_path,
+};
 use lsp_types::{
     CodeAction as LspCodeAction, CodeActionDisabled, CodeActionKind, CodeActionOrCommand,
     CodeActionResponse, Diagnostic, Position, Range, TextEdit, Url, WorkspaceEdit,
@@ -30,16 +36,33 @@ pub(crate) const CODE_ACTION_NEW_TITLE: &str = "Generate `new`";
 pub(crate) const CODE_ACTION_DOC_TITLE: &str = "Generate a documentation template";
 pub(crate) const CODE_ACTION_IMPORT_TITLE: &str = "Import";
 pub(crate) const CODE_ACTION_QUALIFY_TITLE: &str = "Qualify as";
+pub(crate) const CODE_ACTION_ADD_ATTRIBUTE_TITLE: &str = "Add attribute";
+pub(crate) const CODE_ACTION_MATCH_ARMS_TITLE: &str = "Add missing match arms";
+pub(crate) const CODE_ACTION_MISSING_METHODS_TITLE: &str = "Implement missing methods";
+pub(crate) const CODE_ACTION_MUTABLE_TITLE: &str = "Add `mut` to";
+pub(crate) const CODE_ACTION_REMOVE_UNUSED_TITLE: &str = "Remove unused variable";
+pub(crate) const CODE_ACTION_REMOVE_UNUSED_IMPORT_TITLE: &str = "Remove unused import";
+pub(crate) const CODE_ACTION_RENAME_TITLE: &str = "Rename to";
+pub(crate) const CODE_ACTION_EXTRACT_VARIABLE_TITLE: &str = "Extract into variable";
+pub(crate) const CODE_ACTION_EXTRACT_FUNCTION_TITLE: &str = "Extract into function";
+pub(crate) const CODE_ACTION_INLINE_VARIABLE_TITLE: &str = "Inline variable";
+pub(crate) const CODE_ACTION_MOVE_PARAMETER_TITLE: &str = "Move parameter";
+pub(crate) const CODE_ACTION_ADD_PARAMETER_TITLE: &str = "Add parameter";
+pub(crate) const CODE_ACTION_TO_MATCH_TITLE: &str = "Convert to `match`";
+pub(crate) const CODE_ACTION_TO_IF_LET_TITLE: &str = "Convert to `if let`";
 
 #[derive(Clone)]
 pub(crate) struct CodeActionContext<'a> {
     engines: &'a Engines,
     tokens: &'a TokenMap,
-    token: &'a Token,
+    token: Option<&'a Token>,
+    range: &'a Range,
     uri: &'a Url,
     temp_uri: &'a Url,
     diagnostics: &'a Vec<Diagnostic>,
     namespace: &'a Option<Namespace>,
+    document: &'a TextDocument,
+    sync: &'a SyncWorkspace,
 }
 
 pub fn code_actions(
@@ -49,24 +72,26 @@ pub fn code_actions(
     temp_uri: &Url,
     diagnostics: &Vec<Diagnostic>,
 ) -> Option<CodeActionResponse> {
-    let t = session
-        .token_map()
-        .token_at_position(temp_uri, range.start)?;
-    let token = t.value();
+    // The quick fixes for the diagnostics don't need a token at the given position.
+    let t = session.token_map().token_at_position(temp_uri, range.start);
+    let token = t.as_ref().map(|t| t.value());
+    let document = session.get_text_document(temp_uri).ok()?;
 
     let ctx = CodeActionContext {
         engines: &session.engines.read(),
         tokens: session.token_map(),
         token,
+        range,
         uri,
         temp_uri,
         diagnostics,
         namespace: &session.namespace(),
+        document: &document,
+        sync: &session.sync,
     };
 
     let actions_by_type = token
-        .typed
-        .as_ref()
+        .and_then(|token| token.typed.as_ref())
         .and_then(|typed_token| match typed_token {
             TypedAstToken::TypedDeclaration(decl) => match decl {
                 ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. }) => {
@@ -95,8 +120,46 @@ pub fn code_actions(
         .unwrap_or_default();
 
     let actions_by_diagnostic = diagnostic::code_actions(&ctx).unwrap_or_default();
+    let refactorings = refactor::code_actions(&ctx).unwrap_or_default();
+
+    Some([actions_by_type, actions_by_diagnostic, refactorings].concat())
+}
+
+impl CodeActionContext<'_> {
+    /// Returns the range of the `let` statement declaring the variable, including its whole lines
+    /// if nothing else is on them.
+    pub(crate) fn let_statement_range(&self, var_decl: &ty::TyVariableDecl) -> Option<Range> {
+        let text = self.document.get_text();
+        let name_start = self
+            .document
+            .position_to_offset(get_range_from_span(&var_decl.name.span()).start);
+        let body_end = self
+            .document
+            .position_to_offset(get_range_from_span(&var_decl.body.span).end);
+        let start = text[..name_start].rfind("let")?;
+        let semicolon = text[body_end..].find(|c: char| !c.is_whitespace())?;
+        if !text[body_end + semicolon..].starts_with(';') {
+            return None;
+        }
+        Some(self.whole_lines_range(start, body_end + semicolon + 1))
+    }
 
-    Some([actions_by_type, actions_by_diagnostic].concat())
+    /// Returns the range between the byte offsets of the document, including their whole lines if
+    /// nothing else is on them.
+    pub(crate) fn whole_lines_range(&self, mut start: usize, mut end: usize) -> Range {
+        let text = self.document.get_text();
+        let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[end..].find('\n').map_or(text.len(), |i| end + i + 1);
+        if text[line_start..start].trim().is_empty() && text[end..line_end].trim().is_empty() {
+            start = line_start;
+            end = line_end;
+        }
+
+        Range::new(
+            self.document.offset_to_position(start),
+            self.document.offset_to_position(end),
+        )
+    }
 }
 
 pub(crate) trait CodeAction<'a, T: Spanned> {
@@ -175,3 +238,16 @@ pub(crate) trait CodeAction<'a, T: Spanned> {
         }
     }
 }
+
+/// Returns the leading whitespace of the line containing the byte offset.
+pub(crate) fn indentation_at(text: &str, offset: usize) -> &str {
+    let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = &text[line_start..];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+/// Returns the workspace [Url] of the file the ident is in.
+pub(crate) fn workspace_url(ctx: &CodeActionContext, ident: &TokenIdent) -> Option<Url> {
+    let url = get_url_from_path(ident.path.as_ref()?).ok()?;
+    ctx.sync.to_workspace_url(url)
+}
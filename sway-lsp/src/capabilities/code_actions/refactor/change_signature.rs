@@ -0,0 +1,276 @@
+use super::{refactor, unique_name};
+use crate::{
+    capabilities::code_actions::{
+        workspace_url, CodeActionContext, CODE_ACTION_ADD_PARAMETER_TITLE,
+        CODE_ACTION_MOVE_PARAMETER_TITLE,
+    },
+    core::token::{get_range_from_span, TokenIdent, TypedAstToken},
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand, TextEdit, Url};
+use std::collections::HashMap;
+use sway_core::language::ty::{self, TyDecl, TyExpressionVariant};
+use sway_types::{Span, Spanned};
+
+/// The type of the parameters added to a function, which callers can pass as `()` until the
+/// parameter is given its actual type.
+const NEW_PARAMETER_TYPE: &str = "()";
+
+/// Returns a list of [CodeActionOrCommand] for changing the signature of the function whose
+/// parameter list the cursor is in, and updating all of its calls accordingly.
+///
+/// The parameter at the cursor can be moved left or right, and a parameter can be added at the end
+/// of the parameter list.
+pub(crate) fn change_signature_code_action(
+    ctx: &CodeActionContext,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (fn_decl, param_index) = match ctx.token.and_then(|token| token.typed.as_ref()) {
+        Some(TypedAstToken::TypedFunctionParameter(param)) => {
+            let fn_decl = fn_decls(ctx).find(|fn_decl| {
+                fn_decl
+                    .parameters
+                    .iter()
+                    .any(|p| p.name.span() == param.name.span())
+            })?;
+            let index = fn_decl
+                .parameters
+                .iter()
+                .position(|p| p.name.span() == param.name.span());
+            (fn_decl, index)
+        }
+        // Parameters can also be added with the cursor anywhere in the parameter list, e.g. when
+        // there are none yet.
+        _ => {
+            let offset = ctx.document.position_to_offset(ctx.range.start);
+            let fn_decl = fn_decls(ctx).find(|fn_decl| {
+                params_parens(fn_decl).is_some_and(|(open, close)| open < offset && offset <= close)
+            })?;
+            (fn_decl, None)
+        }
+    };
+    // The signatures of trait and ABI methods are fixed by their interface.
+    if !is_signature_changeable(ctx, &fn_decl) {
+        return None;
+    }
+    let calls = calls(ctx, &fn_decl)?;
+
+    let mut actions = vec![];
+    if let Some(index) = param_index {
+        let name = &fn_decl.parameters[index].name;
+        if index > 0 && !fn_decl.parameters[index - 1].is_self() {
+            let changes = swap_parameters(ctx, &fn_decl, &calls, index - 1)?;
+            actions.push(refactor(
+                ctx,
+                format!("{CODE_ACTION_MOVE_PARAMETER_TITLE} `{name}` left"),
+                CodeActionKind::REFACTOR_REWRITE,
+                changes,
+            ));
+        }
+        if index + 1 < fn_decl.parameters.len() && !fn_decl.parameters[index].is_self() {
+            let changes = swap_parameters(ctx, &fn_decl, &calls, index)?;
+            actions.push(refactor(
+                ctx,
+                format!("{CODE_ACTION_MOVE_PARAMETER_TITLE} `{name}` right"),
+                CodeActionKind::REFACTOR_REWRITE,
+                changes,
+            ));
+        }
+    }
+
+    let param_name = unique_name(ctx, "new_param");
+    let parameter = format!("{param_name}: {NEW_PARAMETER_TYPE}");
+    let mut changes = HashMap::new();
+    let (_, close) = params_parens(&fn_decl)?;
+    add_edit(
+        &mut changes,
+        ctx.uri.clone(),
+        append_to_list(&fn_decl.span, close, &parameter)?,
+    );
+    for (url, call) in &calls {
+        let close = call.span.end().checked_sub(1)?;
+        add_edit(
+            &mut changes,
+            url.clone(),
+            append_to_list(&call.span, close, NEW_PARAMETER_TYPE)?,
+        );
+    }
+    actions.push(refactor(
+        ctx,
+        format!("{CODE_ACTION_ADD_PARAMETER_TITLE} `{parameter}`"),
+        CodeActionKind::REFACTOR_REWRITE,
+        changes,
+    ));
+
+    Some(actions)
+}
+
+/// Returns true if the function is neither a trait or ABI method, nor implements one.
+fn is_signature_changeable(ctx: &CodeActionContext, fn_decl: &ty::TyFunctionDecl) -> bool {
+    if fn_decl.is_trait_method_dummy {
+        return false;
+    }
+    match &fn_decl.implementing_type {
+        None => true,
+        Some(TyDecl::ImplTrait(ty::ImplTrait { decl_id, .. })) => ctx
+            .engines
+            .de()
+            .get_impl_trait(decl_id)
+            .trait_decl_ref
+            .is_none(),
+        Some(_) => false,
+    }
+}
+
+/// Returns the calls of the function along with the workspace [Url] of the files they are in, or
+/// `None` if any of them doesn't pass an argument for each parameter.
+fn calls(
+    ctx: &CodeActionContext,
+    fn_decl: &ty::TyFunctionDecl,
+) -> Option<Vec<(Url, ty::TyExpression)>> {
+    let fn_ident = TokenIdent::new(&fn_decl.name, ctx.engines.se());
+    let mut calls = vec![];
+    for item in ctx.tokens.iter() {
+        let Some(TypedAstToken::TypedExpression(expr)) = &item.value().typed else {
+            continue;
+        };
+        let TyExpressionVariant::FunctionApplication { arguments, .. } = &expr.expression else {
+            continue;
+        };
+        if item.value().declared_token_ident(ctx.engines).as_ref() != Some(&fn_ident) {
+            continue;
+        }
+        if arguments.len() != fn_decl.parameters.len() {
+            return None;
+        }
+        calls.push((workspace_url(ctx, item.key())?, expr.clone()));
+    }
+    calls.sort_by_key(|(url, call)| (url.clone(), call.span.start()));
+    Some(calls)
+}
+
+/// Returns the changes swapping the parameter at `index` with the next one, in the signature of
+/// the function and in the arguments of its calls.
+fn swap_parameters(
+    ctx: &CodeActionContext,
+    fn_decl: &ty::TyFunctionDecl,
+    calls: &[(Url, ty::TyExpression)],
+    index: usize,
+) -> Option<HashMap<Url, Vec<TextEdit>>> {
+    let mut changes = HashMap::new();
+    let (left, right) = (
+        param_span(&fn_decl.parameters[index])?,
+        param_span(&fn_decl.parameters[index + 1])?,
+    );
+    for edit in swap(&left, &right) {
+        add_edit(&mut changes, ctx.uri.clone(), edit);
+    }
+    for (url, call) in calls {
+        let TyExpressionVariant::FunctionApplication { arguments, .. } = &call.expression else {
+            continue;
+        };
+        for edit in swap(&arguments[index].1.span, &arguments[index + 1].1.span) {
+            add_edit(&mut changes, url.clone(), edit);
+        }
+    }
+    Some(changes)
+}
+
+/// Returns the [TextEdit]s exchanging the text of the two spans.
+fn swap(left: &Span, right: &Span) -> [TextEdit; 2] {
+    [
+        TextEdit {
+            range: get_range_from_span(left),
+            new_text: right.as_str().to_string(),
+        },
+        TextEdit {
+            range: get_range_from_span(right),
+            new_text: left.as_str().to_string(),
+        },
+    ]
+}
+
+/// Adds the [TextEdit] to the changes of the file at `url`, keeping them sorted by position.
+fn add_edit(changes: &mut HashMap<Url, Vec<TextEdit>>, url: Url, edit: TextEdit) {
+    let edits = changes.entry(url).or_default();
+    edits.push(edit);
+    edits.sort_by_key(|edit| edit.range.start);
+}
+
+/// Returns the span of the parameter in the signature, from its `ref` and `mut` keywords to the
+/// end of its type.
+fn param_span(param: &ty::TyFunctionParameter) -> Option<Span> {
+    let src = param.name.span().src().clone();
+    let before = &src[..param.name.span().start()];
+    let start = before.rfind(['(', ',']).map(|i| i + 1)?;
+    let start = start + (before[start..].len() - before[start..].trim_start().len());
+    let end = param.type_argument.span.end().max(param.name.span().end());
+    Span::new(src, start, end, param.name.span().source_id().cloned())
+}
+
+/// Returns the function declarations of the document.
+fn fn_decls<'a>(ctx: &'a CodeActionContext) -> impl Iterator<Item = ty::TyFunctionDecl> + 'a {
+    ctx.tokens
+        .tokens_for_file(ctx.temp_uri)
+        .filter_map(|item| match &item.value().typed {
+            Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) => Some(fn_decl.clone()),
+            _ => None,
+        })
+}
+
+/// Returns the byte offsets of the parentheses around the parameter list of the function.
+fn params_parens(fn_decl: &ty::TyFunctionDecl) -> Option<(usize, usize)> {
+    let src = fn_decl.span.src();
+    let open = fn_decl.name.span().end() + src[fn_decl.name.span().end()..].find('(')?;
+    let mut depth = 0;
+    for (i, c) in src[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((open, open + i));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the [TextEdit] appending `text` to the comma separated list closed by the parenthesis
+/// at the byte offset `close` in the source of the span.
+fn append_to_list(span: &Span, close: usize, text: &str) -> Option<TextEdit> {
+    let src = span.src();
+    if src.get(close..close + 1)? != ")" {
+        return None;
+    }
+    let mut depth = 0;
+    let open = src[..close]
+        .char_indices()
+        .rev()
+        .find_map(|(i, c)| match c {
+            ')' => {
+                depth += 1;
+                None
+            }
+            '(' if depth == 0 => Some(i),
+            '(' => {
+                depth -= 1;
+                None
+            }
+            _ => None,
+        })?;
+    let items = src[open + 1..close].trim_end();
+    let new_text = if items.trim().is_empty() {
+        text.to_string()
+    } else if items.ends_with(',') {
+        format!(" {text}")
+    } else {
+        format!(", {text}")
+    };
+    let insert_at = open + 1 + items.len();
+    let position = Span::new(src.clone(), insert_at, insert_at, span.source_id().cloned())?;
+    Some(TextEdit {
+        range: get_range_from_span(&position),
+        new_text,
+    })
+}
@@ -0,0 +1,231 @@
+use super::{
+    enclosing_blocks, enclosing_item, is_in_document, offsets_range, parse_expr, refactor,
+    references, reindent, replace, selection, unique_name,
+};
+use crate::{
+    capabilities::{
+        code_actions::{
+            common::generate_impl::TAB, indentation_at, CodeActionContext,
+            CODE_ACTION_EXTRACT_FUNCTION_TITLE,
+        },
+        inlay_hints::type_label,
+    },
+    core::token::TypedAstToken,
+    utils::document::is_within,
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand};
+use std::{collections::HashMap, sync::Arc};
+use sway_ast::{token::TokenTree, Expr, Statement};
+use sway_core::{
+    language::ty::{self, TyDecl, VariableMutability},
+    transform::AttributeKind,
+    TypeId, TypeInfo,
+};
+use sway_error::handler::Handler;
+use sway_types::Spanned;
+
+/// Returns a [CodeActionOrCommand] for moving the selected expression or statements into a new
+/// function, taking the variables they refer to as parameters.
+pub(crate) fn extract_function_code_action(
+    ctx: &CodeActionContext,
+    src: &Arc<str>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (start, end) = selection(ctx, src)?;
+    let is_expr = match parse_expr(src, start, end) {
+        Some(Expr::Reassignment { .. }) => false,
+        Some(_) => true,
+        None if is_statements(src, start, end) => false,
+        None => return None,
+    };
+    // Control flow can't leave the extracted function.
+    if contains_keyword(src, start, end, &["return", "break", "continue", "self"]) {
+        return None;
+    }
+
+    let fn_decl = enclosing_fn(ctx, start, end)?;
+    let range = offsets_range(ctx, start, end);
+    let fn_range = offsets_range(ctx, fn_decl.span.start(), fn_decl.span.end());
+
+    // The variables declared within the function and outside of the selection become parameters.
+    let mut params: Vec<(String, String)> = vec![];
+    for (_, decl_ident) in references(ctx, start, end) {
+        if !is_in_document(ctx, &decl_ident)
+            || !is_within(&decl_ident.range, &fn_range)
+            || is_within(&decl_ident.range, &range)
+            || params.iter().any(|(name, _)| *name == decl_ident.name)
+        {
+            continue;
+        }
+        let type_id = match &ctx.tokens.try_get(&decl_ident).try_unwrap()?.value().typed {
+            Some(TypedAstToken::TypedDeclaration(TyDecl::VariableDecl(var_decl))) => {
+                // Assignments to the variable would not be seen by the caller.
+                if var_decl.mutability == VariableMutability::Mutable {
+                    return None;
+                }
+                var_decl.return_type
+            }
+            Some(TypedAstToken::TypedFunctionParameter(param)) => {
+                if param.is_reference || param.is_mutable {
+                    return None;
+                }
+                param.type_argument.type_id
+            }
+            _ => continue,
+        };
+        params.push((decl_ident.name.clone(), type_name(ctx, type_id)?));
+    }
+
+    // The variables declared by the extracted statements can't be used after them.
+    if !is_expr {
+        let after = offsets_range(ctx, end, fn_decl.span.end());
+        let declared_within = ctx
+            .tokens
+            .tokens_for_file(ctx.temp_uri)
+            .filter(|item| is_within(&item.key().range, &after))
+            .filter_map(|item| item.value().declared_token_ident(ctx.engines))
+            .any(|decl_ident| is_within(&decl_ident.range, &range));
+        if declared_within {
+            return None;
+        }
+    }
+
+    let return_type = if is_expr {
+        let type_id = expr_type(ctx, start, end)?;
+        match type_name(ctx, type_id)?.as_str() {
+            "()" => String::new(),
+            name => format!(" -> {name}"),
+        }
+    } else {
+        String::new()
+    };
+
+    let name = unique_name(ctx, "extracted");
+    let attributes = fn_decl
+        .attributes
+        .get(&AttributeKind::Storage)
+        .into_iter()
+        .flatten()
+        .map(|attr| format!("{}\n", attr.span.as_str()))
+        .collect::<String>();
+    let parameters = params
+        .iter()
+        .map(|(name, type_name)| format!("{name}: {type_name}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let indentation = indentation_at(src, start);
+    let body = reindent(
+        &format!("{indentation}{}", &src[start..end]),
+        indentation,
+        TAB,
+    );
+    let function = format!("\n\n{attributes}fn {name}({parameters}){return_type} {{\n{body}\n}}");
+
+    let arguments = params
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let semicolon = if is_expr { "" } else { ";" };
+    let call = format!("{name}({arguments}){semicolon}");
+
+    let item = enclosing_item(src, start, end)?;
+    let changes = HashMap::from([(
+        ctx.uri.clone(),
+        vec![
+            replace(ctx, start, end, call),
+            replace(ctx, item.end(), item.end(), function),
+        ],
+    )]);
+
+    Some(vec![refactor(
+        ctx,
+        CODE_ACTION_EXTRACT_FUNCTION_TITLE.to_string(),
+        CodeActionKind::REFACTOR_EXTRACT,
+        changes,
+    )])
+}
+
+/// Returns true if the text between the byte offsets is made of whole statements of a block.
+fn is_statements(src: &Arc<str>, start: usize, end: usize) -> bool {
+    enclosing_blocks(src, start, end).iter().any(|block| {
+        let selected = block
+            .statements
+            .iter()
+            .filter(|statement| start <= statement.span().start() && statement.span().end() <= end)
+            .collect::<Vec<_>>();
+        selected
+            .first()
+            .is_some_and(|first| first.span().start() == start)
+            && selected.last().is_some_and(|last| last.span().end() == end)
+            && !selected
+                .iter()
+                .any(|statement| matches!(statement, Statement::Item(_)))
+    })
+}
+
+/// Returns true if any of the keywords is used between the byte offsets.
+fn contains_keyword(src: &Arc<str>, start: usize, end: usize, keywords: &[&str]) -> bool {
+    fn contains(trees: &[TokenTree], keywords: &[&str]) -> bool {
+        trees.iter().any(|tree| match tree {
+            TokenTree::Ident(ident) => keywords.contains(&ident.as_str()),
+            TokenTree::Group(group) => contains(group.token_stream.token_trees(), keywords),
+            _ => false,
+        })
+    }
+    sway_parse::lex(&Handler::default(), src, start, end, None).map_or(true, |token_stream| {
+        contains(token_stream.token_trees(), keywords)
+    })
+}
+
+/// Returns the innermost function declaration the text between the byte offsets is in.
+fn enclosing_fn(ctx: &CodeActionContext, start: usize, end: usize) -> Option<ty::TyFunctionDecl> {
+    ctx.tokens
+        .tokens_for_file(ctx.temp_uri)
+        .filter_map(|item| match &item.value().typed {
+            Some(TypedAstToken::TypedFunctionDeclaration(fn_decl))
+                if fn_decl.span.start() <= start && end <= fn_decl.span.end() =>
+            {
+                Some(fn_decl.clone())
+            }
+            _ => None,
+        })
+        .min_by_key(|fn_decl| fn_decl.span.end() - fn_decl.span.start())
+}
+
+/// Returns the type of the expression between the byte offsets, looked up among the typed
+/// expressions of the tokens, the initializers of variables and the arguments of calls.
+fn expr_type(ctx: &CodeActionContext, start: usize, end: usize) -> Option<TypeId> {
+    let is_selected =
+        |expr: &ty::TyExpression| expr.span.start() == start && expr.span.end() == end;
+    ctx.tokens
+        .tokens_for_file(ctx.temp_uri)
+        .find_map(|item| match &item.value().typed {
+            Some(TypedAstToken::TypedExpression(expr)) => {
+                let arguments = match &expr.expression {
+                    ty::TyExpressionVariant::FunctionApplication { arguments, .. } => {
+                        arguments.iter().map(|(_, arg)| arg).collect()
+                    }
+                    _ => vec![],
+                };
+                std::iter::once(expr)
+                    .chain(arguments)
+                    .find(|expr| is_selected(expr))
+                    .map(|expr| expr.return_type)
+            }
+            Some(TypedAstToken::TypedDeclaration(TyDecl::VariableDecl(var_decl)))
+                if is_selected(&var_decl.body) =>
+            {
+                Some(var_decl.body.return_type)
+            }
+            _ => None,
+        })
+}
+
+/// Returns the name of the type as written in a signature, with numeric literals defaulting to
+/// `u64`.
+fn type_name(ctx: &CodeActionContext, type_id: TypeId) -> Option<String> {
+    match &*ctx.engines.te().get(type_id) {
+        TypeInfo::Numeric => Some("u64".to_string()),
+        _ => type_label(ctx.engines, type_id),
+    }
+}
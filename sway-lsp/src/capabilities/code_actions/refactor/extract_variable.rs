@@ -0,0 +1,79 @@
+use super::{
+    enclosing_blocks, is_in_document, parse_expr, refactor, references, replace, selection,
+    unique_name,
+};
+use crate::capabilities::code_actions::{
+    indentation_at, CodeActionContext, CODE_ACTION_EXTRACT_VARIABLE_TITLE,
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand};
+use std::{collections::HashMap, sync::Arc};
+use sway_ast::{Expr, Statement};
+use sway_types::Spanned;
+
+/// Returns a [CodeActionOrCommand] for binding the selected expression to a new variable, declared
+/// before the statement the expression is in.
+pub(crate) fn extract_variable_code_action(
+    ctx: &CodeActionContext,
+    src: &Arc<str>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let (start, end) = selection(ctx, src)?;
+    let expr = parse_expr(src, start, end)?;
+    if matches!(
+        expr,
+        Expr::Reassignment { .. }
+            | Expr::Return { .. }
+            | Expr::Break { .. }
+            | Expr::Continue { .. }
+    ) {
+        return None;
+    }
+
+    // Find the statement of the innermost block the expression is in.
+    let statement_start = enclosing_blocks(src, start, end)
+        .iter()
+        .find_map(|block| {
+            let statement = block.statements.iter().find(|statement| {
+                statement.span().start() <= start && end <= statement.span().end()
+            });
+            match statement {
+                Some(Statement::Item(_)) => Some(None),
+                Some(statement) => Some(Some(statement.span().start())),
+                None => block
+                    .final_expr_opt
+                    .as_ref()
+                    .filter(|expr| expr.span().start() <= start && end <= expr.span().end())
+                    .map(|expr| Some(expr.span().start())),
+            }
+        })
+        .flatten()?;
+
+    // The variables the expression refers to must be declared before the statement, which isn't
+    // the case of those bound by the arms of a `match` the expression is in.
+    let statement_position = ctx.document.offset_to_position(statement_start);
+    let selection_position = ctx.document.offset_to_position(start);
+    if references(ctx, start, end).iter().any(|(_, decl_ident)| {
+        is_in_document(ctx, decl_ident)
+            && statement_position <= decl_ident.range.start
+            && decl_ident.range.start < selection_position
+    }) {
+        return None;
+    }
+
+    let name = unique_name(ctx, "value");
+    let indentation = indentation_at(src, statement_start);
+    let declaration = format!("let {name} = {};\n{indentation}", &src[start..end]);
+    let changes = HashMap::from([(
+        ctx.uri.clone(),
+        vec![
+            replace(ctx, statement_start, statement_start, declaration),
+            replace(ctx, start, end, name),
+        ],
+    )]);
+
+    Some(vec![refactor(
+        ctx,
+        CODE_ACTION_EXTRACT_VARIABLE_TITLE.to_string(),
+        CodeActionKind::REFACTOR_EXTRACT,
+        changes,
+    )])
+}
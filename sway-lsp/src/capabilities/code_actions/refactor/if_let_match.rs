@@ -0,0 +1,155 @@
+use super::{parse_expr_at, refactor, replace};
+use crate::capabilities::code_actions::{
+    common::generate_impl::TAB, indentation_at, CodeActionContext, CODE_ACTION_TO_IF_LET_TITLE,
+    CODE_ACTION_TO_MATCH_TITLE,
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand};
+use std::{collections::HashMap, sync::Arc};
+use sway_ast::{
+    expr::LoopControlFlow, Expr, IfCondition, IfExpr, MatchBranch, MatchBranchKind, Pattern,
+};
+use sway_types::Spanned;
+
+/// Returns a [CodeActionOrCommand] for converting the `if let` expression at the cursor into a
+/// `match` expression, or the `match` expression at the cursor into an `if let` expression.
+pub(crate) fn if_let_match_code_action(
+    ctx: &CodeActionContext,
+    src: &Arc<str>,
+) -> Option<Vec<CodeActionOrCommand>> {
+    // The cursor must be on the `if` or `match` keyword.
+    let offset = ctx
+        .document
+        .position_to_offset(ctx.range.start)
+        .min(src.len());
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let start = src[..offset].rfind(|c| !is_word(c)).map_or(0, |i| i + 1);
+    let end = src[offset..]
+        .find(|c| !is_word(c))
+        .map_or(src.len(), |i| offset + i);
+
+    if !matches!(&src[start..end], "if" | "match") {
+        return None;
+    }
+
+    let expr = parse_expr_at(src, start)?;
+    let (title, new_text) = match &expr {
+        Expr::If(if_expr) => (
+            CODE_ACTION_TO_MATCH_TITLE,
+            if_let_to_match(src, start, if_expr)?,
+        ),
+        Expr::Match {
+            value, branches, ..
+        } => (
+            CODE_ACTION_TO_IF_LET_TITLE,
+            match_to_if_let(src, start, value.span().as_str(), branches.get())?,
+        ),
+        _ => return None,
+    };
+    let expr_end = expr.span().end();
+    let changes = HashMap::from([(
+        ctx.uri.clone(),
+        vec![replace(ctx, start, expr_end, new_text)],
+    )]);
+
+    Some(vec![refactor(
+        ctx,
+        title.to_string(),
+        CodeActionKind::REFACTOR_REWRITE,
+        changes,
+    )])
+}
+
+/// Returns the `match` expression equivalent to the `if let` expression starting at the byte offset.
+fn if_let_to_match(src: &str, start: usize, if_expr: &IfExpr) -> Option<String> {
+    let IfCondition::Let { lhs, rhs, .. } = &if_expr.condition else {
+        return None;
+    };
+    let else_block = match &if_expr.else_opt {
+        None => "{}".to_string(),
+        Some((_, LoopControlFlow::Break(block))) => indent_block(block.span().as_str()),
+        // `else if` chains are better left as they are.
+        Some((_, LoopControlFlow::Continue(_))) => return None,
+    };
+    let indentation = indentation_at(src, start);
+    Some(format!(
+        "match {} {{\n{indentation}{TAB}{} => {},\n{indentation}{TAB}_ => {else_block},\n{indentation}}}",
+        rhs.span().as_str(),
+        lhs.span().as_str(),
+        indent_block(if_expr.then_block.span().as_str()),
+    ))
+}
+
+/// Returns the `if let` expression equivalent to the `match` expression starting at the byte
+/// offset, if it has a single arm other than a trailing catch-all one.
+fn match_to_if_let(
+    src: &str,
+    start: usize,
+    value: &str,
+    branches: &[MatchBranch],
+) -> Option<String> {
+    let [branch, catch_all] = branches else {
+        return None;
+    };
+    if matches!(branch.pattern, Pattern::Wildcard { .. })
+        || !matches!(catch_all.pattern, Pattern::Wildcard { .. })
+    {
+        return None;
+    }
+    let indentation = indentation_at(src, start);
+    let then_block = branch_block(&branch.kind, indentation)?;
+    let else_block = branch_block(&catch_all.kind, indentation)?;
+    let else_branch = if else_block == "{}" {
+        String::new()
+    } else {
+        format!(" else {else_block}")
+    };
+    Some(format!(
+        "if let {} = {value} {then_block}{else_branch}",
+        branch.pattern.span().as_str(),
+    ))
+}
+
+/// Returns the body of the match arm as a block, indented for an `if let` at `indentation`.
+fn branch_block(kind: &MatchBranchKind, indentation: &str) -> Option<String> {
+    match kind {
+        MatchBranchKind::Block { block, .. } => {
+            if block.get().statements.is_empty() && block.get().final_expr_opt.is_none() {
+                return Some("{}".to_string());
+            }
+            Some(dedent_block(block.span().as_str()))
+        }
+        MatchBranchKind::Expr { expr, .. } => Some(format!(
+            "{{\n{indentation}{TAB}{}\n{indentation}}}",
+            dedent_block(expr.span().as_str())
+        )),
+    }
+}
+
+/// Returns the block with all of its lines but the first one indented one level deeper.
+fn indent_block(block: &str) -> String {
+    block
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 || line.trim().is_empty() {
+                line.to_string()
+            } else {
+                format!("{TAB}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the block with all of its lines but the first one indented one level shallower.
+fn dedent_block(block: &str) -> String {
+    block
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match line.strip_prefix(TAB) {
+            Some(rest) if i > 0 => rest,
+            _ => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,83 @@
+use super::{is_atomic, parse_expr, refactor};
+use crate::{
+    capabilities::code_actions::{CodeActionContext, CODE_ACTION_INLINE_VARIABLE_TITLE},
+    core::token::{get_range_from_span, TypedAstToken},
+};
+use lsp_types::{CodeActionKind, CodeActionOrCommand, TextEdit};
+use std::{collections::HashMap, sync::Arc};
+use sway_core::language::ty::{TyDecl, TyExpressionVariant, VariableMutability};
+use sway_types::Spanned;
+
+/// Returns a [CodeActionOrCommand] for replacing the uses of the variable at the cursor with its
+/// initializer, and removing its declaration.
+pub(crate) fn inline_variable_code_action(
+    ctx: &CodeActionContext,
+) -> Option<Vec<CodeActionOrCommand>> {
+    let decl_ident = ctx.token?.declared_token_ident(ctx.engines)?;
+    let var_decl = match &ctx.tokens.try_get(&decl_ident).try_unwrap()?.value().typed {
+        Some(TypedAstToken::TypedDeclaration(TyDecl::VariableDecl(var_decl))) => var_decl.clone(),
+        _ => return None,
+    };
+    if var_decl.mutability != VariableMutability::Immutable {
+        return None;
+    }
+
+    // Only variables declared on their own by a `let` statement can be inlined, not those bound by
+    // destructuring patterns or match arms.
+    let text = ctx.document.get_text();
+    let statement_range = ctx.let_statement_range(&var_decl)?;
+    let let_end = ctx.document.position_to_offset(statement_range.start)
+        + text[ctx.document.position_to_offset(statement_range.start)..].find("let")?
+        + "let".len();
+    let name_start = ctx
+        .document
+        .position_to_offset(get_range_from_span(&var_decl.name.span()).start);
+    if !text.get(let_end..name_start)?.trim().is_empty() {
+        return None;
+    }
+
+    let initializer = var_decl.body.span.as_str();
+    let src: Arc<str> = Arc::from(initializer);
+    let new_text = match parse_expr(&src, 0, src.len()) {
+        Some(expr) if is_atomic(&expr) => initializer.to_string(),
+        Some(_) => format!("({initializer})"),
+        None => return None,
+    };
+
+    let mut edits = vec![];
+    for item in ctx.tokens.tokens_for_file(ctx.temp_uri) {
+        if *item.key() == decl_ident
+            || item.value().declared_token_ident(ctx.engines).as_ref() != Some(&decl_ident)
+        {
+            continue;
+        }
+        // Uses other than plain reads, such as struct field shorthands, are left to the user.
+        match &item.value().typed {
+            Some(TypedAstToken::TypedExpression(expr))
+                if matches!(
+                    expr.expression,
+                    TyExpressionVariant::VariableExpression { .. }
+                ) =>
+            {
+                edits.push(TextEdit {
+                    range: item.key().range,
+                    new_text: new_text.clone(),
+                });
+            }
+            _ => return None,
+        }
+    }
+    edits.sort_by_key(|edit| edit.range.start);
+    edits.push(TextEdit {
+        range: statement_range,
+        new_text: String::new(),
+    });
+    let changes = HashMap::from([(ctx.uri.clone(), edits)]);
+
+    Some(vec![refactor(
+        ctx,
+        format!("{CODE_ACTION_INLINE_VARIABLE_TITLE} `{}`", var_decl.name),
+        CodeActionKind::REFACTOR_INLINE,
+        changes,
+    )])
+}
@@ -0,0 +1,246 @@
+mod change_signature;
+mod extract_function;
+mod extract_variable;
+mod if_let_match;
+mod inline_variable;
+
+use crate::{
+    capabilities::code_actions::CodeActionContext, core::token::TokenIdent,
+    utils::document::is_within,
+};
+use lsp_types::{
+    CodeAction as LspCodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, Url,
+    WorkspaceEdit,
+};
+use serde_json::Value;
+use std::{collections::HashMap, sync::Arc};
+use sway_ast::{token::TokenTree, CodeBlockContents, Expr};
+use sway_error::handler::Handler;
+use sway_parse::Parser;
+use sway_types::{ast::Delimiter, Span, Spanned};
+
+use self::change_signature::change_signature_code_action;
+use self::extract_function::extract_function_code_action;
+use self::extract_variable::extract_variable_code_action;
+use self::if_let_match::if_let_match_code_action;
+use self::inline_variable::inline_variable_code_action;
+
+/// Returns a list of [CodeActionOrCommand] for the refactorings applicable to the selected code.
+///
+/// Refactorings work on the syntax tree of the document, parsed from its current text, and use the
+/// typed tokens of the token map to resolve the declarations and types involved.
+pub(crate) fn code_actions(ctx: &CodeActionContext) -> Option<Vec<CodeActionOrCommand>> {
+    let src: Arc<str> = Arc::from(ctx.document.get_text());
+
+    extract_variable_code_action(ctx, &src)
+        .into_iter()
+        .chain(extract_function_code_action(ctx, &src))
+        .chain(inline_variable_code_action(ctx))
+        .chain(change_signature_code_action(ctx))
+        .chain(if_let_match_code_action(ctx, &src))
+        .reduce(|mut combined, mut curr| {
+            combined.append(&mut curr);
+            combined
+        })
+}
+
+/// Returns a refactoring [CodeActionOrCommand] of the given kind that applies the given changes.
+fn refactor(
+    ctx: &CodeActionContext,
+    title: String,
+    kind: CodeActionKind,
+    changes: HashMap<Url, Vec<TextEdit>>,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(LspCodeAction {
+        title,
+        kind: Some(kind),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        data: Some(Value::String(ctx.uri.to_string())),
+        ..Default::default()
+    })
+}
+
+/// Returns a [TextEdit] replacing the text between the byte offsets with `new_text`.
+fn replace(ctx: &CodeActionContext, start: usize, end: usize, new_text: String) -> TextEdit {
+    TextEdit {
+        range: offsets_range(ctx, start, end),
+        new_text,
+    }
+}
+
+/// Returns the [Range] between the byte offsets.
+fn offsets_range(ctx: &CodeActionContext, start: usize, end: usize) -> Range {
+    Range::new(
+        ctx.document.offset_to_position(start),
+        ctx.document.offset_to_position(end),
+    )
+}
+
+/// Returns the idents of the tokens between the byte offsets, sorted by their position, along with
+/// the idents of the declarations they refer to.
+fn references(ctx: &CodeActionContext, start: usize, end: usize) -> Vec<(TokenIdent, TokenIdent)> {
+    let range = offsets_range(ctx, start, end);
+    let mut references = ctx
+        .tokens
+        .tokens_for_file(ctx.temp_uri)
+        .filter(|item| is_within(&item.key().range, &range))
+        .filter_map(|item| {
+            let decl_ident = item.value().declared_token_ident(ctx.engines)?;
+            Some((item.key().clone(), decl_ident))
+        })
+        .collect::<Vec<_>>();
+    references.sort_by_key(|(ident, _)| ident.range.start);
+    references
+}
+
+/// Returns the byte offsets of the selected text, without its surrounding whitespace, if any text
+/// is selected.
+fn selection(ctx: &CodeActionContext, src: &str) -> Option<(usize, usize)> {
+    let start = ctx.document.position_to_offset(ctx.range.start);
+    let end = ctx
+        .document
+        .position_to_offset(ctx.range.end)
+        .min(src.len());
+    let selected = src.get(start..end)?;
+    let trimmed_start = start + (selected.len() - selected.trim_start().len());
+    let trimmed_end = start + selected.trim_end().len();
+    (trimmed_start < trimmed_end).then_some((trimmed_start, trimmed_end))
+}
+
+/// Parses the text between the byte offsets as a single expression.
+fn parse_expr(src: &Arc<str>, start: usize, end: usize) -> Option<Expr> {
+    let handler = Handler::default();
+    let token_stream = sway_parse::lex(&handler, src, start, end, None).ok()?;
+    let mut parser = Parser::new(&handler, &token_stream);
+    let expr = parser.parse::<Expr>().ok()?;
+    (parser.is_empty() && !handler.has_errors()).then_some(expr)
+}
+
+/// Parses the expression starting at the byte offset, within the innermost block around it.
+fn parse_expr_at(src: &Arc<str>, start: usize) -> Option<Expr> {
+    let end = brace_groups(src, start, start)
+        .first()
+        .map_or(src.len(), |group| group.end() - 1);
+    let handler = Handler::default();
+    let token_stream = sway_parse::lex(&handler, src, start, end, None).ok()?;
+    let expr = Parser::new(&handler, &token_stream).parse::<Expr>().ok()?;
+    (!handler.has_errors()).then_some(expr)
+}
+
+/// Returns the blocks of code around the text between the byte offsets, innermost first.
+fn enclosing_blocks(src: &Arc<str>, start: usize, end: usize) -> Vec<CodeBlockContents> {
+    brace_groups(src, start, end)
+        .into_iter()
+        .filter_map(|group| {
+            let handler = Handler::default();
+            let token_stream =
+                sway_parse::lex(&handler, src, group.start() + 1, group.end() - 1, None).ok()?;
+            let (block, _) = Parser::new(&handler, &token_stream)
+                .parse_to_end::<CodeBlockContents>()
+                .ok()?;
+            (!handler.has_errors()).then_some(block)
+        })
+        .collect()
+}
+
+/// Returns the spans of the braces around the text between the byte offsets, innermost first.
+fn brace_groups(src: &Arc<str>, start: usize, end: usize) -> Vec<Span> {
+    let handler = Handler::default();
+    let Ok(mut token_stream) = sway_parse::lex(&handler, src, 0, src.len(), None) else {
+        return vec![];
+    };
+    let mut groups = vec![];
+    loop {
+        let group = token_stream
+            .token_trees()
+            .iter()
+            .find_map(|tree| match tree {
+                TokenTree::Group(group) if group.span.start() < start && end < group.span.end() => {
+                    Some(group.clone())
+                }
+                _ => None,
+            });
+        match group {
+            Some(group) => {
+                if group.delimiter == Delimiter::Brace {
+                    groups.push(group.span.clone());
+                }
+                token_stream = group.token_stream;
+            }
+            None => break,
+        }
+    }
+    groups.reverse();
+    groups
+}
+
+/// Returns the span of the top-level item the text between the byte offsets is in.
+fn enclosing_item(src: &Arc<str>, start: usize, end: usize) -> Option<Span> {
+    let module = sway_parse::parse_file(&Handler::default(), src.clone(), None).ok()?;
+    module
+        .value
+        .items
+        .iter()
+        .map(|item| item.value.span())
+        .find(|span| span.start() <= start && end <= span.end())
+}
+
+/// Returns true if the expression is an operand that never needs parentheses around it.
+fn is_atomic(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Path(_)
+            | Expr::Literal(_)
+            | Expr::AbiCast { .. }
+            | Expr::Struct { .. }
+            | Expr::Tuple(_)
+            | Expr::Parens(_)
+            | Expr::Block(_)
+            | Expr::Array(_)
+            | Expr::FuncApp { .. }
+            | Expr::Index { .. }
+            | Expr::MethodCall { .. }
+            | Expr::FieldProjection { .. }
+            | Expr::TupleFieldProjection { .. }
+    )
+}
+
+/// Returns `base`, or `base` followed by a number if a token of that name is already in the
+/// document.
+fn unique_name(ctx: &CodeActionContext, base: &str) -> String {
+    let names = ctx
+        .tokens
+        .tokens_for_file(ctx.temp_uri)
+        .map(|item| item.key().name.clone())
+        .collect::<Vec<_>>();
+    std::iter::once(base.to_string())
+        .chain((1..).map(|i| format!("{base}{i}")))
+        .find(|name| !names.contains(name))
+        .unwrap_or_else(|| base.to_string())
+}
+
+/// Returns true if the ident is in the document the code actions are requested for.
+fn is_in_document(ctx: &CodeActionContext, ident: &TokenIdent) -> bool {
+    ident
+        .path
+        .as_ref()
+        .is_some_and(|path| path.to_str() == Some(ctx.temp_uri.path()))
+}
+
+/// Returns the lines of `text` with their leading whitespace `from` replaced by `to`.
+fn reindent(text: &str, from: &str, to: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                String::new()
+            } else {
+                let rest = line.strip_prefix(from).unwrap_or_else(|| line.trim_start());
+                format!("{to}{rest}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -26,20 +26,21 @@ impl<'a> CodeAction<'a, TyStructDecl> for StructNewCodeAction<'a> {
         // Before the other functions are called, we need to determine if the new function
         // should be generated in a new impl block, an existing impl block, or not at all.
         // Find the first impl block for this struct if it exists.
-        let existing_impl_decl = ctx
-            .tokens
-            .iter()
-            .all_references_of_token(ctx.token, ctx.engines)
-            .find_map(|item| {
-                if let Some(TypedAstToken::TypedDeclaration(ty::TyDecl::ImplTrait(
-                    ty::ImplTrait { decl_id, .. },
-                ))) = item.value().typed
-                {
-                    Some((*ctx.engines.de().get_impl_trait(&decl_id)).clone())
-                } else {
-                    None
-                }
-            });
+        let existing_impl_decl = ctx.token.and_then(|token| {
+            ctx.tokens
+                .iter()
+                .all_references_of_token(token, ctx.engines)
+                .find_map(|item| {
+                    if let Some(TypedAstToken::TypedDeclaration(ty::TyDecl::ImplTrait(
+                        ty::ImplTrait { decl_id, .. },
+                    ))) = item.value().typed
+                    {
+                        Some((*ctx.engines.de().get_impl_trait(&decl_id)).clone())
+                    } else {
+                        None
+                    }
+                })
+        });
         Self {
             decl,
             uri: ctx.uri,
@@ -1,11 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
+use crate::core::{
+    token::{get_range_from_span, AstToken, SymbolKind, TokenIdent, TypeDefinition, TypedAstToken},
+    token_map::TokenMap,
+};
 use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, Position, Range};
 use serde::{Deserialize, Serialize};
+use sway_core::language::parsed::ImportType;
 use sway_error::warning::CompileWarning;
 use sway_error::{error::CompileError, warning::Warning};
-use sway_types::{LineCol, SourceEngine, Spanned};
+use sway_types::{Ident, LineCol, SourceEngine, Spanned};
 
 pub(crate) type DiagnosticMap = HashMap<PathBuf, Diagnostics>;
 
@@ -28,11 +33,14 @@ fn get_error_diagnostic(error: &CompileError) -> Diagnostic {
 }
 
 fn get_warning_diagnostic(warning: &CompileWarning) -> Diagnostic {
+    let data = serde_json::to_value(DiagnosticData::try_from(warning.clone()).ok()).ok();
+
     Diagnostic {
         range: get_range(warning.span().line_col()),
         severity: Some(DiagnosticSeverity::WARNING),
         message: warning.to_friendly_warning_string(),
         tags: get_warning_diagnostic_tags(&warning.warning_content),
+        data,
         ..Default::default()
     }
 }
@@ -65,6 +73,75 @@ pub fn get_diagnostics(
     diagnostics
 }
 
+/// Adds a diagnostic for each item imported by a `use` statement that is never used by the module
+/// importing it.
+///
+/// The compiler doesn't warn about unused imports, so they are found from the token map instead:
+/// an imported item is unused if no other token of its module has the name it is imported as.
+/// Imported traits are never reported, as their methods may be called without naming them.
+pub fn push_unused_import_diagnostics(
+    diagnostics: &mut DiagnosticMap,
+    token_map: &TokenMap,
+    se: &SourceEngine,
+) {
+    let mut imports = vec![];
+    let mut used_names = HashSet::new();
+    for item in token_map.iter() {
+        let (ident, token) = item.pair();
+        let Some(path) = &ident.path else {
+            continue;
+        };
+        match &token.typed {
+            Some(TypedAstToken::TypedUseStatement(use_statement)) => {
+                let ImportType::Item(item) = &use_statement.import_type else {
+                    continue;
+                };
+                let name = use_statement.alias.as_ref().unwrap_or(item);
+                // Only resolved items are reported, and no traits, as their methods are used
+                // without naming them.
+                let is_reported = |decl_ident: &Ident| {
+                    token_map
+                        .try_get(&TokenIdent::new(decl_ident, se))
+                        .try_unwrap()
+                        .is_some_and(|decl| decl.value().kind != SymbolKind::Trait)
+                };
+                if ident.range == get_range_from_span(&name.span())
+                    && matches!(&token.type_def, Some(TypeDefinition::Ident(decl_ident)) if is_reported(decl_ident))
+                {
+                    imports.push((path.clone(), ident.name.clone(), ident.range));
+                }
+            }
+            _ if matches!(token.parsed, AstToken::UseStatement(_)) => {}
+            _ => {
+                used_names.insert((path.clone(), ident.name.clone()));
+            }
+        }
+    }
+
+    imports.sort_by(|a, b| (&a.0, a.2.start).cmp(&(&b.0, b.2.start)));
+    for (path, name, range) in imports {
+        if used_names.contains(&(path.clone(), name.clone())) {
+            continue;
+        }
+        let data = DiagnosticData {
+            unused_import_name: Some(name.clone()),
+            ..Default::default()
+        };
+        diagnostics
+            .entry(path)
+            .or_default()
+            .warnings
+            .push(Diagnostic {
+                range,
+                severity: Some(DiagnosticSeverity::HINT),
+                message: format!("Import `{name}` is never used."),
+                tags: Some(vec![DiagnosticTag::UNNECESSARY]),
+                data: serde_json::to_value(data).ok(),
+                ..Default::default()
+            });
+    }
+}
+
 fn get_range((start, end): (LineCol, LineCol)) -> Range {
     let pos = |lc: LineCol| Position::new(lc.line as u32 - 1, lc.col as u32 - 1);
     let start = pos(start);
@@ -95,13 +172,33 @@ fn get_warning_diagnostic_tags(warning: &Warning) -> Option<Vec<DiagnosticTag>>
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct DiagnosticData {
     pub unknown_symbol_name: Option<String>,
+    /// The storage access the surrounding function is missing, e.g. `read, write`.
+    pub missing_storage_access: Option<String>,
+    /// The patterns a match expression is missing.
+    pub missing_match_patterns: Option<Vec<String>>,
+    /// The names of the methods a trait or ABI implementation is missing.
+    pub missing_interface_methods: Option<Vec<String>>,
+    /// The name of a contract method that should be `#[payable]`.
+    pub non_payable_method: Option<String>,
+    /// The name of a variable that should be declared as mutable.
+    pub immutable_variable_name: Option<String>,
+    /// The name of a declaration that is never used, e.g. a variable.
+    pub unused_declaration_name: Option<String>,
+    /// The name an item that is never used is imported as.
+    pub unused_import_name: Option<String>,
 }
 
 impl TryFrom<CompileWarning> for DiagnosticData {
     type Error = anyhow::Error;
 
-    fn try_from(_value: CompileWarning) -> Result<Self, Self::Error> {
-        anyhow::bail!("Not implemented");
+    fn try_from(value: CompileWarning) -> Result<Self, Self::Error> {
+        match value.warning_content {
+            Warning::DeadDeclaration => Ok(DiagnosticData {
+                unused_declaration_name: Some(value.span.as_str().to_string()),
+                ..Default::default()
+            }),
+            _ => anyhow::bail!("Not implemented"),
+        }
     }
 }
 
@@ -112,12 +209,61 @@ impl TryFrom<CompileError> for DiagnosticData {
         match value {
             CompileError::SymbolNotFound { name, .. } => Ok(DiagnosticData {
                 unknown_symbol_name: Some(name.to_string()),
+                ..Default::default()
             }),
             CompileError::TraitNotFound { name, .. } => Ok(DiagnosticData {
                 unknown_symbol_name: Some(name),
+                ..Default::default()
             }),
             CompileError::UnknownVariable { var_name, .. } => Ok(DiagnosticData {
                 unknown_symbol_name: Some(var_name.to_string()),
+                ..Default::default()
+            }),
+            CompileError::StorageAccessMismatch { attrs, .. }
+            | CompileError::ImpureInPureContext { attrs, .. }
+            | CompileError::TraitImplPurityMismatch { attrs, .. } => Ok(DiagnosticData {
+                missing_storage_access: Some(attrs),
+                ..Default::default()
+            }),
+            CompileError::MatchExpressionNonExhaustive {
+                missing_patterns, ..
+            } => Ok(DiagnosticData {
+                missing_match_patterns: Some(
+                    // The patterns are formatted as "`A`, `B`".
+                    missing_patterns
+                        .split("`, `")
+                        .map(|pattern| pattern.trim_matches('`').to_string())
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            CompileError::MissingInterfaceSurfaceMethods {
+                missing_functions, ..
+            } => Ok(DiagnosticData {
+                missing_interface_methods: Some(
+                    missing_functions
+                        .iter()
+                        .map(|ident| ident.as_str().to_string())
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            CompileError::CoinsPassedToNonPayableMethod { fn_name, .. }
+            | CompileError::TraitImplPayabilityMismatch {
+                fn_name,
+                missing_impl_attribute: true,
+                ..
+            } => Ok(DiagnosticData {
+                non_payable_method: Some(fn_name.to_string()),
+                ..Default::default()
+            }),
+            CompileError::AssignmentToNonMutable { name, .. }
+            | CompileError::MethodRequiresMutableSelf {
+                variable_name: name,
+                ..
+            } => Ok(DiagnosticData {
+                immutable_variable_name: Some(name.to_string()),
+                ..Default::default()
             }),
             _ => anyhow::bail!("Not implemented"),
         }
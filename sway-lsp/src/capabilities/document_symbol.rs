@@ -47,7 +47,7 @@ pub(crate) fn symbol_kind(symbol_kind: &SymbolKind) -> lsp_types::SymbolKind {
 
 #[allow(warnings)]
 // TODO: the "deprecated: None" field is deprecated according to this library
-fn symbol_info(ident: &TokenIdent, token: &Token, url: Url) -> SymbolInformation {
+pub(crate) fn symbol_info(ident: &TokenIdent, token: &Token, url: Url) -> SymbolInformation {
     SymbolInformation {
         name: ident.name.to_string(),
         kind: symbol_kind(&token.kind),
@@ -0,0 +1,148 @@
+//! Folding ranges, i.e., the regions of a document that can be collapsed in the editor.
+//!
+//! The regions are found in the syntax tree of the document, so that they are available while the
+//! program doesn't compile.
+
+use crate::{
+    core::{session::Session, token::get_range_from_span},
+    utils::syntax_tree::{parse_module, syntax_nodes, SyntaxKind},
+};
+use lsp_types::{FoldingRange, FoldingRangeKind, Url};
+use std::sync::Arc;
+use sway_ast::{
+    token::{DocStyle, TokenStream, TokenTree},
+    ItemKind,
+};
+use sway_error::handler::Handler;
+use sway_types::{Span, Spanned};
+
+/// Returns the folding ranges of the document: its items, the items of its `impl`, trait and ABI
+/// blocks, its match arms, its runs of doc comments and its groups of `use` items.
+pub fn folding_ranges(session: Arc<Session>, uri: &Url) -> Option<Vec<FoldingRange>> {
+    let document = session.get_text_document(uri).ok()?;
+    let text = document.get_text();
+    let module = parse_module(&text)?;
+    let nodes = syntax_nodes(&module.value);
+
+    let mut ranges = vec![];
+    for (i, node) in nodes.iter().enumerate() {
+        match node.kind {
+            // Items are folded below their attributes and doc comments, which are folded on their
+            // own, so that their signature stays visible.
+            SyntaxKind::Item => {
+                let attributes_end = nodes[i + 1..]
+                    .iter()
+                    .take_while(|node| node.kind == SyntaxKind::Attribute)
+                    .last()
+                    .map(|attribute| attribute.span.end());
+                let start = match attributes_end {
+                    Some(end) => {
+                        let src = node.span.src();
+                        end + (src[end..].len() - src[end..].trim_start().len())
+                    }
+                    None => node.span.start(),
+                };
+                ranges.extend(folding_range(&node.span, start, node.span.end(), None));
+            }
+            SyntaxKind::MatchBranch => {
+                let span = &node.span;
+                ranges.extend(folding_range(span, span.start(), span.end(), None));
+            }
+            _ => {}
+        }
+    }
+
+    // Consecutive `use` items are folded together.
+    let mut items = module.value.items.iter().peekable();
+    while let Some(item) = items.next() {
+        if !matches!(item.value, ItemKind::Use(_)) {
+            continue;
+        }
+        let mut last = item;
+        while let Some(next) = items.next_if(|next| matches!(next.value, ItemKind::Use(_))) {
+            last = next;
+        }
+        let span = item.span();
+        ranges.extend(folding_range(
+            &span,
+            span.start(),
+            last.span().end(),
+            Some(FoldingRangeKind::Imports),
+        ));
+    }
+
+    let handler = Handler::default();
+    let src: Arc<str> = Arc::from(text.as_str());
+    if let Ok(token_stream) = sway_parse::lex(&handler, &src, 0, src.len(), None) {
+        let mut doc_comments = vec![];
+        collect_doc_comments(&token_stream, &mut doc_comments);
+        ranges.extend(
+            doc_comment_runs(&doc_comments)
+                .into_iter()
+                .filter_map(|(first, last)| {
+                    folding_range(
+                        first,
+                        first.start(),
+                        last.end(),
+                        Some(FoldingRangeKind::Comment),
+                    )
+                }),
+        );
+    }
+
+    ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    ranges.dedup_by_key(|range| (range.start_line, range.end_line));
+    Some(ranges)
+}
+
+/// Returns the [FoldingRange] of the text between the byte offsets in the source of the span, if
+/// it spans multiple lines.
+fn folding_range(
+    span: &Span,
+    start: usize,
+    end: usize,
+    kind: Option<FoldingRangeKind>,
+) -> Option<FoldingRange> {
+    let span = Span::new(span.src().clone(), start, end, span.source_id().cloned())?;
+    let range = get_range_from_span(&span);
+    (range.start.line < range.end.line).then_some(FoldingRange {
+        start_line: range.start.line,
+        start_character: None,
+        end_line: range.end.line,
+        end_character: None,
+        kind,
+        collapsed_text: None,
+    })
+}
+
+/// Collects the doc comments of the token stream and of the groups within it, in order.
+fn collect_doc_comments(token_stream: &TokenStream, doc_comments: &mut Vec<(DocStyle, Span)>) {
+    for tree in token_stream.token_trees() {
+        match tree {
+            TokenTree::DocComment(doc_comment) => {
+                doc_comments.push((doc_comment.doc_style.clone(), doc_comment.span()));
+            }
+            TokenTree::Group(group) => collect_doc_comments(&group.token_stream, doc_comments),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the first and last doc comments of each run of doc comments of the same style on
+/// consecutive lines.
+fn doc_comment_runs(doc_comments: &[(DocStyle, Span)]) -> Vec<(&Span, &Span)> {
+    let mut runs: Vec<(&Span, &Span)> = vec![];
+    let mut prev_style = None;
+    for (style, span) in doc_comments {
+        let continues_run = runs.last().is_some_and(|(_, last)| {
+            prev_style == Some(style)
+                && span.start_pos().line_col().0 == last.end_pos().line_col().0 + 1
+        });
+        match runs.last_mut() {
+            Some((_, last)) if continues_run => *last = span,
+            _ => runs.push((span, span)),
+        }
+        prev_style = Some(style);
+    }
+    runs
+}
@@ -1,6 +1,11 @@
-use crate::error::LanguageServerError;
+use crate::{
+    core::token::get_range_from_span,
+    error::LanguageServerError,
+    utils::syntax_tree::{parse_module, syntax_nodes, SyntaxKind},
+};
 use lsp_types::{Position, Range, TextEdit};
 use std::sync::Arc;
+use sway_types::Span;
 use swayfmt::Formatter;
 
 pub fn get_page_text_edit(
@@ -21,3 +26,92 @@ pub fn get_page_text_edit(
         new_text: formatted_code,
     })
 }
+
+/// Returns the [TextEdit] formatting the items the text between the byte offsets is in, or `None`
+/// if they are already formatted.
+///
+/// The whole text is formatted, and the formatted text of the items is then taken from the items
+/// at the same place in the formatted syntax tree. The selection is widened to the items it
+/// overlaps among the items of the innermost item around it, e.g., to the functions of an `impl`
+/// block, or to that item itself, e.g., to a function the selection is in the body of. As imports
+/// may be reordered, a selection overlapping an import is widened to all of the imports.
+pub fn get_range_text_edit(
+    text: Arc<str>,
+    start: usize,
+    end: usize,
+    formatter: &mut Formatter,
+) -> Result<Option<TextEdit>, LanguageServerError> {
+    let formatted_code = formatter
+        .format(text.clone(), None)
+        .map_err(LanguageServerError::FormatError)?;
+
+    let (Some((kinds, items)), Some((_, formatted_items))) =
+        (item_spans(&text), item_spans(&formatted_code))
+    else {
+        return Ok(None);
+    };
+    // Formatting doesn't add or remove items, so they can be matched by their position.
+    if items.len() != formatted_items.len() {
+        return Ok(None);
+    }
+
+    let contains =
+        |outer: &Span, inner: &Span| outer.start() <= inner.start() && inner.end() <= outer.end();
+    // The innermost item around the selection, unless the selection is that item.
+    let parent = (0..items.len())
+        .filter(|&i| {
+            items[i].start() <= start
+                && end <= items[i].end()
+                && (items[i].start(), items[i].end()) != (start, end)
+        })
+        .min_by_key(|&i| items[i].end() - items[i].start());
+    let is_within_parent = |i: usize| match parent {
+        Some(p) => p != i && contains(&items[p], &items[i]),
+        None => true,
+    };
+    let end = end.max(start + 1);
+    let children = (0..items.len())
+        .filter(|&i| {
+            is_within_parent(i)
+                && !(0..items.len())
+                    .any(|j| j != i && is_within_parent(j) && contains(&items[j], &items[i]))
+                && items[i].start() < end
+                && start < items[i].end()
+        })
+        .collect::<Vec<_>>();
+
+    let (mut first, mut last) = match (children.first(), children.last(), parent) {
+        (Some(first), Some(last), _) => (*first, *last),
+        (_, _, Some(parent)) => (parent, parent),
+        _ => return Ok(None),
+    };
+    let imports = (0..items.len())
+        .filter(|&i| kinds[i] == SyntaxKind::Use)
+        .collect::<Vec<_>>();
+    if children.iter().any(|i| imports.contains(i)) {
+        first = first.min(imports[0]);
+        last = last.max(imports[imports.len() - 1]);
+    }
+    let (start, end) = (items[first].start(), items[last].end());
+    let formatted = &formatted_code[formatted_items[first].start()..formatted_items[last].end()];
+    if &text[start..end] == formatted {
+        return Ok(None);
+    }
+    Ok(Span::new(text, start, end, None).map(|span| TextEdit {
+        range: get_range_from_span(&span),
+        new_text: formatted.to_string(),
+    }))
+}
+
+/// Returns the kinds and spans of the items of the text, including the items within items, e.g.,
+/// the functions of `impl` blocks, in order.
+fn item_spans(text: &str) -> Option<(Vec<SyntaxKind>, Vec<Span>)> {
+    let module = parse_module(text)?;
+    Some(
+        syntax_nodes(&module.value)
+            .into_iter()
+            .filter(|node| matches!(node.kind, SyntaxKind::Item | SyntaxKind::Use))
+            .map(|node| (node.kind, node.span))
+            .unzip(),
+    )
+}
@@ -0,0 +1,157 @@
+use crate::{
+    core::{
+        session::Session,
+        token::{ident_of_type_id, TokenIdent, TypedAstToken},
+        token_map::TokenMap,
+    },
+    utils::document::get_url_from_path,
+};
+use lsp_types::{request::GotoImplementationResponse, Location, Position, Url};
+use std::sync::Arc;
+use sway_core::{
+    decl_engine::InterfaceDeclId,
+    language::ty::{self, TyDecl},
+    Engines,
+};
+use sway_types::{Ident, Spanned};
+
+/// Returns the locations of the implementations of the trait, ABI, type or method declared or
+/// referred to at the given position.
+///
+/// The implementations of a trait or an ABI are the `impl` blocks implementing it, and those of a
+/// struct or an enum are the `impl` blocks implementing a trait or an ABI for it, along with its
+/// inherent ones. The implementations of a method of a trait or an ABI are the methods of the same
+/// name in the `impl` blocks implementing it.
+pub fn implementations(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<GotoImplementationResponse> {
+    let engines = session.engines.read();
+    let decl_ident = session
+        .token_map()
+        .token_at_position(url, position)?
+        .declared_token_ident(&engines)?;
+    let implementations = implementation_idents(session.token_map(), &engines, &decl_ident)?;
+
+    let locations = implementations
+        .into_iter()
+        .filter_map(|ident| {
+            let url = get_url_from_path(ident.path.as_ref()?).ok()?;
+            Some(Location::new(
+                session.sync.to_workspace_url(url)?,
+                ident.range,
+            ))
+        })
+        .collect();
+    Some(GotoImplementationResponse::Array(locations))
+}
+
+/// Returns the idents of the implementations of the trait, ABI, type or method declared by
+/// `decl_ident`, sorted by their location.
+pub(crate) fn implementation_idents(
+    token_map: &TokenMap,
+    engines: &Engines,
+    decl_ident: &TokenIdent,
+) -> Option<Vec<TokenIdent>> {
+    let decl = token_map
+        .try_get(decl_ident)
+        .try_unwrap()?
+        .value()
+        .typed
+        .clone()?;
+    let impls = token_map.impl_trait_declarations(engines);
+
+    let mut implementations: Vec<TokenIdent> = match decl {
+        TypedAstToken::TypedDeclaration(TyDecl::TraitDecl(_) | TyDecl::AbiDecl(_)) => impls
+            .iter()
+            .filter(|impl_trait| interface_ident(engines, impl_trait).as_ref() == Some(decl_ident))
+            .map(|impl_trait| implementing_for_ident(engines, impl_trait))
+            .collect(),
+        TypedAstToken::TypedDeclaration(TyDecl::StructDecl(_) | TyDecl::EnumDecl(_)) => impls
+            .iter()
+            .filter(|impl_trait| {
+                ident_of_type_id(engines, &impl_trait.implementing_for.type_id).as_ref()
+                    == Some(decl_ident)
+            })
+            .map(|impl_trait| implementing_for_ident(engines, impl_trait))
+            .collect(),
+        TypedAstToken::TypedTraitFn(_) | TypedAstToken::TypedFunctionDeclaration(_) => impls
+            .iter()
+            .filter(|impl_trait| {
+                interface_methods(engines, impl_trait)
+                    .iter()
+                    .any(|method| &TokenIdent::new(method, engines.se()) == decl_ident)
+            })
+            .flat_map(|impl_trait| impl_methods(engines, impl_trait))
+            .filter(|method| method.as_str() == decl_ident.name)
+            .map(|method| TokenIdent::new(&method, engines.se()))
+            .collect(),
+        _ => return None,
+    };
+    implementations.sort_by(|a, b| (&a.path, a.range.start).cmp(&(&b.path, b.range.start)));
+    implementations.dedup();
+    Some(implementations)
+}
+
+/// Returns the ident of the name of the trait or ABI implemented by `impl_trait`, if any.
+fn interface_ident(engines: &Engines, impl_trait: &ty::TyImplTrait) -> Option<TokenIdent> {
+    let name = match impl_trait.trait_decl_ref.as_ref()?.id() {
+        InterfaceDeclId::Abi(decl_id) => engines.de().get_abi(decl_id).name.clone(),
+        InterfaceDeclId::Trait(decl_id) => engines.de().get_trait(decl_id).name.clone(),
+    };
+    Some(TokenIdent::new(&name, engines.se()))
+}
+
+/// Returns the ident of the implementing type of `impl_trait`, as written in the `impl` block.
+fn implementing_for_ident(engines: &Engines, impl_trait: &ty::TyImplTrait) -> TokenIdent {
+    let span = impl_trait
+        .implementing_for
+        .call_path_tree
+        .as_ref()
+        .map(|tree| tree.qualified_call_path.call_path.suffix.span())
+        .unwrap_or(impl_trait.implementing_for.span());
+    TokenIdent::new(&Ident::new(span), engines.se())
+}
+
+/// Returns the names of the methods declared by the trait or ABI implemented by `impl_trait`,
+/// whether required or provided by the trait.
+fn interface_methods(engines: &Engines, impl_trait: &ty::TyImplTrait) -> Vec<Ident> {
+    let (interface_surface, items) = match impl_trait.trait_decl_ref.as_ref().map(|r| r.id()) {
+        Some(InterfaceDeclId::Abi(decl_id)) => {
+            let abi_decl = engines.de().get_abi(decl_id);
+            (abi_decl.interface_surface.clone(), abi_decl.items.clone())
+        }
+        Some(InterfaceDeclId::Trait(decl_id)) => {
+            let trait_decl = engines.de().get_trait(decl_id);
+            (
+                trait_decl.interface_surface.clone(),
+                trait_decl.items.clone(),
+            )
+        }
+        None => return vec![],
+    };
+    let required = interface_surface.into_iter().filter_map(|item| match item {
+        ty::TyTraitInterfaceItem::TraitFn(decl_ref) => {
+            Some(engines.de().get_trait_fn(&decl_ref).name.clone())
+        }
+        _ => None,
+    });
+    let provided = items.into_iter().filter_map(|item| match item {
+        ty::TyTraitItem::Fn(decl_ref) => Some(engines.de().get_function(&decl_ref).name.clone()),
+        _ => None,
+    });
+    required.chain(provided).collect()
+}
+
+/// Returns the names of the methods of `impl_trait`.
+fn impl_methods(engines: &Engines, impl_trait: &ty::TyImplTrait) -> Vec<Ident> {
+    impl_trait
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ty::TyTraitItem::Fn(decl_ref) => Some(engines.de().get_function(decl_ref).name.clone()),
+            _ => None,
+        })
+        .collect()
+}
@@ -5,15 +5,25 @@ use crate::{
         token::{get_range_from_span, TypedAstToken},
     },
 };
-use lsp_types::{self, Range, Url};
+use lsp_types::{self, Position, Range, Url};
 use std::sync::Arc;
-use sway_core::{language::ty::TyDecl, type_system::TypeInfo};
-use sway_types::Spanned;
+use sway_core::{
+    language::{
+        ty::{self, TyDecl, TyExpression, TyExpressionVariant},
+        CallPath, Purity,
+    },
+    type_system::{TypeId, TypeInfo},
+    Engines,
+};
+use sway_types::{Ident, Span, Spanned};
 
-// Future PR's will add more kinds
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum InlayKind {
     TypeHint,
+    ParameterHint,
+    ChainingHint,
+    GenericHint,
+    PurityHint,
 }
 
 #[derive(Debug)]
@@ -29,75 +39,286 @@ pub fn inlay_hints(
     range: &Range,
     config: &InlayHintsConfig,
 ) -> Option<Vec<lsp_types::InlayHint>> {
-    // 1. Loop through all our tokens and filter out all tokens that aren't TypedVariableDeclaration tokens
-    // 2. Also filter out all tokens that have a span that fall outside of the provided range
-    // 3. Filter out all variable tokens that have a type_ascription
-    // 4. Look up the type id for the remaining tokens
-    // 5. Convert the type into a string
-    if !config.type_hints {
-        return None;
-    }
-
-    let hints: Vec<lsp_types::InlayHint> = session
+    // 1. Loop through all our tokens and keep the variable declarations and the function calls
+    // 2. Create the hints of each kind enabled in the config for them
+    // 3. Filter out all hints that fall outside of the provided range
+    let engines = session.engines.read();
+    let mut hints: Vec<InlayHint> = session
         .token_map()
         .tokens_for_file(uri)
-        .filter_map(|item| {
-            let token = item.value();
-            token.typed.as_ref().and_then(|t| match t {
-                TypedAstToken::TypedDeclaration(TyDecl::VariableDecl(var_decl)) => {
-                    match var_decl.type_ascription.call_path_tree {
-                        Some(_) => None,
-                        None => {
-                            let var_range = get_range_from_span(&var_decl.name.span());
-                            if var_range.start >= range.start && var_range.end <= range.end {
-                                Some(var_decl.clone())
-                            } else {
-                                None
-                            }
-                        }
-                    }
-                }
-                _ => None,
-            })
-        })
-        .filter_map(|var| {
-            let type_info = session.engines.read().te().get(var.type_ascription.type_id);
-            match &*type_info {
-                TypeInfo::Unknown | TypeInfo::UnknownGeneric { .. } => None,
-                _ => Some(var),
+        .flat_map(|item| match item.value().typed.as_ref() {
+            Some(TypedAstToken::TypedDeclaration(TyDecl::VariableDecl(var_decl)))
+                if config.type_hints =>
+            {
+                type_hint(&engines, var_decl).into_iter().collect()
             }
+            Some(TypedAstToken::TypedExpression(expr)) => call_hints(&engines, config, expr),
+            _ => vec![],
         })
-        .map(|var| {
-            let range = get_range_from_span(&var.name.span());
-            let kind = InlayKind::TypeHint;
-            let label = format!("{}", session.engines.read().help_out(var.type_ascription));
-            let inlay_hint = InlayHint { range, kind, label };
-            self::inlay_hint(config.render_colons, inlay_hint)
+        .filter(|hint| {
+            let position = hint_position(hint);
+            position >= range.start && position <= range.end
         })
         .collect();
+    hints.sort_by(|a, b| (hint_position(a), &a.label).cmp(&(hint_position(b), &b.label)));
+    hints.dedup_by(|a, b| a.range == b.range && a.kind == b.kind && a.label == b.label);
+
+    Some(
+        hints
+            .into_iter()
+            .map(|hint| self::inlay_hint(config, hint))
+            .collect(),
+    )
+}
+
+/// Returns the type hint of a variable declared without a type ascription.
+fn type_hint(engines: &Engines, var_decl: &ty::TyVariableDecl) -> Option<InlayHint> {
+    if var_decl.type_ascription.call_path_tree.is_some() {
+        return None;
+    }
+    Some(InlayHint {
+        range: get_range_from_span(&var_decl.name.span()),
+        kind: InlayKind::TypeHint,
+        label: type_label(engines, var_decl.type_ascription.type_id)?,
+    })
+}
+
+/// Returns the parameter, chaining, generic and purity hints of a function or method call.
+fn call_hints(engines: &Engines, config: &InlayHintsConfig, expr: &TyExpression) -> Vec<InlayHint> {
+    let TyExpressionVariant::FunctionApplication {
+        call_path,
+        arguments,
+        fn_ref,
+        ..
+    } = &expr.expression
+    else {
+        return vec![];
+    };
+    // Operators are desugared into calls, e.g., `a + b` into `add(a, b)`.
+    if call_path.suffix.span().as_str() != call_path.suffix.as_str() {
+        return vec![];
+    }
+    let fn_decl = engines.de().get_function(fn_ref);
+    let is_method_call = is_method_call(call_path, arguments);
+    let mut hints = vec![];
+
+    if config.parameter_hints {
+        let skip = usize::from(is_method_call);
+        hints.extend(
+            arguments
+                .iter()
+                .skip(skip)
+                .filter(|(name, arg)| {
+                    is_in_same_source(&arg.span, &call_path.suffix.span())
+                        && !is_named_like(arg, name)
+                })
+                .map(|(name, arg)| InlayHint {
+                    range: get_range_from_span(&arg.span),
+                    kind: InlayKind::ParameterHint,
+                    label: name.as_str().to_string(),
+                }),
+        );
+    }
+
+    // The receiver of a method called on a new line, when it is itself the result of a method call.
+    if config.chaining_hints && is_method_call {
+        if let Some((_, receiver)) = arguments.first() {
+            let receiver_range = get_range_from_span(&receiver.span);
+            let suffix_range = get_range_from_span(&call_path.suffix.span());
+            if is_method_call_expression(receiver)
+                && is_in_same_source(&receiver.span, &call_path.suffix.span())
+                && receiver_range.end.line < suffix_range.start.line
+            {
+                if let Some(label) = type_label(engines, receiver.return_type) {
+                    hints.push(InlayHint {
+                        range: receiver_range,
+                        kind: InlayKind::ChainingHint,
+                        label,
+                    });
+                }
+            }
+        }
+    }
+
+    if config.generic_hints {
+        // The generic arguments of the implementing type of an associated function, e.g., `Vec::new()`.
+        if let (false, Some(type_name), Some(type_id)) = (
+            is_method_call,
+            call_path.prefixes.last(),
+            fn_decl.implementing_for_typeid,
+        ) {
+            if !has_explicit_type_arguments(type_name) {
+                if let Some(label) =
+                    type_arguments_label(engines, &type_arguments(engines, type_id))
+                {
+                    hints.push(InlayHint {
+                        range: get_range_from_span(&type_name.span()),
+                        kind: InlayKind::GenericHint,
+                        label,
+                    });
+                }
+            }
+        }
+        // The generic arguments of the function itself.
+        if !has_explicit_type_arguments(&call_path.suffix) {
+            let type_ids: Vec<TypeId> = fn_decl
+                .type_parameters
+                .iter()
+                .map(|param| param.type_id)
+                .collect();
+            if let Some(label) = type_arguments_label(engines, &type_ids) {
+                hints.push(InlayHint {
+                    range: get_range_from_span(&call_path.suffix.span()),
+                    kind: InlayKind::GenericHint,
+                    label,
+                });
+            }
+        }
+    }
+
+    if config.purity_hints
+        && fn_decl.purity != Purity::Pure
+        && is_in_same_source(&expr.span, &call_path.suffix.span())
+    {
+        hints.push(InlayHint {
+            range: get_range_from_span(&expr.span),
+            kind: InlayKind::PurityHint,
+            label: format!("#[storage({})]", fn_decl.purity.to_attribute_syntax()),
+        });
+    }
 
-    Some(hints)
+    hints
 }
 
-fn inlay_hint(render_colons: bool, inlay_hint: InlayHint) -> lsp_types::InlayHint {
+/// Returns true if the call is written with the method call syntax, i.e., `receiver.method(..)`.
+fn is_method_call(call_path: &CallPath, arguments: &[(Ident, TyExpression)]) -> bool {
+    arguments.first().is_some_and(|(_, receiver)| {
+        is_in_same_source(&receiver.span, &call_path.suffix.span())
+            && receiver.span.end() <= call_path.suffix.span().start()
+    })
+}
+
+fn is_method_call_expression(expr: &TyExpression) -> bool {
+    match &expr.expression {
+        TyExpressionVariant::FunctionApplication {
+            call_path,
+            arguments,
+            ..
+        } => is_method_call(call_path, arguments),
+        _ => false,
+    }
+}
+
+/// Returns true if the argument already reads as the name of the parameter, e.g., `area(width, 3)`
+/// or `area(self.width, 3)`, in which case the parameter hint is redundant.
+fn is_named_like(arg: &TyExpression, name: &Ident) -> bool {
+    let text = arg.span.as_str();
+    text == name.as_str() || text.ends_with(&format!(".{}", name.as_str()))
+}
+
+fn is_in_same_source(a: &Span, b: &Span) -> bool {
+    a.source_id() == b.source_id()
+}
+
+/// Returns true if the ident is followed by a turbofish in the source, e.g., `foo::<u64>`.
+fn has_explicit_type_arguments(ident: &Ident) -> bool {
+    let span = ident.span();
+    span.src()
+        .get(span.end()..)
+        .is_some_and(|rest| rest.trim_start().starts_with("::<"))
+}
+
+/// Returns the type arguments of a struct or an enum type.
+fn type_arguments(engines: &Engines, type_id: TypeId) -> Vec<TypeId> {
+    let type_parameters = match &*engines.te().get(type_id) {
+        TypeInfo::Struct(decl_ref) => engines.de().get_struct(decl_ref).type_parameters.clone(),
+        TypeInfo::Enum(decl_ref) => engines.de().get_enum(decl_ref).type_parameters.clone(),
+        _ => return vec![],
+    };
+    type_parameters.iter().map(|param| param.type_id).collect()
+}
+
+/// Returns the label of generic arguments, e.g., `::<u64, bool>`, or `None` if there are none or
+/// any of them has not been inferred.
+fn type_arguments_label(engines: &Engines, type_ids: &[TypeId]) -> Option<String> {
+    if type_ids.is_empty() {
+        return None;
+    }
+    let labels = type_ids
+        .iter()
+        .map(|type_id| type_label(engines, *type_id))
+        .collect::<Option<Vec<_>>>()?;
+    Some(format!("::<{}>", labels.join(", ")))
+}
+
+/// Returns the name of the type, or `None` if it has not been inferred.
+pub(crate) fn type_label(engines: &Engines, type_id: TypeId) -> Option<String> {
+    match &*engines.te().get(type_id) {
+        TypeInfo::Unknown | TypeInfo::UnknownGeneric { .. } => None,
+        _ => Some(format!("{}", engines.help_out(type_id))),
+    }
+}
+
+/// Returns the position at which the hint is rendered.
+fn hint_position(inlay_hint: &InlayHint) -> Position {
+    match inlay_hint.kind {
+        // before the argument
+        InlayKind::ParameterHint => inlay_hint.range.start,
+        // after annotated thing
+        InlayKind::TypeHint
+        | InlayKind::ChainingHint
+        | InlayKind::GenericHint
+        | InlayKind::PurityHint => inlay_hint.range.end,
+    }
+}
+
+/// Shortens the label to `max_length` characters, replacing the rest with an ellipsis.
+fn truncate(label: String, max_length: Option<usize>) -> String {
+    match max_length {
+        Some(max_length) if label.chars().count() > max_length => {
+            let mut truncated: String = label.chars().take(max_length).collect();
+            truncated.push('…');
+            truncated
+        }
+        _ => label,
+    }
+}
+
+fn inlay_hint(config: &InlayHintsConfig, inlay_hint: InlayHint) -> lsp_types::InlayHint {
+    let render_colons = config.render_colons;
+    let position = hint_position(&inlay_hint);
+    let label = match inlay_hint.kind {
+        InlayKind::TypeHint | InlayKind::ChainingHint | InlayKind::GenericHint => {
+            truncate(inlay_hint.label, config.max_length)
+        }
+        InlayKind::ParameterHint | InlayKind::PurityHint => inlay_hint.label,
+    };
     lsp_types::InlayHint {
-        position: match inlay_hint.kind {
-            // after annotated thing
-            InlayKind::TypeHint => inlay_hint.range.end,
-        },
+        position,
         label: lsp_types::InlayHintLabel::String(match inlay_hint.kind {
-            InlayKind::TypeHint if render_colons => format!(": {}", inlay_hint.label),
-            _ => inlay_hint.label,
+            InlayKind::TypeHint | InlayKind::ChainingHint if render_colons => format!(": {label}"),
+            InlayKind::ParameterHint if render_colons => format!("{label}:"),
+            _ => label,
         }),
         kind: match inlay_hint.kind {
-            InlayKind::TypeHint => Some(lsp_types::InlayHintKind::TYPE),
+            InlayKind::TypeHint | InlayKind::ChainingHint | InlayKind::GenericHint => {
+                Some(lsp_types::InlayHintKind::TYPE)
+            }
+            InlayKind::ParameterHint => Some(lsp_types::InlayHintKind::PARAMETER),
+            InlayKind::PurityHint => None,
         },
         tooltip: None,
         padding_left: Some(match inlay_hint.kind {
-            InlayKind::TypeHint => !render_colons,
+            InlayKind::TypeHint | InlayKind::ChainingHint => !render_colons,
+            InlayKind::PurityHint => true,
+            InlayKind::ParameterHint | InlayKind::GenericHint => false,
         }),
         padding_right: Some(match inlay_hint.kind {
-            InlayKind::TypeHint => false,
+            InlayKind::ParameterHint => true,
+            InlayKind::TypeHint
+            | InlayKind::ChainingHint
+            | InlayKind::GenericHint
+            | InlayKind::PurityHint => false,
         }),
         text_edits: None,
         data: None,
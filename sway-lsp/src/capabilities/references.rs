@@ -0,0 +1,42 @@
+use crate::{
+    core::{session::Session, token_map::TokenMapExt},
+    utils::document::get_url_from_path,
+};
+use lsp_types::{Location, Position, Url};
+use std::sync::Arc;
+
+/// Returns the locations of all references to the token at the given position, across every
+/// module of the workspace and its path dependencies.
+///
+/// Dependencies fetched by forc only contribute their declarations to the token map, so the
+/// references made from within them aren't found.
+pub fn references(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let engines = session.engines.read();
+    let t = session.token_map().token_at_position(url, position)?;
+    let token = t.value();
+    let decl_ident = token.declared_token_ident(&engines);
+
+    let mut locations: Vec<Location> = session
+        .token_map()
+        .iter()
+        .all_references_of_token(token, &engines)
+        .filter_map(|item| {
+            let ident = item.key();
+            if !include_declaration && Some(ident) == decl_ident.as_ref() {
+                return None;
+            }
+            let url = get_url_from_path(ident.path.as_ref()?).ok()?;
+            let url = session.sync.to_workspace_url(url)?;
+            Some(Location::new(url, ident.range))
+        })
+        .collect();
+
+    locations.sort_by(|a, b| (a.uri.as_str(), a.range.start).cmp(&(b.uri.as_str(), b.range.start)));
+    locations.dedup();
+    Some(locations)
+}
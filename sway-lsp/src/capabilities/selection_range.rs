@@ -0,0 +1,76 @@
+//! Selection ranges, i.e., the ranges an editor successively selects to expand the selection
+//! around the cursor.
+//!
+//! Each range is the token at the cursor or a node of the syntax tree around it, so that the
+//! selection grows from an ident to its path, expression, statement, block and item.
+
+use crate::{
+    core::{session::Session, token::get_range_from_span},
+    utils::syntax_tree::{parse_module, syntax_nodes},
+};
+use lsp_types::{Position, Range, SelectionRange, Url};
+use std::sync::Arc;
+use sway_ast::token::{TokenStream, TokenTree};
+use sway_error::handler::Handler;
+use sway_types::{Span, Spanned};
+
+/// Returns the [SelectionRange] of each of the positions, i.e., the innermost range around the
+/// position along with the ranges around it as its parents.
+pub fn selection_ranges(
+    session: Arc<Session>,
+    uri: &Url,
+    positions: &[Position],
+) -> Option<Vec<SelectionRange>> {
+    let document = session.get_text_document(uri).ok()?;
+    let text = document.get_text();
+    let src: Arc<str> = Arc::from(text.as_str());
+
+    let mut spans: Vec<Span> = vec![];
+    if let Ok(token_stream) = sway_parse::lex(&Handler::default(), &src, 0, src.len(), None) {
+        collect_token_spans(&token_stream, &mut spans);
+    }
+    if let Some(module) = parse_module(&text) {
+        spans.extend(
+            syntax_nodes(&module.value)
+                .into_iter()
+                .map(|node| node.span),
+        );
+    }
+
+    let ranges = positions
+        .iter()
+        .map(|position| {
+            let offset = document.position_to_offset(*position);
+            let mut around = spans
+                .iter()
+                .filter(|span| span.start() <= offset && offset < span.end())
+                .map(|span| (span.start(), span.end(), span))
+                .collect::<Vec<_>>();
+            // Innermost first, with a single range for the nodes sharing the same text.
+            around.sort_by_key(|(start, end, _)| (end - start, *start));
+            around.dedup_by_key(|(start, end, _)| (*start, *end));
+            around.into_iter().rev().fold(
+                SelectionRange {
+                    range: Range::new(*position, *position),
+                    parent: None,
+                },
+                |parent, (.., span)| SelectionRange {
+                    range: get_range_from_span(span),
+                    parent: (parent.range.start != parent.range.end).then(|| Box::new(parent)),
+                },
+            )
+        })
+        .collect();
+    Some(ranges)
+}
+
+/// Collects the spans of the tokens of the token stream, and of the groups within it along with
+/// their tokens.
+fn collect_token_spans(token_stream: &TokenStream, spans: &mut Vec<Span>) {
+    for tree in token_stream.token_trees() {
+        spans.push(tree.span());
+        if let TokenTree::Group(group) = tree {
+            collect_token_spans(&group.token_stream, spans);
+        }
+    }
+}
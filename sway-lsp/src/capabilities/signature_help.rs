@@ -0,0 +1,552 @@
+//! Signature help, i.e., the parameters of the function being called while its arguments are
+//! typed.
+//!
+//! The call is found in the text of the document, as the program usually doesn't compile while
+//! the arguments are being typed. The called function is then resolved from the token map, which
+//! is only updated once the program compiles again.
+
+use crate::core::{
+    session::Session,
+    token::{Token, TypedAstToken},
+};
+use lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, Position,
+    SignatureHelp, SignatureInformation, Url,
+};
+use std::{collections::BTreeMap, sync::Arc};
+use sway_core::{
+    language::ty::{self, TyFunctionDecl, TyFunctionParameter, TyTraitFn},
+    transform, Engines, TypeArgument, TypeParameter,
+};
+use sway_types::{
+    constants::{
+        CONTRACT_CALL_ASSET_ID_PARAMETER_NAME, CONTRACT_CALL_COINS_PARAMETER_NAME,
+        CONTRACT_CALL_GAS_PARAMETER_NAME,
+    },
+    Spanned,
+};
+
+/// The special parameters of contract calls, passed in braces before the arguments, and their
+/// types.
+const CONTRACT_CALL_PARAMETERS: [(&str, &str); 3] = [
+    (CONTRACT_CALL_GAS_PARAMETER_NAME, "u64"),
+    (CONTRACT_CALL_COINS_PARAMETER_NAME, "u64"),
+    (CONTRACT_CALL_ASSET_ID_PARAMETER_NAME, "b256"),
+];
+
+pub fn signature_help(
+    session: Arc<Session>,
+    uri: &Url,
+    position: Position,
+) -> Option<SignatureHelp> {
+    let document = session.get_text_document(uri).ok()?;
+    let text = document.get_text();
+    let offset = document.position_to_offset(position).min(text.len());
+    let call = CallSite::enclosing(text.get(..offset)?)?;
+    let callee_position = document.offset_to_position(call.callee_start);
+
+    let engines = session.engines.read();
+    let signatures: Vec<SignatureInformation> =
+        called_functions(&session, &engines, uri, callee_position, &call)
+            .iter()
+            .map(|function| function.signature_information(&call))
+            .collect();
+    if signatures.is_empty() {
+        return None;
+    }
+    Some(SignatureHelp {
+        signatures,
+        active_signature: Some(0),
+        active_parameter: None,
+    })
+}
+
+/// Returns the functions that may be called at the given position.
+///
+/// If the call has been type-checked, this is the function it calls. Otherwise, this is every
+/// function declared with the same name.
+fn called_functions(
+    session: &Session,
+    engines: &Engines,
+    uri: &Url,
+    callee_position: Position,
+    call: &CallSite,
+) -> Vec<CalledFunction> {
+    let token_map = session.token_map();
+    let token = token_map
+        .token_at_position(uri, callee_position)
+        .map(|item| item.value().clone());
+    if let Some(token) = token {
+        if let Some(TypedAstToken::TypedExpression(ty::TyExpression {
+            expression: ty::TyExpressionVariant::FunctionApplication { fn_ref, .. },
+            ..
+        })) = &token.typed
+        {
+            let fn_decl = engines.de().get_function(fn_ref);
+            return vec![CalledFunction::from_fn_decl(engines, &fn_decl)];
+        }
+        let declaration = token
+            .declared_token_ident(engines)
+            .and_then(|decl_ident| token_map.try_get(&decl_ident).try_unwrap())
+            .map(|item| item.value().clone());
+        if let Some(function) = declaration
+            .and_then(|declaration| CalledFunction::from_declaration(engines, &declaration))
+        {
+            return vec![function];
+        }
+    }
+
+    // Key the functions by their signature, to show each one once.
+    let functions: BTreeMap<String, CalledFunction> = token_map
+        .tokens_for_name(&call.callee)
+        .filter(|item| item.value().declared_token_ident(engines).as_ref() == Some(item.key()))
+        .filter_map(|item| CalledFunction::from_declaration(engines, item.value()))
+        .filter(|function| !call.is_method || function.has_self())
+        .map(|function| (function.signature_information(call).label, function))
+        .collect();
+    functions.into_values().collect()
+}
+
+/// A call enclosing the cursor, found in the text before it.
+#[derive(Debug, PartialEq, Eq)]
+struct CallSite {
+    /// The name of the called function.
+    callee: String,
+    /// The byte offset of the start of the callee's name.
+    callee_start: usize,
+    /// Whether the function is called as a method, i.e., `receiver.callee(..)`.
+    is_method: bool,
+    /// Whether the call passes contract call parameters in braces, i.e., `callee { gas: .. }(..)`.
+    has_contract_call_parameters: bool,
+    /// The argument or parameter being typed.
+    active: ActiveArgument,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ActiveArgument {
+    /// The index of the argument in the parentheses.
+    Argument(usize),
+    /// The contract call parameter in the braces, by the part of its name typed so far.
+    ContractCallParameter(String),
+}
+
+/// A group of tokens in delimiters, opened before the cursor and not yet closed.
+struct OpenGroup {
+    delimiter: char,
+    /// The byte offset of the opening delimiter.
+    start: usize,
+    /// The number of commas directly within the group.
+    commas: usize,
+    /// The byte offset of the start of the last comma-separated item of the group.
+    item_start: usize,
+}
+
+impl CallSite {
+    /// Find the innermost call whose arguments or contract call parameters enclose the end of
+    /// `text`.
+    fn enclosing(text: &str) -> Option<CallSite> {
+        let (open_groups, closed_groups) = scan_groups(text);
+        for group in open_groups.iter().rev() {
+            match group.delimiter {
+                '(' => {
+                    if let Some(call) = Self::before_arguments(text, group, &closed_groups) {
+                        return Some(call);
+                    }
+                }
+                '{' => {
+                    // Either the contract call parameters of a method, or a block, in which case
+                    // no call encloses the cursor.
+                    let (callee, callee_start) = ident_ending_at(text, group.start)?;
+                    if !text[..callee_start].trim_end().ends_with('.') {
+                        return None;
+                    }
+                    let item = &text[group.item_start..];
+                    let name = item.split(':').next().unwrap_or_default().trim();
+                    return Some(CallSite {
+                        callee: callee.to_string(),
+                        callee_start,
+                        is_method: true,
+                        has_contract_call_parameters: true,
+                        active: ActiveArgument::ContractCallParameter(name.to_string()),
+                    });
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Returns the call whose arguments are in `group`, if it's the parentheses of a call rather
+    /// than, e.g., of a tuple or a condition.
+    fn before_arguments(
+        text: &str,
+        group: &OpenGroup,
+        closed_groups: &BTreeMap<usize, usize>,
+    ) -> Option<CallSite> {
+        let mut end = text[..group.start].trim_end().len();
+        let mut has_contract_call_parameters = false;
+        if text[..end].ends_with('}') {
+            let open = closed_groups.get(&(end - 1))?;
+            end = text[..*open].trim_end().len();
+            has_contract_call_parameters = true;
+        }
+        // Skip the type arguments of a turbofish, i.e., `callee::<T>(..)`.
+        if text[..end].ends_with('>') {
+            let mut depth = 0;
+            let open = text[..end].char_indices().rev().find(|(_, c)| {
+                match c {
+                    '>' => depth += 1,
+                    '<' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })?;
+            end = text[..open.0].strip_suffix("::")?.len();
+        }
+        let (callee, callee_start) = ident_ending_at(text, end)?;
+        if sway_parse::RESERVED_KEYWORDS.contains(callee) {
+            return None;
+        }
+        let before_callee = text[..callee_start].trim_end();
+        // The parameters of a function declaration.
+        if ident_ending_at(text, before_callee.len()).map(|(word, _)| word) == Some("fn") {
+            return None;
+        }
+        Some(CallSite {
+            callee: callee.to_string(),
+            callee_start,
+            is_method: before_callee.ends_with('.'),
+            has_contract_call_parameters,
+            active: ActiveArgument::Argument(group.commas),
+        })
+    }
+}
+
+/// Returns the groups of `text` which are still open at its end, from the outermost, and the
+/// byte offsets of the closing delimiters of the other groups mapped to those of their opening
+/// delimiters.
+///
+/// Delimiters within string literals and comments are ignored.
+fn scan_groups(text: &str) -> (Vec<OpenGroup>, BTreeMap<usize, usize>) {
+    let mut open_groups: Vec<OpenGroup> = vec![];
+    let mut closed_groups = BTreeMap::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((ix, c)) = chars.next() {
+        match c {
+            '"' => {
+                while let Some((_, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek().map(|(_, c)| *c) == Some('/') => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek().map(|(_, c)| *c) == Some('*') => {
+                chars.next();
+                let mut depth = 1;
+                while let Some((_, c)) = chars.next() {
+                    match (c, chars.peek().map(|(_, c)| *c)) {
+                        ('*', Some('/')) => {
+                            chars.next();
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        ('/', Some('*')) => {
+                            chars.next();
+                            depth += 1;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            '(' | '[' | '{' => open_groups.push(OpenGroup {
+                delimiter: c,
+                start: ix,
+                commas: 0,
+                item_start: ix + 1,
+            }),
+            ')' | ']' | '}' => {
+                if let Some(group) = open_groups.pop() {
+                    closed_groups.insert(ix, group.start);
+                }
+            }
+            ',' => {
+                if let Some(group) = open_groups.last_mut() {
+                    group.commas += 1;
+                    group.item_start = ix + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    (open_groups, closed_groups)
+}
+
+/// Returns the identifier ending at the byte offset `end` of `text`, ignoring any whitespace
+/// before `end`, along with the byte offset of its start.
+fn ident_ending_at(text: &str, end: usize) -> Option<(&str, usize)> {
+    let text = text[..end].trim_end();
+    let start = text
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map(|(ix, _)| ix)?;
+    let ident = &text[start..];
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((ident, start))
+}
+
+/// The parts of the declaration of a function shown in its signature.
+struct CalledFunction {
+    name: String,
+    type_parameters: Vec<String>,
+    /// The names and types of the parameters, the type being empty for `self`.
+    parameters: Vec<(String, String)>,
+    return_type: Option<String>,
+    is_contract_call: bool,
+    documentation: Option<String>,
+}
+
+impl CalledFunction {
+    fn from_declaration(engines: &Engines, token: &Token) -> Option<CalledFunction> {
+        match token.typed.as_ref()? {
+            TypedAstToken::TypedFunctionDeclaration(fn_decl) => {
+                Some(Self::from_fn_decl(engines, fn_decl))
+            }
+            TypedAstToken::TypedDeclaration(ty::TyDecl::FunctionDecl(ty::FunctionDecl {
+                decl_id,
+                ..
+            })) => Some(Self::from_fn_decl(
+                engines,
+                &engines.de().get_function(decl_id),
+            )),
+            TypedAstToken::TypedTraitFn(trait_fn) => Some(Self::from_trait_fn(engines, trait_fn)),
+            _ => None,
+        }
+    }
+
+    fn from_fn_decl(engines: &Engines, fn_decl: &TyFunctionDecl) -> CalledFunction {
+        // The type parameters of the function, and not those of its `impl` block.
+        let type_parameters = fn_decl
+            .type_parameters
+            .iter()
+            .filter(|type_param| {
+                let span = type_param.name_ident.span();
+                span.source_id() == fn_decl.span.source_id()
+                    && span.start() >= fn_decl.span.start()
+                    && span.end() <= fn_decl.span.end()
+            })
+            .collect::<Vec<_>>();
+        CalledFunction {
+            name: fn_decl.name.as_str().to_string(),
+            type_parameters: type_parameter_names(&type_parameters),
+            parameters: parameters(&fn_decl.parameters),
+            return_type: return_type(engines, &fn_decl.return_type),
+            is_contract_call: fn_decl.is_contract_call,
+            documentation: doc_comment(&fn_decl.attributes),
+        }
+    }
+
+    fn from_trait_fn(engines: &Engines, trait_fn: &TyTraitFn) -> CalledFunction {
+        CalledFunction {
+            name: trait_fn.name.as_str().to_string(),
+            type_parameters: vec![],
+            parameters: parameters(&trait_fn.parameters),
+            return_type: return_type(engines, &trait_fn.return_type),
+            is_contract_call: false,
+            documentation: doc_comment(&trait_fn.attributes),
+        }
+    }
+
+    fn has_self(&self) -> bool {
+        self.parameters
+            .first()
+            .map(|(name, type_str)| name == "self" && type_str.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Returns the signature of the function, with the parameter being typed at `call` active.
+    fn signature_information(&self, call: &CallSite) -> SignatureInformation {
+        let mut label = format!("fn {}", self.name);
+        if !self.type_parameters.is_empty() {
+            label.push_str(&format!("<{}>", self.type_parameters.join(", ")));
+        }
+
+        let mut parameters = vec![];
+        let mut active_parameter = None;
+        if self.is_contract_call || call.has_contract_call_parameters {
+            label.push_str(" { ");
+            for (ix, (name, type_str)) in CONTRACT_CALL_PARAMETERS.iter().enumerate() {
+                if ix > 0 {
+                    label.push_str(", ");
+                }
+                if let ActiveArgument::ContractCallParameter(typed) = &call.active {
+                    if active_parameter.is_none() && !typed.is_empty() && name.starts_with(typed) {
+                        active_parameter = Some(parameters.len() as u32);
+                    }
+                }
+                parameters.push(push_parameter(&mut label, &format!("{name}: {type_str}")));
+            }
+            label.push_str(" }");
+        }
+
+        label.push('(');
+        let mut argument_ix = 0;
+        for (ix, (name, type_str)) in self.parameters.iter().enumerate() {
+            if ix > 0 {
+                label.push_str(", ");
+            }
+            let is_self = type_str.is_empty();
+            if is_self && call.is_method {
+                // The receiver of a method call isn't among its arguments.
+                label.push_str(name);
+                continue;
+            }
+            if call.active == ActiveArgument::Argument(argument_ix) {
+                active_parameter = Some(parameters.len() as u32);
+            }
+            let parameter = match is_self {
+                true => name.clone(),
+                false => format!("{name}: {type_str}"),
+            };
+            parameters.push(push_parameter(&mut label, &parameter));
+            argument_ix += 1;
+        }
+        label.push(')');
+        if let Some(return_type) = &self.return_type {
+            label.push_str(&format!(" -> {return_type}"));
+        }
+
+        SignatureInformation {
+            label,
+            documentation: self.documentation.as_ref().map(|doc| {
+                Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: doc.clone(),
+                })
+            }),
+            parameters: Some(parameters),
+            active_parameter,
+        }
+    }
+}
+
+/// Appends the label of a parameter to the label of a signature, returning the parameter
+/// information, which refers to it by its offsets in UTF-16 code units.
+fn push_parameter(label: &mut String, parameter: &str) -> ParameterInformation {
+    let start = label.encode_utf16().count() as u32;
+    label.push_str(parameter);
+    let end = label.encode_utf16().count() as u32;
+    ParameterInformation {
+        label: ParameterLabel::LabelOffsets([start, end]),
+        documentation: None,
+    }
+}
+
+fn type_parameter_names(type_parameters: &[&TypeParameter]) -> Vec<String> {
+    type_parameters
+        .iter()
+        .map(|type_param| type_param.name_ident.as_str().to_string())
+        .collect()
+}
+
+fn parameters(parameters: &[TyFunctionParameter]) -> Vec<(String, String)> {
+    parameters
+        .iter()
+        .map(|param| {
+            let type_str = match param.is_self() {
+                true => String::new(),
+                false => param.type_argument.span.as_str().to_string(),
+            };
+            (param.name.as_str().to_string(), type_str)
+        })
+        .collect()
+}
+
+/// Returns the return type as written in the declaration, or `None` if it's the unit type.
+fn return_type(engines: &Engines, return_type: &TypeArgument) -> Option<String> {
+    let type_str = match return_type.span.as_str() {
+        "" => engines.help_out(return_type.type_id).to_string(),
+        type_str => type_str.to_string(),
+    };
+    (type_str != "()").then_some(type_str)
+}
+
+fn doc_comment(attributes: &transform::AttributesMap) -> Option<String> {
+    let doc_comment = attributes
+        .get(&transform::AttributeKind::DocComment)?
+        .iter()
+        .filter_map(|attribute| attribute.args.first())
+        .fold(String::new(), |output, arg| {
+            format!("{output}{}\n", arg.name.as_str())
+        });
+    Some(doc_comment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call_at_end(text: &str) -> Option<CallSite> {
+        CallSite::enclosing(text)
+    }
+
+    #[test]
+    fn test_call_site_arguments() {
+        let text = "fn main() {\n    let x = add(1, foo(2), ";
+        let call = call_at_end(text).unwrap();
+        assert_eq!(call.callee, "add");
+        assert_eq!(&text[call.callee_start..call.callee_start + 3], "add");
+        assert!(!call.is_method);
+        assert_eq!(call.active, ActiveArgument::Argument(2));
+
+        let call = call_at_end("    let x = add(1, foo(2, (3, ").unwrap();
+        assert_eq!(call.callee, "foo");
+        assert_eq!(call.active, ActiveArgument::Argument(1));
+
+        let call = call_at_end("    p.scale::<u64>(\"a, (b\", ").unwrap();
+        assert_eq!(call.callee, "scale");
+        assert!(call.is_method);
+        assert_eq!(call.active, ActiveArgument::Argument(1));
+    }
+
+    #[test]
+    fn test_call_site_contract_call_parameters() {
+        let call = call_at_end("    counter.increment { gas: 1000, coi").unwrap();
+        assert_eq!(call.callee, "increment");
+        assert!(call.has_contract_call_parameters);
+        assert_eq!(
+            call.active,
+            ActiveArgument::ContractCallParameter("coi".to_string())
+        );
+
+        let call = call_at_end("    counter.increment { gas: 1000 }(1, ").unwrap();
+        assert_eq!(call.callee, "increment");
+        assert!(call.has_contract_call_parameters);
+        assert_eq!(call.active, ActiveArgument::Argument(1));
+    }
+
+    #[test]
+    fn test_call_site_outside_calls() {
+        assert_eq!(call_at_end("fn main() {\n    let x = 1"), None);
+        assert_eq!(call_at_end("fn add(a: u64, "), None);
+        assert_eq!(call_at_end("    if (a"), None);
+        assert_eq!(call_at_end("    let x = (1, "), None);
+        assert_eq!(call_at_end("    foo(1) // bar("), None);
+    }
+}
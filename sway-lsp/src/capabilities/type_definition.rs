@@ -0,0 +1,84 @@
+use crate::{
+    core::{
+        session::Session,
+        token::{ident_of_type_id, Token, TokenIdent, TypedAstToken},
+    },
+    utils::document::get_url_from_path,
+};
+use lsp_types::{request::GotoTypeDefinitionResponse, Location, Position, Url};
+use std::sync::Arc;
+use sway_core::{
+    language::ty::{self, TyDecl},
+    type_system::{AbiName, TypeId, TypeInfo},
+    Engines,
+};
+
+/// Returns the location of the declaration of the type of the variable, parameter, field or
+/// expression at the given position.
+///
+/// The type of an array, reference or pointer is that of its elements, and the type of a contract
+/// caller, i.e., the result of `abi(..)`, is its ABI.
+pub fn type_definition(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<GotoTypeDefinitionResponse> {
+    let engines = session.engines.read();
+    let type_id = {
+        let item = session.token_map().token_at_position(url, position)?;
+        type_of_token(&engines, item.value())?
+    };
+    let decl_ident = type_declaration(&session, &engines, type_id)?;
+    let url = get_url_from_path(decl_ident.path.as_ref()?).ok()?;
+    let url = session.sync.to_workspace_url(url)?;
+    Some(GotoTypeDefinitionResponse::Scalar(Location::new(
+        url,
+        decl_ident.range,
+    )))
+}
+
+/// Returns the type of the value the token refers to.
+fn type_of_token(engines: &Engines, token: &Token) -> Option<TypeId> {
+    match token.typed.as_ref()? {
+        TypedAstToken::TypedDeclaration(decl) => match decl {
+            TyDecl::VariableDecl(var_decl) => Some(var_decl.return_type),
+            TyDecl::ConstantDecl(ty::ConstantDecl { decl_id, .. }) => {
+                Some(engines.de().get_constant(decl_id).return_type)
+            }
+            _ => None,
+        },
+        TypedAstToken::TypedConstantDeclaration(const_decl) => Some(const_decl.return_type),
+        TypedAstToken::TypedExpression(expr) => Some(expr.return_type),
+        TypedAstToken::TypedFunctionParameter(param) => Some(param.type_argument.type_id),
+        TypedAstToken::TypedStructField(field) => Some(field.type_argument.type_id),
+        TypedAstToken::TypedStorageField(field) => Some(field.type_argument.type_id),
+        TypedAstToken::TypedArgument(type_arg) => Some(type_arg.type_id),
+        TypedAstToken::TypedFunctionDeclaration(fn_decl) => Some(fn_decl.return_type.type_id),
+        TypedAstToken::TypedReassignment(reassignment) => Some(reassignment.lhs_type),
+        _ => None,
+    }
+}
+
+/// Returns the ident of the name of the declaration of the type.
+fn type_declaration(session: &Session, engines: &Engines, type_id: TypeId) -> Option<TokenIdent> {
+    match &*engines.te().get(type_id) {
+        TypeInfo::Array(elem, _)
+        | TypeInfo::Ref(elem)
+        | TypeInfo::Ptr(elem)
+        | TypeInfo::Slice(elem) => type_declaration(session, engines, elem.type_id),
+        TypeInfo::ContractCaller {
+            abi_name: AbiName::Known(call_path),
+            ..
+        } => session
+            .token_map()
+            .tokens_for_name(&call_path.suffix.as_str().to_string())
+            .find(|item| {
+                matches!(
+                    item.value().typed,
+                    Some(TypedAstToken::TypedDeclaration(TyDecl::AbiDecl(_)))
+                ) && item.value().declared_token_ident(engines).as_ref() == Some(item.key())
+            })
+            .map(|item| item.key().clone()),
+        _ => ident_of_type_id(engines, &type_id),
+    }
+}
@@ -0,0 +1,336 @@
+//! Supertraits and implementors of traits and ABIs, and the traits and ABIs implemented by types.
+//!
+//! Types implementing a trait or an ABI without being declared in the program, e.g., `u64` or
+//! `Contract`, are represented by the items of their `impl` blocks.
+
+use crate::{
+    core::{
+        session::Session,
+        token::{ident_of_type_id, Token, TokenIdent, TypedAstToken},
+    },
+    utils::document::get_url_from_path,
+};
+use lsp_types::{Position, SymbolKind, TypeHierarchyItem, Url};
+use std::sync::Arc;
+use sway_core::{
+    decl_engine::InterfaceDeclId,
+    language::{parsed::Supertrait, ty},
+    Engines,
+};
+use sway_types::{Ident, Span, Spanned};
+
+/// Returns the trait, ABI, struct or enum declared or referred to at the given position.
+pub fn prepare_type_hierarchy(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let engines = session.engines.read();
+    let decl_ident = session
+        .token_map()
+        .token_at_position(url, position)?
+        .declared_token_ident(&engines)?;
+    let decl = declaration(&session, &engines, &decl_ident)?;
+    Some(vec![decl.type_hierarchy_item(&session, &engines)?])
+}
+
+/// Returns the supertraits or superABIs of the trait or ABI of `item`, or the traits and ABIs
+/// implemented by its type.
+pub fn supertypes(
+    session: Arc<Session>,
+    url: &Url,
+    item: &TypeHierarchyItem,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let engines = session.engines.read();
+    let decl = item_declaration(&session, &engines, url, item.selection_range.start)?;
+    let supertypes: Vec<HierarchyDecl> = match &decl {
+        HierarchyDecl::Trait(trait_decl) => trait_decl
+            .supertraits
+            .iter()
+            .filter_map(|supertrait| resolve_supertrait(&session, &engines, supertrait))
+            .collect(),
+        HierarchyDecl::Abi(abi_decl) => abi_decl
+            .supertraits
+            .iter()
+            .filter_map(|supertrait| resolve_supertrait(&session, &engines, supertrait))
+            .collect(),
+        HierarchyDecl::Type { .. } => impls(&session, &engines)
+            .into_iter()
+            .filter(|impl_trait| {
+                ident_of_type_id(&engines, &impl_trait.implementing_for.type_id).as_ref()
+                    == Some(&decl.ident(&engines))
+            })
+            .filter_map(|impl_trait| interface_of_impl(&engines, &impl_trait))
+            .collect(),
+        HierarchyDecl::Impl(impl_trait) => interface_of_impl(&engines, impl_trait)
+            .into_iter()
+            .collect(),
+    };
+    Some(type_hierarchy_items(&session, &engines, supertypes))
+}
+
+/// Returns the traits and ABIs with the trait or ABI of `item` as a supertrait or superABI, and
+/// the types implementing it.
+pub fn subtypes(
+    session: Arc<Session>,
+    url: &Url,
+    item: &TypeHierarchyItem,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let engines = session.engines.read();
+    let decl = item_declaration(&session, &engines, url, item.selection_range.start)?;
+    if !matches!(decl, HierarchyDecl::Trait(_) | HierarchyDecl::Abi(_)) {
+        return Some(vec![]);
+    }
+    let decl_ident = decl.ident(&engines);
+
+    let mut subtypes: Vec<HierarchyDecl> = declarations(&session, &engines)
+        .into_iter()
+        .filter(|other| {
+            let supertraits = match other {
+                HierarchyDecl::Trait(trait_decl) => &trait_decl.supertraits,
+                HierarchyDecl::Abi(abi_decl) => &abi_decl.supertraits,
+                _ => return false,
+            };
+            supertraits.iter().any(|supertrait| {
+                resolve_supertrait(&session, &engines, supertrait)
+                    .map(|supertrait| supertrait.ident(&engines))
+                    .as_ref()
+                    == Some(&decl_ident)
+            })
+        })
+        .collect();
+    for impl_trait in impls(&session, &engines) {
+        let implements_decl = interface_of_impl(&engines, &impl_trait)
+            .map(|interface| interface.ident(&engines))
+            .as_ref()
+            == Some(&decl_ident);
+        if !implements_decl {
+            continue;
+        }
+        let implementing_type = ident_of_type_id(&engines, &impl_trait.implementing_for.type_id)
+            .and_then(|type_ident| declaration(&session, &engines, &type_ident));
+        subtypes.push(implementing_type.unwrap_or(HierarchyDecl::Impl(impl_trait)));
+    }
+    Some(type_hierarchy_items(&session, &engines, subtypes))
+}
+
+/// A declaration in a type hierarchy.
+enum HierarchyDecl {
+    Trait(Arc<ty::TyTraitDecl>),
+    Abi(Arc<ty::TyAbiDecl>),
+    /// A struct or an enum.
+    Type {
+        name: Ident,
+        span: Span,
+        kind: SymbolKind,
+    },
+    /// An implementation of a trait or an ABI for a type which isn't declared in the program.
+    Impl(Arc<ty::TyImplTrait>),
+}
+
+impl HierarchyDecl {
+    fn from_token(engines: &Engines, token: &Token) -> Option<HierarchyDecl> {
+        let decl = match token.typed.as_ref()? {
+            TypedAstToken::TypedDeclaration(decl) => decl,
+            _ => return None,
+        };
+        match decl {
+            ty::TyDecl::TraitDecl(ty::TraitDecl { decl_id, .. }) => {
+                Some(HierarchyDecl::Trait(engines.de().get_trait(decl_id)))
+            }
+            ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. }) => {
+                Some(HierarchyDecl::Abi(engines.de().get_abi(decl_id)))
+            }
+            ty::TyDecl::StructDecl(ty::StructDecl { decl_id, .. }) => {
+                let struct_decl = engines.de().get_struct(decl_id);
+                Some(HierarchyDecl::Type {
+                    name: struct_decl.call_path.suffix.clone(),
+                    span: struct_decl.span.clone(),
+                    kind: SymbolKind::STRUCT,
+                })
+            }
+            ty::TyDecl::EnumDecl(ty::EnumDecl { decl_id, .. }) => {
+                let enum_decl = engines.de().get_enum(decl_id);
+                Some(HierarchyDecl::Type {
+                    name: enum_decl.call_path.suffix.clone(),
+                    span: enum_decl.span.clone(),
+                    kind: SymbolKind::ENUM,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the ident of the name of the declaration, or of the implementing type of an `impl`.
+    fn ident(&self, engines: &Engines) -> TokenIdent {
+        let name = match self {
+            HierarchyDecl::Trait(trait_decl) => &trait_decl.name,
+            HierarchyDecl::Abi(abi_decl) => &abi_decl.name,
+            HierarchyDecl::Type { name, .. } => name,
+            HierarchyDecl::Impl(impl_trait) => {
+                return TokenIdent::new(
+                    &Ident::new(impl_trait.implementing_for.span()),
+                    engines.se(),
+                )
+            }
+        };
+        TokenIdent::new(name, engines.se())
+    }
+
+    fn type_hierarchy_item(
+        &self,
+        session: &Session,
+        engines: &Engines,
+    ) -> Option<TypeHierarchyItem> {
+        let ident = self.ident(engines);
+        let (span, kind, detail) = match self {
+            HierarchyDecl::Trait(trait_decl) => (
+                trait_decl.span.clone(),
+                SymbolKind::INTERFACE,
+                Some("trait".to_string()),
+            ),
+            HierarchyDecl::Abi(abi_decl) => (
+                abi_decl.span.clone(),
+                SymbolKind::INTERFACE,
+                Some("abi".to_string()),
+            ),
+            HierarchyDecl::Type { span, kind, .. } => (span.clone(), *kind, None),
+            HierarchyDecl::Impl(impl_trait) => (
+                impl_trait.span.clone(),
+                SymbolKind::OBJECT,
+                Some(format!(
+                    "impl {} for {}",
+                    impl_trait.trait_name.suffix.as_str(),
+                    ident.name
+                )),
+            ),
+        };
+        let url = get_url_from_path(ident.path.as_ref()?).ok()?;
+        Some(TypeHierarchyItem {
+            name: ident.name.clone(),
+            kind,
+            tags: None,
+            detail,
+            uri: session.sync.to_workspace_url(url)?,
+            range: TokenIdent::new(&Ident::new(span), engines.se()).range,
+            selection_range: ident.range,
+            data: None,
+        })
+    }
+}
+
+/// Returns the declaration whose name is `decl_ident`.
+fn declaration(
+    session: &Session,
+    engines: &Engines,
+    decl_ident: &TokenIdent,
+) -> Option<HierarchyDecl> {
+    session
+        .token_map()
+        .try_get(decl_ident)
+        .try_unwrap()
+        .and_then(|decl| HierarchyDecl::from_token(engines, decl.value()))
+}
+
+/// Returns the declaration of an item at the given position, i.e., at the name of a declaration
+/// or at the implementing type of an `impl` block.
+fn item_declaration(
+    session: &Session,
+    engines: &Engines,
+    url: &Url,
+    position: Position,
+) -> Option<HierarchyDecl> {
+    let decl = session
+        .token_map()
+        .token_at_position(url, position)
+        .and_then(|token| token.declared_token_ident(engines))
+        .and_then(|decl_ident| declaration(session, engines, &decl_ident));
+    if decl.is_some() {
+        return decl;
+    }
+    impls(session, engines)
+        .into_iter()
+        .map(HierarchyDecl::Impl)
+        .find(|decl| {
+            let ident = decl.ident(engines);
+            ident.range.start == position
+                && ident.path.as_ref().and_then(|path| path.to_str()) == Some(url.path())
+        })
+}
+
+/// Returns every trait, ABI, struct and enum declared in the program or its dependencies.
+fn declarations(session: &Session, engines: &Engines) -> Vec<HierarchyDecl> {
+    session
+        .token_map()
+        .iter()
+        .filter_map(|entry| {
+            let decl = HierarchyDecl::from_token(engines, entry.value())?;
+            (decl.ident(engines) == *entry.key()).then_some(decl)
+        })
+        .collect()
+}
+
+/// Returns every implementation of a trait or an ABI.
+fn impls(session: &Session, engines: &Engines) -> Vec<Arc<ty::TyImplTrait>> {
+    session
+        .token_map()
+        .impl_trait_declarations(engines)
+        .into_iter()
+        .filter(|impl_trait| impl_trait.trait_decl_ref.is_some())
+        .collect()
+}
+
+/// Returns the trait or ABI implemented by `impl_trait`.
+fn interface_of_impl(engines: &Engines, impl_trait: &ty::TyImplTrait) -> Option<HierarchyDecl> {
+    match impl_trait.trait_decl_ref.as_ref()?.id() {
+        InterfaceDeclId::Abi(decl_id) => Some(HierarchyDecl::Abi(engines.de().get_abi(decl_id))),
+        InterfaceDeclId::Trait(decl_id) => {
+            Some(HierarchyDecl::Trait(engines.de().get_trait(decl_id)))
+        }
+    }
+}
+
+/// Returns the declaration of a supertrait or superABI.
+///
+/// SuperABIs aren't resolved by the compiler, so they are looked up by name.
+fn resolve_supertrait(
+    session: &Session,
+    engines: &Engines,
+    supertrait: &Supertrait,
+) -> Option<HierarchyDecl> {
+    if let Some(decl_ref) = &supertrait.decl_ref {
+        return Some(HierarchyDecl::Trait(engines.de().get_trait(decl_ref)));
+    }
+    let name = supertrait.name.suffix.as_str().to_string();
+    let mut candidates: Vec<_> = session
+        .token_map()
+        .tokens_for_name(&name)
+        .filter_map(|entry| {
+            let decl = HierarchyDecl::from_token(engines, entry.value())?;
+            let is_interface = matches!(decl, HierarchyDecl::Trait(_) | HierarchyDecl::Abi(_));
+            (is_interface && decl.ident(engines) == *entry.key()).then_some(decl)
+        })
+        .collect();
+    candidates.sort_by_key(|decl| {
+        let ident = decl.ident(engines);
+        (ident.path, ident.range.start)
+    });
+    candidates.into_iter().next()
+}
+
+/// Returns the items of `decls`, ordered by their location and without duplicates.
+fn type_hierarchy_items(
+    session: &Session,
+    engines: &Engines,
+    decls: Vec<HierarchyDecl>,
+) -> Vec<TypeHierarchyItem> {
+    let mut items: Vec<TypeHierarchyItem> = decls
+        .iter()
+        .filter_map(|decl| decl.type_hierarchy_item(session, engines))
+        .collect();
+    items.sort_by(|a, b| {
+        (a.uri.as_str(), a.selection_range.start).cmp(&(b.uri.as_str(), b.selection_range.start))
+    });
+    items.dedup_by(|a, b| a.uri == b.uri && a.selection_range == b.selection_range);
+    items
+}
@@ -0,0 +1,139 @@
+use crate::{
+    capabilities::document_symbol::symbol_info,
+    core::{
+        session::Session,
+        token::{SymbolKind, Token, TokenIdent},
+    },
+    utils::document::get_url_from_path,
+};
+use lsp_types::SymbolInformation;
+use sway_core::Engines;
+
+/// The maximum number of symbols returned for a single query.
+pub const MAX_WORKSPACE_SYMBOLS: usize = 128;
+
+/// A symbol matching a workspace symbol query, along with how well it matches.
+///
+/// Lower scores are better matches.
+pub struct WorkspaceSymbol {
+    pub score: usize,
+    pub symbol: SymbolInformation,
+}
+
+/// Returns the declarations of the session that fuzzily match the query.
+///
+/// The workspace and its path dependencies are searched, but not the dependencies fetched by
+/// forc, such as the standard library, which would otherwise drown out the symbols of the
+/// workspace.
+pub fn workspace_symbols(session: &Session, query: &str) -> Vec<WorkspaceSymbol> {
+    let engines = session.engines.read();
+    let fetched_dependencies_dir = forc_util::user_forc_directory();
+    session
+        .token_map()
+        .iter()
+        .filter_map(|item| {
+            let (ident, token) = item.pair();
+            let path = ident.path.as_ref()?;
+            if path.starts_with(&fetched_dependencies_dir)
+                || !is_declaration(ident, token, &engines)
+            {
+                return None;
+            }
+            let score = fuzzy_score(query, &ident.name)?;
+            let url = session
+                .sync
+                .to_workspace_url(get_url_from_path(path).ok()?)?;
+            Some(WorkspaceSymbol {
+                score,
+                symbol: symbol_info(ident, token, url),
+            })
+        })
+        .collect()
+}
+
+/// Sort the symbols matching a query from the best to the worst match, removing duplicates, and
+/// keep at most [MAX_WORKSPACE_SYMBOLS] of them.
+pub fn sort_workspace_symbols(mut symbols: Vec<WorkspaceSymbol>) -> Vec<SymbolInformation> {
+    symbols.sort_by(|a, b| {
+        (a.score, &a.symbol.name, a.symbol.location.uri.as_str())
+            .cmp(&(b.score, &b.symbol.name, b.symbol.location.uri.as_str()))
+            .then(
+                a.symbol
+                    .location
+                    .range
+                    .start
+                    .cmp(&b.symbol.location.range.start),
+            )
+    });
+    symbols.dedup_by(|a, b| a.symbol.location == b.symbol.location);
+    symbols
+        .into_iter()
+        .take(MAX_WORKSPACE_SYMBOLS)
+        .map(|symbol| symbol.symbol)
+        .collect()
+}
+
+/// Whether the token is the declaration of an item worth searching for, rather than a reference
+/// to one or a local.
+fn is_declaration(ident: &TokenIdent, token: &Token, engines: &Engines) -> bool {
+    matches!(
+        token.kind,
+        SymbolKind::Const
+            | SymbolKind::Enum
+            | SymbolKind::Field
+            | SymbolKind::Function
+            | SymbolKind::Struct
+            | SymbolKind::Trait
+            | SymbolKind::TraitType
+            | SymbolKind::TypeAlias
+            | SymbolKind::Variant
+    ) && token.declared_token_ident(engines).as_ref() == Some(ident)
+}
+
+/// Returns how well `name` matches `query`, or `None` if it doesn't.
+///
+/// The characters of the query must all appear in the name, in order but not necessarily
+/// contiguously, ignoring case. Exact matches score best, then prefixes, then substrings, and
+/// then other matches by how spread out the matched characters are.
+pub(crate) fn fuzzy_score(query: &str, name: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let name_lower = name.to_lowercase();
+    if query == name_lower {
+        return Some(0);
+    }
+    if name_lower.starts_with(&query) {
+        return Some(1);
+    }
+    if name_lower.contains(&query) {
+        return Some(2);
+    }
+
+    let mut gaps = 0;
+    let mut name_chars = name_lower.chars();
+    for query_char in query.chars() {
+        loop {
+            let name_char = name_chars.next()?;
+            if name_char == query_char {
+                break;
+            }
+            gaps += 1;
+        }
+    }
+    Some(3 + gaps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_ranks_matches() {
+        assert_eq!(fuzzy_score("point", "Point"), Some(0));
+        assert_eq!(fuzzy_score("poi", "Point"), Some(1));
+        assert_eq!(fuzzy_score("int", "Point"), Some(2));
+        assert_eq!(fuzzy_score("dst", "DeepStruct"), Some(6));
+        assert_eq!(fuzzy_score("dt", "DeepStruct"), Some(7));
+        assert_eq!(fuzzy_score("tsp", "Point"), None);
+        assert_eq!(fuzzy_score("", "Point"), Some(1));
+    }
+}
@@ -93,12 +93,20 @@ pub enum Warnings {
 
 // Options for configuring inlay hints.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct InlayHintsConfig {
     /// Whether to render leading colons for type hints, and trailing colons for parameter hints.
     pub render_colons: bool,
     /// Whether to show inlay type hints for variables.
     pub type_hints: bool,
+    /// Whether to show the names of the parameters at the arguments of function and method calls.
+    pub parameter_hints: bool,
+    /// Whether to show the types of the intermediate results of method chains spanning multiple lines.
+    pub chaining_hints: bool,
+    /// Whether to show the inferred generic arguments of calls without explicit ones.
+    pub generic_hints: bool,
+    /// Whether to show the storage access of called functions.
+    pub purity_hints: bool,
     /// Maximum length for inlay hints. Set to null to have an unlimited length.
     pub max_length: Option<usize>,
 }
@@ -108,6 +116,10 @@ impl Default for InlayHintsConfig {
         Self {
             render_colons: true,
             type_hints: true,
+            parameter_hints: true,
+            chaining_hints: true,
+            generic_hints: true,
+            purity_hints: false,
             max_length: Some(25),
         }
     }
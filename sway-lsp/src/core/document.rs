@@ -48,6 +48,16 @@ impl TextDocument {
     pub fn get_text(&self) -> String {
         self.content.to_string()
     }
+
+    /// Returns the byte offset of the given [Position] in the document.
+    pub fn position_to_offset(&self, position: Position) -> usize {
+        self.content.char_to_byte(self.position_to_index(position))
+    }
+
+    /// Returns the [Position] of the given byte offset in the document.
+    pub fn offset_to_position(&self, offset: usize) -> Position {
+        self.byte_to_position(offset)
+    }
 }
 
 // private methods
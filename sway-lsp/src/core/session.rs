@@ -2,7 +2,7 @@ use crate::{
     capabilities::{
         self,
         diagnostic::DiagnosticMap,
-        formatting::get_page_text_edit,
+        formatting::{get_page_text_edit, get_range_text_edit},
         runnable::{Runnable, RunnableMainFn, RunnableTestFn},
     },
     core::{
@@ -40,7 +40,10 @@ use sway_core::{
     BuildTarget, Engines, LspConfig, Namespace, Programs,
 };
 use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
-use sway_types::{SourceEngine, SourceId, Spanned};
+use sway_types::{
+    constants::{CORE, STD},
+    SourceEngine, SourceId, Spanned,
+};
 use sway_utils::{helpers::get_sway_files, PerformanceData};
 use tokio::{fs::File, io::AsyncWriteExt};
 
@@ -223,6 +226,31 @@ impl Session {
             .map(|page_text_edit| vec![page_text_edit])
     }
 
+    /// Formats the items of the document within the range, see [get_range_text_edit].
+    pub fn format_range(
+        &self,
+        url: &Url,
+        range: &Range,
+    ) -> Result<Vec<TextEdit>, LanguageServerError> {
+        let document = self
+            .documents
+            .try_get(url.path())
+            .try_unwrap()
+            .ok_or_else(|| DocumentError::DocumentNotFound {
+                path: url.path().to_string(),
+            })?;
+
+        let start = document.position_to_offset(range.start);
+        let end = document.position_to_offset(range.end);
+        get_range_text_edit(
+            Arc::from(document.get_text()),
+            start,
+            end,
+            &mut <_>::default(),
+        )
+        .map(|range_text_edit| range_text_edit.into_iter().collect())
+    }
+
     pub async fn handle_open_file(&self, uri: &Url) {
         if !self.documents.contains_key(uri.path()) {
             if let Ok(text_document) = TextDocument::build_from_path(uri.path()).await {
@@ -411,25 +439,16 @@ pub fn traverse(
 
         // The final element in the results is the main program.
         if i == results_len - 1 {
-            // First, populate our token_map with sway keywords.
-            lexed_tree::parse(&lexed, &ctx);
-
-            // Next, populate our token_map with un-typed yet parsed ast nodes.
-            let parsed_tree = ParsedTree::new(&ctx);
-            parsed_tree.collect_module_spans(&parsed);
-            parse_ast_to_tokens(&parsed, &ctx, |an, _ctx| parsed_tree.traverse_node(an));
-
-            // Finally, populate our token_map with typed ast nodes.
-            let typed_tree = TypedTree::new(&ctx);
-            typed_tree.collect_module_spans(typed_program);
-            parse_ast_to_typed_tokens(typed_program, &ctx, |node, _ctx| {
-                typed_tree.traverse_node(node)
-            });
+            traverse_program(&lexed, &parsed, typed_program, &ctx);
 
             let compiled_program = &mut *session.compiled_program.write();
             compiled_program.lexed = Some(lexed);
             compiled_program.parsed = Some(parsed);
             compiled_program.typed = Some(typed_program.clone());
+        } else if is_path_dependency(&lexed, typed_program, engines) {
+            // Path dependencies are traversed in full, so that the references made from within
+            // them are found along with those of the workspace.
+            traverse_program(&lexed, &parsed, typed_program, &ctx);
         } else {
             // Collect tokens from dependencies and the standard library prelude.
             parse_ast_to_tokens(&parsed, &ctx, |an, ctx| {
@@ -445,6 +464,55 @@ pub fn traverse(
     Ok(Some(diagnostics))
 }
 
+/// Populate the token map with every token of the program, rather than only its declarations.
+fn traverse_program(
+    lexed: &LexedProgram,
+    parsed: &ParseProgram,
+    typed_program: &ty::TyProgram,
+    ctx: &ParseContext,
+) {
+    // First, populate our token_map with sway keywords.
+    lexed_tree::parse(lexed, ctx);
+
+    // Next, populate our token_map with un-typed yet parsed ast nodes.
+    let parsed_tree = ParsedTree::new(ctx);
+    parsed_tree.collect_module_spans(parsed);
+    parse_ast_to_tokens(parsed, ctx, |an, _ctx| parsed_tree.traverse_node(an));
+
+    // Finally, populate our token_map with typed ast nodes.
+    let typed_tree = TypedTree::new(ctx);
+    typed_tree.collect_module_spans(typed_program);
+    parse_ast_to_typed_tokens(typed_program, ctx, |node, _ctx| {
+        typed_tree.traverse_node(node)
+    });
+}
+
+/// Whether the program belongs to a path dependency, rather than to a dependency fetched by forc
+/// from git, IPFS or a registry into the user's forc directory.
+///
+/// The standard library and core are only path dependencies within the Sway repository itself,
+/// and are treated as fetched dependencies, as traversing them in full is too costly.
+fn is_path_dependency(
+    lexed: &LexedProgram,
+    typed_program: &ty::TyProgram,
+    engines: &Engines,
+) -> bool {
+    let is_library = matches!(
+        &typed_program.root.namespace.module().name,
+        Some(name) if name.as_str() == STD || name.as_str() == CORE
+    );
+    if is_library {
+        return false;
+    }
+    let fetched_dependencies_dir = forc_util::user_forc_directory();
+    lexed.root.tree.span().source_id().is_some_and(|source_id| {
+        !engines
+            .se()
+            .get_path(source_id)
+            .starts_with(fetched_dependencies_dir)
+    })
+}
+
 /// Parses the project and returns true if the compiler diagnostics are new and should be published.
 pub fn parse_project(
     uri: &Url,
@@ -462,8 +530,14 @@ pub fn parse_project(
         // Only write the diagnostics results on didSave or didOpen.
         if !config.optimized_build {
             if let Some((errors, warnings)) = &diagnostics {
-                *session.diagnostics.write() =
+                let mut diagnostics =
                     capabilities::diagnostic::get_diagnostics(warnings, errors, engines.se());
+                capabilities::diagnostic::push_unused_import_diagnostics(
+                    &mut diagnostics,
+                    session.token_map(),
+                    engines.se(),
+                );
+                *session.diagnostics.write() = diagnostics;
             }
         }
     }
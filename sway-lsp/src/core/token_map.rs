@@ -8,7 +8,7 @@ use dashmap::{
     DashMap,
 };
 use lsp_types::{Position, Url};
-use std::{thread, time::Duration};
+use std::{sync::Arc, thread, time::Duration};
 use sway_core::{language::ty, type_system::TypeId, Engines};
 use sway_types::{Ident, SourceEngine, Spanned};
 
@@ -219,6 +219,20 @@ impl<'a> TokenMap {
             })
     }
 
+    /// Returns the declarations of every `impl` block, implementing a trait or an ABI as well as
+    /// inherent ones.
+    pub fn impl_trait_declarations(&self, engines: &Engines) -> Vec<Arc<ty::TyImplTrait>> {
+        self.iter()
+            .filter_map(|entry| match &entry.value().typed {
+                Some(TypedAstToken::TypedDeclaration(ty::TyDecl::ImplTrait(ty::ImplTrait {
+                    decl_id,
+                    ..
+                }))) => Some(engines.de().get_impl_trait(decl_id)),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Returns the [ty::TyStructDecl] associated with the TypeId if it exists
     /// within the TokenMap.
     pub fn struct_declaration_of_type_id(
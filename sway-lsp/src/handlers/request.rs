@@ -6,10 +6,20 @@ use crate::{
 };
 use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions, TracingWriterMode};
 use lsp_types::{
-    CodeLens, CompletionResponse, DocumentFormattingParams, DocumentSymbolResponse,
-    InitializeResult, InlayHint, InlayHintParams, PrepareRenameResponse, RenameParams,
-    SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensRangeResult,
-    SemanticTokensResult, TextDocumentIdentifier, Url, WorkspaceEdit,
+    request::{
+        GotoImplementationParams, GotoImplementationResponse, GotoTypeDefinitionParams,
+        GotoTypeDefinitionResponse,
+    },
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams,
+    CodeLens, CompletionResponse, DocumentFormattingParams, DocumentRangeFormattingParams,
+    DocumentSymbolResponse, FoldingRange, FoldingRangeParams, InitializeResult, InlayHint,
+    InlayHintParams, Location, PrepareRenameResponse, ReferenceParams, RenameParams,
+    SelectionRange, SelectionRangeParams, SemanticTokensParams, SemanticTokensRangeParams,
+    SemanticTokensRangeResult, SemanticTokensResult, SignatureHelp, SignatureHelpParams,
+    SymbolInformation, TextDocumentIdentifier, TypeHierarchyItem, TypeHierarchyPrepareParams,
+    TypeHierarchySubtypesParams, TypeHierarchySupertypesParams, Url, WorkspaceEdit,
+    WorkspaceSymbolParams,
 };
 use std::{
     fs::File,
@@ -41,6 +51,7 @@ pub fn handle_initialize(
         };
         init_tracing_subscriber(tracing_options);
     }
+    *state.client_capabilities.write() = params.capabilities.clone();
     tracing::info!("Initializing the Sway Language Server");
     Ok(InitializeResult {
         server_info: None,
@@ -89,6 +100,52 @@ pub async fn handle_goto_definition(
     }
 }
 
+pub async fn handle_goto_implementation(
+    state: &ServerState,
+    params: GotoImplementationParams,
+) -> Result<Option<GotoImplementationResponse>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => {
+            let position = params.text_document_position_params.position;
+            Ok(capabilities::implementation::implementations(
+                session, &uri, position,
+            ))
+        }
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_goto_type_definition(
+    state: &ServerState,
+    params: GotoTypeDefinitionParams,
+) -> Result<Option<GotoTypeDefinitionResponse>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => {
+            let position = params.text_document_position_params.position;
+            Ok(capabilities::type_definition::type_definition(
+                session, &uri, position,
+            ))
+        }
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
 pub async fn handle_completion(
     state: &ServerState,
     params: lsp_types::CompletionParams,
@@ -214,6 +271,207 @@ pub async fn handle_document_highlight(
     }
 }
 
+pub async fn handle_references(
+    state: &ServerState,
+    params: ReferenceParams,
+) -> Result<Option<Vec<Location>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document_position.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => {
+            let position = params.text_document_position.position;
+            Ok(capabilities::references::references(
+                session,
+                &uri,
+                position,
+                params.context.include_declaration,
+            ))
+        }
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+/// Doesn't wait for the document to be parsed, as the call is found in its latest text and the
+/// called function is resolved from the tokens of the last successful compilation.
+pub async fn handle_signature_help(
+    state: &ServerState,
+    params: SignatureHelpParams,
+) -> Result<Option<SignatureHelp>> {
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => {
+            let position = params.text_document_position_params.position;
+            Ok(capabilities::signature_help::signature_help(
+                session, &uri, position,
+            ))
+        }
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_prepare_call_hierarchy(
+    state: &ServerState,
+    params: CallHierarchyPrepareParams,
+) -> Result<Option<Vec<CallHierarchyItem>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::call_hierarchy::prepare_call_hierarchy(
+            session,
+            &uri,
+            params.text_document_position_params.position,
+        )),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_incoming_calls(
+    state: &ServerState,
+    params: CallHierarchyIncomingCallsParams,
+) -> Result<Option<Vec<CallHierarchyIncomingCall>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.item.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::call_hierarchy::incoming_calls(
+            session,
+            &uri,
+            &params.item,
+        )),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_outgoing_calls(
+    state: &ServerState,
+    params: CallHierarchyOutgoingCallsParams,
+) -> Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.item.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::call_hierarchy::outgoing_calls(
+            session,
+            &uri,
+            &params.item,
+        )),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_prepare_type_hierarchy(
+    state: &ServerState,
+    params: TypeHierarchyPrepareParams,
+) -> Result<Option<Vec<TypeHierarchyItem>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::type_hierarchy::prepare_type_hierarchy(
+            session,
+            &uri,
+            params.text_document_position_params.position,
+        )),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_supertypes(
+    state: &ServerState,
+    params: TypeHierarchySupertypesParams,
+) -> Result<Option<Vec<TypeHierarchyItem>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.item.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::type_hierarchy::supertypes(
+            session,
+            &uri,
+            &params.item,
+        )),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_subtypes(
+    state: &ServerState,
+    params: TypeHierarchySubtypesParams,
+) -> Result<Option<Vec<TypeHierarchyItem>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.item.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::type_hierarchy::subtypes(
+            session,
+            &uri,
+            &params.item,
+        )),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+/// Searches the declarations of every session, i.e., of every workspace member the client has
+/// opened a document of, along with their path dependencies.
+pub async fn handle_workspace_symbol(
+    state: &ServerState,
+    params: WorkspaceSymbolParams,
+) -> Result<Option<Vec<SymbolInformation>>> {
+    let _ = state.wait_for_parsing().await;
+    let symbols = state
+        .sessions
+        .iter()
+        .flat_map(|item| {
+            capabilities::workspace_symbol::workspace_symbols(item.value(), &params.query)
+        })
+        .collect();
+    Ok(Some(
+        capabilities::workspace_symbol::sort_workspace_symbols(symbols),
+    ))
+}
+
 pub async fn handle_formatting(
     state: &ServerState,
     params: DocumentFormattingParams,
@@ -230,6 +488,60 @@ pub async fn handle_formatting(
         })
 }
 
+pub async fn handle_range_formatting(
+    state: &ServerState,
+    params: DocumentRangeFormattingParams,
+) -> Result<Option<Vec<lsp_types::TextEdit>>> {
+    let _ = state.wait_for_parsing().await;
+    state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document.uri)
+        .await
+        .and_then(|(uri, session)| session.format_range(&uri, &params.range).map(Some))
+        .or_else(|err| {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        })
+}
+
+pub async fn handle_folding_range(
+    state: &ServerState,
+    params: FoldingRangeParams,
+) -> Result<Option<Vec<FoldingRange>>> {
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::folding_range::folding_ranges(session, &uri)),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_selection_range(
+    state: &ServerState,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>> {
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::selection_range::selection_ranges(
+            session,
+            &uri,
+            &params.positions,
+        )),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
 pub async fn handle_code_action(
     state: &ServerState,
     params: lsp_types::CodeActionParams,
@@ -313,7 +625,7 @@ pub async fn handle_semantic_tokens_full(
     }
 }
 
-pub(crate) async fn handle_inlay_hints(
+pub async fn handle_inlay_hints(
     state: &ServerState,
     params: InlayHintParams,
 ) -> Result<Option<Vec<InlayHint>>> {
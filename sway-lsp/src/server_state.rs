@@ -10,7 +10,9 @@ use crate::{
 use crossbeam_channel::{Receiver, Sender};
 use dashmap::DashMap;
 use forc_pkg::PackageManifestFile;
-use lsp_types::{Diagnostic, Url};
+use lsp_types::{
+    ClientCapabilities, Diagnostic, Registration, TypeHierarchyRegistrationOptions, Url,
+};
 use parking_lot::RwLock;
 use std::{
     mem,
@@ -27,6 +29,7 @@ use tower_lsp::{jsonrpc, Client};
 /// `ServerState` is the primary mutable state of the language server
 pub struct ServerState {
     pub(crate) client: Option<Client>,
+    pub(crate) client_capabilities: Arc<RwLock<ClientCapabilities>>,
     pub(crate) config: Arc<RwLock<Config>>,
     pub(crate) keyword_docs: Arc<KeywordDocs>,
     pub(crate) sessions: Arc<Sessions>,
@@ -43,6 +46,7 @@ impl Default for ServerState {
         let (cb_tx, cb_rx) = crossbeam_channel::bounded(1);
         let state = ServerState {
             client: None,
+            client_capabilities: Arc::new(RwLock::new(ClientCapabilities::default())),
             config: Arc::new(RwLock::new(Default::default())),
             keyword_docs: Arc::new(KeywordDocs::new()),
             sessions: Arc::new(Sessions(DashMap::new())),
@@ -210,6 +214,34 @@ impl ServerState {
         Ok(())
     }
 
+    /// Registers the capabilities which can't be declared in the result of `initialize`, i.e.,
+    /// type hierarchies, if the client supports registering them dynamically.
+    pub(crate) async fn register_dynamic_capabilities(&self) {
+        let Some(client) = self.client.as_ref() else {
+            return;
+        };
+        let type_hierarchy_registration = self
+            .client_capabilities
+            .read()
+            .text_document
+            .as_ref()
+            .and_then(|text_document| text_document.type_hierarchy.as_ref())
+            .and_then(|type_hierarchy| type_hierarchy.dynamic_registration)
+            .unwrap_or(false);
+        if !type_hierarchy_registration {
+            return;
+        }
+        let registration = Registration {
+            id: "textDocument/prepareTypeHierarchy".to_string(),
+            method: "textDocument/prepareTypeHierarchy".to_string(),
+            register_options: serde_json::to_value(TypeHierarchyRegistrationOptions::default())
+                .ok(),
+        };
+        if let Err(err) = client.register_capability(vec![registration]).await {
+            tracing::error!("Unable to register the type hierarchy capability: {}", err);
+        }
+    }
+
     pub(crate) async fn publish_diagnostics(
         &self,
         uri: Url,
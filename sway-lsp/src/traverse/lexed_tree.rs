@@ -4,11 +4,11 @@ use crate::{
 };
 use rayon::iter::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
 use sway_ast::{
-    expr::LoopControlFlow, ty::TyTupleDescriptor, Assignable, CodeBlockContents, ConfigurableField,
-    Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor, FnArg, FnArgs, FnSignature,
-    IfCondition, IfExpr, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl,
-    ItemImplItem, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTypeAlias, ItemUse,
-    MatchBranchKind, ModuleKind, Pattern, PatternStructField, Statement, StatementLet,
+    expr::LoopControlFlow, ty::TyTupleDescriptor, Assignable, ClosureParams, CodeBlockContents,
+    ConfigurableField, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor, FnArg,
+    FnArgs, FnSignature, IfCondition, IfExpr, ItemAbi, ItemConfigurable, ItemConst, ItemEnum,
+    ItemFn, ItemImpl, ItemImplItem, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTypeAlias,
+    ItemUse, MatchBranchKind, ModuleKind, Pattern, PatternStructField, Statement, StatementLet,
     StorageField, TraitType, Ty, TypeField, UseTree,
 };
 use sway_core::language::{lexed::LexedProgram, HasSubmodules};
@@ -216,6 +216,9 @@ impl Parse for Expr {
             Expr::Not { expr, .. } => {
                 expr.parse(ctx);
             }
+            Expr::Try { expr, .. } => {
+                expr.parse(ctx);
+            }
             Expr::Mul { lhs, rhs, .. }
             | Expr::Div { lhs, rhs, .. }
             | Expr::Pow { lhs, rhs, .. }
@@ -250,6 +253,22 @@ impl Parse for Expr {
             Expr::Continue { continue_token } => {
                 insert_keyword(ctx, continue_token.span());
             }
+            Expr::Closure {
+                params,
+                return_type_opt,
+                body,
+            } => {
+                if let ClosureParams::Params { params, .. } = params {
+                    params
+                        .into_iter()
+                        .filter_map(|param| param.ty_opt.as_ref())
+                        .for_each(|(_, ty)| ty.parse(ctx));
+                }
+                if let Some((_, ty)) = return_type_opt {
+                    ty.parse(ctx);
+                }
+                body.parse(ctx);
+            }
             _ => {}
         }
     }
@@ -507,6 +526,17 @@ impl Parse for Ty {
                 insert_keyword(ctx, str_token.span());
                 length.get().parse(ctx);
             }
+            Ty::Fn {
+                fn_token,
+                params,
+                return_type_opt,
+            } => {
+                insert_keyword(ctx, fn_token.span());
+                params.get().into_iter().for_each(|ty| ty.parse(ctx));
+                if let Some((_, ty)) = return_type_opt {
+                    ty.parse(ctx);
+                }
+            }
             _ => {}
         }
     }
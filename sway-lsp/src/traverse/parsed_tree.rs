@@ -19,9 +19,9 @@ use sway_core::{
     language::{
         parsed::{
             AbiCastExpression, AbiDeclaration, AmbiguousPathExpression, ArrayExpression,
-            ArrayIndexExpression, AstNode, AstNodeContent, ConstantDeclaration, Declaration,
-            DelineatedPathExpression, EnumDeclaration, EnumVariant, Expression, ExpressionKind,
-            ForLoopExpression, FunctionApplicationExpression, FunctionDeclaration,
+            ArrayIndexExpression, AstNode, AstNodeContent, ClosureExpression, ConstantDeclaration,
+            Declaration, DelineatedPathExpression, EnumDeclaration, EnumVariant, Expression,
+            ExpressionKind, ForLoopExpression, FunctionApplicationExpression, FunctionDeclaration,
             FunctionParameter, IfExpression, ImplItem, ImplSelf, ImplTrait, ImportType,
             IncludeStatement, IntrinsicFunctionExpression, LazyOperatorExpression, MatchExpression,
             MethodApplicationExpression, MethodName, ParseModule, ParseProgram, ParseSubmodule,
@@ -350,12 +350,36 @@ impl Parse for Expression {
             ExpressionKind::Ref(expr) | ExpressionKind::Deref(expr) => {
                 expr.parse(ctx);
             }
+            ExpressionKind::Closure(closure) => {
+                closure.parse(ctx);
+            }
             // We are collecting these tokens in the lexed phase.
             ExpressionKind::Break | ExpressionKind::Continue => {}
         }
     }
 }
 
+impl Parse for ClosureExpression {
+    fn parse(&self, ctx: &ParseContext) {
+        self.parameters.par_iter().for_each(|param| {
+            // The parameters of a closure are the parameters of the function holding its body.
+            FunctionParameter {
+                name: param.name.clone(),
+                is_reference: false,
+                is_mutable: false,
+                mutability_span: Span::dummy(),
+                type_argument: param.type_argument.clone(),
+            }
+            .parse(ctx);
+        });
+        self.return_type.parse(ctx);
+        self.body
+            .contents
+            .par_iter()
+            .for_each(|node| node.parse(ctx));
+    }
+}
+
 impl Parse for ReassignmentExpression {
     fn parse(&self, ctx: &ParseContext) {
         self.rhs.parse(ctx);
@@ -120,39 +120,31 @@ impl Parse for ty::TySideEffect {
                 }
                 match &import_type {
                     ImportType::Item(item) => {
-                        if let Some(mut token) = ctx.tokens.try_get_mut_with_retry(&ctx.ident(item))
+                        let mut symbol_kind = SymbolKind::Unknown;
+                        let mut type_def = None;
+                        if let Some(decl_ident) = ctx
+                            .namespace
+                            .submodule(call_path)
+                            .and_then(|module| module.current_items().symbols().get(item))
+                            .and_then(|decl| decl.get_decl_ident())
                         {
-                            token.typed =
-                                Some(TypedAstToken::TypedUseStatement(use_statement.clone()));
-                            let mut symbol_kind = SymbolKind::Unknown;
-                            let mut type_def = None;
-                            if let Some(decl_ident) = ctx
-                                .namespace
-                                .submodule(call_path)
-                                .and_then(|module| module.current_items().symbols().get(item))
-                                .and_then(|decl| decl.get_decl_ident())
+                            // Update the symbol kind to match the declarations symbol kind
+                            if let Some(decl) =
+                                ctx.tokens.try_get(&ctx.ident(&decl_ident)).try_unwrap()
                             {
-                                // Update the symbol kind to match the declarations symbol kind
-                                if let Some(decl) =
-                                    ctx.tokens.try_get(&ctx.ident(&decl_ident)).try_unwrap()
-                                {
-                                    symbol_kind = decl.value().kind.clone();
-                                }
-                                type_def = Some(TypeDefinition::Ident(decl_ident));
+                                symbol_kind = decl.value().kind.clone();
                             }
-                            token.kind = symbol_kind.clone();
-                            token.type_def = type_def.clone();
-                            // the alias should take on the same symbol kind and type definition
-                            if let Some(alias) = alias {
-                                if let Some(mut token) =
-                                    ctx.tokens.try_get_mut_with_retry(&ctx.ident(alias))
-                                {
-                                    token.typed = Some(TypedAstToken::TypedUseStatement(
-                                        use_statement.clone(),
-                                    ));
-                                    token.kind = symbol_kind;
-                                    token.type_def = type_def;
-                                }
+                            type_def = Some(TypeDefinition::Ident(decl_ident));
+                        }
+                        // the alias should take on the same symbol kind and type definition
+                        for ident in std::iter::once(item).chain(alias) {
+                            if let Some(mut token) =
+                                ctx.tokens.try_get_mut_with_retry(&ctx.ident(ident))
+                            {
+                                token.typed =
+                                    Some(TypedAstToken::TypedUseStatement(use_statement.clone()));
+                                token.kind = symbol_kind.clone();
+                                token.type_def = type_def.clone();
                             }
                         }
                     }
@@ -261,7 +253,10 @@ impl Parse for ty::TyExpression {
                 contract_call_params.values().for_each(|exp| exp.parse(ctx));
                 arguments.par_iter().for_each(|(ident, exp)| {
                     if let Some(mut token) = ctx.tokens.try_get_mut_with_retry(&ctx.ident(ident)) {
-                        token.typed = Some(TypedAstToken::Ident(ident.clone()));
+                        // Keep the typed parameter of the callee if it has already been collected.
+                        if !matches!(token.typed, Some(TypedAstToken::TypedFunctionParameter(_))) {
+                            token.typed = Some(TypedAstToken::Ident(ident.clone()));
+                        }
                     }
                     exp.parse(ctx);
                 });
@@ -586,6 +581,34 @@ impl Parse for ty::TyExpression {
             ty::TyExpressionVariant::Ref(exp) | ty::TyExpressionVariant::Deref(exp) => {
                 exp.parse(ctx)
             }
+            ty::TyExpressionVariant::Closure { fn_ref, captures } => {
+                let function_decl = ctx.engines.de().get_function(fn_ref);
+                if function_decl.span != self.span {
+                    // A function used as a value.
+                    if let Some(mut token) = ctx
+                        .tokens
+                        .try_get_mut_with_retry(&ctx.ident(&Ident::new(self.span.clone())))
+                    {
+                        token.typed = Some(TypedAstToken::TypedExpression(self.clone()));
+                        token.type_def = Some(TypeDefinition::Ident(function_decl.name.clone()));
+                    }
+                    return;
+                }
+                // The captured values are passed before the parameters of the closure.
+                function_decl.parameters[captures.len()..]
+                    .par_iter()
+                    .for_each(|param| param.parse(ctx));
+                function_decl
+                    .body
+                    .contents
+                    .par_iter()
+                    .for_each(|node| node.parse(ctx));
+                collect_type_argument(ctx, &function_decl.return_type);
+            }
+            ty::TyExpressionVariant::ClosureCall { closure, arguments } => {
+                closure.parse(ctx);
+                arguments.par_iter().for_each(|arg| arg.parse(ctx));
+            }
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::error::DirectoryError;
-use lsp_types::Url;
+use lsp_types::{Range, Url};
 use std::path::PathBuf;
 use sway_types::{SourceEngine, Span};
 
@@ -29,3 +29,8 @@ pub fn get_url_from_span(source_engine: &SourceEngine, span: &Span) -> Result<Ur
         })
     }
 }
+
+/// Returns true if the `inner` [Range] is within the `outer` one.
+pub(crate) fn is_within(inner: &Range, outer: &Range) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
@@ -4,3 +4,4 @@ pub(crate) mod document;
 pub mod keyword_docs;
 pub mod markdown;
 pub(crate) mod markup;
+pub(crate) mod syntax_tree;
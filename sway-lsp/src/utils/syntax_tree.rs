@@ -0,0 +1,569 @@
+//! The nodes of the syntax tree of a document, i.e., of the `sway-ast` module parsed from its
+//! current text.
+//!
+//! Unlike the token map, the syntax tree is available as soon as the document parses, even if it
+//! doesn't compile, and has a node for every expression and statement, not only for the ones that
+//! declare or reference a symbol.
+
+use std::sync::Arc;
+use sway_ast::{
+    attribute::Annotated, expr::LoopControlFlow, ty::TyTupleDescriptor, Assignable, ClosureParams,
+    CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor, FnArgs,
+    FnSignature, IfCondition, IfExpr, Item, ItemFn, ItemImplItem, ItemKind, ItemTraitItem,
+    MatchBranch, MatchBranchKind, Module, Pattern, PatternStructField, Statement, Ty, UseTree,
+};
+use sway_error::handler::Handler;
+use sway_types::{Span, Spanned};
+
+/// The kind of a [SyntaxNode].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SyntaxKind {
+    /// An item, or an item of an `impl`, trait or ABI block, including its attributes.
+    Item,
+    /// A `use` item, including its attributes.
+    Use,
+    Attribute,
+    /// A field of a struct, an enum, a storage or a configurable block.
+    Field,
+    Signature,
+    Parameter,
+    Statement,
+    Block,
+    Expr,
+    MatchBranch,
+    Pattern,
+    Ty,
+    UseTree,
+}
+
+/// A node of the syntax tree, along with the span of its text.
+#[derive(Debug, Clone)]
+pub(crate) struct SyntaxNode {
+    pub kind: SyntaxKind,
+    pub span: Span,
+}
+
+/// Parses the text of a document into a [Module], recovering from syntax errors where possible.
+pub(crate) fn parse_module(text: &str) -> Option<Annotated<Module>> {
+    sway_parse::parse_file(&Handler::default(), Arc::from(text), None).ok()
+}
+
+/// Returns the nodes of the syntax tree of the module, with every node before the nodes within
+/// it.
+pub(crate) fn syntax_nodes(module: &Module) -> Vec<SyntaxNode> {
+    let mut collector = Collector::default();
+    for item in &module.items {
+        collector.item(item);
+    }
+    collector.nodes
+}
+
+#[derive(Default)]
+struct Collector {
+    nodes: Vec<SyntaxNode>,
+}
+
+impl Collector {
+    fn push(&mut self, kind: SyntaxKind, span: Span) {
+        self.nodes.push(SyntaxNode { kind, span });
+    }
+
+    fn item(&mut self, item: &Item) {
+        let kind = match item.value {
+            ItemKind::Use(_) => SyntaxKind::Use,
+            _ => SyntaxKind::Item,
+        };
+        self.annotated(kind, item, |collector| collector.item_kind(&item.value));
+    }
+
+    /// Pushes the item, spanning from its first attribute, and its attributes, then calls `inner`
+    /// to push the nodes within the item.
+    fn annotated<T: Spanned>(
+        &mut self,
+        kind: SyntaxKind,
+        annotated: &Annotated<T>,
+        inner: impl FnOnce(&mut Self),
+    ) {
+        let span = annotated.value.span();
+        let span = match annotated.attribute_list.first() {
+            Some(attribute) => Span::join(attribute.span(), span),
+            None => span,
+        };
+        self.push(kind, span);
+        for attribute in &annotated.attribute_list {
+            self.push(SyntaxKind::Attribute, attribute.span());
+        }
+        inner(self);
+    }
+
+    /// Pushes the field, spanning from its first attribute, its type and its initializer.
+    fn field<T: Spanned>(&mut self, field: &Annotated<T>, ty: &Ty, initializer: Option<&Expr>) {
+        let span = match field.attribute_list.first() {
+            Some(attribute) => Span::join(attribute.span(), field.value.span()),
+            None => field.value.span(),
+        };
+        self.push(SyntaxKind::Field, span);
+        for attribute in &field.attribute_list {
+            self.push(SyntaxKind::Attribute, attribute.span());
+        }
+        self.ty(ty);
+        if let Some(initializer) = initializer {
+            self.expr(initializer);
+        }
+    }
+
+    fn item_kind(&mut self, item: &ItemKind) {
+        match item {
+            ItemKind::Use(item_use) => self.use_tree(&item_use.tree),
+            ItemKind::Struct(item_struct) => {
+                for field in item_struct.fields.get() {
+                    self.field(field, &field.value.ty, None);
+                }
+            }
+            ItemKind::Enum(item_enum) => {
+                for field in item_enum.fields.get() {
+                    self.field(field, &field.value.ty, None);
+                }
+            }
+            ItemKind::Fn(item_fn) => self.item_fn(item_fn),
+            ItemKind::Trait(item_trait) => {
+                self.push(SyntaxKind::Block, item_trait.trait_items.span());
+                for item in item_trait.trait_items.get() {
+                    self.annotated(SyntaxKind::Item, item, |collector| {
+                        collector.trait_item(&item.value)
+                    });
+                }
+                if let Some(defs) = &item_trait.trait_defs_opt {
+                    self.push(SyntaxKind::Block, defs.span());
+                    for item in defs.get() {
+                        self.annotated(SyntaxKind::Item, item, |collector| {
+                            collector.item_fn(&item.value)
+                        });
+                    }
+                }
+            }
+            ItemKind::Impl(item_impl) => {
+                self.ty(&item_impl.ty);
+                self.push(SyntaxKind::Block, item_impl.contents.span());
+                for item in item_impl.contents.get() {
+                    self.annotated(SyntaxKind::Item, item, |collector| match &item.value {
+                        ItemImplItem::Fn(item_fn) => collector.item_fn(item_fn),
+                        ItemImplItem::Const(item_const) => {
+                            if let Some((_, ty)) = &item_const.ty_opt {
+                                collector.ty(ty);
+                            }
+                            if let Some(expr) = &item_const.expr_opt {
+                                collector.expr(expr);
+                            }
+                        }
+                        ItemImplItem::Type(trait_type) => {
+                            if let Some(ty) = &trait_type.ty_opt {
+                                collector.ty(ty);
+                            }
+                        }
+                    });
+                }
+            }
+            ItemKind::Abi(item_abi) => {
+                self.push(SyntaxKind::Block, item_abi.abi_items.span());
+                for item in item_abi.abi_items.get() {
+                    self.annotated(SyntaxKind::Item, item, |collector| {
+                        collector.trait_item(&item.value)
+                    });
+                }
+                if let Some(defs) = &item_abi.abi_defs_opt {
+                    self.push(SyntaxKind::Block, defs.span());
+                    for item in defs.get() {
+                        self.annotated(SyntaxKind::Item, item, |collector| {
+                            collector.item_fn(&item.value)
+                        });
+                    }
+                }
+            }
+            ItemKind::Const(item_const) => {
+                if let Some((_, ty)) = &item_const.ty_opt {
+                    self.ty(ty);
+                }
+                if let Some(expr) = &item_const.expr_opt {
+                    self.expr(expr);
+                }
+            }
+            ItemKind::Storage(item_storage) => {
+                for field in item_storage.fields.get() {
+                    self.field(field, &field.value.ty, Some(&field.value.initializer));
+                }
+            }
+            ItemKind::Configurable(item_configurable) => {
+                for field in item_configurable.fields.get() {
+                    self.field(field, &field.value.ty, Some(&field.value.initializer));
+                }
+            }
+            ItemKind::TypeAlias(item_type_alias) => self.ty(&item_type_alias.ty),
+            ItemKind::Submodule(_) | ItemKind::Error(..) => {}
+        }
+    }
+
+    fn trait_item(&mut self, item: &ItemTraitItem) {
+        match item {
+            ItemTraitItem::Fn(fn_signature, _) => self.fn_signature(fn_signature),
+            ItemTraitItem::Const(item_const, _) => {
+                if let Some((_, ty)) = &item_const.ty_opt {
+                    self.ty(ty);
+                }
+                if let Some(expr) = &item_const.expr_opt {
+                    self.expr(expr);
+                }
+            }
+            ItemTraitItem::Type(trait_type, _) => {
+                if let Some(ty) = &trait_type.ty_opt {
+                    self.ty(ty);
+                }
+            }
+            ItemTraitItem::Error(..) => {}
+        }
+    }
+
+    fn item_fn(&mut self, item_fn: &ItemFn) {
+        self.fn_signature(&item_fn.fn_signature);
+        self.push(SyntaxKind::Block, item_fn.body.span());
+        self.code_block(item_fn.body.get());
+    }
+
+    fn fn_signature(&mut self, fn_signature: &FnSignature) {
+        self.push(SyntaxKind::Signature, fn_signature.span());
+        self.push(SyntaxKind::Parameter, fn_signature.arguments.span());
+        let args = match fn_signature.arguments.get() {
+            FnArgs::Static(args) => Some(args),
+            FnArgs::NonStatic { args_opt, .. } => args_opt.as_ref().map(|(_, args)| args),
+        };
+        for arg in args.into_iter().flatten() {
+            self.push(SyntaxKind::Parameter, arg.span());
+            self.pattern(&arg.pattern);
+            self.ty(&arg.ty);
+        }
+        if let Some((_, ty)) = &fn_signature.return_type_opt {
+            self.ty(ty);
+        }
+    }
+
+    fn code_block(&mut self, block: &CodeBlockContents) {
+        if !block.span.as_str().trim().is_empty() {
+            self.push(SyntaxKind::Block, block.span());
+        }
+        for statement in &block.statements {
+            self.statement(statement);
+        }
+        if let Some(expr) = &block.final_expr_opt {
+            self.expr(expr);
+        }
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Let(statement_let) => {
+                self.push(SyntaxKind::Statement, statement_let.span());
+                self.pattern(&statement_let.pattern);
+                if let Some((_, ty)) = &statement_let.ty_opt {
+                    self.ty(ty);
+                }
+                self.expr(&statement_let.expr);
+            }
+            Statement::Item(item) => self.item(item),
+            Statement::Expr { expr, .. } => {
+                self.push(SyntaxKind::Statement, statement.span());
+                self.expr(expr);
+            }
+            Statement::Error(..) => {}
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        self.push(SyntaxKind::Expr, expr.span());
+        match expr {
+            Expr::AbiCast { args, .. } => self.expr(&args.get().address),
+            Expr::Struct { fields, .. } => {
+                for field in fields.get() {
+                    self.struct_field(field);
+                }
+            }
+            Expr::Tuple(tuple) => {
+                if let ExprTupleDescriptor::Cons { head, tail, .. } = tuple.get() {
+                    self.expr(head);
+                    for expr in tail {
+                        self.expr(expr);
+                    }
+                }
+            }
+            Expr::Parens(parens) => self.expr(parens.get()),
+            Expr::Block(block) => self.code_block(block.get()),
+            Expr::Array(array) => match array.get() {
+                ExprArrayDescriptor::Sequence(exprs) => {
+                    for expr in exprs {
+                        self.expr(expr);
+                    }
+                }
+                ExprArrayDescriptor::Repeat { value, length, .. } => {
+                    self.expr(value);
+                    self.expr(length);
+                }
+            },
+            Expr::Return { expr_opt, .. } => {
+                if let Some(expr) = expr_opt {
+                    self.expr(expr);
+                }
+            }
+            Expr::If(if_expr) => self.if_expr(if_expr),
+            Expr::Match {
+                value, branches, ..
+            } => {
+                self.expr(value);
+                self.push(SyntaxKind::Block, branches.span());
+                for branch in branches.get() {
+                    self.match_branch(branch);
+                }
+            }
+            Expr::While {
+                condition, block, ..
+            } => {
+                self.expr(condition);
+                self.push(SyntaxKind::Block, block.span());
+                self.code_block(block.get());
+            }
+            Expr::For {
+                value_pattern,
+                iterator,
+                block,
+                ..
+            } => {
+                self.pattern(value_pattern);
+                self.expr(iterator);
+                self.push(SyntaxKind::Block, block.span());
+                self.code_block(block.get());
+            }
+            Expr::FuncApp { func, args } => {
+                self.expr(func);
+                self.push(SyntaxKind::Expr, args.span());
+                for arg in args.get() {
+                    self.expr(arg);
+                }
+            }
+            Expr::Index { target, arg } => {
+                self.expr(target);
+                self.expr(arg.get());
+            }
+            Expr::MethodCall {
+                target,
+                contract_args_opt,
+                args,
+                ..
+            } => {
+                self.expr(target);
+                if let Some(contract_args) = contract_args_opt {
+                    for field in contract_args.get() {
+                        self.struct_field(field);
+                    }
+                }
+                self.push(SyntaxKind::Expr, args.span());
+                for arg in args.get() {
+                    self.expr(arg);
+                }
+            }
+            Expr::FieldProjection { target, .. } | Expr::TupleFieldProjection { target, .. } => {
+                self.expr(target);
+            }
+            Expr::Ref { expr, .. }
+            | Expr::Deref { expr, .. }
+            | Expr::Not { expr, .. }
+            | Expr::Try { expr, .. } => {
+                self.expr(expr);
+            }
+            Expr::Mul { lhs, rhs, .. }
+            | Expr::Div { lhs, rhs, .. }
+            | Expr::Pow { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. }
+            | Expr::Add { lhs, rhs, .. }
+            | Expr::Sub { lhs, rhs, .. }
+            | Expr::Shl { lhs, rhs, .. }
+            | Expr::Shr { lhs, rhs, .. }
+            | Expr::BitAnd { lhs, rhs, .. }
+            | Expr::BitXor { lhs, rhs, .. }
+            | Expr::BitOr { lhs, rhs, .. }
+            | Expr::Equal { lhs, rhs, .. }
+            | Expr::NotEqual { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. }
+            | Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::LessThanEq { lhs, rhs, .. }
+            | Expr::GreaterThanEq { lhs, rhs, .. }
+            | Expr::LogicalAnd { lhs, rhs, .. }
+            | Expr::LogicalOr { lhs, rhs, .. } => {
+                self.expr(lhs);
+                self.expr(rhs);
+            }
+            Expr::Reassignment {
+                assignable, expr, ..
+            } => {
+                self.assignable(assignable);
+                self.expr(expr);
+            }
+            Expr::Closure {
+                params,
+                return_type_opt,
+                body,
+            } => {
+                if let ClosureParams::Params { params, .. } = params {
+                    for (_, ty) in params.into_iter().filter_map(|param| param.ty_opt.as_ref()) {
+                        self.ty(ty);
+                    }
+                }
+                if let Some((_, ty)) = return_type_opt {
+                    self.ty(ty);
+                }
+                self.expr(body);
+            }
+            Expr::Error(..)
+            | Expr::Path(_)
+            | Expr::Literal(_)
+            | Expr::Asm(_)
+            | Expr::Break { .. }
+            | Expr::Continue { .. } => {}
+        }
+    }
+
+    fn if_expr(&mut self, if_expr: &IfExpr) {
+        match &if_expr.condition {
+            IfCondition::Expr(expr) => self.expr(expr),
+            IfCondition::Let { lhs, rhs, .. } => {
+                self.pattern(lhs);
+                self.expr(rhs);
+            }
+        }
+        self.push(SyntaxKind::Block, if_expr.then_block.span());
+        self.code_block(if_expr.then_block.get());
+        match &if_expr.else_opt {
+            Some((_, LoopControlFlow::Break(block))) => {
+                self.push(SyntaxKind::Block, block.span());
+                self.code_block(block.get());
+            }
+            Some((_, LoopControlFlow::Continue(if_expr))) => {
+                self.push(SyntaxKind::Expr, if_expr.span());
+                self.if_expr(if_expr);
+            }
+            None => {}
+        }
+    }
+
+    fn match_branch(&mut self, branch: &MatchBranch) {
+        self.push(SyntaxKind::MatchBranch, branch.span());
+        self.pattern(&branch.pattern);
+        match &branch.kind {
+            MatchBranchKind::Block { block, .. } => {
+                self.push(SyntaxKind::Block, block.span());
+                self.code_block(block.get());
+            }
+            MatchBranchKind::Expr { expr, .. } => self.expr(expr),
+        }
+    }
+
+    fn struct_field(&mut self, field: &ExprStructField) {
+        self.push(SyntaxKind::Field, field.span());
+        if let Some((_, expr)) = &field.expr_opt {
+            self.expr(expr);
+        }
+    }
+
+    fn assignable(&mut self, assignable: &Assignable) {
+        self.push(SyntaxKind::Expr, assignable.span());
+        match assignable {
+            Assignable::Index { target, arg } => {
+                self.assignable(target);
+                self.expr(arg.get());
+            }
+            Assignable::FieldProjection { target, .. }
+            | Assignable::TupleFieldProjection { target, .. } => self.assignable(target),
+            Assignable::Var(_) => {}
+        }
+    }
+
+    fn pattern(&mut self, pattern: &Pattern) {
+        self.push(SyntaxKind::Pattern, pattern.span());
+        match pattern {
+            Pattern::Or { lhs, rhs, .. } => {
+                self.pattern(lhs);
+                self.pattern(rhs);
+            }
+            Pattern::Constructor { args, .. } | Pattern::Tuple(args) => {
+                for pattern in args.get() {
+                    self.pattern(pattern);
+                }
+            }
+            Pattern::Struct { fields, .. } => {
+                for field in fields.get() {
+                    self.push(SyntaxKind::Pattern, field.span());
+                    if let PatternStructField::Field {
+                        pattern_opt: Some((_, pattern)),
+                        ..
+                    } = field
+                    {
+                        self.pattern(pattern);
+                    }
+                }
+            }
+            Pattern::Wildcard { .. }
+            | Pattern::AmbiguousSingleIdent(_)
+            | Pattern::Var { .. }
+            | Pattern::Literal(_)
+            | Pattern::Constant(_)
+            | Pattern::Error(..) => {}
+        }
+    }
+
+    fn ty(&mut self, ty: &Ty) {
+        self.push(SyntaxKind::Ty, ty.span());
+        match ty {
+            Ty::Tuple(tuple) => {
+                if let TyTupleDescriptor::Cons { head, tail, .. } = tuple.get() {
+                    self.ty(head);
+                    for ty in tail {
+                        self.ty(ty);
+                    }
+                }
+            }
+            Ty::Array(array) => {
+                self.ty(&array.get().ty);
+                self.expr(&array.get().length);
+            }
+            Ty::StringArray { length, .. } => self.expr(length.get()),
+            Ty::Ptr { ty, .. } | Ty::Slice { ty, .. } => self.ty(ty.get()),
+            Ty::Ref { ty, .. } => self.ty(ty),
+            Ty::Fn {
+                params,
+                return_type_opt,
+                ..
+            } => {
+                for ty in params.get() {
+                    self.ty(ty);
+                }
+                if let Some((_, ty)) = return_type_opt {
+                    self.ty(ty);
+                }
+            }
+            Ty::Path(_) | Ty::StringSlice(_) | Ty::Infer { .. } => {}
+        }
+    }
+
+    fn use_tree(&mut self, use_tree: &UseTree) {
+        self.push(SyntaxKind::UseTree, use_tree.span());
+        match use_tree {
+            UseTree::Group { imports } => {
+                for use_tree in imports.get() {
+                    self.use_tree(use_tree);
+                }
+            }
+            UseTree::Path { suffix, .. } => self.use_tree(suffix),
+            UseTree::Name { .. }
+            | UseTree::Rename { .. }
+            | UseTree::Glob { .. }
+            | UseTree::Error { .. } => {}
+        }
+    }
+}
@@ -0,0 +1,21 @@
+script;
+
+mod shapes;
+
+use core::ops::Add;
+use shapes::{area, perimeter, Rectangle};
+use shapes::Circle;
+use shapes::{Point as Position, origin};
+use shapes::{
+    Point,
+    Circle as Round,
+};
+
+fn main() -> u64 {
+    let rect = Rectangle {
+        width: 2,
+        height: 3,
+    };
+    let p: Point = origin();
+    area(rect).add(p.x)
+}
@@ -0,0 +1,27 @@
+library;
+
+pub struct Rectangle {
+    pub width: u64,
+    pub height: u64,
+}
+
+pub struct Circle {
+    pub radius: u64,
+}
+
+pub struct Point {
+    pub x: u64,
+    pub y: u64,
+}
+
+pub fn area(rect: Rectangle) -> u64 {
+    rect.width * rect.height
+}
+
+pub fn perimeter(rect: Rectangle) -> u64 {
+    2 * (rect.width + rect.height)
+}
+
+pub fn origin() -> Point {
+    Point { x: 0, y: 0 }
+}
@@ -0,0 +1,54 @@
+contract;
+
+abi Owned {
+    fn owner() -> u64;
+}
+
+abi Counter : Owned {
+    fn increment(amount: u64) -> u64;
+}
+
+trait Shape {
+    fn area(self) -> u64;
+}
+
+trait Polygon : Shape {
+    fn sides(self) -> u64;
+}
+
+struct Square {
+    side: u64,
+}
+
+impl Shape for Square {
+    fn area(self) -> u64 {
+        square(self.side)
+    }
+}
+
+impl Polygon for Square {
+    fn sides(self) -> u64 {
+        4
+    }
+}
+
+fn square(x: u64) -> u64 {
+    x * x
+}
+
+fn total_area(a: Square, b: Square) -> u64 {
+    a.area() + b.area() + square(2)
+}
+
+impl Owned for Contract {
+    fn owner() -> u64 {
+        0
+    }
+}
+
+impl Counter for Contract {
+    fn increment(amount: u64) -> u64 {
+        let square = Square { side: amount };
+        total_area(square, square)
+    }
+}
@@ -0,0 +1,64 @@
+contract;
+
+struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn new(value: T) -> Self {
+        Wrapper { value }
+    }
+
+    fn get(self) -> T {
+        self.value
+    }
+}
+
+struct Counter {
+    count: u64,
+}
+
+impl Counter {
+    fn add(self, amount: u64) -> Self {
+        Counter {
+            count: self.count + amount,
+        }
+    }
+
+    fn scaled(self, factor: u64) -> u64 {
+        self.count * factor
+    }
+}
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn area(width: u64, height: u64) -> u64 {
+    width * height
+}
+
+#[storage(read)]
+fn stored_total() -> u64 {
+    0
+}
+
+abi Store {
+    #[storage(read)]
+    fn total() -> u64;
+}
+
+impl Store for Contract {
+    #[storage(read)]
+    fn total() -> u64 {
+        let width = 2u64;
+        let a = area(width, 3);
+        let wrapper = Wrapper::new(a);
+        let value = identity(wrapper.get());
+        let scaled = Counter { count: value }
+            .add(1)
+            .add(2)
+            .scaled(3);
+        scaled + stored_total()
+    }
+}
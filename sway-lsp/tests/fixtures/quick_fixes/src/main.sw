@@ -0,0 +1,60 @@
+contract;
+
+abi Wallet {
+    fn deposit();
+}
+
+abi Vault {
+    fn open();
+    #[storage(read)]
+    fn balance() -> u64;
+    fn owner(index: u64, verbose: bool) -> b256;
+}
+
+enum Color {
+    Red: (),
+    Green: (),
+    Blue: (),
+}
+
+fn color_code(color: Color) -> u64 {
+    match color {
+        Color::Red => 1,
+    }
+}
+
+#[storage(read)]
+fn stored_count() -> u64 {
+    0
+}
+
+fn count_twice() -> u64 {
+    stored_count() * 2
+}
+
+impl Wallet for Contract {
+    fn deposit() {}
+}
+
+impl Vault for Contract {
+    fn open() {}
+}
+
+fn send(wallet_id: b256) {
+    let wallet = abi(Wallet, wallet_id);
+    wallet.deposit {
+        coins: 10,
+    }();
+}
+
+fn increment() -> u64 {
+    let count = 1;
+    count = count + 1;
+    count
+}
+
+fn unused_variable() -> u64 {
+    let unused = color_code(Color::Red);
+    let used = 1;
+    used
+}
@@ -0,0 +1,42 @@
+contract;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Shape {
+    Circle: u64,
+    Square: u64,
+}
+
+fn area(width: u64, height: u64) -> u64 {
+    width * height
+}
+
+fn scaled_area(point: Point, factor: u64) -> u64 {
+    let base = point.x + point.y;
+    let total = area(base, factor) * 2;
+    total
+}
+
+fn radius(shape: Shape) -> u64 {
+    if let Shape::Circle(radius) = shape {
+        radius * 3
+    } else {
+        0
+    }
+}
+
+fn side(shape: Shape) -> u64 {
+    match shape {
+        Shape::Square(side) => side,
+        _ => 0,
+    }
+}
+
+fn log_areas() {
+    let small = area(1, 2);
+    let large = area(3, 4);
+    let _ = small + large;
+}
@@ -0,0 +1,19 @@
+script;
+
+mod shapes;
+
+use shapes::{area, Rectangle};
+
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+fn main() -> u64 {
+    let rect = Rectangle::new(2, 3);
+    let a = area(rect);
+    double(a) + double(shapes::perimeter(rect))
+}
+
+fn scaled(x: u64) -> u64 {
+    references_dep::scale(x) + references_dep::scale_twice(x)
+}
@@ -0,0 +1,20 @@
+library;
+
+pub struct Rectangle {
+    width: u64,
+    height: u64,
+}
+
+impl Rectangle {
+    pub fn new(width: u64, height: u64) -> Self {
+        Rectangle { width, height }
+    }
+}
+
+pub fn area(rect: Rectangle) -> u64 {
+    rect.width * rect.height
+}
+
+pub fn perimeter(rect: Rectangle) -> u64 {
+    2 * (rect.width + rect.height)
+}
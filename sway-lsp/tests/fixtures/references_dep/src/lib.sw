@@ -0,0 +1,9 @@
+library;
+
+pub fn scale(x: u64) -> u64 {
+    x * 10
+}
+
+pub fn scale_twice(x: u64) -> u64 {
+    scale(scale(x))
+}
@@ -0,0 +1,34 @@
+script;
+
+abi Counter {
+    /// Increments the counter by `amount`, `times` times.
+    #[storage(read, write)]
+    fn increment(amount: u64, times: u64) -> u64;
+}
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+impl Point {
+    fn scale(self, factor: u64) -> Point {
+        Point {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+/// Returns `a` if `fallback` is false, and `b` otherwise.
+fn pick<T>(a: T, b: T, fallback: bool) -> T {
+    if fallback { b } else { a }
+}
+
+fn main() -> u64 {
+    let point = Point { x: 1, y: 2 };
+    let scaled = point.scale(pick(2, 3, false));
+    let counter = abi(Counter, 0x0000000000000000000000000000000000000000000000000000000000000000);
+    let count = counter.increment { gas: 1000, coins: 0 }(scaled.x, 2);
+    count
+}
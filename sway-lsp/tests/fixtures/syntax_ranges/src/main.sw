@@ -0,0 +1,37 @@
+contract;
+
+use core::ops::Eq;
+use core::ops::Ord;
+
+/// The shape of a region.
+/// Either a circle or a square.
+enum Shape {
+    Circle: u64,
+    Square: u64,
+}
+
+abi Area {
+    fn area(shape: Shape) -> u64;
+}
+
+impl Area for Contract {
+    fn area(shape: Shape) -> u64 {
+        match shape {
+            Shape::Circle(radius) => {
+                let r = radius * radius;
+                r * 3
+            },
+            Shape::Square(side) => side * side,
+        }
+    }
+}
+
+fn double(x: u64) -> u64 {
+    let y   =   x*2;
+    y
+}
+
+fn triple(x: u64) -> u64 {
+    let y   =   x*3;
+    y
+}
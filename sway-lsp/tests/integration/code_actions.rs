@@ -507,6 +507,7 @@ pub(crate) async fn code_action_auto_import_struct_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("EvmAddress".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -550,6 +551,7 @@ pub(crate) async fn code_action_auto_import_struct_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepStruct".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -597,6 +599,7 @@ pub(crate) async fn code_action_auto_import_enum_request(server: &ServerState, u
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("AuthError".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -640,6 +643,7 @@ pub(crate) async fn code_action_auto_import_enum_request(server: &ServerState, u
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepEnum".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -688,6 +692,7 @@ pub(crate) async fn code_action_auto_import_function_request(server: &ServerStat
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("deep_fun".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -736,6 +741,7 @@ pub(crate) async fn code_action_auto_import_constant_request(server: &ServerStat
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("TEST_CONST".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -781,6 +787,7 @@ pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("TryFrom".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -824,6 +831,7 @@ pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepTrait".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -870,6 +878,7 @@ pub(crate) async fn code_action_auto_import_alias_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("A".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -909,3 +918,337 @@ pub(crate) async fn code_action_auto_import_alias_request(server: &ServerState,
     let actual = send_request(server, &params).await;
     assert_eq!(expected, actual);
 }
+
+pub(crate) async fn code_action_quick_fixes_request(server: &ServerState, uri: &Url) {
+    let range = |start_line, start_char, end_line, end_char| Range {
+        start: Position::new(start_line, start_char),
+        end: Position::new(end_line, end_char),
+    };
+    let quick_fix = |title: &str, changes| {
+        create_code_action(
+            uri.clone(),
+            title.to_string(),
+            changes,
+            None,
+            Some(CodeActionKind::QUICKFIX),
+        )
+    };
+    // The diagnostic ranges are also selections some refactorings apply to, which are left out.
+    let request = |range, data| {
+        let params =
+            create_code_action_params(uri.clone(), range, create_diagnostic_from_data(range, data));
+        async move {
+            send_request(server, &params)
+                .await
+                .into_iter()
+                .filter(|action| {
+                    matches!(action, CodeActionOrCommand::CodeAction(action)
+                        if action.kind == Some(CodeActionKind::QUICKFIX))
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+
+    // Storage access from a pure function
+    let actual = request(
+        range(31, 4, 31, 16),
+        DiagnosticData {
+            missing_storage_access: Some("read".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    let expected = vec![quick_fix(
+        "Add attribute `#[storage(read)]`",
+        create_changes_map(uri, range(30, 0, 30, 0), "#[storage(read)]\n"),
+    )];
+    assert_eq!(expected, actual);
+
+    // Non-exhaustive match expression
+    let actual = request(
+        range(20, 4, 22, 5),
+        DiagnosticData {
+            missing_match_patterns: Some(vec![
+                "Color::Blue(_)".to_string(),
+                "Color::Green(_)".to_string(),
+            ]),
+            ..Default::default()
+        },
+    )
+    .await;
+    let expected = vec![quick_fix(
+        "Add missing match arms",
+        create_changes_map(
+            uri,
+            range(22, 0, 22, 0),
+            "        Color::Blue(_) => {},\n        Color::Green(_) => {},\n",
+        ),
+    )];
+    assert_eq!(expected, actual);
+
+    // Missing ABI methods
+    let actual = request(
+        range(38, 0, 40, 1),
+        DiagnosticData {
+            missing_interface_methods: Some(vec!["balance".to_string(), "owner".to_string()]),
+            ..Default::default()
+        },
+    )
+    .await;
+    let expected = vec![quick_fix(
+        "Implement missing methods",
+        create_changes_map(
+            uri,
+            range(40, 0, 40, 0),
+            "\n    #[storage(read)]\n    fn balance() -> u64 {\n    }\n\n    fn owner(index: u64, verbose: bool) -> b256 {\n    }\n",
+        ),
+    )];
+    assert_eq!(expected, actual);
+
+    // Coins passed to a non-payable method
+    let actual = request(
+        range(44, 4, 46, 7),
+        DiagnosticData {
+            non_payable_method: Some("deposit".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    let expected = vec![quick_fix(
+        "Add attribute `#[payable]`",
+        HashMap::from([(
+            uri.clone(),
+            vec![
+                TextEdit {
+                    range: range(3, 0, 3, 0),
+                    new_text: "    #[payable]\n".to_string(),
+                },
+                TextEdit {
+                    range: range(35, 0, 35, 0),
+                    new_text: "    #[payable]\n".to_string(),
+                },
+            ],
+        )]),
+    )];
+    assert_eq!(expected, actual);
+
+    // Assignment to an immutable variable
+    let actual = request(
+        range(51, 4, 51, 21),
+        DiagnosticData {
+            immutable_variable_name: Some("count".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    let expected = vec![quick_fix(
+        "Add `mut` to `count`",
+        create_changes_map(uri, range(50, 8, 50, 8), "mut "),
+    )];
+    assert_eq!(expected, actual);
+
+    // Unused variable
+    let actual = request(
+        range(56, 8, 56, 14),
+        DiagnosticData {
+            unused_declaration_name: Some("unused".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    let expected = vec![
+        quick_fix(
+            "Remove unused variable `unused`",
+            create_changes_map(uri, range(56, 0, 57, 0), ""),
+        ),
+        quick_fix(
+            "Rename to `_unused`",
+            create_changes_map(uri, range(56, 8, 56, 8), "_"),
+        ),
+    ];
+    assert_eq!(expected, actual);
+}
+
+pub(crate) async fn code_action_unused_imports_request(server: &ServerState, uri: &Url) {
+    let range = |start_line, start_char, end_line, end_char| Range {
+        start: Position::new(start_line, start_char),
+        end: Position::new(end_line, end_char),
+    };
+    let request = |range, name: &str| {
+        let data = DiagnosticData {
+            unused_import_name: Some(name.to_string()),
+            ..Default::default()
+        };
+        let params =
+            create_code_action_params(uri.clone(), range, create_diagnostic_from_data(range, data));
+        async move {
+            send_request(server, &params)
+                .await
+                .into_iter()
+                .filter(|action| {
+                    matches!(action, CodeActionOrCommand::CodeAction(action)
+                        if action.kind == Some(CodeActionKind::QUICKFIX))
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+    let quick_fix = |name: &str, range| {
+        vec![create_code_action(
+            uri.clone(),
+            format!("Remove unused import `{name}`"),
+            create_changes_map(uri, range, ""),
+            None,
+            Some(CodeActionKind::QUICKFIX),
+        )]
+    };
+
+    // Within a group, followed by another item
+    let actual = request(range(5, 19, 5, 28), "perimeter").await;
+    assert_eq!(quick_fix("perimeter", range(5, 19, 5, 30)), actual);
+
+    // The only item of the statement
+    let actual = request(range(6, 12, 6, 18), "Circle").await;
+    assert_eq!(quick_fix("Circle", range(6, 0, 7, 0)), actual);
+
+    // Imported with an alias
+    let actual = request(range(7, 22, 7, 30), "Position").await;
+    assert_eq!(quick_fix("Position", range(7, 13, 7, 32)), actual);
+
+    // The last item of a group spanning multiple lines, with a trailing comma
+    let actual = request(range(10, 14, 10, 19), "Round").await;
+    assert_eq!(quick_fix("Round", range(9, 10, 10, 20)), actual);
+}
+
+pub(crate) async fn code_action_refactor_request(server: &ServerState, uri: &Url) {
+    let range = |start_line, start_char, end_line, end_char| Range {
+        start: Position::new(start_line, start_char),
+        end: Position::new(end_line, end_char),
+    };
+    let edit = |range, new_text: &str| TextEdit {
+        range,
+        new_text: new_text.to_string(),
+    };
+    let refactor = |title: &str, kind: CodeActionKind, edits| {
+        create_code_action(
+            uri.clone(),
+            title.to_string(),
+            HashMap::from([(uri.clone(), edits)]),
+            None,
+            Some(kind),
+        )
+    };
+    let request = |range| {
+        let params = create_code_action_params(uri.clone(), range, None);
+        async move { send_request(server, &params).await }
+    };
+
+    // Extract an expression
+    let actual = request(range(17, 15, 17, 32)).await;
+    let expected = vec![
+        refactor(
+            "Extract into variable",
+            CodeActionKind::REFACTOR_EXTRACT,
+            vec![
+                edit(range(17, 4, 17, 4), "let value = point.x + point.y;\n    "),
+                edit(range(17, 15, 17, 32), "value"),
+            ],
+        ),
+        refactor(
+            "Extract into function",
+            CodeActionKind::REFACTOR_EXTRACT,
+            vec![
+                edit(range(17, 15, 17, 32), "extracted(point)"),
+                edit(
+                    range(20, 1, 20, 1),
+                    "\n\nfn extracted(point: Point) -> u64 {\n    point.x + point.y\n}",
+                ),
+            ],
+        ),
+    ];
+    assert_eq!(expected, actual);
+
+    // Extract statements
+    let actual = request(range(39, 4, 40, 26)).await;
+    let expected = vec![refactor(
+        "Extract into function",
+        CodeActionKind::REFACTOR_EXTRACT,
+        vec![
+            edit(range(39, 4, 40, 26), "extracted(small);"),
+            edit(
+                range(41, 1, 41, 1),
+                "\n\nfn extracted(small: u64) {\n    let large = area(3, 4);\n    let _ = small + large;\n}",
+            ),
+        ],
+    )];
+    assert_eq!(expected, actual);
+
+    // Statements declaring variables used after them can't be extracted
+    let actual = request(range(38, 4, 39, 27)).await;
+    assert!(actual.is_empty());
+
+    // Inline a variable
+    let actual = request(range(19, 5, 19, 5)).await;
+    let expected = vec![refactor(
+        "Inline variable `total`",
+        CodeActionKind::REFACTOR_INLINE,
+        vec![
+            edit(range(19, 4, 19, 9), "(area(base, factor) * 2)"),
+            edit(range(18, 0, 19, 0), ""),
+        ],
+    )];
+    assert_eq!(expected, actual);
+
+    // Change the signature of a function
+    let actual = request(range(12, 20, 12, 20)).await;
+    let expected = vec![
+        refactor(
+            "Move parameter `height` left",
+            CodeActionKind::REFACTOR_REWRITE,
+            vec![
+                edit(range(12, 8, 12, 18), "height: u64"),
+                edit(range(12, 20, 12, 31), "width: u64"),
+                edit(range(18, 21, 18, 25), "factor"),
+                edit(range(18, 27, 18, 33), "base"),
+                edit(range(38, 21, 38, 22), "2"),
+                edit(range(38, 24, 38, 25), "1"),
+                edit(range(39, 21, 39, 22), "4"),
+                edit(range(39, 24, 39, 25), "3"),
+            ],
+        ),
+        refactor(
+            "Add parameter `new_param: ()`",
+            CodeActionKind::REFACTOR_REWRITE,
+            vec![
+                edit(range(12, 31, 12, 31), ", new_param: ()"),
+                edit(range(18, 33, 18, 33), ", ()"),
+                edit(range(38, 25, 38, 25), ", ()"),
+                edit(range(39, 25, 39, 25), ", ()"),
+            ],
+        ),
+    ];
+    assert_eq!(expected, actual);
+
+    // Convert `if let` to `match`
+    let actual = request(range(23, 4, 23, 4)).await;
+    let expected = vec![refactor(
+        "Convert to `match`",
+        CodeActionKind::REFACTOR_REWRITE,
+        vec![edit(
+            range(23, 4, 27, 5),
+            "match shape {\n        Shape::Circle(radius) => {\n            radius * 3\n        },\n        _ => {\n            0\n        },\n    }",
+        )],
+    )];
+    assert_eq!(expected, actual);
+
+    // Convert `match` to `if let`
+    let actual = request(range(31, 5, 31, 5)).await;
+    let expected = vec![refactor(
+        "Convert to `if let`",
+        CodeActionKind::REFACTOR_REWRITE,
+        vec![edit(
+            range(31, 4, 34, 5),
+            "if let Shape::Square(side) = shape {\n        side\n    } else {\n        0\n    }",
+        )],
+    )];
+    assert_eq!(expected, actual);
+}
@@ -553,3 +553,295 @@ pub(crate) async fn rename_request<'a>(
     let worspace_edit = request::handle_rename(server, params).await.unwrap();
     worspace_edit.unwrap()
 }
+
+pub(crate) async fn references_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    character: u32,
+    include_declaration: bool,
+) -> Vec<Location> {
+    let params = ReferenceParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        context: ReferenceContext {
+            include_declaration,
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_references(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+pub(crate) async fn workspace_symbol_request(
+    server: &ServerState,
+    query: &str,
+) -> Vec<SymbolInformation> {
+    let params = WorkspaceSymbolParams {
+        query: query.to_string(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_workspace_symbol(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+pub(crate) async fn signature_help_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> Option<SignatureHelp> {
+    let params = SignatureHelpParams {
+        context: None,
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        work_done_progress_params: Default::default(),
+    };
+    request::handle_signature_help(server, params)
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn prepare_call_hierarchy_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> CallHierarchyItem {
+    let params = CallHierarchyPrepareParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        work_done_progress_params: Default::default(),
+    };
+    let items = request::handle_prepare_call_hierarchy(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(items.len(), 1);
+    items[0].clone()
+}
+
+pub(crate) async fn incoming_calls_request(
+    server: &ServerState,
+    item: &CallHierarchyItem,
+) -> Vec<CallHierarchyIncomingCall> {
+    let params = CallHierarchyIncomingCallsParams {
+        item: item.clone(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_incoming_calls(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+pub(crate) async fn outgoing_calls_request(
+    server: &ServerState,
+    item: &CallHierarchyItem,
+) -> Vec<CallHierarchyOutgoingCall> {
+    let params = CallHierarchyOutgoingCallsParams {
+        item: item.clone(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_outgoing_calls(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+pub(crate) async fn prepare_type_hierarchy_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> TypeHierarchyItem {
+    let params = TypeHierarchyPrepareParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        work_done_progress_params: Default::default(),
+    };
+    let items = request::handle_prepare_type_hierarchy(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(items.len(), 1);
+    items[0].clone()
+}
+
+pub(crate) async fn supertypes_request(
+    server: &ServerState,
+    item: &TypeHierarchyItem,
+) -> Vec<TypeHierarchyItem> {
+    let params = TypeHierarchySupertypesParams {
+        item: item.clone(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_supertypes(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+pub(crate) async fn subtypes_request(
+    server: &ServerState,
+    item: &TypeHierarchyItem,
+) -> Vec<TypeHierarchyItem> {
+    let params = TypeHierarchySubtypesParams {
+        item: item.clone(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_subtypes(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+pub(crate) async fn goto_implementation_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> Vec<Location> {
+    let params = GotoDefinitionParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    match request::handle_goto_implementation(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+    {
+        GotoDefinitionResponse::Array(locations) => locations,
+        response => panic!("unexpected response: {response:?}"),
+    }
+}
+
+pub(crate) async fn goto_type_definition_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> Option<GotoDefinitionResponse> {
+    let params = GotoDefinitionParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_goto_type_definition(server, params)
+        .await
+        .unwrap()
+}
+
+/// Returns the position and label of each inlay hint of the document.
+pub(crate) async fn inlay_hints_request(
+    server: &ServerState,
+    uri: &Url,
+) -> Vec<(Position, String)> {
+    let params = InlayHintParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        range: Range::new(Position::new(0, 0), Position::new(u32::MAX, 0)),
+        work_done_progress_params: Default::default(),
+    };
+    request::handle_inlay_hints(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+        .into_iter()
+        .map(|hint| match hint.label {
+            InlayHintLabel::String(label) => (hint.position, label),
+            label => panic!("unexpected label: {label:?}"),
+        })
+        .collect()
+}
+
+/// Returns the lines and kind of each folding range of the document.
+pub(crate) async fn folding_range_request(
+    server: &ServerState,
+    uri: &Url,
+) -> Vec<(u32, u32, Option<FoldingRangeKind>)> {
+    let params = FoldingRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    request::handle_folding_range(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+        .into_iter()
+        .map(|range| (range.start_line, range.end_line, range.kind))
+        .collect()
+}
+
+/// Returns the ranges the selection expands to from the position, innermost first.
+pub(crate) async fn selection_range_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> Vec<Range> {
+    let params = SelectionRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        positions: vec![Position { line, character }],
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let mut selection_ranges = request::handle_selection_range(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(selection_ranges.len(), 1);
+    let mut ranges = vec![];
+    let mut selection_range = Some(Box::new(selection_ranges.remove(0)));
+    while let Some(current) = selection_range {
+        ranges.push(current.range);
+        selection_range = current.parent;
+    }
+    ranges
+}
+
+pub(crate) async fn range_format_request(
+    server: &ServerState,
+    uri: &Url,
+    range: Range,
+) -> Vec<TextEdit> {
+    let params = DocumentRangeFormattingParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        range,
+        options: FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        },
+        work_done_progress_params: Default::default(),
+    };
+    request::handle_range_formatting(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+}
@@ -1610,6 +1610,501 @@ async fn hover_docs_for_boolean_keywords() {
     let _ = server.shutdown_server().await;
 }
 
+#[tokio::test]
+async fn references() {
+    let server = ServerState::default();
+    let uri = open(&server, test_fixtures_dir().join("references/src/main.sw")).await;
+    let shapes_uri = uri.join("shapes.sw").unwrap();
+    let location = |uri: &Url, line, start, end| {
+        Location::new(
+            uri.clone(),
+            Range::new(Position::new(line, start), Position::new(line, end)),
+        )
+    };
+
+    // Function declared in the same module
+    let expected = vec![
+        location(&uri, 6, 3, 9),
+        location(&uri, 13, 4, 10),
+        location(&uri, 13, 16, 22),
+    ];
+    let references = lsp::references_request(&server, &uri, 13, 5, true).await;
+    assert_eq!(references, expected);
+    let references = lsp::references_request(&server, &uri, 6, 5, false).await;
+    assert_eq!(references, expected[1..]);
+
+    // Function declared in another module, referred to by a path
+    let references = lsp::references_request(&server, &uri, 13, 32, true).await;
+    assert_eq!(
+        references,
+        vec![location(&uri, 13, 31, 40), location(&shapes_uri, 17, 7, 16)]
+    );
+
+    // Struct declared in another module, from its declaration
+    let references = lsp::references_request(&server, &shapes_uri, 2, 13, true).await;
+    assert!(references.contains(&location(&uri, 4, 19, 28)));
+    assert!(references.contains(&location(&uri, 11, 15, 24)));
+    assert!(references.contains(&location(&shapes_uri, 2, 11, 20)));
+    assert!(references.contains(&location(&shapes_uri, 13, 18, 27)));
+
+    // Function declared in a path dependency, which refers to it as well
+    let dep_uri =
+        Url::from_file_path(test_fixtures_dir().join("references_dep/src/lib.sw")).unwrap();
+    let references = lsp::references_request(&server, &uri, 17, 21, true).await;
+    assert_eq!(
+        references,
+        vec![
+            location(&uri, 17, 20, 25),
+            location(&dep_uri, 2, 7, 12),
+            location(&dep_uri, 7, 4, 9),
+            location(&dep_uri, 7, 10, 15),
+        ]
+    );
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn workspace_symbol() {
+    let server = ServerState::default();
+    let uri = open(&server, test_fixtures_dir().join("references/src/main.sw")).await;
+    let shapes_uri = uri.join("shapes.sw").unwrap();
+    let names = |symbols: &[SymbolInformation]| {
+        symbols
+            .iter()
+            .map(|symbol| symbol.name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let symbols = lsp::workspace_symbol_request(&server, "rect").await;
+    assert_eq!(names(&symbols), vec!["Rectangle"]);
+    assert_eq!(symbols[0].kind, SymbolKind::STRUCT);
+    assert_eq!(symbols[0].location.uri, shapes_uri);
+
+    // Prefix matches are ranked before fuzzy matches.
+    let symbols = lsp::workspace_symbol_request(&server, "per").await;
+    assert_eq!(names(&symbols)[0], "perimeter");
+
+    let symbols = lsp::workspace_symbol_request(&server, "dbl").await;
+    assert_eq!(names(&symbols), vec!["double"]);
+    assert_eq!(symbols[0].location.uri, uri);
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn call_hierarchy() {
+    let server = ServerState::default();
+    let uri = open(&server, test_fixtures_dir().join("hierarchy/src/main.sw")).await;
+    let range = |line, start, end| Range::new(Position::new(line, start), Position::new(line, end));
+
+    // From the declaration of a function
+    let square = lsp::prepare_call_hierarchy_request(&server, &uri, 34, 4).await;
+    assert_eq!(square.name, "square");
+    assert_eq!(square.kind, SymbolKind::FUNCTION);
+    assert_eq!(square.selection_range, range(34, 3, 9));
+    let incoming = lsp::incoming_calls_request(&server, &square).await;
+    let callers = incoming
+        .iter()
+        .map(|call| (call.from.name.as_str(), call.from_ranges.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        callers,
+        vec![
+            ("area", vec![range(24, 8, 14)]),
+            ("total_area", vec![range(39, 26, 32)]),
+        ]
+    );
+    assert_eq!(incoming[0].from.kind, SymbolKind::METHOD);
+
+    // From a call to a function
+    let total_area = lsp::prepare_call_hierarchy_request(&server, &uri, 51, 10).await;
+    assert_eq!(total_area.selection_range, range(38, 3, 13));
+    let outgoing = lsp::outgoing_calls_request(&server, &total_area).await;
+    let callees = outgoing
+        .iter()
+        .map(|call| (call.to.name.as_str(), call.from_ranges.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        callees,
+        vec![
+            ("area", vec![range(39, 6, 10), range(39, 17, 21)]),
+            ("square", vec![range(39, 26, 32)]),
+        ]
+    );
+    assert_eq!(outgoing[0].to.selection_range, range(23, 7, 11));
+
+    // ABI methods implemented by the contract are entry points, which no function calls.
+    let increment = lsp::prepare_call_hierarchy_request(&server, &uri, 49, 8).await;
+    assert!(lsp::incoming_calls_request(&server, &increment)
+        .await
+        .is_empty());
+    let outgoing = lsp::outgoing_calls_request(&server, &increment).await;
+    assert_eq!(outgoing.len(), 1);
+    assert_eq!(outgoing[0].to.name, "total_area");
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn type_hierarchy() {
+    let server = ServerState::default();
+    let uri = open(&server, test_fixtures_dir().join("hierarchy/src/main.sw")).await;
+    let names = |items: &[TypeHierarchyItem]| {
+        items
+            .iter()
+            .map(|item| item.name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    // Traits and their supertraits
+    let polygon = lsp::prepare_type_hierarchy_request(&server, &uri, 14, 7).await;
+    assert_eq!(polygon.name, "Polygon");
+    assert_eq!(polygon.kind, SymbolKind::INTERFACE);
+    let supertypes = lsp::supertypes_request(&server, &polygon).await;
+    assert_eq!(names(&supertypes), vec!["Shape"]);
+    let subtypes = lsp::subtypes_request(&server, &supertypes[0]).await;
+    assert_eq!(names(&subtypes), vec!["Polygon", "Square"]);
+    assert_eq!(subtypes[1].kind, SymbolKind::STRUCT);
+
+    // The traits implemented by a struct, from a reference to it
+    let square = lsp::prepare_type_hierarchy_request(&server, &uri, 38, 17).await;
+    assert_eq!(square.name, "Square");
+    let supertypes = lsp::supertypes_request(&server, &square).await;
+    assert_eq!(names(&supertypes), vec!["Shape", "Polygon"]);
+
+    // ABIs, their superABIs and the contract implementing them
+    let owned = lsp::prepare_type_hierarchy_request(&server, &uri, 2, 5).await;
+    let subtypes = lsp::subtypes_request(&server, &owned).await;
+    assert_eq!(names(&subtypes), vec!["Counter", "Contract"]);
+    assert_eq!(
+        subtypes[1].detail.as_deref(),
+        Some("impl Owned for Contract")
+    );
+    let supertypes = lsp::supertypes_request(&server, &subtypes[0]).await;
+    assert_eq!(names(&supertypes), vec!["Owned"]);
+    let supertypes = lsp::supertypes_request(&server, &subtypes[1]).await;
+    assert_eq!(names(&supertypes), vec!["Owned"]);
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn goto_implementation() {
+    let server = ServerState::default();
+    let uri = open(&server, test_fixtures_dir().join("hierarchy/src/main.sw")).await;
+    let location = |line, start, end| {
+        Location::new(
+            uri.clone(),
+            Range::new(Position::new(line, start), Position::new(line, end)),
+        )
+    };
+
+    // Trait
+    let implementations = lsp::goto_implementation_request(&server, &uri, 10, 7).await;
+    assert_eq!(implementations, vec![location(22, 15, 21)]);
+
+    // Struct, from a reference to it
+    let implementations = lsp::goto_implementation_request(&server, &uri, 38, 17).await;
+    assert_eq!(
+        implementations,
+        vec![location(22, 15, 21), location(28, 17, 23)]
+    );
+
+    // Trait method
+    let implementations = lsp::goto_implementation_request(&server, &uri, 11, 8).await;
+    assert_eq!(implementations, vec![location(23, 7, 11)]);
+
+    // ABI and ABI method
+    let implementations = lsp::goto_implementation_request(&server, &uri, 2, 5).await;
+    assert_eq!(implementations, vec![location(42, 15, 23)]);
+    let implementations = lsp::goto_implementation_request(&server, &uri, 7, 8).await;
+    assert_eq!(implementations, vec![location(49, 7, 16)]);
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn goto_type_definition() {
+    let server = ServerState::default();
+    let uri = open(&server, test_fixtures_dir().join("hierarchy/src/main.sw")).await;
+    let square_decl = GotoDefinitionResponse::Scalar(Location::new(
+        uri.clone(),
+        Range::new(Position::new(18, 7), Position::new(18, 13)),
+    ));
+
+    // Variable declaration and use
+    let response = lsp::goto_type_definition_request(&server, &uri, 50, 13).await;
+    assert_eq!(response, Some(square_decl.clone()));
+    let response = lsp::goto_type_definition_request(&server, &uri, 51, 20).await;
+    assert_eq!(response, Some(square_decl.clone()));
+
+    // Function parameter
+    let response = lsp::goto_type_definition_request(&server, &uri, 38, 14).await;
+    assert_eq!(response, Some(square_decl));
+
+    // Builtin types have no declaration
+    let response = lsp::goto_type_definition_request(&server, &uri, 34, 10).await;
+    assert_eq!(response, None);
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn inlay_hints() {
+    let server = ServerState::default();
+    let uri = open(&server, test_fixtures_dir().join("inlay_hints/src/main.sw")).await;
+    let hint = |line, character, label: &str| (Position::new(line, character), label.to_string());
+
+    let hints = lsp::inlay_hints_request(&server, &uri).await;
+    assert_eq!(
+        hints,
+        vec![
+            hint(53, 17, ": u64"),
+            hint(54, 13, ": u64"),
+            hint(54, 28, "height:"),
+            hint(55, 19, ": Wrapper<u64>"),
+            hint(55, 29, "::<u64>"),
+            hint(55, 35, "value:"),
+            hint(56, 17, ": u64"),
+            hint(56, 28, "::<u64>"),
+            hint(56, 29, "value:"),
+            hint(57, 18, ": u64"),
+            hint(58, 17, "amount:"),
+            hint(58, 19, ": Counter"),
+            hint(59, 17, "amount:"),
+            hint(59, 19, ": Counter"),
+            hint(60, 20, "factor:"),
+        ]
+    );
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn inlay_hints_config() {
+    let server = ServerState::default();
+    let params = InitializeParams {
+        initialization_options: Some(serde_json::json!({
+            "inlayHints": {
+                "renderColons": false,
+                "typeHints": true,
+                "parameterHints": false,
+                "chainingHints": false,
+                "genericHints": false,
+                "purityHints": true,
+                "maxLength": 4,
+            }
+        })),
+        ..Default::default()
+    };
+    let _ = request::handle_initialize(&server, params);
+    let uri = open(&server, test_fixtures_dir().join("inlay_hints/src/main.sw")).await;
+    let hint = |line, character, label: &str| (Position::new(line, character), label.to_string());
+
+    let hints = lsp::inlay_hints_request(&server, &uri).await;
+    assert_eq!(
+        hints,
+        vec![
+            hint(53, 17, "u64"),
+            hint(54, 13, "u64"),
+            hint(55, 19, "Wrap…"),
+            hint(56, 17, "u64"),
+            hint(57, 18, "u64"),
+            hint(61, 31, "#[storage(read)]"),
+        ]
+    );
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn signature_help() {
+    let server = ServerState::default();
+    let uri = open(
+        &server,
+        test_fixtures_dir().join("signature_help/src/main.sw"),
+    )
+    .await;
+    let signature = |help: Option<SignatureHelp>| {
+        let help = help.expect("signature help");
+        assert_eq!(help.signatures.len(), 1);
+        help.signatures[0].clone()
+    };
+    let parameter_labels = |signature: &SignatureInformation| {
+        signature
+            .parameters
+            .iter()
+            .flatten()
+            .map(|param| match param.label {
+                ParameterLabel::LabelOffsets([start, end]) => {
+                    signature.label[start as usize..end as usize].to_string()
+                }
+                ParameterLabel::Simple(ref label) => label.clone(),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Method call, whose receiver isn't an argument
+    let scale = signature(lsp::signature_help_request(&server, &uri, 29, 29).await);
+    assert_eq!(scale.label, "fn scale(self, factor: u64) -> Point");
+    assert_eq!(parameter_labels(&scale), vec!["factor: u64"]);
+    assert_eq!(scale.active_parameter, Some(0));
+
+    // Generic function with documentation, nested in the arguments of another call
+    let pick = signature(lsp::signature_help_request(&server, &uri, 29, 40).await);
+    assert_eq!(pick.label, "fn pick<T>(a: T, b: T, fallback: bool) -> T");
+    assert_eq!(pick.active_parameter, Some(2));
+    match pick.documentation {
+        Some(Documentation::MarkupContent(content)) => {
+            assert!(content.value.contains("Returns `a` if `fallback` is false"))
+        }
+        documentation => panic!("unexpected documentation: {documentation:?}"),
+    }
+
+    // Contract call, in its parameters and its arguments
+    let increment = signature(lsp::signature_help_request(&server, &uri, 31, 52).await);
+    assert_eq!(
+        increment.label,
+        "fn increment { gas: u64, coins: u64, asset_id: b256 }(amount: u64, times: u64) -> u64"
+    );
+    assert_eq!(
+        parameter_labels(&increment),
+        vec![
+            "gas: u64",
+            "coins: u64",
+            "asset_id: b256",
+            "amount: u64",
+            "times: u64"
+        ]
+    );
+    assert_eq!(increment.active_parameter, Some(1));
+    let increment = signature(lsp::signature_help_request(&server, &uri, 31, 68).await);
+    assert_eq!(increment.active_parameter, Some(4));
+
+    // Outside of any call
+    assert!(lsp::signature_help_request(&server, &uri, 28, 20)
+        .await
+        .is_none());
+
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn folding_range() {
+    let server = ServerState::default();
+    let uri = open(
+        &server,
+        test_fixtures_dir().join("syntax_ranges/src/main.sw"),
+    )
+    .await;
+    let ranges = lsp::folding_range_request(&server, &uri).await;
+    assert_eq!(
+        ranges,
+        vec![
+            // `use` items
+            (2, 3, Some(FoldingRangeKind::Imports)),
+            // Doc comments, and the enum below them
+            (5, 6, Some(FoldingRangeKind::Comment)),
+            (7, 10, None),
+            (12, 14, None),
+            // `impl` block, its function and the match arm spanning multiple lines
+            (16, 26, None),
+            (17, 25, None),
+            (19, 22, None),
+            (28, 31, None),
+            (33, 36, None),
+        ]
+    );
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn selection_range() {
+    let server = ServerState::default();
+    let uri = open(
+        &server,
+        test_fixtures_dir().join("syntax_ranges/src/main.sw"),
+    )
+    .await;
+    let range = |start_line, start_char, end_line, end_char| {
+        Range::new(
+            Position::new(start_line, start_char),
+            Position::new(end_line, end_char),
+        )
+    };
+
+    // From an operand to its expression, statement, function body and function
+    let ranges = lsp::selection_range_request(&server, &uri, 29, 16).await;
+    assert_eq!(
+        ranges,
+        vec![
+            range(29, 16, 29, 17),
+            range(29, 16, 29, 19),
+            range(29, 4, 29, 20),
+            range(28, 26, 31, 0),
+            range(28, 25, 31, 1),
+            range(28, 0, 31, 1),
+        ]
+    );
+
+    // From the final expression of a match arm to the match, the method and the `impl` block
+    let ranges = lsp::selection_range_request(&server, &uri, 21, 20).await;
+    assert_eq!(
+        ranges,
+        vec![
+            range(21, 20, 21, 21),
+            range(21, 16, 21, 21),
+            range(19, 38, 22, 12),
+            range(19, 37, 22, 13),
+            range(19, 12, 22, 14),
+            range(18, 20, 24, 9),
+            range(18, 8, 24, 9),
+            range(17, 34, 25, 4),
+            range(17, 33, 25, 5),
+            range(17, 4, 25, 5),
+            range(16, 23, 26, 1),
+            range(16, 0, 26, 1),
+        ]
+    );
+    let _ = server.shutdown_server().await;
+}
+
+#[tokio::test]
+async fn range_format() {
+    let server = ServerState::default();
+    let uri = open(
+        &server,
+        test_fixtures_dir().join("syntax_ranges/src/main.sw"),
+    )
+    .await;
+
+    // Only the function the selection is in is formatted, not the next one.
+    let edits = lsp::range_format_request(
+        &server,
+        &uri,
+        Range::new(Position::new(29, 0), Position::new(29, 20)),
+    )
+    .await;
+    assert_eq!(
+        edits,
+        vec![TextEdit {
+            range: Range::new(Position::new(28, 0), Position::new(31, 1)),
+            new_text: "fn double(x: u64) -> u64 {\n    let y = x * 2;\n    y\n}".to_string(),
+        }]
+    );
+
+    // The `impl` block is already formatted.
+    let edits = lsp::range_format_request(
+        &server,
+        &uri,
+        Range::new(Position::new(18, 0), Position::new(24, 0)),
+    )
+    .await;
+    assert!(edits.is_empty());
+    let _ = server.shutdown_server().await;
+}
+
 #[tokio::test]
 async fn rename() {
     let server = ServerState::default();
@@ -1759,6 +2254,23 @@ async fn publish_diagnostics_multi_file() {
     shutdown_and_exit(&mut service).await;
 }
 
+#[tokio::test]
+async fn publish_diagnostics_unused_imports() {
+    let (mut service, socket) = LspService::new(ServerState::new);
+    let fixture = get_fixture(test_fixtures_dir().join("diagnostics/unused_imports/expected.json"));
+    let expected_requests = vec![fixture];
+    let socket_handle = assert_server_requests(socket, expected_requests).await;
+    let _ = init_and_open(
+        &mut service,
+        test_fixtures_dir().join("diagnostics/unused_imports/src/main.sw"),
+    )
+    .await;
+    socket_handle
+        .await
+        .unwrap_or_else(|e| panic!("Test failed: {e:?}"));
+    shutdown_and_exit(&mut service).await;
+}
+
 // This macro allows us to spin up a server / client for testing
 // It initializes and performs the necessary handshake and then loads
 // the sway example that was passed into `example_dir`.
@@ -1864,6 +2376,21 @@ lsp_capability_test!(
     code_actions::code_action_auto_import_alias_request,
     test_fixtures_dir().join("auto_import/src/main.sw")
 );
+lsp_capability_test!(
+    code_action_quick_fixes,
+    code_actions::code_action_quick_fixes_request,
+    test_fixtures_dir().join("quick_fixes/src/main.sw")
+);
+lsp_capability_test!(
+    code_action_unused_imports,
+    code_actions::code_action_unused_imports_request,
+    test_fixtures_dir().join("diagnostics/unused_imports/src/main.sw")
+);
+lsp_capability_test!(
+    code_action_refactor,
+    code_actions::code_action_refactor_request,
+    test_fixtures_dir().join("refactor/src/main.sw")
+);
 lsp_capability_test!(
     code_lens,
     lsp::code_lens_request,
@@ -4,16 +4,16 @@ use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant};
 use sway_ast::keywords::{
     AbiToken, AddEqToken, AsmToken, CommaToken, ConfigurableToken, ConstToken, DivEqToken,
-    DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken, LetToken,
-    OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken,
-    StorageToken, StructToken, SubEqToken, TraitToken, TrueToken, TypeToken, UseToken,
+    DoubleColonToken, DoublePipeToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken,
+    LetToken, OpenAngleBracketToken, PipeToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken,
+    StarEqToken, StorageToken, StructToken, SubEqToken, TraitToken, TrueToken, TypeToken, UseToken,
 };
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
 use sway_ast::{
-    AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-    ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, LitInt, Literal, MatchBranch,
-    MatchBranchKind, PathExpr, PathExprSegment, Statement, StatementLet,
+    AbiCastArgs, ClosureParam, ClosureParams, CodeBlockContents, Expr, ExprArrayDescriptor,
+    ExprStructField, ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, LitInt, Literal,
+    MatchBranch, MatchBranchKind, PathExpr, PathExprSegment, Statement, StatementLet,
 };
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::{ast::Delimiter, Ident, Span, Spanned};
@@ -532,6 +532,13 @@ fn parse_projection(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr>
             expr = Expr::Index { target, arg };
             continue;
         }
+        if let Some(question_mark_token) = parser.take() {
+            expr = Expr::Try {
+                expr: Box::new(expr),
+                question_mark_token,
+            };
+            continue;
+        }
         if let Some(dot_token) = parser.take() {
             let target = Box::new(expr);
 
@@ -702,6 +709,9 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if let Some(if_expr) = parser.guarded_parse::<IfToken, _>()? {
         return Ok(Expr::If(if_expr));
     }
+    if parser.peek::<PipeToken>().is_some() || parser.peek::<DoublePipeToken>().is_some() {
+        return parse_closure(parser, ctx);
+    }
     if let Some(match_token) = parser.take() {
         let condition = Box::new(parse_condition(parser)?);
         let branches = parser.parse()?;
@@ -760,6 +770,56 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     Err(parser.emit_error(ParseErrorKind::ExpectedExpression))
 }
 
+fn parse_closure(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
+    let params = match parser.take() {
+        Some(double_pipe_token) => ClosureParams::Empty(double_pipe_token),
+        None => {
+            let open_pipe_token = parser.parse()?;
+            let mut params = Punctuated::empty();
+            while parser.peek::<PipeToken>().is_none() {
+                let param = parser.parse()?;
+                match parser.take() {
+                    Some(comma_token) => params.value_separator_pairs.push((param, comma_token)),
+                    None => {
+                        params.final_value_opt = Some(Box::new(param));
+                        break;
+                    }
+                }
+            }
+            ClosureParams::Params {
+                open_pipe_token,
+                params,
+                close_pipe_token: parser.parse()?,
+            }
+        }
+    };
+    // As in Rust, the body of a closure with an explicit return type must be a block.
+    let (return_type_opt, body) = match parser.take() {
+        Some(right_arrow_token) => {
+            let ty = parser.parse()?;
+            let body = Expr::Block(parser.parse()?);
+            (Some((right_arrow_token, ty)), body)
+        }
+        None => (None, parse_reassignment(parser, ctx.not_statement())?),
+    };
+    Ok(Expr::Closure {
+        params,
+        return_type_opt,
+        body: Box::new(body),
+    })
+}
+
+impl Parse for ClosureParam {
+    fn parse(parser: &mut Parser) -> ParseResult<ClosureParam> {
+        let name = parser.parse()?;
+        let ty_opt = match parser.take() {
+            Some(colon_token) => Some((colon_token, parser.parse()?)),
+            None => None,
+        };
+        Ok(ClosureParam { name, ty_opt })
+    }
+}
+
 impl Parse for ExprStructField {
     fn parse(parser: &mut Parser) -> ParseResult<ExprStructField> {
         let field_name = parser.parse()?;
@@ -845,3 +905,51 @@ impl Parse for MatchBranchKind {
         Ok(MatchBranchKind::Expr { expr, comma_token })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn parse_try() {
+        // `?` binds tighter than unary operators, and can be chained with projections.
+        let expr = parse::<Expr>("!foo.bar()?.baz?");
+        assert_matches!(expr, Expr::Not { expr, .. } => {
+            assert_matches!(*expr, Expr::Try { expr, .. } => {
+                assert_matches!(*expr, Expr::FieldProjection { target, name, .. } => {
+                    assert_eq!(name.as_str(), "baz");
+                    assert_matches!(*target, Expr::Try { expr, .. } => {
+                        assert_matches!(*expr, Expr::MethodCall { .. });
+                    });
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn parse_closure() {
+        let expr = parse::<Expr>("|x: u64, y| x + y");
+        assert_matches!(expr, Expr::Closure { params: ClosureParams::Params { params, .. }, return_type_opt: None, body } => {
+            let params = params.into_iter().collect::<Vec<_>>();
+            assert_eq!(params.len(), 2);
+            assert_matches!(params[0].ty_opt, Some(_));
+            assert_matches!(params[1].ty_opt, None);
+            assert_matches!(*body, Expr::Add { .. });
+        });
+
+        let expr = parse::<Expr>("|| -> bool { true }");
+        assert_matches!(expr, Expr::Closure { params: ClosureParams::Empty(_), return_type_opt: Some(_), body } => {
+            assert_matches!(*body, Expr::Block(_));
+        });
+
+        // A closure argument extends as far as possible, as in Rust.
+        let expr = parse::<Expr>("foo.map(|x| x * 2, 3)");
+        assert_matches!(expr, Expr::MethodCall { args, .. } => {
+            let args = args.into_inner().into_iter().collect::<Vec<_>>();
+            assert_eq!(args.len(), 2);
+            assert_matches!(args[0], Expr::Closure { .. });
+        });
+    }
+}
@@ -151,7 +151,8 @@ token_impls! {
     DoublePipeToken,
     UnderscoreToken,
     HashToken,
-    HashBangToken
+    HashBangToken,
+    QuestionMarkToken
 }
 
 // Keep this in sync with the list in `sway-ast/keywords.rs` defined by define_keyword!
@@ -67,6 +67,18 @@ impl Parse for Ty {
                 ty,
             });
         }
+        if let Some(fn_token) = parser.take() {
+            let params = Parens::parse(parser)?;
+            let return_type_opt = match parser.take() {
+                Some(right_arrow_token) => Some((right_arrow_token, Box::new(parser.parse()?))),
+                None => None,
+            };
+            return Ok(Ty::Fn {
+                fn_token,
+                params,
+                return_type_opt,
+            });
+        }
         if parser.peek::<OpenAngleBracketToken>().is_some()
             || parser.peek::<DoubleColonToken>().is_some()
             || parser.peek::<Ident>().is_some()
@@ -135,4 +147,33 @@ mod tests {
         );
         assert_matches!(item, Ty::Ref { .. });
     }
+
+    #[test]
+    fn parse_fn() {
+        let item = parse::<Ty>(
+            r#"
+            fn(T, u64) -> Option<T>
+            "#,
+        );
+        assert_matches!(
+            item,
+            Ty::Fn {
+                return_type_opt: Some(_),
+                ..
+            }
+        );
+
+        let item = parse::<Ty>(
+            r#"
+            fn()
+            "#,
+        );
+        assert_matches!(
+            item,
+            Ty::Fn {
+                return_type_opt: None,
+                ..
+            }
+        );
+    }
 }
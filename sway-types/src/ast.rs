@@ -42,6 +42,7 @@ pub enum PunctKind {
     Pipe,
     Underscore,
     Sharp,
+    QuestionMark,
 }
 
 impl PunctKind {
@@ -65,6 +66,7 @@ impl PunctKind {
             PunctKind::Pipe => '|',
             PunctKind::Underscore => '_',
             PunctKind::Sharp => '#',
+            PunctKind::QuestionMark => '?',
         }
     }
 }
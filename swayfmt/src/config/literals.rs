@@ -8,6 +8,8 @@ pub struct Literals {
     pub format_strings: bool,
     /// Format hexadecimal integer literals.
     pub hex_literal_case: HexLiteralCase,
+    /// Insert or remove `_` digit separators in integer literals.
+    pub digit_separators: DigitSeparators,
 }
 
 impl Literals {
@@ -16,6 +18,7 @@ impl Literals {
         Self {
             format_strings: opts.format_strings.unwrap_or(default.format_strings),
             hex_literal_case: opts.hex_literal_case.unwrap_or(default.hex_literal_case),
+            digit_separators: opts.digit_separators.unwrap_or(default.digit_separators),
         }
     }
 }
@@ -31,3 +34,19 @@ pub enum HexLiteralCase {
     /// Ensure all literals use lowercase lettering
     Lower,
 }
+
+/// Controls how swayfmt should handle `_` digit separators in integer literals.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum DigitSeparators {
+    /// Leave the literal as-is
+    #[default]
+    Preserve,
+    /// Separate the digits of large literals into groups, replacing any existing separators
+    ///
+    /// Decimal and octal digits are grouped in threes, e.g., `1_000_000`, and binary and
+    /// hexadecimal digits in fours, e.g., `0xffff_ffff`. Hexadecimal literals of more than 16
+    /// digits, such as `b256` constants, are grouped in sixteens, i.e., one 64-bit word per group.
+    Group,
+    /// Remove all digit separators
+    Remove,
+}
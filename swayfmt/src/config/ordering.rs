@@ -4,8 +4,14 @@ use crate::config::user_opts::OrderingOptions;
 #[derive(Debug, Clone)]
 pub struct Ordering {
     /// Reorder import and extern crate statements alphabetically.
+    ///
+    /// Off by default until the Sway sources formatted by CI, e.g., `core` and `std`, have their
+    /// imports reordered.
     pub reorder_imports: bool,
     /// Reorder module statements alphabetically in group.
+    ///
+    /// Off by default, as the order of the modules of a library can affect the order they are
+    /// compiled in, e.g., for the operators of `core`.
     pub reorder_modules: bool,
     /// Reorder `impl` items.
     pub reorder_impl_items: bool,
@@ -14,8 +20,8 @@ pub struct Ordering {
 impl Default for Ordering {
     fn default() -> Self {
         Self {
-            reorder_imports: true,
-            reorder_modules: true,
+            reorder_imports: false,
+            reorder_modules: false,
             reorder_impl_items: false,
         }
     }
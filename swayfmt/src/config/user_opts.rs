@@ -4,7 +4,7 @@ use crate::config::{
     heuristics::HeuristicsPreferences,
     imports::{GroupImports, ImportGranularity},
     items::{ItemBraceStyle, ItemsLayout},
-    literals::HexLiteralCase,
+    literals::{DigitSeparators, HexLiteralCase},
     user_def::FieldAlignment,
     whitespace::{IndentStyle, NewlineStyle},
 };
@@ -46,6 +46,7 @@ pub struct ItemsOptions {
 pub struct LiteralsOptions {
     pub format_strings: Option<bool>,
     pub hex_literal_case: Option<HexLiteralCase>,
+    pub digit_separators: Option<DigitSeparators>,
 }
 /// See parent struct [Expressions].
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
@@ -1,8 +1,13 @@
 use self::shape::Shape;
 use crate::comments::{write_comments, CommentsContext};
+use crate::module::reorder::reorder_items;
 use crate::parse::parse_file;
 use crate::utils::map::comments::CommentMap;
-use crate::utils::map::{newline::handle_newlines, newline_style::apply_newline_style};
+use crate::utils::map::{
+    comment_style::{normalize_comments, wrap_comments},
+    newline::handle_newlines,
+    newline_style::apply_newline_style,
+};
 pub use crate::{
     config::manifest::Config,
     error::{ConfigError, FormatterError},
@@ -93,6 +98,10 @@ impl Formatter {
                 .to_width_heuristics(self.config.whitespace.max_width),
         );
         let src = src.trim();
+        // Regroup, merge and reorder the `use` and `mod` items before formatting them.
+        let src = &*reorder_items(&self.source_engine, src, &self.config)?;
+        // Convert `/* */` comments to `//` comments before formatting them, if configured.
+        let src = &*normalize_comments(src, &self.config.comments)?;
 
         let path = build_config.map(|build_config| build_config.canonical_root_module());
         // Formatted code will be pushed here with raw newline stlye.
@@ -125,6 +134,12 @@ impl Formatter {
             &mut formatted_code,
             self,
         )?;
+        // Wrap line and doc comments to the configured width
+        wrap_comments(
+            &self.config.comments,
+            self.config.whitespace.max_width,
+            &mut formatted_code,
+        )?;
         // Replace newlines with specified `NewlineStyle`
         apply_newline_style(
             self.config.whitespace.newline_style,
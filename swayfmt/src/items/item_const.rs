@@ -1,7 +1,10 @@
 use crate::{
     comments::rewrite_with_comments,
     formatter::*,
-    utils::map::byte_span::{ByteSpan, LeafSpans},
+    utils::{
+        language::literal::write_eq_before_expr,
+        map::byte_span::{ByteSpan, LeafSpans},
+    },
 };
 use std::fmt::Write;
 use sway_ast::{keywords::Token, ItemConst};
@@ -36,7 +39,15 @@ impl Format for ItemConst {
 
         // Check if ` = ` exists
         if let Some(eq_token) = &self.eq_token_opt {
-            write!(formatted_code, " {} ", eq_token.ident().as_str())?;
+            match &self.expr_opt {
+                Some(expr) => write_eq_before_expr(
+                    eq_token.ident().as_str(),
+                    expr,
+                    formatted_code,
+                    formatter,
+                )?,
+                None => write!(formatted_code, " {} ", eq_token.ident().as_str())?,
+            }
         }
 
         // Check if expression exists
@@ -0,0 +1,157 @@
+//! Merging and splitting `use` items to the configured [ImportGranularity].
+
+use crate::config::imports::ImportGranularity;
+use sway_ast::{ItemUse, UseTree};
+use sway_types::Spanned;
+
+/// The path of a single imported item, e.g., `["std", "hash", "sha256"]` for `std::hash::sha256`.
+///
+/// The last segment is the imported item itself, which may also be a rename, e.g., `a as b`, or a
+/// glob `*`. A module imported as `self` is imported by its own path.
+pub(crate) type UsePath = Vec<String>;
+
+/// Returns the paths of the items imported by the tree, in order.
+pub(crate) fn use_paths(tree: &UseTree) -> Vec<UsePath> {
+    let mut paths = vec![];
+    collect_use_paths(tree, &mut vec![], &mut paths);
+    paths
+}
+
+fn collect_use_paths(tree: &UseTree, prefix: &mut Vec<String>, paths: &mut Vec<UsePath>) {
+    let mut push = |last: String| {
+        let mut path = prefix.clone();
+        path.push(last);
+        paths.push(path);
+    };
+    match tree {
+        UseTree::Group { imports } => {
+            for tree in &imports.inner {
+                collect_use_paths(tree, prefix, paths);
+            }
+        }
+        UseTree::Name { name } if name.as_str() == "self" && !prefix.is_empty() => {
+            paths.push(prefix.clone())
+        }
+        UseTree::Name { name } => push(name.as_str().to_string()),
+        UseTree::Rename { name, alias, .. } => {
+            push(format!("{} as {}", name.as_str(), alias.as_str()))
+        }
+        UseTree::Glob { star_token } => push(star_token.span().as_str().to_string()),
+        UseTree::Path {
+            prefix: name,
+            suffix,
+            ..
+        } => {
+            prefix.push(name.as_str().to_string());
+            collect_use_paths(suffix, prefix, paths);
+            prefix.pop();
+        }
+        UseTree::Error { .. } => {}
+    }
+}
+
+/// Returns whether the `use` item can be merged with others or split, i.e., whether it doesn't
+/// contain comments, which would be lost by rewriting its tree.
+pub(crate) fn is_mergeable(item_use: &ItemUse) -> bool {
+    let src = item_use.span();
+    let src = src.as_str();
+    !src.contains("//") && !src.contains("/*") && !matches!(item_use.tree, UseTree::Error { .. })
+}
+
+/// Merges or splits the paths imported by `use` items of the same visibility and root to the
+/// granularity, returning the trees of the resulting `use` items in order of first appearance.
+///
+/// Each path is given along with the index of the item it is imported by, and each tree is
+/// returned along with the indices of the items its paths are imported by.
+pub(crate) fn regroup_use_paths(
+    paths: Vec<(UsePath, usize)>,
+    granularity: ImportGranularity,
+) -> Vec<(String, Vec<usize>)> {
+    let mut groups: Vec<(Vec<String>, UseTrie, Vec<usize>)> = vec![];
+    for (path, item) in paths {
+        let key = match granularity {
+            ImportGranularity::Preserve | ImportGranularity::Item => path.clone(),
+            ImportGranularity::Module => path[..path.len() - 1].to_vec(),
+            ImportGranularity::Crate => path[..1].to_vec(),
+            ImportGranularity::One => vec![],
+        };
+        let position = match groups.iter().position(|(group_key, ..)| *group_key == key) {
+            Some(position) => position,
+            None => {
+                groups.push((key, UseTrie::default(), vec![]));
+                groups.len() - 1
+            }
+        };
+        let (_, trie, items) = &mut groups[position];
+        trie.insert(&path);
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(_, trie, items)| {
+            let trees = trie.render();
+            let tree = match trees.as_slice() {
+                [tree] => tree.clone(),
+                _ => format!("{{{}}}", trees.join(", ")),
+            };
+            (tree, items)
+        })
+        .collect()
+}
+
+/// The paths of imported items, sharing their common prefixes.
+#[derive(Default)]
+struct UseTrie {
+    /// The items imported from the module of the node.
+    items: Vec<String>,
+    /// The modules within the module of the node that items are imported from.
+    modules: Vec<(String, UseTrie)>,
+}
+
+impl UseTrie {
+    fn insert(&mut self, path: &[String]) {
+        match path {
+            [] => {}
+            [item] => {
+                if !self.items.contains(item) {
+                    self.items.push(item.clone());
+                }
+            }
+            [module, rest @ ..] => {
+                let position = match self.modules.iter().position(|(name, _)| name == module) {
+                    Some(position) => position,
+                    None => {
+                        self.modules.push((module.clone(), UseTrie::default()));
+                        self.modules.len() - 1
+                    }
+                };
+                self.modules[position].1.insert(rest);
+            }
+        }
+    }
+
+    /// Returns the trees importing the items of the node, e.g., `a`, `b::c` and `d::{self, e}`.
+    fn render(&self) -> Vec<String> {
+        let is_module = |item: &String| self.modules.iter().any(|(name, _)| name == item);
+        let mut trees = self
+            .items
+            .iter()
+            .filter(|item| !is_module(item))
+            .cloned()
+            .collect::<Vec<_>>();
+        for (name, module) in &self.modules {
+            let mut subtrees = module.render();
+            // A module imported along with items within it is imported as `self`.
+            if self.items.contains(name) {
+                subtrees.insert(0, "self".to_string());
+            }
+            match subtrees.as_slice() {
+                [tree] => trees.push(format!("{name}::{tree}")),
+                _ => trees.push(format!("{name}::{{{}}}", subtrees.join(", "))),
+            }
+        }
+        trees
+    }
+}
@@ -15,6 +15,7 @@ use sway_types::{
     Spanned,
 };
 
+pub(crate) mod granularity;
 #[cfg(test)]
 mod tests;
 
@@ -91,17 +92,7 @@ impl Format for UseTree {
 
                         ord_vec.push(buf);
                     }
-                    ord_vec.sort_by(|a, b| {
-                        if a == b {
-                            std::cmp::Ordering::Equal
-                        } else if a == "self" || b == "*" {
-                            std::cmp::Ordering::Less
-                        } else if b == "self" || a == "*" {
-                            std::cmp::Ordering::Greater
-                        } else {
-                            a.to_lowercase().cmp(&b.to_lowercase())
-                        }
-                    });
+                    ord_vec.sort_by(|a, b| compare_use_trees(a, b));
                     for (use_tree, comma) in ord_vec.iter_mut().zip(commas.iter()) {
                         write!(use_tree, "{}", comma.span().as_str())?;
                     }
@@ -203,6 +194,19 @@ impl CurlyBrace for UseTree {
     }
 }
 
+/// Compares the trees of a group of a `use` item, ordering `self` first and globs last.
+pub(crate) fn compare_use_trees(a: &str, b: &str) -> std::cmp::Ordering {
+    if a == b {
+        std::cmp::Ordering::Equal
+    } else if a == "self" || b == "*" {
+        std::cmp::Ordering::Less
+    } else if b == "self" || a == "*" {
+        std::cmp::Ordering::Greater
+    } else {
+        a.to_lowercase().cmp(&b.to_lowercase())
+    }
+}
+
 fn format_use_stmt(
     item_use: &ItemUse,
     formatted_code: &mut FormattedCode,
@@ -9,4 +9,4 @@ mod item_struct;
 mod item_trait;
 mod item_trait_type;
 mod item_type_alias;
-mod item_use;
+pub(crate) mod item_use;
@@ -8,6 +8,7 @@ use sway_ast::{Item, ItemKind, Module, ModuleKind};
 use sway_types::Spanned;
 
 pub(crate) mod item;
+pub(crate) mod reorder;
 pub(crate) mod submodule;
 
 impl Format for Module {
@@ -0,0 +1,400 @@
+//! Regrouping, merging and reordering the `use` and `mod` items of a module, as configured by the
+//! [Imports](crate::config::imports::Imports) and [Ordering](crate::config::ordering::Ordering)
+//! options.
+//!
+//! The items are rewritten in the source before it is formatted, so that the rest of the formatter
+//! doesn't need to account for items changing places. Each item is moved along with the comments
+//! directly above it and the comment following it on the same line.
+
+use crate::{
+    config::{
+        imports::{GroupImports, ImportGranularity},
+        manifest::Config,
+    },
+    items::item_use::{
+        compare_use_trees,
+        granularity::{is_mergeable, regroup_use_paths, use_paths, UsePath},
+    },
+    parse::parse_file,
+    FormatterError,
+};
+use std::{borrow::Cow, cmp::Ordering, sync::Arc};
+use sway_ast::{Item, ItemKind};
+use sway_types::{SourceEngine, Span, Spanned};
+
+/// An item of a run of `use` or `mod` items, along with its comments.
+struct Entry {
+    /// The comments above the item, one per line.
+    comments: Vec<String>,
+    /// The source of the item.
+    code: String,
+    /// The comment following the item on its line.
+    trailing_comment: Option<String>,
+    /// Whether the item is separated from the previous item by a blank line, i.e., whether it
+    /// starts a new group of items.
+    starts_group: bool,
+    /// The visibility and root of the `use` item, e.g., `pub use ::`, and the paths it imports, if
+    /// it can be merged with others.
+    use_paths: Option<(String, Vec<UsePath>)>,
+}
+
+impl Entry {
+    fn to_code(&self) -> String {
+        let mut lines = self.comments.clone();
+        match &self.trailing_comment {
+            Some(comment) => lines.push(format!("{} {comment}", self.code)),
+            None => lines.push(self.code.clone()),
+        }
+        lines.join("\n")
+    }
+}
+
+/// Returns the source with its `use` and `mod` items regrouped, merged and reordered as configured,
+/// or the source itself if they are already.
+pub(crate) fn reorder_items<'a>(
+    source_engine: &SourceEngine,
+    src: &'a str,
+    config: &Config,
+) -> Result<Cow<'a, str>, FormatterError> {
+    let imports = config.imports;
+    let ordering = &config.ordering;
+    let reorders_imports = ordering.reorder_imports
+        || !matches!(imports.group_imports, GroupImports::Preserve)
+        || !matches!(imports.imports_granularity, ImportGranularity::Preserve);
+    if !reorders_imports && !ordering.reorder_modules {
+        return Ok(Cow::Borrowed(src));
+    }
+
+    let module = parse_file(source_engine, Arc::from(src), None)?.value;
+    let mut rewrites = vec![];
+    // The end of the previous item, or of the module kind, which comments above an item follow.
+    let mut prev_end = module.semicolon_token.span().end();
+    // Items with attributes, e.g., doc comments, stay in place.
+    let is_use =
+        |item: &Item| item.attribute_list.is_empty() && matches!(item.value, ItemKind::Use(_));
+    let is_mod = |item: &Item| {
+        item.attribute_list.is_empty() && matches!(item.value, ItemKind::Submodule(_))
+    };
+    let mut items = module.items.iter().peekable();
+    while let Some(item) = items.next() {
+        let is_use_run = is_use(item) && reorders_imports;
+        let is_mod_run = is_mod(item) && ordering.reorder_modules;
+        if !is_use_run && !is_mod_run {
+            prev_end = item.span().end();
+            continue;
+        }
+        let mut run = vec![item];
+        while let Some(next) =
+            items.next_if(|next| is_use_run && is_use(next) || is_mod_run && is_mod(next))
+        {
+            run.push(next);
+        }
+
+        let (start, end, entries) = run_entries(src, prev_end, &run);
+        let groups = if is_use_run {
+            reorder_use_entries(entries, config)
+        } else {
+            reorder_mod_entries(entries)
+        };
+        let code = groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(Entry::to_code)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        if code != src[start..end] {
+            rewrites.push((start, end, code));
+        }
+        prev_end = run.last().map_or(prev_end, |item| item.span().end());
+    }
+
+    if rewrites.is_empty() {
+        return Ok(Cow::Borrowed(src));
+    }
+    let mut reordered = src.to_string();
+    for (start, end, code) in rewrites.into_iter().rev() {
+        reordered.replace_range(start..end, &code);
+    }
+    Ok(Cow::Owned(reordered))
+}
+
+/// Returns the entries of the run of items following `prev_end`, and the start and end of their
+/// source, including their comments.
+fn run_entries(src: &str, prev_end: usize, run: &[&Item]) -> (usize, usize, Vec<Entry>) {
+    let mut entries: Vec<Entry> = vec![];
+    let mut start = prev_end;
+    let mut end = prev_end;
+    for item in run {
+        let span = item_span(item);
+        let gap = &src[end..span.start()];
+        let (comments, starts_group) = match entries.last_mut() {
+            // Only the comments directly above the first item are moved along with it, the others
+            // stay above the run.
+            None => {
+                let mut comments = vec![];
+                start = span.start();
+                let mut above = &gap[..gap.rfind('\n').map_or(0, |i| i + 1)];
+                while let Some(lines) = above.strip_suffix('\n') {
+                    let line_start = lines.rfind('\n').map_or(0, |i| i + 1);
+                    let line = &lines[line_start..];
+                    // The line of the previous item isn't above the item, and inner doc comments
+                    // document the module.
+                    if line_start == 0 || !is_comment(line) || line.trim().starts_with("//!") {
+                        break;
+                    }
+                    comments.insert(0, line.trim().to_string());
+                    start = end + line_start;
+                    above = &lines[..line_start];
+                }
+                (comments, false)
+            }
+            Some(prev) => {
+                // A comment on the line of the previous item follows it.
+                let (trailing, above) = gap.split_once('\n').unwrap_or((gap, ""));
+                let trailing = trailing.trim();
+                if !trailing.is_empty() {
+                    prev.trailing_comment = Some(trailing.to_string());
+                }
+                let lines = above.lines().map(str::trim).collect::<Vec<_>>();
+                let starts_group = lines.iter().any(|line| line.is_empty());
+                let comments = lines
+                    .into_iter()
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                (comments, starts_group)
+            }
+        };
+        let use_paths = match &item.value {
+            ItemKind::Use(item_use) if is_mergeable(item_use) => {
+                let mut prefix = String::new();
+                if item_use.visibility.is_some() {
+                    prefix.push_str("pub ");
+                }
+                prefix.push_str("use ");
+                if item_use.root_import.is_some() {
+                    prefix.push_str("::");
+                }
+                Some((prefix, use_paths(&item_use.tree)))
+            }
+            _ => None,
+        };
+        entries.push(Entry {
+            comments,
+            code: span.as_str().to_string(),
+            trailing_comment: None,
+            starts_group,
+            use_paths,
+        });
+        end = span.end();
+    }
+
+    // A line comment on the line of the last item follows it.
+    let rest = &src[end..];
+    let trailing = rest.split_once('\n').map_or(rest, |(trailing, _)| trailing);
+    if trailing.trim_start().starts_with("//") {
+        if let Some(last) = entries.last_mut() {
+            last.trailing_comment = Some(trailing.trim().to_string());
+        }
+        end += trailing.len();
+    }
+    (start, end, entries)
+}
+
+/// Returns the span of the item, including the visibility of a `mod` item, which isn't part of
+/// the span of the [Submodule](sway_ast::submodule::Submodule) itself.
+fn item_span(item: &Item) -> Span {
+    match &item.value {
+        ItemKind::Submodule(submodule) => match &submodule.visibility {
+            Some(pub_token) => Span::join(pub_token.span(), item.span()),
+            None => item.span(),
+        },
+        _ => item.span(),
+    }
+}
+
+fn is_comment(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("//") || line.starts_with("/*")
+}
+
+/// Splits the entries into the groups of items separated by blank lines.
+fn blank_line_groups(entries: Vec<Entry>) -> Vec<Vec<Entry>> {
+    let mut groups: Vec<Vec<Entry>> = vec![];
+    for entry in entries {
+        match groups.last_mut() {
+            Some(group) if !entry.starts_group => group.push(entry),
+            _ => groups.push(vec![entry]),
+        }
+    }
+    groups
+}
+
+/// The groups of `use` items of the [GroupImports::StdExternalCrate] option.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ImportGroup {
+    Std,
+    External,
+    Local,
+}
+
+impl ImportGroup {
+    fn of(entry: &Entry) -> Self {
+        let code = entry
+            .code
+            .trim_start_matches("pub ")
+            .trim_start_matches("use ")
+            .trim_start_matches('{');
+        if code.starts_with("::") {
+            return ImportGroup::Local;
+        }
+        match code.split("::").next().map(str::trim) {
+            Some("std" | "core") => ImportGroup::Std,
+            Some("self" | "super" | "crate") => ImportGroup::Local,
+            _ => ImportGroup::External,
+        }
+    }
+}
+
+fn reorder_use_entries(entries: Vec<Entry>, config: &Config) -> Vec<Vec<Entry>> {
+    let imports = config.imports;
+    let mut groups = match imports.group_imports {
+        GroupImports::Preserve => blank_line_groups(entries)
+            .into_iter()
+            .map(|group| merge_use_entries(group, imports.imports_granularity))
+            .collect(),
+        GroupImports::One => vec![merge_use_entries(entries, imports.imports_granularity)],
+        GroupImports::StdExternalCrate => {
+            let mut groups: Vec<(ImportGroup, Vec<Entry>)> = vec![];
+            for entry in merge_use_entries(entries, imports.imports_granularity) {
+                let import_group = ImportGroup::of(&entry);
+                match groups.iter_mut().find(|(group, _)| *group == import_group) {
+                    Some((_, group)) => group.push(entry),
+                    None => groups.push((import_group, vec![entry])),
+                }
+            }
+            groups.sort_by_key(|(group, _)| *group);
+            groups.into_iter().map(|(_, group)| group).collect()
+        }
+    };
+    if config.ordering.reorder_imports {
+        for group in &mut groups {
+            group.sort_by(|a, b| compare_paths(use_tree_of(&a.code), use_tree_of(&b.code)));
+        }
+    }
+    groups
+}
+
+/// Merges or splits the mergeable `use` items of the entries to the granularity.
+fn merge_use_entries(entries: Vec<Entry>, granularity: ImportGranularity) -> Vec<Entry> {
+    if let ImportGranularity::Preserve = granularity {
+        return entries;
+    }
+    // Items are only merged with items of the same visibility and root.
+    enum Merged {
+        Kept(Entry),
+        Paths {
+            prefix: String,
+            paths: Vec<(UsePath, usize)>,
+        },
+    }
+    let mut merged: Vec<Merged> = vec![];
+    let mut comments = vec![];
+    for (i, entry) in entries.into_iter().enumerate() {
+        let Some((prefix, paths)) = entry.use_paths else {
+            comments.push(vec![]);
+            merged.push(Merged::Kept(entry));
+            continue;
+        };
+        let mut entry_comments = entry.comments;
+        entry_comments.extend(entry.trailing_comment);
+        comments.push(entry_comments);
+        let paths = paths.into_iter().map(|path| (path, i));
+        let same_prefix = merged.iter_mut().find_map(|merged| match merged {
+            Merged::Paths {
+                prefix: merged_prefix,
+                paths,
+            } if *merged_prefix == prefix => Some(paths),
+            _ => None,
+        });
+        match same_prefix {
+            Some(merged_paths) => merged_paths.extend(paths),
+            None => merged.push(Merged::Paths {
+                prefix,
+                paths: paths.collect(),
+            }),
+        }
+    }
+    // The merged entries, along with the items their paths are imported by.
+    let mut merged_entries: Vec<(Entry, Vec<usize>)> = vec![];
+    for merged in merged {
+        match merged {
+            Merged::Kept(entry) => merged_entries.push((entry, vec![])),
+            Merged::Paths { prefix, paths } => {
+                for (tree, items) in regroup_use_paths(paths, granularity) {
+                    let entry = Entry {
+                        comments: vec![],
+                        code: format!("{prefix}{tree};"),
+                        trailing_comment: None,
+                        starts_group: false,
+                        use_paths: None,
+                    };
+                    merged_entries.push((entry, items));
+                }
+            }
+        }
+    }
+    // The comments of an item are moved above the first of the items its paths are merged into.
+    for (i, comments) in comments.into_iter().enumerate() {
+        let first = merged_entries
+            .iter_mut()
+            .filter(|(_, items)| items.contains(&i))
+            .min_by(|(a, _), (b, _)| compare_paths(use_tree_of(&a.code), use_tree_of(&b.code)));
+        if let Some((entry, _)) = first {
+            entry.comments.extend(comments);
+        }
+    }
+    merged_entries.into_iter().map(|(entry, _)| entry).collect()
+}
+
+fn reorder_mod_entries(entries: Vec<Entry>) -> Vec<Vec<Entry>> {
+    let mut groups = blank_line_groups(entries);
+    for group in &mut groups {
+        group.sort_by(|a, b| {
+            let name = |code: &str| {
+                code.trim_start_matches("pub ")
+                    .trim_start_matches("mod ")
+                    .trim_start_matches("r#")
+                    .to_lowercase()
+            };
+            name(&a.code).cmp(&name(&b.code))
+        });
+    }
+    groups
+}
+
+/// Returns the tree of the source of a `use` item, including its root, e.g., `::a::b`.
+fn use_tree_of(code: &str) -> &str {
+    code.trim_start_matches("pub ")
+        .trim_start_matches("use ")
+        .trim_end_matches(';')
+}
+
+/// Compares paths segment by segment, as the trees within a group of a `use` item are compared,
+/// ordering paths from the root, e.g., `::a::b`, last.
+fn compare_paths(a: &str, b: &str) -> Ordering {
+    let is_from_root = |path: &str| path.starts_with("::");
+    if is_from_root(a) != is_from_root(b) {
+        return is_from_root(a).cmp(&is_from_root(b));
+    }
+    a.split("::")
+        .zip(b.split("::"))
+        .map(|(a, b)| compare_use_trees(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.split("::").count().cmp(&b.split("::").count()))
+}
@@ -21,6 +21,7 @@ use sway_types::{ast::Delimiter, Spanned};
 pub(crate) mod abi_cast;
 pub(crate) mod asm_block;
 pub(crate) mod assignable;
+pub(crate) mod closure;
 pub(crate) mod code_block;
 pub(crate) mod collections;
 pub(crate) mod conditional;
@@ -421,6 +422,13 @@ impl Format for Expr {
                     field_span.as_str(),
                 )?;
             }
+            Self::Try {
+                expr,
+                question_mark_token,
+            } => {
+                expr.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", question_mark_token.span().as_str())?;
+            }
             Self::Ref {
                 ampersand_token,
                 expr,
@@ -670,6 +678,19 @@ impl Format for Expr {
             Self::Continue { continue_token } => {
                 write!(formatted_code, "{}", continue_token.span().as_str())?;
             }
+            Self::Closure {
+                params,
+                return_type_opt,
+                body,
+            } => {
+                params.format(formatted_code, formatter)?;
+                if let Some((right_arrow_token, ty)) = return_type_opt {
+                    write!(formatted_code, " {} ", right_arrow_token.span().as_str())?;
+                    ty.format(formatted_code, formatter)?;
+                }
+                write!(formatted_code, " ")?;
+                body.format(formatted_code, formatter)?;
+            }
         }
 
         Ok(())
@@ -1103,6 +1124,14 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.push(ByteSpan::from(field_span.clone()));
             collected_spans
         }
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => {
+            let mut collected_spans = expr.leaf_spans();
+            collected_spans.push(ByteSpan::from(question_mark_token.span()));
+            collected_spans
+        }
         Expr::Ref {
             ampersand_token,
             expr,
@@ -1327,5 +1356,18 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
         Expr::Continue { continue_token } => {
             vec![ByteSpan::from(continue_token.span())]
         }
+        Expr::Closure {
+            params,
+            return_type_opt,
+            body,
+        } => {
+            let mut collected_spans = params.leaf_spans();
+            if let Some((right_arrow_token, ty)) = return_type_opt {
+                collected_spans.push(ByteSpan::from(right_arrow_token.span()));
+                collected_spans.append(&mut ty.leaf_spans());
+            }
+            collected_spans.append(&mut body.leaf_spans());
+            collected_spans
+        }
     }
 }
@@ -1,22 +1,22 @@
 use crate::{
+    config::literals::{DigitSeparators, HexLiteralCase},
     formatter::*,
     utils::map::byte_span::{ByteSpan, LeafSpans},
 };
 use std::fmt::Write;
-use sway_ast::Literal;
+use sway_ast::{literal::LitInt, Expr, Literal};
 
 impl Format for Literal {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         match self {
-            // TODO: do more digging into `Literal` and see if there is more formatting to do.
             Self::String(lit_string) => write!(formatted_code, "{}", lit_string.span.as_str())?,
             Self::Char(lit_char) => write!(formatted_code, "{}", lit_char.span.as_str())?,
             Self::Int(lit_int) => {
-                write!(formatted_code, "{}", lit_int.span.as_str())?;
+                write!(formatted_code, "{}", format_int(lit_int, formatter))?;
                 if let Some((_, ty_span)) = &lit_int.ty_opt {
                     write!(formatted_code, "{}", ty_span.as_str())?;
                 }
@@ -27,6 +27,90 @@ impl Format for Literal {
     }
 }
 
+/// Returns the integer literal, without its type suffix, with the case of its hexadecimal digits
+/// and its digit separators formatted as configured.
+fn format_int(lit_int: &LitInt, formatter: &Formatter) -> String {
+    let literal = lit_int.span.as_str();
+    let literals = formatter.config.literals;
+    let (prefix, digits) = match literal.get(..2) {
+        Some(prefix @ ("0x" | "0o" | "0b")) => (prefix, &literal[2..]),
+        _ => ("", literal),
+    };
+    let digits = match (prefix, literals.hex_literal_case) {
+        ("0x", HexLiteralCase::Upper) => digits.to_ascii_uppercase(),
+        ("0x", HexLiteralCase::Lower) => digits.to_ascii_lowercase(),
+        _ => digits.to_string(),
+    };
+    // A separator between the digits and the type suffix, e.g., `1_u8`, is kept as it is.
+    let suffix_separator = if lit_int.ty_opt.is_some() && digits.ends_with('_') {
+        "_"
+    } else {
+        ""
+    };
+    let digits = match literals.digit_separators {
+        DigitSeparators::Preserve => return format!("{prefix}{digits}"),
+        DigitSeparators::Group => {
+            let digits = digits.replace('_', "");
+            let group_len = match prefix {
+                "0x" if digits.len() > 16 => 16,
+                "0x" | "0b" => 4,
+                _ => 3,
+            };
+            group_digits(&digits, group_len)
+        }
+        DigitSeparators::Remove => digits.replace('_', ""),
+    };
+    format!("{prefix}{digits}{suffix_separator}")
+}
+
+/// Separates the digits into groups of `group_len` digits from the right, if there are more digits
+/// than fit in a single group.
+fn group_digits(digits: &str, group_len: usize) -> String {
+    // Literals only slightly longer than a group, such as `1000`, are readable as they are.
+    if digits.len() <= group_len + 1 {
+        return digits.to_string();
+    }
+    let first_len = match digits.len() % group_len {
+        0 => group_len,
+        len => len,
+    };
+    let mut grouped = digits[..first_len].to_string();
+    for group in digits.as_bytes()[first_len..].chunks(group_len) {
+        grouped.push('_');
+        grouped.push_str(std::str::from_utf8(group).expect("digits are ASCII"));
+    }
+    grouped
+}
+
+/// Writes the `=` before the expression of a `let` statement or a constant, followed by a space, or
+/// by a newline and an indentation if the expression is a string literal which would exceed the max
+/// width of the line and `format_strings` is enabled.
+///
+/// String literals can't be split across lines without changing their value, so they are moved to
+/// a line of their own instead.
+pub(crate) fn write_eq_before_expr(
+    eq: &str,
+    expr: &Expr,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError> {
+    if let Expr::Literal(Literal::String(lit_string)) = expr {
+        let line = formatted_code.rsplit('\n').next().unwrap_or_default();
+        // The width of the line with ` = `, the literal and `;`.
+        let width = line.chars().count() + eq.len() + lit_string.span.as_str().chars().count() + 3;
+        if formatter.config.literals.format_strings && width > formatter.config.whitespace.max_width
+        {
+            writeln!(formatted_code, " {eq}")?;
+            formatter.indent();
+            write!(formatted_code, "{}", formatter.indent_to_str()?)?;
+            formatter.unindent();
+            return Ok(());
+        }
+    }
+    write!(formatted_code, " {eq} ")?;
+    Ok(())
+}
+
 impl LeafSpans for Literal {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         match self {
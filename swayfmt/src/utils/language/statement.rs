@@ -1,5 +1,6 @@
 use crate::{
     formatter::{shape::LineStyle, *},
+    utils::language::literal::write_eq_before_expr,
     utils::map::byte_span::{ByteSpan, LeafSpans},
 };
 use std::fmt::Write;
@@ -65,6 +66,16 @@ fn remove_arguments_from_expr(expr: Expr) -> Expr {
                 name,
             }
         }
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => {
+            let expr = remove_arguments_from_expr(*expr);
+            Expr::Try {
+                expr: Box::new(expr),
+                question_mark_token,
+            }
+        }
         _ => expr,
     }
 }
@@ -88,6 +99,7 @@ fn format_statement(
                 let update_expr_new_line = if !matches!(
                     expr,
                     Expr::MethodCall { .. }
+                        | Expr::Try { .. }
                         | Expr::FuncApp { func: _, args: _ }
                         | Expr::If(_)
                         | Expr::While {
@@ -149,7 +161,12 @@ impl Format for StatementLet {
             ty.1.format(formatted_code, formatter)?;
         }
         // ` = `
-        write!(formatted_code, " {} ", self.eq_token.span().as_str())?;
+        write_eq_before_expr(
+            self.eq_token.span().as_str(),
+            &self.expr,
+            formatted_code,
+            formatter,
+        )?;
         // expr
         self.expr.format(formatted_code, formatter)?;
         if formatter.shape.code_line.line_style == LineStyle::Inline {
@@ -6,7 +6,11 @@ use std::fmt::Write;
 use sway_ast::{
     brackets::SquareBrackets,
     expr::Expr,
-    keywords::{AmpersandToken, PtrToken, SliceToken, StrToken, Token, UnderscoreToken},
+    keywords::{
+        AmpersandToken, CommaToken, FnToken, PtrToken, RightArrowToken, SliceToken, StrToken,
+        Token, UnderscoreToken,
+    },
+    punctuated::Punctuated,
     ty::{Ty, TyArrayDescriptor, TyTupleDescriptor},
 };
 use sway_types::{ast::Delimiter, Spanned};
@@ -49,6 +53,17 @@ impl Format for Ty {
                 ampersand_token,
                 ty,
             } => format_ref(formatted_code, ampersand_token.clone(), ty.clone()),
+            Self::Fn {
+                fn_token,
+                params,
+                return_type_opt,
+            } => format_fn(
+                formatted_code,
+                formatter,
+                fn_token,
+                params.get(),
+                return_type_opt,
+            ),
         }
     }
 }
@@ -133,6 +148,33 @@ fn format_ref(
     Ok(())
 }
 
+fn format_fn(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    fn_token: &FnToken,
+    params: &Punctuated<Ty, CommaToken>,
+    return_type_opt: &Option<(RightArrowToken, Box<Ty>)>,
+) -> Result<(), FormatterError> {
+    write!(
+        formatted_code,
+        "{}{}",
+        fn_token.span().as_str(),
+        Delimiter::Parenthesis.as_open_char()
+    )?;
+    for (index, param) in params.into_iter().enumerate() {
+        if index > 0 {
+            write!(formatted_code, ", ")?;
+        }
+        param.format(formatted_code, formatter)?;
+    }
+    write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
+    if let Some((right_arrow_token, ty)) = return_type_opt {
+        write!(formatted_code, " {} ", right_arrow_token.span().as_str())?;
+        ty.format(formatted_code, formatter)?;
+    }
+    Ok(())
+}
+
 impl Format for TyTupleDescriptor {
     fn format(
         &self,
@@ -198,6 +240,19 @@ impl LeafSpans for Ty {
                 collected_spans.append(&mut ty.leaf_spans());
                 collected_spans
             }
+            Ty::Fn {
+                fn_token,
+                params,
+                return_type_opt,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(fn_token.span())];
+                collected_spans.append(&mut params.leaf_spans());
+                if let Some((right_arrow_token, ty)) = return_type_opt {
+                    collected_spans.push(ByteSpan::from(right_arrow_token.span()));
+                    collected_spans.append(&mut ty.leaf_spans());
+                }
+                collected_spans
+            }
         }
     }
 }
@@ -0,0 +1,378 @@
+//! Functions and tests that apply the user defined [Comments] style, i.e., normalizing block
+//! comments to line comments and wrapping line and doc comments to the configured width.
+use crate::{config::comments::Comments, formatter::FormattedCode, parse::lex, FormatterError};
+use std::{borrow::Cow, sync::Arc};
+use sway_ast::token::{CommentedTokenTree, CommentedTree};
+use sway_types::{Span, Spanned};
+
+/// Returns the source with its `/* */` comments converted to `//` comments, or the source itself if
+/// there are none that can be converted.
+///
+/// The comments are converted in the source before it is formatted, so that a block comment on a
+/// line of its own is formatted just like a line comment. A block comment is only converted if
+/// nothing but whitespace follows it on its line, and a comment spanning multiple lines only if
+/// nothing but whitespace precedes it on its line either. Each of its lines becomes a line comment,
+/// without the leading `*` of the lines, if any.
+pub(crate) fn normalize_comments<'a>(
+    src: &'a str,
+    comments: &Comments,
+) -> Result<Cow<'a, str>, FormatterError> {
+    if !comments.normalize_comments || !src.contains("/*") {
+        return Ok(Cow::Borrowed(src));
+    }
+    let mut edits = vec![];
+    for span in comment_spans(src)? {
+        let comment = span.as_str();
+        let (start, end) = (span.start(), span.end());
+        // `/**` and `/*!` comments are left as they are, as well as nested block comments.
+        if !comment.starts_with("/*")
+            || comment.starts_with("/**")
+            || comment.starts_with("/*!")
+            || comment[2..].contains("/*")
+        {
+            continue;
+        }
+        let line_start = src[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = src[end..].find('\n').map_or(src.len(), |i| end + i);
+        if !src[end..line_end].trim().is_empty() {
+            continue;
+        }
+        let before = &src[line_start..start];
+        let inner = &comment[2..comment.len() - 2];
+        let lines = if inner.contains('\n') {
+            if !before.trim().is_empty() {
+                continue;
+            }
+            block_comment_lines(inner)
+        } else {
+            vec![inner.trim().to_string()]
+        };
+        let replacement = lines
+            .iter()
+            .map(|line| line_comment("//", line))
+            .collect::<Vec<_>>()
+            .join(&format!("\n{before}"));
+        edits.push((start..line_end, replacement));
+    }
+    if edits.is_empty() {
+        return Ok(Cow::Borrowed(src));
+    }
+    let mut normalized = src.to_string();
+    for (range, replacement) in edits.into_iter().rev() {
+        normalized.replace_range(range, &replacement);
+    }
+    Ok(Cow::Owned(normalized))
+}
+
+/// Returns the lines of the text of a block comment spanning multiple lines, without the leading
+/// `*` of the lines if all of them start with one, or else without their common indentation.
+fn block_comment_lines(inner: &str) -> Vec<String> {
+    let mut lines = inner.split('\n');
+    let first = lines.next().unwrap_or_default().trim().to_string();
+    let rest = lines.map(str::trim_end).collect::<Vec<_>>();
+    let is_starred = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| line.trim_start().starts_with('*'));
+    let indent = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or_default();
+    let mut texts = vec![first];
+    texts.extend(rest.iter().map(|line| {
+        if line.trim().is_empty() {
+            String::new()
+        } else if is_starred {
+            let line = &line.trim_start()[1..];
+            line.strip_prefix(' ').unwrap_or(line).to_string()
+        } else {
+            line.chars()
+                .skip(indent)
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        }
+    }));
+    // The lines of `/*` and `*/` are dropped if they are empty.
+    while texts.len() > 1 && texts.first().is_some_and(String::is_empty) {
+        texts.remove(0);
+    }
+    while texts.len() > 1 && texts.last().is_some_and(String::is_empty) {
+        texts.pop();
+    }
+    texts
+}
+
+/// Wraps the line and doc comments of the formatted code that are on lines of their own, so that
+/// the lines are at most `comment_width` long, or `max_width` if it is shorter.
+///
+/// Only the paragraphs of a comment that have a line exceeding the width are reflowed, to leave the
+/// line breaks of other paragraphs as they are. Lists are wrapped with a hanging indent, while
+/// Markdown code blocks, headings, block quotes and tables, as well as lines with columns aligned by
+/// whitespace, are never wrapped. Words that don't fit on a line are put on a line of their own.
+pub(crate) fn wrap_comments(
+    comments: &Comments,
+    max_width: usize,
+    formatted_code: &mut FormattedCode,
+) -> Result<(), FormatterError> {
+    if !comments.wrap_comments {
+        return Ok(());
+    }
+    let width = comments.comment_width.min(max_width);
+    let code = formatted_code.as_str();
+    let mut lines: Vec<CommentLine> = vec![];
+    let (mut line, mut counted) = (0, 0);
+    for span in comment_spans(code)? {
+        let comment = span.as_str().trim_end();
+        let (start, end) = (span.start(), span.start() + comment.len());
+        let line_start = code[..start].rfind('\n').map_or(0, |i| i + 1);
+        let indent = &code[line_start..start];
+        if !comment.starts_with("//") || !indent.trim().is_empty() {
+            continue;
+        }
+        let marker_len = comment.len() - comment.trim_start_matches('/').len();
+        let marker_len = marker_len + usize::from(comment[marker_len..].starts_with('!'));
+        let text = &comment[marker_len..];
+        line += code[counted..start].matches('\n').count();
+        counted = start;
+        lines.push(CommentLine {
+            line,
+            start: line_start,
+            end,
+            indent: indent.to_string(),
+            marker: comment[..marker_len].to_string(),
+            text: text.strip_prefix(' ').unwrap_or(text).to_string(),
+        });
+    }
+
+    let mut edits = vec![];
+    let mut lines = lines.into_iter().peekable();
+    while let Some(first) = lines.next() {
+        let mut group = vec![first];
+        while let Some(next) = lines.next_if(|next| {
+            let last = group.last().expect("groups are not empty");
+            next.line == last.line + 1 && next.indent == last.indent && next.marker == last.marker
+        }) {
+            group.push(next);
+        }
+        let (first, last) = (&group[0], &group[group.len() - 1]);
+        let prefix_width = first.indent.chars().count() + first.marker.chars().count() + 1;
+        let texts = group
+            .iter()
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>();
+        if let Some(wrapped) = reflow(&texts, width.saturating_sub(prefix_width)) {
+            let replacement = wrapped
+                .iter()
+                .map(|text| format!("{}{}", first.indent, line_comment(&first.marker, text)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            edits.push((first.start..last.end, replacement));
+        }
+    }
+    for (range, replacement) in edits.into_iter().rev() {
+        formatted_code.replace_range(range, &replacement);
+    }
+    Ok(())
+}
+
+/// A line comment on a line of its own.
+struct CommentLine {
+    /// The index of the line.
+    line: usize,
+    /// The start of the line.
+    start: usize,
+    /// The end of the comment, without trailing whitespace.
+    end: usize,
+    indent: String,
+    /// The slashes of the comment, along with the `!` of an inner doc comment.
+    marker: String,
+    /// The text of the comment, without the space following the marker.
+    text: String,
+}
+
+fn line_comment(marker: &str, text: &str) -> String {
+    if text.is_empty() {
+        marker.to_string()
+    } else {
+        format!("{marker} {text}")
+    }
+}
+
+/// Returns the spans of the comments and doc comments of the source, in order.
+fn comment_spans(src: &str) -> Result<Vec<Span>, FormatterError> {
+    fn collect(token_tree: &CommentedTokenTree, spans: &mut Vec<Span>) {
+        match token_tree {
+            CommentedTokenTree::Comment(comment) => spans.push(comment.span()),
+            CommentedTokenTree::Tree(CommentedTree::DocComment(doc_comment)) => {
+                spans.push(doc_comment.span())
+            }
+            CommentedTokenTree::Tree(CommentedTree::Group(group)) => {
+                for token_tree in group.token_stream.token_trees() {
+                    collect(token_tree, spans);
+                }
+            }
+            _ => {}
+        }
+    }
+    let mut spans = vec![];
+    for token_tree in lex(&Arc::from(src))?.token_trees() {
+        collect(token_tree, &mut spans);
+    }
+    Ok(spans)
+}
+
+/// Reflows the paragraphs of the lines of a comment that have a line longer than `width`, returning
+/// the reflowed lines, or `None` if there are no such paragraphs.
+fn reflow(texts: &[&str], width: usize) -> Option<Vec<String>> {
+    let mut reflowed = vec![];
+    let mut is_reflowed = false;
+    let mut paragraph: Vec<&str> = vec![];
+    let mut fence = None;
+    let mut flush = |paragraph: &mut Vec<&str>, reflowed: &mut Vec<String>| {
+        if paragraph.iter().any(|text| text.chars().count() > width) {
+            is_reflowed = true;
+            reflowed.extend(fill(paragraph, width));
+        } else {
+            reflowed.extend(paragraph.iter().map(|text| text.to_string()));
+        }
+        paragraph.clear();
+    };
+    for text in texts {
+        let trimmed = text.trim_start();
+        let indent = text.len() - trimmed.len();
+        if let Some(marker) = fence {
+            // Code blocks are kept as they are, up to and including their closing fence.
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            reflowed.push(text.to_string());
+            continue;
+        }
+        if let Some(marker) = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker))
+        {
+            flush(&mut paragraph, &mut reflowed);
+            fence = Some(marker);
+            reflowed.push(text.to_string());
+            continue;
+        }
+        // Lines with columns aligned by whitespace are kept as they are, like code.
+        let is_aligned = trimmed.contains('\t') || trimmed.contains("  ");
+        if trimmed.is_empty() || indent >= 4 || is_aligned || trimmed.starts_with(['#', '>', '|']) {
+            flush(&mut paragraph, &mut reflowed);
+            reflowed.push(text.to_string());
+            continue;
+        }
+        if list_marker_len(trimmed).is_some() {
+            flush(&mut paragraph, &mut reflowed);
+        }
+        paragraph.push(text);
+    }
+    flush(&mut paragraph, &mut reflowed);
+    is_reflowed.then_some(reflowed)
+}
+
+/// Fills the lines with the words of a paragraph, continuing the lines of a list item with a
+/// hanging indent.
+fn fill(paragraph: &[&str], width: usize) -> Vec<String> {
+    let first = paragraph[0];
+    let indent = first.len() - first.trim_start().len();
+    let prefix_len = indent + list_marker_len(first.trim_start()).unwrap_or_default();
+    let hanging_indent = " ".repeat(prefix_len);
+    let mut lines = vec![];
+    let mut line = first[..prefix_len].to_string();
+    let mut is_empty = true;
+    let words = first[prefix_len..].split_whitespace().chain(
+        paragraph[1..]
+            .iter()
+            .flat_map(|text| text.split_whitespace()),
+    );
+    for word in words {
+        if !is_empty && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(line);
+            line = hanging_indent.clone();
+            is_empty = true;
+        }
+        if !is_empty {
+            line.push(' ');
+        }
+        line.push_str(word);
+        is_empty = false;
+    }
+    lines.push(line);
+    lines
+}
+
+/// Returns the length of the list marker the text starts with, along with the space following it,
+/// e.g., `- ` or `1. `.
+fn list_marker_len(text: &str) -> Option<usize> {
+    let digits = text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let marker_len = match text[digits..].chars().next() {
+        Some('-' | '*' | '+') if digits == 0 => 1,
+        Some('.' | ')') if digits > 0 => digits + 1,
+        _ => return None,
+    };
+    text[marker_len..]
+        .starts_with(' ')
+        .then_some(marker_len + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reflowed(text: &str, width: usize) -> Option<String> {
+        reflow(&text.lines().collect::<Vec<_>>(), width).map(|lines| lines.join("\n"))
+    }
+
+    #[test]
+    fn reflows_only_paragraphs_with_long_lines() {
+        let text =
+            "Short lines\nare kept.\n\nA paragraph with a line that is too long is reflowed.";
+        assert_eq!(
+            reflowed(text, 20).as_deref(),
+            Some(
+                "Short lines\nare kept.\n\nA paragraph with a\nline that is too\nlong is reflowed."
+            )
+        );
+        assert_eq!(reflowed(text, 80), None);
+    }
+
+    #[test]
+    fn reflows_list_items_with_hanging_indent() {
+        let text = "- The first item of the list.\n- The second.\n10. A numbered item of the list.";
+        assert_eq!(
+            reflowed(text, 16).as_deref(),
+            Some("- The first item\n  of the list.\n- The second.\n10. A numbered\n    item of the\n    list.")
+        );
+    }
+
+    #[test]
+    fn keeps_code_blocks_and_long_words() {
+        let text = "```sway\nlet a_long_line_of_code = 1;\n```\nhttps://a.long/url/on/a/line";
+        assert_eq!(
+            reflowed(text, 16).as_deref(),
+            Some("```sway\nlet a_long_line_of_code = 1;\n```\nhttps://a.long/url/on/a/line")
+        );
+        let text = "bytes   type        description\n32      byte[32]    Contract ID to call.";
+        assert_eq!(reflowed(text, 16), None);
+    }
+
+    #[test]
+    fn normalizes_block_comments() -> Result<(), FormatterError> {
+        let comments = Comments {
+            normalize_comments: true,
+            ..Default::default()
+        };
+        let src = "/* Own line. */\nlet a = 1; /* Trailing. */\nlet b = /* Inlined. */ 2;\n    /*\n     * Multiple\n     *\n     * lines.\n     */\n";
+        assert_eq!(
+            normalize_comments(src, &comments)?,
+            "// Own line.\nlet a = 1; // Trailing.\nlet b = /* Inlined. */ 2;\n    // Multiple\n    //\n    // lines.\n"
+        );
+        Ok(())
+    }
+}
@@ -1,5 +1,12 @@
 use std::sync::Arc;
-use swayfmt::{config::user_def::FieldAlignment, Formatter};
+use swayfmt::{
+    config::{
+        imports::{GroupImports, ImportGranularity},
+        literals::{DigitSeparators, HexLiteralCase},
+        user_def::FieldAlignment,
+    },
+    Formatter,
+};
 use test_macros::assert_eq_pretty;
 
 /// Takes a configured formatter as input and formats a given input and checks the actual output against an
@@ -2765,6 +2772,234 @@ use ::option::Option::{self, bar, foo, z, *};
     );
 }
 
+#[test]
+fn reorder_imports() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_imports = true;
+    check_with_formatter(
+        r#"library;
+
+use std::vec::Vec;
+// Hashing.
+use std::hash::*;
+use ::utils::numbers::*;
+use core::ops::Eq; // Equality.
+
+use std::b512::B512;
+use std::auth::msg_sender;
+"#,
+        r#"library;
+
+use core::ops::Eq; // Equality.
+// Hashing.
+use std::hash::*;
+use std::vec::Vec;
+use ::utils::numbers::*;
+
+use std::auth::msg_sender;
+use std::b512::B512;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn reorder_modules() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_modules = true;
+    check_with_formatter(
+        r#"library;
+
+// The first module.
+mod foo;
+pub mod bar;
+mod baz;
+
+use std::vec::Vec;
+"#,
+        r#"library;
+
+pub mod bar;
+mod baz;
+// The first module.
+mod foo;
+
+use std::vec::Vec;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn preserve_import_order() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_imports = false;
+    check_with_formatter(
+        r#"library;
+
+mod foo;
+mod bar;
+
+use std::vec::Vec;
+use core::ops::Eq;
+"#,
+        r#"library;
+
+mod foo;
+mod bar;
+
+use std::vec::Vec;
+use core::ops::Eq;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn group_imports_std_external_crate() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.group_imports = GroupImports::StdExternalCrate;
+    formatter.config.ordering.reorder_imports = true;
+    check_with_formatter(
+        r#"library;
+
+use ::utils::numbers::*;
+use src20::SRC20;
+use std::vec::Vec;
+
+// The traits.
+use core::ops::Eq;
+use ownership::*;
+"#,
+        r#"library;
+
+// The traits.
+use core::ops::Eq;
+use std::vec::Vec;
+
+use ownership::*;
+use src20::SRC20;
+
+use ::utils::numbers::*;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn group_imports_one() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.group_imports = GroupImports::One;
+    formatter.config.ordering.reorder_imports = true;
+    check_with_formatter(
+        r#"library;
+
+use std::vec::Vec;
+
+use core::ops::Eq;
+"#,
+        r#"library;
+
+use core::ops::Eq;
+use std::vec::Vec;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn imports_granularity_crate() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.imports_granularity = ImportGranularity::Crate;
+    formatter.config.ordering.reorder_imports = true;
+    check_with_formatter(
+        r#"library;
+
+use std::hash::sha256;
+// Vectors.
+use std::vec::Vec;
+use std::hash::{keccak256, sha256};
+pub use std::bytes::Bytes;
+use core::ops::Eq; // Equality.
+use std::hash;
+"#,
+        r#"library;
+
+// Equality.
+use core::ops::Eq;
+pub use std::bytes::Bytes;
+// Vectors.
+use std::{hash::{self, keccak256, sha256}, vec::Vec};
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn imports_granularity_module() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.imports_granularity = ImportGranularity::Module;
+    formatter.config.ordering.reorder_imports = true;
+    check_with_formatter(
+        r#"library;
+
+use std::{hash::{keccak256, sha256}, vec::Vec, auth::*};
+use std::hash::Hash;
+"#,
+        r#"library;
+
+use std::auth::*;
+use std::hash::{Hash, keccak256, sha256};
+use std::vec::Vec;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn imports_granularity_item() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.imports_granularity = ImportGranularity::Item;
+    formatter.config.ordering.reorder_imports = true;
+    check_with_formatter(
+        r#"library;
+
+// Hashing.
+use std::hash::{keccak256 as keccak, sha256, self};
+use std::vec::Vec; // Vectors.
+"#,
+        r#"library;
+
+// Hashing.
+use std::hash;
+use std::hash::keccak256 as keccak;
+use std::hash::sha256;
+// Vectors.
+use std::vec::Vec;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn imports_granularity_one() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.imports_granularity = ImportGranularity::One;
+    check_with_formatter(
+        r#"library;
+
+use std::vec::Vec;
+use core::ops::Eq;
+use std::hash::*;
+"#,
+        r#"library;
+
+use {core::ops::Eq, std::{hash::*, vec::Vec}};
+"#,
+        &mut formatter,
+    );
+}
+
 #[test]
 fn whitespace_after_doccomment() {
     check(
@@ -2937,3 +3172,314 @@ where
 "#,
     );
 }
+
+#[test]
+fn wrap_comments() {
+    let mut formatter = Formatter::default();
+    formatter.config.comments.wrap_comments = true;
+    formatter.config.comments.comment_width = 40;
+    check_with_formatter(
+        r#"library;
+
+//! A module doc comment that is too long to fit on a single line.
+
+/// A doc comment that is too long to fit on a single line.
+///
+/// - A list item that is too long as well.
+/// - A short item.
+///
+/// ```sway
+/// let a_line_of_code_that_is_too_long_to_fit = 1;
+/// ```
+fn foo() {
+    // A comment within the function that is too long to fit.
+    // It is reflowed along with this line.
+    let x = 1; // A trailing comment is never wrapped, however long it is.
+    // Short comment lines
+    // are kept.
+}
+"#,
+        r#"library;
+
+//! A module doc comment that is too
+//! long to fit on a single line.
+
+/// A doc comment that is too long to
+/// fit on a single line.
+///
+/// - A list item that is too long as
+///   well.
+/// - A short item.
+///
+/// ```sway
+/// let a_line_of_code_that_is_too_long_to_fit = 1;
+/// ```
+fn foo() {
+    // A comment within the function
+    // that is too long to fit. It is
+    // reflowed along with this line.
+    let x = 1; // A trailing comment is never wrapped, however long it is.
+    // Short comment lines
+    // are kept.
+}
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn normalize_comments() {
+    let mut formatter = Formatter::default();
+    formatter.config.comments.normalize_comments = true;
+    check_with_formatter(
+        r#"library;
+
+/* A block comment on its own line. */
+fn foo() {
+    /* Inside a function. */
+    let x = 1; /* Trailing. */
+    /*
+     * A block comment
+     * spanning multiple lines.
+     */
+    let y = /* Inlined. */ 2;
+}
+"#,
+        r#"library;
+
+// A block comment on its own line.
+fn foo() {
+    // Inside a function.
+    let x = 1; // Trailing.
+    // A block comment
+    // spanning multiple lines.
+    let y = /* Inlined. */ 2;
+}
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn normalize_and_wrap_comments() {
+    let mut formatter = Formatter::default();
+    formatter.config.comments.normalize_comments = true;
+    formatter.config.comments.wrap_comments = true;
+    formatter.config.comments.comment_width = 30;
+    check_with_formatter(
+        r#"library;
+
+/* A block comment that is too long to fit on a single line. */
+fn foo() {}
+"#,
+        r#"library;
+
+// A block comment that is too
+// long to fit on a single
+// line.
+fn foo() {}
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn hex_literal_case() {
+    let mut formatter = Formatter::default();
+    formatter.config.literals.hex_literal_case = HexLiteralCase::Upper;
+    check_with_formatter(
+        r#"library;
+
+const A: u64 = 0xabcdef;
+const B: u256 = 0xdeadbeefu256;
+const C: u64 = 0b1010;
+"#,
+        r#"library;
+
+const A: u64 = 0xABCDEF;
+const B: u256 = 0xDEADBEEFu256;
+const C: u64 = 0b1010;
+"#,
+        &mut formatter,
+    );
+    formatter.config.literals.hex_literal_case = HexLiteralCase::Lower;
+    check_with_formatter(
+        r#"library;
+
+const A: u64 = 0xABCDEF;
+"#,
+        r#"library;
+
+const A: u64 = 0xabcdef;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn group_digit_separators() {
+    let mut formatter = Formatter::default();
+    formatter.config.literals.digit_separators = DigitSeparators::Group;
+    check_with_formatter(
+        r#"library;
+
+const ASSET_ID: b256 = 0x8a9b5e3e0f3b2a1c4d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4;
+const ONE: b256 = 0x0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0001;
+
+fn foo() {
+    let a = 1000;
+    let b = 1000000u64;
+    let c = 1_0000_0000;
+    let d = 0xffffffff;
+    let e = 0b10101010;
+    let f = 1000000_u64;
+}
+"#,
+        r#"library;
+
+const ASSET_ID: b256 = 0x8a9b5e3e0f3b2a1c_4d6e7f8091a2b3c4_d5e6f708192a3b4c_5d6e7f8091a2b3c4;
+const ONE: b256 = 0x0000000000000000_0000000000000000_0000000000000000_0000000000000001;
+
+fn foo() {
+    let a = 1000;
+    let b = 1_000_000u64;
+    let c = 100_000_000;
+    let d = 0xffff_ffff;
+    let e = 0b1010_1010;
+    let f = 1_000_000_u64;
+}
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn remove_digit_separators() {
+    let mut formatter = Formatter::default();
+    formatter.config.literals.digit_separators = DigitSeparators::Remove;
+    check_with_formatter(
+        r#"library;
+
+const A: u64 = 1_000_000;
+const B: u64 = 0xffff_ffff;
+"#,
+        r#"library;
+
+const A: u64 = 1000000;
+const B: u64 = 0xffffffff;
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn format_long_strings() {
+    let mut formatter = Formatter::default();
+    formatter.config.literals.format_strings = true;
+    check_with_formatter(
+        r#"library;
+
+const MESSAGE: str = "A constant string literal which is too long to fit on the line of the constant";
+const SHORT: str = "A short string";
+
+fn foo() {
+    let message = "A string literal which is much too long to fit on the whole line of its let statement";
+    let short = "A short string";
+}
+"#,
+        r#"library;
+
+const MESSAGE: str =
+    "A constant string literal which is too long to fit on the line of the constant";
+const SHORT: str = "A short string";
+
+fn foo() {
+    let message =
+        "A string literal which is much too long to fit on the whole line of its let statement";
+    let short = "A short string";
+}
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn try_operator() {
+    check(
+        r#"library;
+
+fn foo() -> Result<u64, Error> {
+    let a = bar() ? ;
+    let b = baz(a)?.qux ?.quux()?;
+    Ok(a+b?)
+}
+"#,
+        r#"library;
+
+fn foo() -> Result<u64, Error> {
+    let a = bar()?;
+    let b = baz(a)?.qux?.quux()?;
+    Ok(a + b?)
+}
+"#,
+    );
+}
+
+#[test]
+fn try_operator_chained_methods() {
+    check(
+        r#"library;
+
+fn test() -> Option<u64> {
+    fuel.really_long_field?.other_really_long_field.foo()?.bar().baz.quux()?.yet_another_call().to_go_above_max_line_length()?;
+    None
+}
+"#,
+        r#"library;
+
+fn test() -> Option<u64> {
+    fuel.really_long_field?
+        .other_really_long_field
+        .foo()?
+        .bar()
+        .baz
+        .quux()?
+        .yet_another_call()
+        .to_go_above_max_line_length()?;
+    None
+}
+"#,
+    );
+}
+
+#[test]
+fn closures() {
+    check(
+        r#"library;
+
+fn apply(value:u64,f:fn( u64 )->u64,g : fn(u64, bool)) -> u64 {
+    let a = map(value, | x | x+1);
+    let b = map(value,|x:u64|->u64{ x * 2 });
+    let c = map(value, ||  {
+        let y = a + b;
+        y
+    });
+    f(a)
+}
+"#,
+        r#"library;
+
+fn apply(value: u64, f: fn(u64) -> u64, g: fn(u64, bool)) -> u64 {
+    let a = map(value, |x| x + 1);
+    let b = map(value, |x: u64| -> u64 {
+        x * 2
+    });
+    let c = map(value, || {
+        let y = a + b;
+        y
+    });
+    f(a)
+}
+"#,
+    );
+}
@@ -0,0 +1,17 @@
+script;
+
+fn apply(value: u64, f: fn(u64) -> u64) -> u64 {
+    f(value)
+}
+
+fn main() {
+    let mut total = 0;
+    let add = |x: u64| {
+        total = total + x;
+        total
+    };
+
+    let _ = apply(1, |x, y| x);
+    let _ = apply(1, |x| x == 1);
+    let _ = add(1, 2);
+}
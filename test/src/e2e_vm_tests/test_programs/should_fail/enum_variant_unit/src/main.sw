@@ -16,8 +16,7 @@ impl S {
 
 fn main() -> u64 {
 
-    // check that calling function and methods with no parameters still requires parenthesis
-    let _b = func;
+    // check that calling methods with no parameters still requires parenthesis
     let _b = func();
 
 
@@ -25,7 +24,6 @@ fn main() -> u64 {
     let _s = S::new();
 
 
-    let _b = lib_a::inner_lib::func;
     let _b = lib_a::inner_lib::func();
 
 
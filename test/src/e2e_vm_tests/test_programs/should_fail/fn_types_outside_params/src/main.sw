@@ -0,0 +1,9 @@
+script;
+
+fn make_adder(offset: u64) -> fn(u64) -> u64 {
+    |x| x + offset
+}
+
+fn main() {
+    let _ = make_adder(1);
+}
@@ -0,0 +1,31 @@
+script;
+
+fn parse(value: u64) -> Result<u64, bool> {
+    if value == 0 {
+        return Err(false);
+    }
+    Ok(value)
+}
+
+fn mismatched_error() -> Result<u64, u8> {
+    Ok(parse(1)?)
+}
+
+fn not_result() -> u64 {
+    parse(1)?
+}
+
+fn option_in_result() -> Result<u64, bool> {
+    Ok(Some(1)?)
+}
+
+fn not_option_or_result() -> Option<u64> {
+    Some(1u64?)
+}
+
+fn main() {
+    let _ = mismatched_error();
+    let _ = not_result();
+    let _ = option_in_result();
+    let _ = not_option_or_result();
+}
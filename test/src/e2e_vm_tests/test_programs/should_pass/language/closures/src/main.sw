@@ -0,0 +1,122 @@
+script;
+
+mod utils;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn apply(value: u64, f: fn(u64) -> u64) -> u64 {
+    f(value)
+}
+
+fn apply_twice<T>(value: T, f: fn(T) -> T) -> T {
+    f(f(value))
+}
+
+fn compose<A, B, C>(value: A, f: fn(A) -> B, g: fn(B) -> C) -> C {
+    g(f(value))
+}
+
+fn count_matching(values: [u64; 5], check: fn(u64) -> bool) -> u64 {
+    let mut count = 0;
+    let mut i = 0;
+    while i < 5 {
+        if check(values[i]) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+fn double(value: u64) -> u64 {
+    value * 2
+}
+
+fn main() -> bool {
+    // Closures without captures, with inferred and annotated types.
+    assert(apply(1, |x| x + 1) == 2);
+    assert(apply(1, |x: u64| -> u64 { x * 10 }) == 10);
+
+    // Closures capturing variables by value.
+    let offset = 5;
+    let point = Point { x: 1, y: 2 };
+    assert(apply(1, |x| x + offset) == 6);
+    assert(apply(1, |x| x + point.x + point.y) == 4);
+
+    // Captured values are copied when the closure is created.
+    let mut counter = 1;
+    let add_counter = |x: u64| x + counter;
+    counter = 100;
+    assert(add_counter(1) == 2);
+    assert(counter == 100);
+
+    // Generic functions taking closures.
+    assert(apply_twice(3, |x| x * x) == 81);
+    assert(apply_twice(Point { x: 1, y: 1 }, |p| Point { x: p.x + p.y, y: p.y }).x == 3);
+    assert(compose(2, |x| x == 2, |b| if b { 7 } else { 0 }) == 7);
+
+    // Closures used in loops.
+    let values = [1, 2, 3, 4, 5];
+    let threshold = 2;
+    assert(count_matching(values, |v| v > threshold) == 3);
+    assert(count_matching(values, |v| v % 2 == 0) == 2);
+
+    // Functions as values.
+    assert(apply(4, double) == 8);
+    let f = double;
+    assert(f(5) == 10);
+    assert(apply(4, utils::triple) == 12);
+
+    // Nested closures.
+    let base = 10;
+    assert(apply(1, |x| apply(x, |y| y + base) + x) == 12);
+
+    // Closures passed to methods.
+    let some = Some(42);
+    let none: Option<u64> = None;
+    assert(some.map(|x| x + base).unwrap() == 52);
+    assert(none.map(|x| x + base).is_none());
+    assert(some.and_then(|x| if x > base { Some(x) } else { None }).unwrap() == 42);
+    assert(some.and_then(|x| if x > 50 { Some(x) } else { None }).is_none());
+
+    let mut vec: Vec<u64> = Vec::new();
+    vec.push(3);
+    vec.push(1);
+    vec.push(2);
+
+    let mapped = vec.map(|x| Point { x, y: x + base });
+    assert(mapped.len() == 3);
+    assert(mapped.get(0).unwrap().x == 3);
+    assert(mapped.get(2).unwrap().y == 12);
+
+    vec.sort_by_key(|x| x);
+    assert(vec.get(0).unwrap() == 1);
+    assert(vec.get(1).unwrap() == 2);
+    assert(vec.get(2).unwrap() == 3);
+
+    // Sorting is stable.
+    let mut points: Vec<Point> = Vec::new();
+    points.push(Point { x: 2, y: 0 });
+    points.push(Point { x: 1, y: 1 });
+    points.push(Point { x: 1, y: 2 });
+    points.sort_by_key(|p| p.x);
+    assert(points.get(0).unwrap().y == 1);
+    assert(points.get(1).unwrap().y == 2);
+    assert(points.get(2).unwrap().y == 0);
+
+    // Iterator helpers.
+    assert(vec.iter().fold(0, |acc, x| acc + x) == 6);
+    assert(vec.iter().any(|x| x == 2));
+    assert(!vec.iter().any(|x| x > base));
+    assert(vec.iter().all(|x| x > 0));
+    assert(!vec.iter().all(|x| x > 1));
+    assert(vec.iter().find(|x| x > 1).unwrap() == 2);
+    assert(vec.iter().find(|x| x > base).is_none());
+    assert(vec.iter().count_where(|x| x >= 2) == 2);
+    vec.iter().for_each(|x| assert(x > 0));
+
+    true
+}
@@ -0,0 +1,5 @@
+library;
+
+pub fn triple(value: u64) -> u64 {
+    value * 3
+}
@@ -0,0 +1,79 @@
+script;
+
+enum Error {
+    Odd: u64,
+    TooLarge: u64,
+}
+
+fn half(value: u64) -> Result<u64, Error> {
+    if value % 2 == 1 {
+        return Err(Error::Odd(value));
+    }
+    Ok(value / 2)
+}
+
+fn check_size(value: u64) -> Result<u64, Error> {
+    if value > 100 {
+        return Err(Error::TooLarge(value));
+    }
+    Ok(value)
+}
+
+fn quarter(value: u64) -> Result<u64, Error> {
+    let halved = half(check_size(value)?)?;
+    Ok(half(halved)?)
+}
+
+struct Pair {
+    first: Option<u64>,
+    second: Option<u64>,
+}
+
+fn sum(pair: Pair) -> Option<u64> {
+    Some(pair.first? + pair.second?)
+}
+
+fn first_of_vec(vec: Vec<Option<u64>>) -> Option<u64> {
+    vec.get(0)?
+}
+
+fn main() -> bool {
+    // `?` on `Result`.
+    assert(quarter(8).unwrap() == 2);
+    match quarter(6) {
+        Err(Error::Odd(value)) => assert(value == 3),
+        _ => revert(0),
+    }
+    match quarter(102) {
+        Err(Error::TooLarge(value)) => assert(value == 102),
+        _ => revert(0),
+    }
+
+    // `?` on `Option`.
+    let both = Pair {
+        first: Some(1),
+        second: Some(2),
+    };
+    let no_first = Pair {
+        first: None,
+        second: Some(2),
+    };
+    let no_second = Pair {
+        first: Some(1),
+        second: None,
+    };
+    assert(sum(both).unwrap() == 3);
+    assert(sum(no_first).is_none());
+    assert(sum(no_second).is_none());
+
+    // Chained `?`.
+    let mut vec = Vec::new();
+    assert(first_of_vec(vec).is_none());
+    vec.push(None);
+    assert(first_of_vec(vec).is_none());
+    let mut vec = Vec::new();
+    vec.push(Some(42));
+    assert(first_of_vec(vec).unwrap() == 42);
+
+    true
+}
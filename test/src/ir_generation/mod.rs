@@ -345,6 +345,10 @@ pub(super) async fn run(
                             for pass in passes {
                                 if pass == "o1" {
                                     group = sway_ir::create_o1_pass_group();
+                                } else if pass == "o2" {
+                                    group = sway_ir::create_o2_pass_group();
+                                } else if pass == "os" {
+                                    group = sway_ir::create_os_pass_group();
                                 } else {
                                     // pass needs a 'static str
                                     let pass = Box::leak(Box::new(pass));
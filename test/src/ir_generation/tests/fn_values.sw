@@ -0,0 +1,36 @@
+// This is to test that a function used as a value is compiled once, like a called one, while each
+// closure is compiled into a function of its own.
+
+script;
+
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+fn apply(f: fn(u64) -> u64, x: u64) -> u64 {
+    f(x)
+}
+
+fn main() -> u64 {
+    let a = apply(double, 1);
+    let b = apply(double, 2);
+    let c = apply(|x: u64| x + 1, 3);
+    let d = apply(|x: u64| x + 1, 4);
+    a + b + c + d + double(5)
+}
+
+// `double` exists once, also called directly, and each closure once.
+//
+// regex: DOUBLE_FN=double_\d+
+// regex: CLOSURE_FN=closure_\d+
+
+// check: fn main
+// check: call $DOUBLE_FN(
+
+// check: fn $DOUBLE_FN(
+// not: fn $DOUBLE_FN(
+// check: fn $CLOSURE_FN(
+// not: fn $DOUBLE_FN(
+// check: fn $CLOSURE_FN(
+// not: fn $DOUBLE_FN(
+// not: fn $CLOSURE_FN(